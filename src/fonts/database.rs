@@ -0,0 +1,324 @@
+//! The selection of a font face among the available ones.
+//!
+//! [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#font-style-matching)
+use crate::style::{FontFamily, FontStyle, FontWeight, Value};
+
+use super::{
+    Font, DEFAULT_FONT_BOLD, DEFAULT_FONT_BOLD_ITALIC, DEFAULT_FONT_ITALIC, DEFAULT_FONT_REGULAR,
+};
+
+/// A font face, described as by the `@font-face` descriptors.
+#[derive(Clone)]
+pub struct FontFace {
+    pub family: String,
+    pub weight: f32,
+    pub style: FontStyle,
+    /// The width, as a fraction of the normal one.
+    pub stretch: f32,
+    pub font: Font,
+}
+
+impl FontFace {
+    /// A face of normal weight, style and width.
+    pub fn new(family: &str, font: Font) -> Self {
+        Self {
+            family: family.to_string(),
+            weight: FontWeight::NORMAL,
+            style: FontStyle::Normal,
+            stretch: 1.0,
+            font,
+        }
+    }
+}
+
+/// The properties of the font to select.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontQuery {
+    pub weight: f32,
+    pub style: FontStyle,
+    /// The width, as a fraction of the normal one.
+    pub stretch: f32,
+}
+
+impl Default for FontQuery {
+    fn default() -> Self {
+        Self {
+            weight: FontWeight::NORMAL,
+            style: FontStyle::Normal,
+            stretch: 1.0,
+        }
+    }
+}
+
+/// The registered font faces, and the bundled Liberation Sans faces used
+/// when none of the families of a list is available.
+pub struct FontDatabase {
+    faces: Vec<FontFace>,
+    fallback: Vec<FontFace>,
+}
+
+impl Default for FontDatabase {
+    fn default() -> Self {
+        let face = |bytes, weight, style| FontFace {
+            weight,
+            style,
+            ..FontFace::new("Liberation Sans", Font::from_static(bytes))
+        };
+
+        Self {
+            faces: Vec::default(),
+            fallback: vec![
+                face(DEFAULT_FONT_REGULAR, FontWeight::NORMAL, FontStyle::Normal),
+                face(DEFAULT_FONT_BOLD, FontWeight::BOLD, FontStyle::Normal),
+                face(DEFAULT_FONT_ITALIC, FontWeight::NORMAL, FontStyle::Italic),
+                face(
+                    DEFAULT_FONT_BOLD_ITALIC,
+                    FontWeight::BOLD,
+                    FontStyle::Italic,
+                ),
+            ],
+        }
+    }
+}
+
+impl FontDatabase {
+    pub fn register(&mut self, face: FontFace) {
+        self.faces.push(face);
+    }
+
+    /// Select the face of the first family of the list with available faces,
+    /// the generic families resolving to the bundled faces.
+    pub fn select(&self, family: &FontFamily, query: &FontQuery) -> &FontFace {
+        let faces = family
+            .iter()
+            .map(|family| match family {
+                Value::String(name) | Value::CustomIdent(name) => self
+                    .faces
+                    .iter()
+                    .filter(|face| face.family.eq_ignore_ascii_case(name))
+                    .collect::<Vec<_>>(),
+                _ => self.fallback.iter().collect(),
+            })
+            .find(|faces| !faces.is_empty())
+            .unwrap_or_else(|| self.fallback.iter().collect());
+
+        match_face(faces, query)
+    }
+}
+
+/// Narrow the faces by width, then style, then weight, keeping the closest
+/// ones in the order of preference of the query at each step.
+fn match_face<'a>(faces: Vec<&'a FontFace>, query: &FontQuery) -> &'a FontFace {
+    let faces = narrow(faces, |face| stretch_key(query.stretch, face.stretch));
+    let faces = narrow(faces, |face| style_key(query.style, face.style));
+    let faces = narrow(faces, |face| weight_key(query.weight, face.weight));
+
+    faces[0]
+}
+
+/// The faces with the lowest key, a tier of preference then a distance.
+fn narrow(faces: Vec<&FontFace>, key: impl Fn(&FontFace) -> (u8, f32)) -> Vec<&FontFace> {
+    let best = faces
+        .iter()
+        .map(|face| key(face))
+        .min_by(|a, b| a.partial_cmp(b).expect("the descriptors are finite"))
+        .expect("there is at least one face");
+
+    faces.into_iter().filter(|face| key(face) == best).collect()
+}
+
+/// The narrower widths are preferred for a condensed or normal query, the
+/// wider ones for an expanded query.
+fn stretch_key(desired: f32, available: f32) -> (u8, f32) {
+    let narrower = available <= desired;
+    match desired <= 1.0 {
+        true if narrower => (0, desired - available),
+        false if !narrower || available == desired => (0, available - desired),
+        _ => (1, (available - desired).abs()),
+    }
+}
+
+/// The weights up to 500 are preferred for a query between 400 and 500, then
+/// the lighter ones, then the heavier ones; the lighter ones for a light
+/// query, and the heavier ones for a bold query.
+fn weight_key(desired: f32, available: f32) -> (u8, f32) {
+    let distance = (available - desired).abs();
+
+    match desired {
+        _ if available == desired => (0, 0.0),
+        desired if (400.0..=500.0).contains(&desired) => match available {
+            available if available > desired && available <= 500.0 => (0, distance),
+            available if available < desired => (1, distance),
+            _ => (2, distance),
+        },
+        desired if desired < 400.0 => (u8::from(available > desired), distance),
+        _ => (u8::from(available < desired), distance),
+    }
+}
+
+/// Italic faces are preferred for an italic query, then oblique and normal
+/// ones; for an oblique or normal query, the normal faces counting as an
+/// oblique angle of zero, the closest angles in the direction of the desired
+/// one, then the others, then italic faces.
+fn style_key(desired: FontStyle, available: FontStyle) -> (u8, f32) {
+    let angle = |style| match style {
+        FontStyle::Oblique(angle) => Some(angle.to_deg()),
+        FontStyle::Normal => Some(0.0),
+        FontStyle::Italic => None,
+    };
+
+    match (angle(desired), angle(available)) {
+        (None, None) => (0, 0.0),
+        (None, Some(available)) => match available {
+            0.0 => (2, 0.0),
+            available => (
+                1,
+                (available - FontStyle::default_oblique_angle().to_deg()).abs(),
+            ),
+        },
+        (Some(_), None) => (2, 0.0),
+        (Some(desired), Some(available)) => {
+            let same_direction = match desired >= 0.0 {
+                true => available >= desired,
+                false => available <= desired,
+            };
+            (u8::from(!same_direction), (available - desired).abs())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{Angle, FontFamily, FontStyle, Keyword, Value};
+
+    use super::{Font, FontDatabase, FontFace, FontQuery, DEFAULT_FONT_BOLD, DEFAULT_FONT_REGULAR};
+
+    fn database() -> FontDatabase {
+        let face = |weight, style, stretch| FontFace {
+            weight,
+            style,
+            stretch,
+            ..FontFace::new("Inter", Font::from_static(DEFAULT_FONT_REGULAR))
+        };
+
+        let mut database = FontDatabase::default();
+        for face in [
+            face(300.0, FontStyle::Normal, 1.0),
+            face(400.0, FontStyle::Normal, 1.0),
+            face(600.0, FontStyle::Normal, 1.0),
+            face(900.0, FontStyle::Normal, 1.0),
+            face(400.0, FontStyle::Italic, 1.0),
+            face(400.0, FontStyle::Oblique(Angle::deg(10.0)), 1.0),
+            face(400.0, FontStyle::Normal, 0.75),
+            face(400.0, FontStyle::Normal, 1.25),
+        ] {
+            database.register(face);
+        }
+        database.register(FontFace {
+            weight: 700.0,
+            ..FontFace::new("Mono", Font::from_static(DEFAULT_FONT_BOLD))
+        });
+
+        database
+    }
+
+    fn select(family: Value, query: FontQuery) -> (String, f32, FontStyle, f32) {
+        let database = database();
+        let face = database.select(&FontFamily::try_from(family).unwrap(), &query);
+
+        (face.family.clone(), face.weight, face.style, face.stretch)
+    }
+
+    #[test]
+    fn test_001_match_weight() {
+        let inter = Value::from("inter".to_string());
+        let weight = |weight| {
+            select(
+                inter.clone(),
+                FontQuery {
+                    weight,
+                    ..FontQuery::default()
+                },
+            )
+            .1
+        };
+
+        assert_eq!(weight(400.0), 400.0);
+        assert_eq!(weight(450.0), 400.0);
+        assert_eq!(weight(500.0), 400.0);
+        assert_eq!(weight(350.0), 300.0);
+        assert_eq!(weight(200.0), 300.0);
+        assert_eq!(weight(700.0), 900.0);
+        assert_eq!(weight(950.0), 900.0);
+    }
+
+    #[test]
+    fn test_002_match_style_and_stretch() {
+        let inter = Value::from("Inter".to_string());
+        let face = |style, stretch| {
+            let (_, _, style, stretch) = select(
+                inter.clone(),
+                FontQuery {
+                    style,
+                    stretch,
+                    ..FontQuery::default()
+                },
+            );
+            (style, stretch)
+        };
+
+        assert_eq!(face(FontStyle::Italic, 1.0), (FontStyle::Italic, 1.0));
+        assert_eq!(
+            face(FontStyle::Oblique(Angle::deg(20.0)), 1.0),
+            (FontStyle::Oblique(Angle::deg(10.0)), 1.0)
+        );
+        assert_eq!(
+            face(FontStyle::Oblique(Angle::deg(5.0)), 1.0),
+            (FontStyle::Oblique(Angle::deg(10.0)), 1.0)
+        );
+        assert_eq!(face(FontStyle::Normal, 0.875), (FontStyle::Normal, 0.75));
+        assert_eq!(face(FontStyle::Normal, 1.125), (FontStyle::Normal, 1.25));
+        assert_eq!(face(FontStyle::Normal, 0.5), (FontStyle::Normal, 0.75));
+    }
+
+    #[test]
+    fn test_003_fallback() {
+        let families = Value::List;
+
+        let (family, weight, ..) = select(
+            families(vec![
+                Value::from("Missing".to_string()),
+                Value::from("Mono".to_string()),
+            ]),
+            FontQuery::default(),
+        );
+        assert_eq!((family.as_str(), weight), ("Mono", 700.0));
+
+        // An unquoted name matches as a quoted one.
+        let (family, ..) = select(
+            families(vec![Value::CustomIdent("mono".to_string())]),
+            FontQuery::default(),
+        );
+        assert_eq!(family, "Mono");
+
+        let (family, weight, style, _) = select(
+            families(vec![
+                Value::from("Missing".to_string()),
+                Value::from(Keyword::SansSerif),
+                Value::from("Inter".to_string()),
+            ]),
+            FontQuery {
+                weight: 800.0,
+                style: FontStyle::Italic,
+                ..FontQuery::default()
+            },
+        );
+        assert_eq!(
+            (family.as_str(), weight, style),
+            ("Liberation Sans", 700.0, FontStyle::Italic)
+        );
+
+        let (family, ..) = select(Value::from("Missing".to_string()), FontQuery::default());
+        assert_eq!(family, "Liberation Sans");
+    }
+}
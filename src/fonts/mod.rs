@@ -1,18 +1,83 @@
 use std::ops::Deref;
 
-/// Données pour la police par défaut.
-static DEFAULT_FONT_REGULAR: &'static [u8] = include_bytes!("../../assets/fonts/LiberationSans/LiberationSans-Regular.ttf");
+use rusttype::Scale;
 
-/// Un glyphe d'une police.
+use crate::style::FontMetrics;
+
+mod database;
+
+pub use database::*;
+
+/// The data of the default font faces, Liberation Sans.
+static DEFAULT_FONT_REGULAR: &[u8] =
+    include_bytes!("../../assets/fonts/LiberationSans/LiberationSans-Regular.ttf");
+static DEFAULT_FONT_BOLD: &[u8] =
+    include_bytes!("../../assets/fonts/LiberationSans/LiberationSans-Bold.ttf");
+static DEFAULT_FONT_ITALIC: &[u8] =
+    include_bytes!("../../assets/fonts/LiberationSans/LiberationSans-Italic.ttf");
+static DEFAULT_FONT_BOLD_ITALIC: &[u8] =
+    include_bytes!("../../assets/fonts/LiberationSans/LiberationSans-BoldItalic.ttf");
+
+/// A glyph of a font.
 pub struct Glyph(rusttype::Glyph<'static>);
 
-/// Une police chargée en mémoire.
+/// A font loaded in memory.
 #[derive(Clone)]
 pub struct Font(rusttype::Font<'static>);
 
+impl Font {
+    /// Load a font from the data of a TrueType or OpenType file, none if it
+    /// is invalid.
+    pub fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+        rusttype::Font::try_from_vec(bytes).map(Self)
+    }
+
+    /// The glyph of a character, the missing glyph if the font lacks it.
+    pub fn glyph(&self, c: char) -> Glyph {
+        Glyph(self.0.glyph(c))
+    }
+
+    /// The metrics of the font at a size, in CSS pixels, the line height
+    /// being the normal one, and those of the missing glyphs approximated.
+    pub fn metrics(&self, font_size: f32) -> FontMetrics {
+        // The scale is the height from the descent to the ascent, not the em.
+        let unscaled = self.0.v_metrics_unscaled();
+        let scale = Scale::uniform(
+            font_size * (unscaled.ascent - unscaled.descent) / f32::from(self.0.units_per_em()),
+        );
+        let v_metrics = self.0.v_metrics(scale);
+
+        let glyph = |c| {
+            Some(self.0.glyph(c))
+                .filter(|glyph| glyph.id().0 != 0)
+                .map(|glyph| glyph.scaled(scale))
+        };
+        let height = |c| {
+            glyph(c)
+                .and_then(|glyph| glyph.exact_bounding_box())
+                .map(|bounds| -bounds.min.y)
+        };
+        let advance = |c| glyph(c).map(|glyph| glyph.h_metrics().advance_width);
+
+        let approximate = FontMetrics::approximate(font_size);
+        FontMetrics {
+            font_size,
+            x_height: height('x').unwrap_or(approximate.x_height),
+            ch_width: advance('0').unwrap_or(approximate.ch_width),
+            cap_height: height('H').unwrap_or(approximate.cap_height),
+            ic_width: advance('水').unwrap_or(approximate.ic_width),
+            line_height: v_metrics.ascent - v_metrics.descent + v_metrics.line_gap,
+        }
+    }
+
+    fn from_static(bytes: &'static [u8]) -> Self {
+        Self(rusttype::Font::try_from_bytes(bytes).expect("the bundled fonts are valid"))
+    }
+}
+
 impl Default for Font {
     fn default() -> Self {
-        Self(rusttype::Font::try_from_bytes(DEFAULT_FONT_REGULAR).unwrap())
+        Self::from_static(DEFAULT_FONT_REGULAR)
     }
 }
 
@@ -20,6 +85,14 @@ impl Deref for Font {
     type Target = rusttype::Font<'static>;
 
     fn deref(&self) -> &Self::Target {
-        todo!()
+        &self.0
+    }
+}
+
+impl Deref for Glyph {
+    type Target = rusttype::Glyph<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-#[derive(Default)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct Edge<Unit> {
     pub left: Unit,
     pub right: Unit,
@@ -4,5 +4,6 @@ mod rect;
 mod vec;
 
 pub use edge::Edge;
+pub use r#box::Box;
 pub use rect::{Rect, RectArea};
 pub use vec::Vec2D;
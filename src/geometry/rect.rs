@@ -2,8 +2,8 @@ use super::Vec2D;
 
 #[derive(Default)]
 pub struct RectArea<Unit> {
-    width: Unit,
-    height: Unit,
+    pub width: Unit,
+    pub height: Unit,
 }
 
 #[derive(Default)]
@@ -20,7 +20,7 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         let mut elements = Vec::<T>::default();
 
-        while let Some(el) = self.iterator.next() {
+        for el in self.iterator.by_ref() {
             if (self.predicate)(&el) {
                 break;
             }
@@ -32,7 +32,7 @@ where
             return None;
         }
 
-        return Some(elements.into_iter());
+        Some(elements.into_iter())
     }
 }
 
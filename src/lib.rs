@@ -1,3 +1,4 @@
+pub mod fonts;
 pub mod geometry;
 pub mod iter;
 pub mod style;
@@ -0,0 +1,188 @@
+use crate::style::{ComponentValue, TokenKind};
+
+/// The identifier of a layer in a [LayerTree], the root being the implicit
+/// layer of the unlayered rules.
+pub type LayerId = usize;
+
+#[derive(Debug, Clone)]
+struct LayerNode {
+    /// The name of the layer, None for an anonymous layer.
+    name: Option<String>,
+    children: Vec<LayerId>,
+}
+
+/// The cascade layers declared by the stylesheets of an origin.
+///
+/// Layers are ordered by their first declaration. Sub-layers come before
+/// the rules directly in their parent layer, and the unlayered rules come
+/// last.
+///
+/// [CSS Cascade 5](https://drafts.csswg.org/css-cascade-5/#layering)
+#[derive(Debug, Clone)]
+pub struct LayerTree {
+    nodes: Vec<LayerNode>,
+    ranks: Vec<u32>,
+}
+
+impl Default for LayerTree {
+    fn default() -> Self {
+        Self {
+            nodes: vec![LayerNode {
+                name: None,
+                children: Vec::default(),
+            }],
+            ranks: vec![0],
+        }
+    }
+}
+
+impl LayerTree {
+    pub const ROOT: LayerId = 0;
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.len() == 1
+    }
+
+    /// Declare a layer, or get it if it exists, given its dot-separated
+    /// path relative to the parent layer.
+    pub fn declare<S: AsRef<str>>(&mut self, parent: LayerId, path: &[S]) -> LayerId {
+        let mut layer = parent;
+
+        for name in path.iter().map(AsRef::as_ref) {
+            layer = match self.child(layer, name) {
+                Some(child) => child,
+                None => self.push(layer, Some(name.to_string())),
+            };
+        }
+
+        layer
+    }
+
+    /// Declare an anonymous layer, which is always a new layer.
+    pub fn declare_anonymous(&mut self, parent: LayerId) -> LayerId {
+        self.push(parent, None)
+    }
+
+    /// Get a layer from its full dot-separated name.
+    pub fn get(&self, name: &str) -> Option<LayerId> {
+        name.split('.')
+            .try_fold(Self::ROOT, |layer, name| self.child(layer, name))
+    }
+
+    /// The position of the layer in the layer order, higher wins for
+    /// normal declarations.
+    pub fn rank(&self, layer: LayerId) -> u32 {
+        self.ranks[layer]
+    }
+
+    fn child(&self, parent: LayerId, name: &str) -> Option<LayerId> {
+        self.nodes[parent]
+            .children
+            .iter()
+            .copied()
+            .find(|child| self.nodes[*child].name.as_deref() == Some(name))
+    }
+
+    fn push(&mut self, parent: LayerId, name: Option<String>) -> LayerId {
+        let id = self.nodes.len();
+        self.nodes.push(LayerNode {
+            name,
+            children: Vec::default(),
+        });
+        self.nodes[parent].children.push(id);
+        self.compute_ranks();
+        id
+    }
+
+    /// Rank the layers in post-order.
+    fn compute_ranks(&mut self) {
+        fn visit(tree: &LayerTree, layer: LayerId, ranks: &mut [u32], next: &mut u32) {
+            for child in tree.nodes[layer].children.iter() {
+                visit(tree, *child, ranks, next);
+            }
+            ranks[layer] = *next;
+            *next += 1;
+        }
+
+        let mut ranks = vec![0; self.nodes.len()];
+        visit(self, Self::ROOT, &mut ranks, &mut 0);
+        self.ranks = ranks;
+    }
+}
+
+/// Parse the prelude of a @layer rule into a list of layer paths.
+///
+/// # Grammar
+/// <layer-name>#
+/// <layer-name> = <ident> [ '.' <ident> ]*
+pub fn parse_layer_names(prelude: &[ComponentValue]) -> Option<Vec<Vec<String>>> {
+    let mut names = Vec::<Vec<String>>::default();
+    let mut current = Vec::<String>::default();
+    let mut expect_ident = true;
+
+    for cv in prelude.iter().filter(|cv| !cv.is_whitespace()) {
+        match cv.as_token_kind() {
+            Some(TokenKind::Ident(ident)) if expect_ident => {
+                current.push(ident.clone());
+                expect_ident = false;
+            }
+            Some(TokenKind::Delim(del)) if del == "." && !expect_ident => expect_ident = true,
+            Some(TokenKind::Comma) if !expect_ident => {
+                names.push(std::mem::take(&mut current));
+                expect_ident = true;
+            }
+            _ => return None,
+        }
+    }
+
+    if !current.is_empty() {
+        names.push(current);
+    } else if !names.is_empty() {
+        // Trailing comma or dot.
+        return None;
+    }
+
+    Some(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::parse_component_values;
+
+    use super::{parse_layer_names, LayerTree};
+
+    #[test]
+    fn test_001_layer_order() {
+        let mut tree = LayerTree::default();
+        let reset = tree.declare(LayerTree::ROOT, &["reset"]);
+        let theme = tree.declare(LayerTree::ROOT, &["theme"]);
+        let dark = tree.declare(LayerTree::ROOT, &["theme", "dark"]);
+        let anonymous = tree.declare_anonymous(LayerTree::ROOT);
+
+        assert_eq!(tree.declare(LayerTree::ROOT, &["reset"]), reset);
+        assert_eq!(tree.get("theme.dark"), Some(dark));
+
+        let ranks = [reset, dark, theme, anonymous, LayerTree::ROOT].map(|l| tree.rank(l));
+        assert!(ranks.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_002_parse_layer_names() {
+        let names = |source: &str| parse_layer_names(&parse_component_values(source));
+
+        assert_eq!(
+            names("reset, theme.dark"),
+            Some(vec![
+                vec!["reset".to_string()],
+                vec!["theme".to_string(), "dark".to_string()]
+            ])
+        );
+        assert_eq!(names(" "), Some(vec![]));
+        assert_eq!(names("a,"), None);
+        assert_eq!(names("a b"), None);
+    }
+}
@@ -0,0 +1,16 @@
+//! The cascade.
+//!
+//! The declarations applying to an element are sorted by origin and
+//! importance, element-attached styles, cascade layers, specificity, scope
+//! proximity, and order of appearance. The greatest of each property wins.
+mod layer;
+mod origin;
+mod rules;
+mod scope;
+mod values;
+
+pub use layer::*;
+pub use origin::*;
+pub use rules::*;
+pub use scope::*;
+pub use values::*;
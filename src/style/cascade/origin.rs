@@ -0,0 +1,63 @@
+/// The origin of a declaration.
+///
+/// [CSS Cascade 5](https://drafts.csswg.org/css-cascade-5/#cascading-origins)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Origin {
+    UserAgent,
+    User,
+    Author,
+    /// Values produced by running animations.
+    Animation,
+    /// Values produced by running transitions.
+    Transition,
+}
+
+/// The precedence of a declaration given its origin and importance,
+/// in ascending order.
+///
+/// Important declarations invert the precedence of the user-agent, user
+/// and author origins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CascadeLevel {
+    UserAgentNormal,
+    UserNormal,
+    AuthorNormal,
+    Animation,
+    AuthorImportant,
+    UserImportant,
+    UserAgentImportant,
+    Transition,
+}
+
+impl CascadeLevel {
+    /// Animations and transitions ignore the importance of their declarations.
+    pub fn new(origin: Origin, important: bool) -> Self {
+        match (origin, important) {
+            (Origin::UserAgent, false) => Self::UserAgentNormal,
+            (Origin::User, false) => Self::UserNormal,
+            (Origin::Author, false) => Self::AuthorNormal,
+            (Origin::Author, true) => Self::AuthorImportant,
+            (Origin::User, true) => Self::UserImportant,
+            (Origin::UserAgent, true) => Self::UserAgentImportant,
+            (Origin::Animation, _) => Self::Animation,
+            (Origin::Transition, _) => Self::Transition,
+        }
+    }
+
+    pub fn origin(&self) -> Origin {
+        match self {
+            Self::UserAgentNormal | Self::UserAgentImportant => Origin::UserAgent,
+            Self::UserNormal | Self::UserImportant => Origin::User,
+            Self::AuthorNormal | Self::AuthorImportant => Origin::Author,
+            Self::Animation => Origin::Animation,
+            Self::Transition => Origin::Transition,
+        }
+    }
+
+    pub fn is_important(&self) -> bool {
+        matches!(
+            self,
+            Self::AuthorImportant | Self::UserImportant | Self::UserAgentImportant
+        )
+    }
+}
@@ -0,0 +1,578 @@
+use std::collections::BTreeMap;
+
+use crate::style::{
+    AncestorFilter, DeclarationBlock, Element, PropertyRegistry, PseudoElement, Rule, RuleIndex,
+    Rules, Specificity, Stylesheet,
+};
+
+use super::{
+    parse_layer_names, CascadeLevel, CascadePriority, CascadedDeclaration, CascadedValues, LayerId,
+    LayerTree, Origin, ScopeRule,
+};
+
+/// A style rule, with the layer and the scope it is nested in.
+#[derive(Debug, Clone)]
+struct CascadeRule {
+    origin: Origin,
+    layer: LayerId,
+    scope: Option<usize>,
+    declarations: DeclarationBlock,
+    /// The order of appearance of the first declaration of the rule.
+    order: usize,
+}
+
+/// A @scope rule, and the @scope rule it is nested in.
+#[derive(Debug, Clone)]
+struct CascadeScope {
+    rule: ScopeRule,
+    parent: Option<usize>,
+}
+
+/// The declarations that are not coming from the stylesheets.
+#[derive(Debug, Clone, Default)]
+pub struct CascadeInputs {
+    /// The current values of the running animations.
+    pub animations: DeclarationBlock,
+    /// The current values of the running transitions.
+    pub transitions: DeclarationBlock,
+}
+
+/// The style rules of a set of stylesheets, ready to be cascaded.
+///
+/// Style rules nested in @layer and @scope rules are flattened, the @property
+/// rules are registered, the conditional rules (@media, @supports, ...) are
+/// not evaluated and skipped.
+///
+/// [CSS Cascade 5](https://drafts.csswg.org/css-cascade-5/#cascade-sort)
+#[derive(Debug, Clone, Default)]
+pub struct Cascade {
+    rules: Vec<CascadeRule>,
+    scopes: Vec<CascadeScope>,
+    layers: BTreeMap<Origin, LayerTree>,
+    index: RuleIndex,
+    registry: PropertyRegistry,
+    declarations: usize,
+}
+
+impl Cascade {
+    /// Collect the style rules of the stylesheets, given in order of appearance.
+    pub fn new<'a, I>(stylesheets: I) -> Self
+    where
+        I: IntoIterator<Item = (Origin, &'a Stylesheet)>,
+    {
+        let mut cascade = Self::default();
+
+        for (stylesheet, (origin, sheet)) in stylesheets.into_iter().enumerate() {
+            cascade.collect(&sheet.rules, stylesheet, origin, LayerTree::ROOT, None);
+        }
+
+        cascade
+    }
+
+    /// The layers declared by the stylesheets of the origin.
+    pub fn layers(&self, origin: Origin) -> Option<&LayerTree> {
+        self.layers.get(&origin)
+    }
+
+    /// The custom properties registered by the @property rules.
+    pub fn registry(&self) -> &PropertyRegistry {
+        &self.registry
+    }
+
+    /// The number of style rules.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Cascade the declarations applying to the element, or one of its pseudo-elements.
+    ///
+    /// If an ancestor filter is given, it must hold the ancestors of the element.
+    pub fn cascade<E: Element>(
+        &self,
+        element: &E,
+        pseudo: Option<PseudoElement>,
+        filter: Option<&AncestorFilter>,
+        inputs: &CascadeInputs,
+    ) -> CascadedValues {
+        let mut values = CascadedValues::default();
+        let allows = |name: &str| pseudo.map(|p| p.allows_property(name)).unwrap_or(true);
+
+        // A rule matched by several selectors of its list gets the highest specificity.
+        let mut matched = BTreeMap::<usize, Specificity>::default();
+        for entry in self.index.matching(element, pseudo, filter) {
+            let specificity = matched.entry(entry.data.rule).or_default();
+            *specificity = (*specificity).max(entry.specificity);
+        }
+
+        for (index, specificity) in matched {
+            let rule = &self.rules[index];
+
+            let generations = match rule.scope {
+                Some(scope) => match self.scope_proximity(scope, element) {
+                    Some(generations) => Some(generations),
+                    None => continue,
+                },
+                None => None,
+            };
+
+            let rank = self.layers[&rule.origin].rank(rule.layer);
+
+            for (i, declaration) in rule.declarations.iter().enumerate() {
+                if !allows(&declaration.name) {
+                    continue;
+                }
+
+                values.push(CascadedDeclaration {
+                    priority: CascadePriority {
+                        level: CascadeLevel::new(rule.origin, declaration.important),
+                        element_attached: false,
+                        layer: if declaration.important {
+                            u32::MAX - rank
+                        } else {
+                            rank
+                        },
+                        specificity,
+                        proximity: CascadePriority::proximity(generations),
+                        order: rule.order + i,
+                    },
+                    layer: rule.layer,
+                    declaration: declaration.clone(),
+                });
+            }
+        }
+
+        if let Some(style) = element.style_attribute().filter(|_| pseudo.is_none()) {
+            self.push_unruled(
+                &mut values,
+                Origin::Author,
+                &DeclarationBlock::from(style),
+                true,
+            );
+        }
+
+        self.push_unruled(&mut values, Origin::Animation, &inputs.animations, false);
+        self.push_unruled(&mut values, Origin::Transition, &inputs.transitions, false);
+
+        values
+    }
+
+    /// Push declarations which are not coming from a style rule.
+    fn push_unruled(
+        &self,
+        values: &mut CascadedValues,
+        origin: Origin,
+        declarations: &DeclarationBlock,
+        element_attached: bool,
+    ) {
+        let rank = self
+            .layers(origin)
+            .map(|layers| layers.rank(LayerTree::ROOT))
+            .unwrap_or_default();
+
+        for (i, declaration) in declarations.iter().enumerate() {
+            values.push(CascadedDeclaration {
+                priority: CascadePriority {
+                    level: CascadeLevel::new(origin, declaration.important),
+                    element_attached,
+                    layer: if declaration.important {
+                        u32::MAX - rank
+                    } else {
+                        rank
+                    },
+                    specificity: Specificity::default(),
+                    proximity: CascadePriority::proximity(None),
+                    order: self.declarations + i,
+                },
+                layer: LayerTree::ROOT,
+                declaration: declaration.clone(),
+            });
+        }
+    }
+
+    /// The generations between the element and the root of the innermost scope,
+    /// None if the element is out of any of the nested scopes.
+    fn scope_proximity<E: Element>(&self, scope: usize, element: &E) -> Option<u32> {
+        let CascadeScope { rule, parent } = &self.scopes[scope];
+        let generations = rule.proximity(element)?;
+
+        if let Some(parent) = parent {
+            let mut root = element.clone();
+            for _ in 0..generations {
+                root = root.parent_element()?;
+            }
+            self.scope_proximity(*parent, &root)?;
+        }
+
+        Some(generations)
+    }
+
+    fn collect(
+        &mut self,
+        rules: &Rules,
+        stylesheet: usize,
+        origin: Origin,
+        layer: LayerId,
+        scope: Option<usize>,
+    ) {
+        self.layers.entry(origin).or_default();
+
+        for rule in rules {
+            match rule {
+                Rule::Qualified(qualified) => {
+                    let Ok(selectors) = qualified.selectors() else {
+                        continue;
+                    };
+
+                    let declarations = DeclarationBlock::from(qualified.block());
+                    self.index.insert(selectors, stylesheet, self.rules.len());
+                    self.rules.push(CascadeRule {
+                        origin,
+                        layer,
+                        scope,
+                        order: self.declarations,
+                        declarations,
+                    });
+                    self.declarations += self.rules.last().unwrap().declarations.len();
+                }
+                Rule::At(at) => match at.name().map(str::to_ascii_lowercase).as_deref() {
+                    Some("layer") => {
+                        let Some(names) = parse_layer_names(at.prelude()) else {
+                            continue;
+                        };
+                        let layers = self.layers.get_mut(&origin).unwrap();
+
+                        // A statement only declares the layers, and a block rule
+                        // has at most one name.
+                        let nested = match names.as_slice() {
+                            [] => layers.declare_anonymous(layer),
+                            [name] => layers.declare(layer, name),
+                            names => {
+                                for name in names {
+                                    layers.declare(layer, name);
+                                }
+                                continue;
+                            }
+                        };
+
+                        let block = Rules::from(at.block().values.as_slice());
+                        self.collect(&block, stylesheet, origin, nested, scope);
+                    }
+                    Some("scope") => {
+                        let Ok(rule) = ScopeRule::try_from(at.prelude()) else {
+                            continue;
+                        };
+
+                        self.scopes.push(CascadeScope {
+                            rule,
+                            parent: scope,
+                        });
+
+                        let nested = self.scopes.len() - 1;
+                        let block = Rules::from(at.block().values.as_slice());
+                        self.collect(&block, stylesheet, origin, layer, Some(nested));
+                    }
+                    Some("property") => {
+                        // Invalid registrations are ignored.
+                        let _ = self.registry.register(at);
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{testing::TestDocument, DeclarationBlock, PseudoElement, Stylesheet};
+
+    use super::{Cascade, CascadeInputs, Origin};
+
+    fn winner(cascade: &Cascade, doc: &TestDocument, node: usize, name: &str) -> Option<String> {
+        cascade
+            .cascade(&doc.element(node), None, None, &CascadeInputs::default())
+            .winner(name)
+            .map(|decl| decl.declaration.to_string())
+    }
+
+    #[test]
+    fn test_001_origins_and_importance() {
+        let ua = Stylesheet::from("p { color: black !important; margin: 1px; padding: 1px }");
+        let user = Stylesheet::from("p { margin: 2px !important; padding: 2px }");
+        let author = Stylesheet::from("p { color: red; margin: 3px !important; padding: 3px }");
+        let cascade = Cascade::new([
+            (Origin::Author, &author),
+            (Origin::UserAgent, &ua),
+            (Origin::User, &user),
+        ]);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[]);
+
+        assert_eq!(
+            winner(&cascade, &doc, p, "color").as_deref(),
+            Some("color: black !important")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, p, "margin-top").as_deref(),
+            Some("margin: 2px !important")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, p, "padding-top").as_deref(),
+            Some("padding: 3px")
+        );
+    }
+
+    #[test]
+    fn test_002_specificity_and_order() {
+        let author = Stylesheet::from(
+            "#intro { color: red } p.a, p { color: blue } p { color: green; margin: 0 } .a { margin: 1px }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[("class", "a")]);
+        let intro = doc.create(None, "p", &[("id", "intro")]);
+
+        assert_eq!(
+            winner(&cascade, &doc, p, "color").as_deref(),
+            Some("color: blue")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, p, "margin-top").as_deref(),
+            Some("margin: 1px")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, intro, "color").as_deref(),
+            Some("color: red")
+        );
+    }
+
+    #[test]
+    fn test_003_layers() {
+        let author = Stylesheet::from(
+            "@layer base, theme;
+            @layer theme { #x { color: blue; margin: 1px !important } }
+            @layer base { #x { color: green } p { margin: 2px !important } }
+            @layer theme.dark { #x { color: white } }
+            @layer { p { padding: 1px } }
+            p { color: black; padding: 2px; margin: 3px !important }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+        let layers = cascade.layers(Origin::Author).unwrap();
+        assert_eq!(layers.len(), 5);
+        assert!(
+            layers.rank(layers.get("theme.dark").unwrap())
+                < layers.rank(layers.get("theme").unwrap())
+        );
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[("id", "x")]);
+
+        // Unlayered rules win over the layers, whatever the specificity.
+        assert_eq!(
+            winner(&cascade, &doc, p, "color").as_deref(),
+            Some("color: black")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, p, "padding-top").as_deref(),
+            Some("padding: 2px")
+        );
+        // The precedence of the layers is inverted for important declarations.
+        assert_eq!(
+            winner(&cascade, &doc, p, "margin-top").as_deref(),
+            Some("margin: 2px !important")
+        );
+
+        let candidates = cascade
+            .cascade(&doc.element(p), None, None, &CascadeInputs::default())
+            .candidates("color")
+            .iter()
+            .map(|decl| decl.declaration.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            candidates,
+            vec![
+                "color: black",
+                "color: blue",
+                "color: white",
+                "color: green"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_004_style_attribute() {
+        let author = Stylesheet::from("#x { color: blue; margin: 1px !important }");
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(
+            None,
+            "p",
+            &[
+                ("id", "x"),
+                ("style", "color: red; margin: 2px; padding: 0 !important"),
+            ],
+        );
+
+        assert_eq!(
+            winner(&cascade, &doc, p, "color").as_deref(),
+            Some("color: red")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, p, "margin-top").as_deref(),
+            Some("margin: 1px !important")
+        );
+
+        let user = Stylesheet::from("p { padding: 1px !important }");
+        let cascade = Cascade::new([(Origin::Author, &author), (Origin::User, &user)]);
+        assert_eq!(
+            winner(&cascade, &doc, p, "padding-top").as_deref(),
+            Some("padding: 1px !important")
+        );
+    }
+
+    #[test]
+    fn test_005_scope_proximity() {
+        let author = Stylesheet::from(
+            "@scope (.inner) { p { color: blue } }
+            @scope (.outer) to (.limit) { p { color: red; margin: 0 } }
+            @scope (.outer) { @scope (.inner) { p { padding: 0 } } }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+        assert_eq!(cascade.len(), 3);
+
+        let mut doc = TestDocument::default();
+        let outer = doc.create(None, "div", &[("class", "outer")]);
+        let inner = doc.create(Some(outer), "div", &[("class", "inner")]);
+        let p = doc.create(Some(inner), "p", &[]);
+        let limit = doc.create(Some(outer), "div", &[("class", "limit inner")]);
+        let limited = doc.create(Some(limit), "p", &[]);
+
+        // The closest scoping root wins over the order of appearance.
+        assert_eq!(
+            winner(&cascade, &doc, p, "color").as_deref(),
+            Some("color: blue")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, p, "padding-top").as_deref(),
+            Some("padding: 0")
+        );
+        assert_eq!(winner(&cascade, &doc, limited, "margin-top"), None);
+
+        let lonely = doc.create(None, "div", &[("class", "inner")]);
+        let p = doc.create(Some(lonely), "p", &[]);
+        assert_eq!(winner(&cascade, &doc, p, "padding-top"), None);
+    }
+
+    #[test]
+    fn test_006_animations_and_transitions() {
+        let author = Stylesheet::from("p { color: red; margin: 0 !important; padding: 0 }");
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[]);
+
+        let inputs = CascadeInputs {
+            animations: DeclarationBlock::from("color: blue; margin: 1px"),
+            transitions: DeclarationBlock::from("padding: 2px"),
+        };
+        let values = cascade.cascade(&doc.element(p), None, None, &inputs);
+        let winner = |name| values.winner(name).unwrap().declaration.to_string();
+
+        assert_eq!(winner("color"), "color: blue");
+        assert_eq!(winner("margin-top"), "margin: 0 !important");
+        assert_eq!(winner("padding-top"), "padding: 2px");
+        assert_eq!(values.winner("color").unwrap().origin(), Origin::Animation);
+    }
+
+    #[test]
+    fn test_007_pseudo_elements() {
+        let author = Stylesheet::from(
+            "p { color: red } p::first-line { color: blue; margin: 0 } p::before { margin: 1px }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[("style", "color: green")]);
+        let element = doc.element(p);
+        let inputs = CascadeInputs::default();
+
+        let first_line = cascade.cascade(&element, Some(PseudoElement::FirstLine), None, &inputs);
+        assert_eq!(first_line.len(), 1);
+        assert_eq!(
+            first_line.winner("color").unwrap().declaration.to_string(),
+            "color: blue"
+        );
+
+        let before = cascade.cascade(&element, Some(PseudoElement::Before), None, &inputs);
+        assert!(before.winner("color").is_none());
+        assert!(before.winner("margin-top").is_some());
+    }
+
+    #[test]
+    fn test_008_unterminated_input() {
+        let author = Stylesheet::from("p { margin: 1px } div { color: blue } span");
+        assert_eq!(author.rules.len(), 2);
+
+        let author = Stylesheet::from("p { margin: 1px; color: red");
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[("style", "padding: 2px; width: calc((1px")]);
+        let div = doc.create(None, "div", &[("style", "a: [b")]);
+
+        assert_eq!(
+            winner(&cascade, &doc, p, "color").as_deref(),
+            Some("color: red")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, p, "padding-top").as_deref(),
+            Some("padding: 2px")
+        );
+        assert!(winner(&cascade, &doc, div, "color").is_none());
+    }
+
+    /// The invalid declarations, and those of unknown properties, don't take
+    /// part in the cascade.
+    #[test]
+    fn test_009_invalid_declarations() {
+        let author = Stylesheet::from(
+            "p { color: red; color: 12px; margin: 1px; margin: red; colour: blue; --x: 12px }
+            p.a { color: var(--x) }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[("style", "padding: 2px; padding: auto")]);
+        let a = doc.create(None, "p", &[("class", "a")]);
+
+        assert_eq!(
+            winner(&cascade, &doc, p, "color").as_deref(),
+            Some("color: red")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, p, "margin-top").as_deref(),
+            Some("margin: 1px")
+        );
+        assert_eq!(
+            winner(&cascade, &doc, p, "padding-top").as_deref(),
+            Some("padding: 2px")
+        );
+        assert!(winner(&cascade, &doc, p, "colour").is_none());
+        assert_eq!(
+            winner(&cascade, &doc, p, "--x").as_deref(),
+            Some("--x: 12px")
+        );
+        // A declaration with variables is only invalid at computed-value time.
+        assert_eq!(
+            winner(&cascade, &doc, a, "color").as_deref(),
+            Some("color: var(--x)")
+        );
+    }
+}
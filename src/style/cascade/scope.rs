@@ -0,0 +1,121 @@
+use crate::style::{BlockDelimiter, ComponentValue, Element, SelectorList, StyleError, TokenKind};
+
+/// A @scope rule, limiting its style rules to a subtree of the document.
+///
+/// # Grammar
+/// @scope [(<scope-start>)]? [to (<scope-end>)]? { <rule-list> }
+///
+/// [CSS Cascade 6](https://drafts.csswg.org/css-cascade-6/#scoped-styles)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeRule {
+    /// The selector of the scoping roots, the root element if None.
+    pub start: Option<SelectorList>,
+    /// The selector of the scoping limits.
+    pub end: Option<SelectorList>,
+}
+
+impl ScopeRule {
+    /// Returns the number of generations between the element and its closest
+    /// scoping root, None if the element is out of scope.
+    ///
+    /// The scoping limits, and their descendants, are out of scope.
+    pub fn proximity<E: Element>(&self, element: &E) -> Option<u32> {
+        let mut next = Some(element.clone());
+        let mut generations = 0;
+
+        while let Some(ancestor) = next {
+            let is_root = match &self.start {
+                Some(start) => start.matches(&ancestor),
+                None => ancestor.is_root(),
+            };
+
+            if is_root {
+                return Some(generations);
+            }
+
+            if self
+                .end
+                .as_ref()
+                .map(|end| end.matches(&ancestor))
+                .unwrap_or(false)
+            {
+                return None;
+            }
+
+            next = ancestor.parent_element();
+            generations += 1;
+        }
+
+        None
+    }
+}
+
+impl TryFrom<&[ComponentValue]> for ScopeRule {
+    type Error = StyleError;
+
+    /// Parse the prelude of the rule.
+    fn try_from(value: &[ComponentValue]) -> Result<Self, Self::Error> {
+        let mut rule = Self {
+            start: None,
+            end: None,
+        };
+        let mut values = value.iter().filter(|cv| !cv.is_whitespace()).peekable();
+
+        let selectors = |cv: Option<&ComponentValue>| match cv {
+            Some(ComponentValue::Block(block))
+                if block.is_delimited_by(BlockDelimiter::Parenthesis) =>
+            {
+                SelectorList::try_from(block.values.as_slice())
+            }
+            _ => Err(StyleError::InvalidSelector),
+        };
+
+        if matches!(values.peek(), Some(ComponentValue::Block(_))) {
+            rule.start = Some(selectors(values.next())?);
+        }
+
+        match values.next().and_then(ComponentValue::as_token_kind) {
+            Some(TokenKind::Ident(to)) if to.eq_ignore_ascii_case("to") => {
+                rule.end = Some(selectors(values.next())?);
+            }
+            Some(_) => return Err(StyleError::InvalidSelector),
+            None => {}
+        }
+
+        match values.next() {
+            Some(_) => Err(StyleError::InvalidSelector),
+            None => Ok(rule),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, testing::TestDocument};
+
+    use super::ScopeRule;
+
+    #[test]
+    fn test_001_scope_proximity() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let card = doc.create(Some(html), "div", &[("class", "card")]);
+        let content = doc.create(Some(card), "div", &[("class", "content")]);
+        let p = doc.create(Some(content), "p", &[]);
+        let title = doc.create(Some(card), "h2", &[]);
+
+        let scope = ScopeRule::try_from(parse_component_values("(.card) to (.content)").as_slice())
+            .unwrap();
+
+        assert_eq!(scope.proximity(&doc.element(card)), Some(0));
+        assert_eq!(scope.proximity(&doc.element(title)), Some(1));
+        assert_eq!(scope.proximity(&doc.element(content)), None);
+        assert_eq!(scope.proximity(&doc.element(p)), None);
+        assert_eq!(scope.proximity(&doc.element(html)), None);
+
+        let scope = ScopeRule::try_from(parse_component_values("").as_slice()).unwrap();
+        assert_eq!(scope.proximity(&doc.element(p)), Some(3));
+
+        assert!(ScopeRule::try_from(parse_component_values("(.a) from (.b)").as_slice()).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+
+use crate::style::{
+    Declaration, Direction, PropertyDeclaration, PropertyId, ShorthandId, Specificity, WritingMode,
+};
+
+use super::{CascadeLevel, LayerId, Origin};
+
+/// The sort key of a declaration in the cascade, compared lexicographically,
+/// the greatest wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CascadePriority {
+    pub level: CascadeLevel,
+    /// Declarations of the style attribute win over the rules.
+    pub element_attached: bool,
+    /// The rank of the layer, inverted for important declarations.
+    pub layer: u32,
+    pub specificity: Specificity,
+    /// The generations between the element and the scoping root, inverted
+    /// so that closer wins. Zero for unscoped declarations.
+    pub proximity: u32,
+    /// The order of appearance.
+    pub order: usize,
+}
+
+impl CascadePriority {
+    /// The proximity of a declaration, given the generations between the
+    /// element and its scoping root, if any.
+    pub fn proximity(generations: Option<u32>) -> u32 {
+        generations.map(|g| u32::MAX - g).unwrap_or(0)
+    }
+}
+
+/// A declaration taking part in the cascade of an element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CascadedDeclaration {
+    pub declaration: Declaration,
+    pub priority: CascadePriority,
+    /// The layer of the declaration, within the layers of its origin.
+    pub layer: LayerId,
+}
+
+impl CascadedDeclaration {
+    pub fn origin(&self) -> Origin {
+        self.priority.level.origin()
+    }
+}
+
+/// The declarations applying to an element, grouped by longhand and
+/// sorted by decreasing priority.
+///
+/// Lower priority declarations are kept, so that the `revert` and
+/// `revert-layer` keywords can roll back to them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CascadedValues(BTreeMap<String, Vec<CascadedDeclaration>>);
+
+impl CascadedValues {
+    /// Add a declaration, a shorthand being added to each of its longhands.
+    ///
+    /// The invalid declarations, and those of unknown properties, are dropped
+    /// so that they don't win over the valid ones.
+    pub fn push(&mut self, declaration: CascadedDeclaration) {
+        let Declaration { name, value, .. } = &declaration.declaration;
+        if PropertyDeclaration::parse_all(name, value).is_err() {
+            return;
+        }
+
+        if let Ok(shorthand) = ShorthandId::try_from(declaration.declaration.name.as_str()) {
+            for longhand in shorthand.longhands() {
+                self.insert(longhand.name(), declaration.clone());
+            }
+            return;
+        }
+
+        let name = declaration.declaration.name.clone();
+        self.insert(&name, declaration);
+    }
+
+    fn insert(&mut self, name: &str, declaration: CascadedDeclaration) {
+        let candidates = self.0.entry(name.to_string()).or_default();
+        let position = candidates
+            .iter()
+            .position(|candidate| candidate.priority < declaration.priority)
+            .unwrap_or(candidates.len());
+        candidates.insert(position, declaration);
+    }
+
+    /// The values with the declarations of the logical longhands added to
+    /// the physical longhands they map to, so that they compete by priority.
+    pub fn to_physical(&self, writing_mode: WritingMode, direction: Direction) -> Self {
+        let mut values = self.clone();
+
+        for (name, candidates) in &self.0 {
+            let Ok(id) = PropertyId::try_from(name.as_str()) else {
+                continue;
+            };
+            if !id.is_logical() {
+                continue;
+            }
+
+            let physical = id.to_physical(writing_mode, direction);
+            for candidate in candidates {
+                values.insert(physical.name(), candidate.clone());
+            }
+        }
+
+        values
+    }
+
+    /// The winning declaration of the property.
+    pub fn winner(&self, name: &str) -> Option<&CascadedDeclaration> {
+        self.candidates(name).first()
+    }
+
+    /// The declarations of the property, the winner first.
+    pub fn candidates(&self, name: &str) -> &[CascadedDeclaration] {
+        self.0.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The names of the cascaded properties.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Iterate over the winning declaration of each property.
+    pub fn winners(&self) -> impl Iterator<Item = &CascadedDeclaration> {
+        self.0.values().filter_map(|candidates| candidates.first())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
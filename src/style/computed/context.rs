@@ -0,0 +1,32 @@
+use crate::style::{FontMetrics, FontSize, FontWeight, Length, LengthContext};
+
+/// The values the relative units of an element are resolved against.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputeContext {
+    /// The computed font size of the element.
+    pub font_size: Length,
+    /// The computed font size of the parent element.
+    pub parent_font_size: Length,
+    /// The computed font size of the root element.
+    pub root_font_size: Length,
+    /// The computed font weight of the parent element.
+    pub parent_font_weight: f32,
+    /// The metrics of the font of the parent element, which the font-relative
+    /// units of font-size, and lh in line-height, refer to.
+    pub parent_font: FontMetrics,
+    /// What the lengths of the element are resolved against.
+    pub length: LengthContext,
+}
+
+impl Default for ComputeContext {
+    fn default() -> Self {
+        Self {
+            font_size: FontSize::medium(),
+            parent_font_size: FontSize::medium(),
+            root_font_size: FontSize::medium(),
+            parent_font_weight: FontWeight::NORMAL,
+            parent_font: FontMetrics::default(),
+            length: LengthContext::default(),
+        }
+    }
+}
@@ -0,0 +1,366 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::style::{
+    CascadedValues, ComponentValue, Function, SimpleBlock, SpecProperty, StyleError, TokenKind,
+};
+
+use super::{specified_with, ComputeContext, PropertyRegistration, PropertyRegistry, SyntaxValue};
+
+/// Checks if the value references a custom property with var().
+pub fn contains_var(value: &[ComponentValue]) -> bool {
+    value.iter().any(|cv| match cv {
+        ComponentValue::Function(function) => function.is("var") || contains_var(&function.value),
+        ComponentValue::Block(block) => contains_var(&block.values),
+        ComponentValue::Token(_) => false,
+    })
+}
+
+fn trim(value: &[ComponentValue]) -> &[ComponentValue] {
+    let start = value.iter().take_while(|cv| cv.is_whitespace()).count();
+    let end = value.len()
+        - value[start..]
+            .iter()
+            .rev()
+            .take_while(|cv| cv.is_whitespace())
+            .count();
+    &value[start..end]
+}
+
+/// Split the arguments of var() into the custom property name and the fallback.
+///
+/// # Grammar
+/// var( <custom-property-name> , <declaration-value>? )
+fn var_arguments(function: &Function) -> Result<(&str, Option<&[ComponentValue]>), StyleError> {
+    let mut args = function
+        .value
+        .iter()
+        .enumerate()
+        .filter(|(_, cv)| !cv.is_whitespace());
+
+    let name = match args.next().and_then(|(_, cv)| cv.as_token_kind()) {
+        Some(TokenKind::Ident(name)) if name.starts_with("--") => name.as_str(),
+        _ => return Err(StyleError::InvalidValue(&["<custom-property-name>"])),
+    };
+
+    match args.next() {
+        None => Ok((name, None)),
+        Some((i, cv)) if cv.is_comma() => Ok((name, Some(trim(&function.value[i + 1..])))),
+        Some(_) => Err(StyleError::InvalidValue(&[","])),
+    }
+}
+
+/// Substitute the var() functions of the value, the references being resolved
+/// by the given function.
+fn substitute_with<F>(
+    value: &[ComponentValue],
+    resolve: &mut F,
+) -> Result<Vec<ComponentValue>, StyleError>
+where
+    F: FnMut(&str) -> Option<Vec<ComponentValue>>,
+{
+    let mut substituted = Vec::<ComponentValue>::default();
+
+    for cv in value.iter() {
+        match cv {
+            ComponentValue::Function(function) if function.is("var") => {
+                let (name, fallback) = var_arguments(function)?;
+
+                match (resolve(name), fallback) {
+                    (Some(value), _) => substituted.extend(value),
+                    (None, Some(fallback)) => {
+                        substituted.extend(substitute_with(fallback, resolve)?)
+                    }
+                    (None, None) => {
+                        return Err(StyleError::InvalidValue(&["<custom-property-name>"]))
+                    }
+                }
+            }
+            ComponentValue::Function(function) => {
+                substituted.push(ComponentValue::Function(Function {
+                    name: function.name.clone(),
+                    value: substitute_with(&function.value, resolve)?,
+                }))
+            }
+            ComponentValue::Block(block) => substituted.push(ComponentValue::Block(SimpleBlock {
+                delimiter: block.delimiter,
+                values: substitute_with(&block.values, resolve)?,
+            })),
+            cv => substituted.push(cv.clone()),
+        }
+    }
+
+    Ok(substituted)
+}
+
+/// The computed values of the custom properties of an element, as token lists.
+///
+/// Custom properties are always inherited, unless registered otherwise, a
+/// property with the guaranteed-invalid value is absent. The values of the
+/// registered properties are also kept typed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CustomProperties {
+    values: BTreeMap<String, Vec<ComponentValue>>,
+    typed: BTreeMap<String, SyntaxValue>,
+}
+
+impl CustomProperties {
+    pub fn get(&self, name: &str) -> Option<&[ComponentValue]> {
+        self.values.get(name).map(Vec::as_slice)
+    }
+
+    /// The typed value of a registered property.
+    pub fn get_typed(&self, name: &str) -> Option<&SyntaxValue> {
+        self.typed.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[ComponentValue])> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_slice()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn insert_typed(&mut self, name: &str, value: SyntaxValue) {
+        self.values
+            .insert(name.to_string(), value.to_component_values());
+        self.typed.insert(name.to_string(), value);
+    }
+
+    fn remove(&mut self, name: &str) {
+        self.values.remove(name);
+        self.typed.remove(name);
+    }
+
+    /// Copy the value of the property from another set of properties.
+    fn copy_from(&mut self, name: &str, other: Option<&CustomProperties>) {
+        match other.and_then(|other| other.values.get(name)) {
+            Some(value) => {
+                self.values.insert(name.to_string(), value.clone());
+            }
+            None => {
+                self.values.remove(name);
+            }
+        }
+
+        match other.and_then(|other| other.typed.get(name)) {
+            Some(value) => {
+                self.typed.insert(name.to_string(), value.clone());
+            }
+            None => {
+                self.typed.remove(name);
+            }
+        }
+    }
+
+    /// Reset the property to its initial value.
+    fn reset(&mut self, name: &str, registration: Option<&PropertyRegistration>) {
+        match registration.and_then(|registration| registration.initial.clone()) {
+            Some(initial) => self.insert_typed(name, initial),
+            None => self.remove(name),
+        }
+    }
+
+    /// Substitute the var() functions of the value.
+    ///
+    /// Fails if a referenced property is invalid and the var() has no fallback.
+    pub fn substitute(&self, value: &[ComponentValue]) -> Result<Vec<ComponentValue>, StyleError> {
+        substitute_with(value, &mut |name| self.get(name).map(<[_]>::to_vec))
+    }
+
+    /// Compute the custom properties of an element, given the ones of its parent.
+    ///
+    /// The references between the properties of the element are substituted, the
+    /// properties taking part in a cycle are invalid at computed-value time.
+    ///
+    /// The values of the registered properties must match their syntax, else
+    /// the declaration is skipped, or the property is invalid at computed-value
+    /// time if the mismatch comes from a substitution.
+    pub fn compute(
+        values: &CascadedValues,
+        parent: Option<&CustomProperties>,
+        registry: &PropertyRegistry,
+    ) -> Self {
+        let mut computed = parent.cloned().unwrap_or_default();
+        let mut specified = BTreeMap::<String, Vec<ComponentValue>>::default();
+
+        for (name, registration) in registry.iter() {
+            if !registration.inherits || parent.and_then(|p| p.get(name)).is_none() {
+                computed.reset(name, Some(registration));
+            }
+        }
+
+        for name in values.names().filter(|name| name.starts_with("--")) {
+            let registration = registry.get(name);
+
+            match specified_with(values.candidates(name), |decl| {
+                match registration {
+                    Some(registration) if !contains_var(&decl.value) => {
+                        SyntaxValue::parse(&registration.syntax, &decl.value)?;
+                    }
+                    _ => {}
+                }
+                Ok(SpecProperty::Value(decl.value.clone()))
+            }) {
+                SpecProperty::Initial => computed.reset(name, registration),
+                SpecProperty::Inherit => computed.copy_from(name, parent),
+                SpecProperty::Unset => {}
+                SpecProperty::Value(value) => {
+                    specified.insert(name.to_string(), value);
+                }
+            }
+        }
+
+        let mut resolver = CustomResolver {
+            specified: &specified,
+            inherited: &computed,
+            resolved: BTreeMap::default(),
+            stack: Vec::default(),
+            cyclic: BTreeSet::default(),
+        };
+
+        for name in specified.keys() {
+            resolver.resolve(name);
+        }
+
+        for (name, value) in resolver.resolved {
+            let Some(registration) = registry.get(&name) else {
+                match value {
+                    Some(value) => computed.values.insert(name, value),
+                    None => computed.values.remove(&name),
+                };
+                continue;
+            };
+
+            match value.map(|value| SyntaxValue::parse(&registration.syntax, &value)) {
+                Some(Ok(value)) => computed.insert_typed(&name, value),
+                // Invalid at computed-value time, the property is unset.
+                _ if registration.inherits => computed.copy_from(&name, parent),
+                _ => computed.reset(&name, Some(registration)),
+            }
+        }
+
+        computed
+    }
+
+    /// Compute the typed values of the registered properties, resolving
+    /// their relative units.
+    pub fn compute_typed(&mut self, context: &ComputeContext) {
+        let names = self.typed.keys().cloned().collect::<Vec<_>>();
+
+        for name in names {
+            let value = self.typed[&name].compute(context);
+            self.insert_typed(&name, value);
+        }
+    }
+}
+
+/// Resolve the references between the custom properties of an element.
+struct CustomResolver<'a> {
+    specified: &'a BTreeMap<String, Vec<ComponentValue>>,
+    inherited: &'a CustomProperties,
+    /// The computed values, None if invalid at computed-value time.
+    resolved: BTreeMap<String, Option<Vec<ComponentValue>>>,
+    /// The properties being resolved.
+    stack: Vec<String>,
+    cyclic: BTreeSet<String>,
+}
+
+impl CustomResolver<'_> {
+    fn resolve(&mut self, name: &str) -> Option<Vec<ComponentValue>> {
+        if let Some(resolved) = self.resolved.get(name) {
+            return resolved.clone();
+        }
+
+        let specified = self.specified;
+        let Some(value) = specified.get(name) else {
+            return self.inherited.get(name).map(<[_]>::to_vec);
+        };
+
+        if let Some(position) = self.stack.iter().position(|n| n == name) {
+            self.cyclic.extend(self.stack[position..].iter().cloned());
+            return None;
+        }
+
+        self.stack.push(name.to_string());
+        let substituted = substitute_with(value, &mut |reference| self.resolve(reference));
+        self.stack.pop();
+
+        let resolved = match substituted {
+            Ok(value) if !self.cyclic.contains(name) => Some(value),
+            _ => None,
+        };
+
+        self.resolved.insert(name.to_string(), resolved.clone());
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        parse_component_values, testing::TestDocument, Cascade, CascadeInputs, Origin, Stylesheet,
+    };
+
+    use super::{contains_var, CustomProperties};
+
+    fn serialize(value: Option<&[crate::style::ComponentValue]>) -> Option<String> {
+        value.map(|value| value.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn test_001_substitute() {
+        let author = Stylesheet::from(
+            "html { --brand: #0af; --gap: 1em; --pair: var(--gap) var(--gap) }
+            body { --brand: initial; --accent: var(--missing, var(--gap)); --empty:; }
+            p { --a: var(--b); --b: var(--c, 1px); --c: var(--a); --d: var(--a, 2px); --gap: inherit }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let p = doc.create(Some(body), "p", &[]);
+
+        let compute = |node, parent: Option<&CustomProperties>| {
+            let values = cascade.cascade(&doc.element(node), None, None, &CascadeInputs::default());
+            CustomProperties::compute(&values, parent, cascade.registry())
+        };
+
+        let html = compute(html, None);
+        assert_eq!(serialize(html.get("--brand")).as_deref(), Some("#0af"));
+        assert_eq!(serialize(html.get("--pair")).as_deref(), Some("1em 1em"));
+
+        let body = compute(body, Some(&html));
+        assert_eq!(body.get("--brand"), None);
+        assert_eq!(serialize(body.get("--accent")).as_deref(), Some("1em"));
+        assert_eq!(serialize(body.get("--empty")).as_deref(), Some(""));
+        assert_eq!(serialize(body.get("--pair")).as_deref(), Some("1em 1em"));
+
+        // --a, --b and --c form a cycle, even if --b has a fallback.
+        let p = compute(p, Some(&body));
+        assert_eq!(p.get("--a"), None);
+        assert_eq!(p.get("--b"), None);
+        assert_eq!(p.get("--c"), None);
+        assert_eq!(serialize(p.get("--d")).as_deref(), Some("2px"));
+        assert_eq!(serialize(p.get("--gap")).as_deref(), Some("1em"));
+
+        let value = parse_component_values("calc(var(--gap) * 2) var(--brand, red)");
+        assert!(contains_var(&value));
+        assert_eq!(
+            p.substitute(&value)
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<String>(),
+            "calc(1em * 2) red"
+        );
+        assert!(p.substitute(&parse_component_values("var(--a)")).is_err());
+    }
+}
@@ -0,0 +1,18 @@
+//! The computed values.
+//!
+//! The cascaded declarations of an element are turned into a [SpecStyle],
+//! applying the explicit defaulting keywords, then into a [ComputedStyle]
+//! by inheriting from the parent and resolving the relative units.
+mod context;
+mod custom;
+mod registry;
+mod resolver;
+mod specified;
+mod syntax;
+
+pub use context::*;
+pub use custom::*;
+pub use registry::*;
+pub use resolver::*;
+pub use specified::*;
+pub use syntax::*;
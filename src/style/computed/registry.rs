@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+
+use crate::style::{AtRule, DeclarationBlock, StyleError, TokenKind};
+
+use super::{PropertySyntax, SyntaxValue};
+
+/// A custom property registered by an @property rule.
+///
+/// [CSS Properties and Values API](https://drafts.css-houdini.org/css-properties-values-api/#at-property-rule)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyRegistration {
+    pub syntax: PropertySyntax,
+    pub inherits: bool,
+    /// The initial value, only optional with the universal syntax.
+    pub initial: Option<SyntaxValue>,
+}
+
+impl TryFrom<&AtRule> for PropertyRegistration {
+    type Error = StyleError;
+
+    /// Parse the descriptors of the rule, which is invalid if any is missing
+    /// or invalid.
+    fn try_from(rule: &AtRule) -> Result<Self, Self::Error> {
+        let descriptors = DeclarationBlock::from(rule.block());
+        let descriptor = |name: &str| {
+            descriptors.get(name).map(|decl| {
+                decl.value
+                    .iter()
+                    .filter(|cv| !cv.is_whitespace())
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        let syntax = match descriptor("syntax").as_deref() {
+            Some([cv]) => match cv.as_token_kind() {
+                Some(TokenKind::String(syntax)) => PropertySyntax::try_from(syntax.as_str())?,
+                _ => return Err(StyleError::InvalidValue(&["<string>"])),
+            },
+            _ => return Err(StyleError::InvalidDeclaration),
+        };
+
+        let inherits = match descriptor("inherits").as_deref() {
+            Some([cv]) => match cv.as_token_kind() {
+                Some(TokenKind::Ident(kw)) if kw.eq_ignore_ascii_case("true") => true,
+                Some(TokenKind::Ident(kw)) if kw.eq_ignore_ascii_case("false") => false,
+                _ => return Err(StyleError::InvalidValue(&["true", "false"])),
+            },
+            _ => return Err(StyleError::InvalidDeclaration),
+        };
+
+        let initial = match descriptors.get("initial-value") {
+            Some(decl) => {
+                let initial = SyntaxValue::parse(&syntax, &decl.value)?;
+                if !initial.is_computationally_independent() {
+                    return Err(StyleError::InvalidValue(&["<declaration-value>"]));
+                }
+                Some(initial)
+            }
+            None if syntax == PropertySyntax::Universal => None,
+            None => return Err(StyleError::InvalidDeclaration),
+        };
+
+        Ok(Self {
+            syntax,
+            inherits,
+            initial,
+        })
+    }
+}
+
+/// The custom properties registered by the stylesheets.
+#[derive(Debug, Clone, Default)]
+pub struct PropertyRegistry(BTreeMap<String, PropertyRegistration>);
+
+impl PropertyRegistry {
+    pub fn get(&self, name: &str) -> Option<&PropertyRegistration> {
+        self.0.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PropertyRegistration)> {
+        self.0
+            .iter()
+            .map(|(name, registration)| (name.as_str(), registration))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Register the property of an @property rule, the last valid rule wins.
+    pub fn register(&mut self, rule: &AtRule) -> Result<(), StyleError> {
+        let mut prelude = rule.prelude().iter().filter(|cv| !cv.is_whitespace());
+
+        let name = match (
+            prelude.next().and_then(|cv| cv.as_token_kind()),
+            prelude.next(),
+        ) {
+            (Some(TokenKind::Ident(name)), None) if name.starts_with("--") => name.clone(),
+            _ => return Err(StyleError::InvalidValue(&["<custom-property-name>"])),
+        };
+
+        let registration = PropertyRegistration::try_from(rule)?;
+        self.0.insert(name, registration);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        Angle, PropertySyntax, Rule, Stylesheet, SyntaxComponent, SyntaxComponentName,
+        SyntaxDataType, SyntaxMultiplier, SyntaxValue,
+    };
+
+    use super::PropertyRegistry;
+
+    #[test]
+    fn test_001_register() {
+        let sheet = Stylesheet::from(
+            "@property --angle { syntax: '<angle>'; inherits: false; initial-value: 0deg }
+            @property --sizes { syntax: '<length>+ | auto'; inherits: true; initial-value: auto }
+            @property --any { syntax: '*'; inherits: true }
+            @property --missing { syntax: '<length>'; inherits: true }
+            @property --relative { syntax: '<length>'; inherits: true; initial-value: 1em }
+            @property --mismatch { syntax: '<color>'; inherits: true; initial-value: 1cm }",
+        );
+
+        let mut registry = PropertyRegistry::default();
+        let results = sheet
+            .rules
+            .iter()
+            .map(|rule| match rule {
+                Rule::At(at) => registry.register(at).is_ok(),
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(results, vec![true, true, true, false, false, false]);
+        assert_eq!(registry.len(), 3);
+
+        let angle = registry.get("--angle").unwrap();
+        assert!(!angle.inherits);
+        assert_eq!(angle.initial, Some(SyntaxValue::Angle(Angle::deg(0))));
+
+        let sizes = registry.get("--sizes").unwrap();
+        assert_eq!(
+            sizes.syntax,
+            PropertySyntax::Components(vec![
+                SyntaxComponent {
+                    name: SyntaxComponentName::DataType(SyntaxDataType::Length),
+                    multiplier: SyntaxMultiplier::Space,
+                },
+                SyntaxComponent {
+                    name: SyntaxComponentName::Ident("auto".to_string()),
+                    multiplier: SyntaxMultiplier::One,
+                },
+            ])
+        );
+        assert_eq!(sizes.syntax.to_string(), "<length>+ | auto");
+        assert_eq!(sizes.initial, Some(SyntaxValue::Ident("auto".to_string())));
+
+        assert_eq!(registry.get("--any").unwrap().initial, None);
+    }
+}
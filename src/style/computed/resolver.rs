@@ -0,0 +1,895 @@
+use crate::{
+    fonts::{FontDatabase, FontQuery},
+    style::{
+        AncestorFilter, Cascade, CascadeInputs, ComputedStyle, Element, FontMetrics, FontSize,
+        FontWeight, LengthContext, PseudoElement, SpecStyle, ViewportSize,
+    },
+};
+
+use super::ComputeContext;
+
+impl ComputedStyle {
+    /// Compute the specified style of an element, given the computed styles
+    /// of its parent and of the root element, the size of the viewport, and
+    /// the fonts the font-relative units are measured with.
+    pub fn compute(
+        spec: &SpecStyle,
+        parent: Option<&ComputedStyle>,
+        root: Option<&ComputedStyle>,
+        viewport: ViewportSize,
+        fonts: &FontDatabase,
+    ) -> Self {
+        let parent_font_size = parent
+            .map(|parent| parent.font_size.clone())
+            .unwrap_or_else(FontSize::medium);
+        let root_font_size = root
+            .map(|root| root.font_size.clone())
+            .unwrap_or_else(FontSize::medium);
+
+        let mut context = ComputeContext {
+            font_size: parent_font_size.clone(),
+            parent_font_size,
+            root_font_size,
+            parent_font_weight: parent
+                .map(|parent| parent.font_weight)
+                .unwrap_or(FontWeight::NORMAL),
+            parent_font: FontMetrics::default(),
+            length: LengthContext::default(),
+        };
+
+        // The font of an element without parent is the initial one.
+        let font_metrics = |style: Option<&ComputedStyle>| match style {
+            Some(style) => style.font_metrics(fonts),
+            None => ComputedStyle::default().font_metrics(fonts),
+        };
+        context.parent_font = font_metrics(parent);
+        context.length = LengthContext {
+            font: context.parent_font,
+            root_font: font_metrics(root),
+            ..LengthContext::new(0.0, 0.0, viewport)
+        };
+        context.length.writing_mode = spec
+            .writing_mode
+            .compute(parent.map(|parent| &parent.writing_mode), &context);
+
+        // The other properties are relative to the font of the element, and
+        // lh to its computed line height.
+        context.font_size = spec
+            .font_size
+            .compute(parent.map(|parent| &parent.font_size), &context);
+        let font = spec.font(parent, &context, fonts);
+        context.length.font = font;
+        context.length.font.line_height = spec
+            .line_height
+            .compute(parent.map(|parent| &parent.line_height), &context)
+            .to_px(&font);
+
+        let mut style = Self::compute_longhands(spec, parent, &context);
+        style.compute_color(parent);
+        style.compute_border_widths();
+        style.compute_text_align(parent);
+        style.compute_item_display(parent);
+        style.compute_positioning(parent.is_none());
+        style.compute_overflow();
+        style
+    }
+
+    /// The metrics of the font selected for the style, the line height being
+    /// the computed one.
+    pub(crate) fn font_metrics(&self, fonts: &FontDatabase) -> FontMetrics {
+        let query = FontQuery {
+            weight: self.font_weight,
+            style: self.font_style,
+            stretch: f32::from(self.font_stretch),
+        };
+        let font_size = self.font_size.to_px(&LengthContext::default());
+
+        let mut metrics = fonts
+            .select(&self.font_family, &query)
+            .font
+            .metrics(font_size);
+        metrics.line_height = self.line_height.to_px(&metrics);
+        metrics
+    }
+}
+
+impl SpecStyle {
+    /// The metrics of the font selected for the specified style, the font
+    /// size being computed, with the normal line height.
+    fn font(
+        &self,
+        parent: Option<&ComputedStyle>,
+        context: &ComputeContext,
+        fonts: &FontDatabase,
+    ) -> FontMetrics {
+        let query = FontQuery {
+            weight: self
+                .font_weight
+                .compute(parent.map(|parent| &parent.font_weight), context),
+            style: self
+                .font_style
+                .compute(parent.map(|parent| &parent.font_style), context),
+            stretch: f32::from(
+                self.font_stretch
+                    .compute(parent.map(|parent| &parent.font_stretch), context),
+            ),
+        };
+        let family = self
+            .font_family
+            .compute(parent.map(|parent| &parent.font_family), context);
+        let font_size = context.font_size.to_px(&LengthContext::default());
+
+        fonts.select(&family, &query).font.metrics(font_size)
+    }
+}
+
+/// Resolve the computed styles of the elements of a document.
+pub struct StyleResolver<'a> {
+    cascade: &'a Cascade,
+    viewport: ViewportSize,
+    fonts: FontDatabase,
+}
+
+impl<'a> StyleResolver<'a> {
+    pub fn new(cascade: &'a Cascade) -> Self {
+        Self {
+            cascade,
+            viewport: ViewportSize::default(),
+            fonts: FontDatabase::default(),
+        }
+    }
+
+    /// Set the size of the viewport the viewport-relative units refer to.
+    pub fn with_viewport(mut self, viewport: ViewportSize) -> Self {
+        self.viewport = viewport;
+        self
+    }
+
+    /// Set the fonts the font-relative units are measured with.
+    pub fn with_fonts(mut self, fonts: FontDatabase) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// Resolve the style of an element, or one of its pseudo-elements, given
+    /// the computed styles of its parent, or originating element, and of the root.
+    pub fn resolve<E: Element>(
+        &self,
+        element: &E,
+        pseudo: Option<PseudoElement>,
+        parent: Option<&ComputedStyle>,
+        root: Option<&ComputedStyle>,
+        filter: Option<&AncestorFilter>,
+        inputs: &CascadeInputs,
+    ) -> ComputedStyle {
+        let values = self.cascade.cascade(element, pseudo, filter, inputs);
+        let spec = SpecStyle::new(&values, parent, self.cascade.registry());
+        ComputedStyle::compute(&spec, parent, root, self.viewport, &self.fonts)
+    }
+
+    /// Resolve the styles of the element and its descendants, in tree order.
+    ///
+    /// The element is styled as the root of the document.
+    pub fn resolve_tree<E: Element>(&self, root: &E) -> Vec<(E, ComputedStyle)> {
+        let mut styles = Vec::<(E, ComputedStyle)>::default();
+        let mut filter = AncestorFilter::for_ancestors_of(root);
+        self.resolve_subtree(root, None, &mut filter, &mut styles);
+        styles
+    }
+
+    fn resolve_subtree<E: Element>(
+        &self,
+        element: &E,
+        parent: Option<usize>,
+        filter: &mut AncestorFilter,
+        styles: &mut Vec<(E, ComputedStyle)>,
+    ) {
+        let style = self.resolve(
+            element,
+            None,
+            parent.map(|parent| &styles[parent].1),
+            styles.first().map(|(_, root)| root),
+            Some(filter),
+            &CascadeInputs::default(),
+        );
+
+        let index = styles.len();
+        styles.push((element.clone(), style));
+
+        filter.push(element);
+        for child in element.child_elements() {
+            self.resolve_subtree(&child, Some(index), filter, styles);
+        }
+        filter.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::Edge,
+        style::{
+            testing::TestDocument, BackgroundClip, BaselinePosition, BoxSizing, Cascade, Color,
+            ContainingBlock, ContainingBlockArea, ContentAlignment, ContentPosition, Direction,
+            Display, FlexWrap, Float, FontFamily, FontStyle, FontVariantCaps, GridLine,
+            GridTemplateAreas, Inset, Keyword, Length, LengthContext, LengthPercentage, LineHeight,
+            Margin, MaxSize, Origin, Overflow, OverflowPosition, PositionScheme, PropertyId, Rgba,
+            SelfAlignment, Size, Style, Stylesheet, SyntaxValue, TabSize, TextAlign, TextAlignLast,
+            TextDecorationStyle, TextWrap, Value, ViewportSize, WhiteSpaceCollapse, WritingMode,
+            ZIndex,
+        },
+    };
+
+    use super::StyleResolver;
+
+    #[test]
+    fn test_001_inheritance_and_relative_units() {
+        let author = Stylesheet::from(
+            "html { font-size: 2em; font-family: serif }
+            body { font-size: 50%; display: block; background-clip: content-box }
+            p { font-size: 1.5rem; background-clip: inherit }
+            span { display: inherit; font-family: initial }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let p = doc.create(Some(body), "p", &[]);
+        let span = doc.create(Some(body), "span", &[]);
+
+        let styles = StyleResolver::new(&cascade).resolve_tree(&doc.element(html));
+        assert_eq!(
+            styles.iter().map(|(e, _)| e.node).collect::<Vec<_>>(),
+            vec![html, body, p, span]
+        );
+
+        let font_sizes = styles
+            .iter()
+            .map(|(_, style)| style.font_size.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            font_sizes,
+            vec![
+                Length::px(32.0),
+                Length::px(16.0),
+                Length::px(48.0),
+                Length::px(16.0)
+            ]
+        );
+
+        let serif = FontFamily::try_from(Value::from(Keyword::Serif)).unwrap();
+        assert_eq!(styles[2].1.font_family, serif);
+        assert_eq!(styles[3].1.font_family, FontFamily::default());
+
+        // background-clip is not inherited, unless explicitly.
+        assert_eq!(
+            styles[2].1.background_clip[..],
+            [BackgroundClip::ContentBox]
+        );
+        assert_eq!(styles[3].1.background_clip[..], [BackgroundClip::BorderBox]);
+
+        assert_eq!(styles[3].1.display, styles[1].1.display);
+        assert_ne!(styles[2].1.display, styles[1].1.display);
+        assert_eq!(styles[2].1.display, Display::default());
+    }
+
+    #[test]
+    fn test_002_var_substitution() {
+        let author = Stylesheet::from(
+            "html { --size: 2em; --color: red; font-size: 20% }
+            body { font-size: var(--size) }
+            p { font-size: 10%; font-size: var(--color) }
+            span { font-size: var(--missing, 50%) }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+        doc.create(Some(body), "span", &[]);
+
+        let styles = StyleResolver::new(&cascade).resolve_tree(&doc.element(html));
+        let font_sizes = styles
+            .iter()
+            .map(|(_, style)| style.font_size.clone())
+            .collect::<Vec<_>>();
+
+        // The invalid substitution makes the font-size of p unset, thus inherited.
+        assert_eq!(
+            font_sizes,
+            vec![
+                Length::px(3.2),
+                Length::px(6.4),
+                Length::px(6.4),
+                Length::px(3.2)
+            ]
+        );
+        assert!(styles[3].1.custom.get("--color").is_some());
+    }
+
+    #[test]
+    fn test_003_registered_properties() {
+        let author = Stylesheet::from(
+            "@property --angle { syntax: '<angle>'; inherits: false; initial-value: 0deg }
+            @property --gap { syntax: '<length>'; inherits: true; initial-value: 1cm }
+            @property --colors { syntax: '<color>#'; inherits: true; initial-value: #000 }
+            html { font-size: 2em; --angle: 45deg; --gap: 2em; --colors: #fff, #f00 }
+            body { --gap: red; --colors: var(--angle) }
+            span { --angle: inherit; --gap: var(--angle) }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+        assert_eq!(cascade.registry().len(), 3);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+        doc.create(Some(html), "span", &[]);
+
+        let styles = StyleResolver::new(&cascade).resolve_tree(&doc.element(html));
+        let typed = |index: usize, name: &str| styles[index].1.custom.get_typed(name).cloned();
+        let serialize = |index: usize, name: &str| typed(index, name).unwrap().to_string();
+        let gap = Some(SyntaxValue::Length(Length::px(64.0)));
+
+        // The relative lengths are computed.
+        assert_eq!(serialize(0, "--angle"), "45deg");
+        assert_eq!(typed(0, "--gap"), gap);
+        assert_eq!(
+            serialize(0, "--colors"),
+            "rgb(255, 255, 255), rgb(255, 0, 0)"
+        );
+
+        // --angle is not inherited, the invalid --gap is skipped, and the
+        // mismatching substitution makes --colors invalid at computed-value time.
+        assert_eq!(serialize(1, "--angle"), "0deg");
+        assert_eq!(typed(1, "--gap"), gap);
+        assert_eq!(
+            serialize(1, "--colors"),
+            "rgb(255, 255, 255), rgb(255, 0, 0)"
+        );
+
+        assert_eq!(serialize(2, "--angle"), "45deg");
+        assert_eq!(typed(2, "--gap"), gap);
+    }
+
+    #[test]
+    fn test_004_math_functions() {
+        let author = Stylesheet::from(
+            "html { font-size: calc(1in + 50%) }
+            body { font-size: calc(2em - 1rem) }
+            p { font-size: min(10%, 1in) }
+            span { font-size: calc(1s + 1em) }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+        doc.create(Some(body), "span", &[]);
+
+        let styles = StyleResolver::new(&cascade).resolve_tree(&doc.element(html));
+        let font_sizes = styles
+            .iter()
+            .map(|(_, style)| style.font_size.clone())
+            .collect::<Vec<_>>();
+
+        // The mistyped expression of span is invalid, thus the font size inherited.
+        assert_eq!(
+            font_sizes,
+            vec![
+                Length::px(104.0),
+                Length::px(104.0),
+                Length::px(10.4),
+                Length::px(104.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_005_relative_colors() {
+        let author = Stylesheet::from(
+            "@property --color { syntax: '<color>'; inherits: true; initial-value: black }
+            html { --base: #0000ff; --color: rgb(from var(--base) r g b / 50%) }
+            body { --color: color-mix(in srgb, var(--base), white) }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = StyleResolver::new(&cascade).resolve_tree(&doc.element(html));
+        let serialize = |index: usize| {
+            styles[index]
+                .1
+                .custom
+                .get_typed("--color")
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(serialize(0), "rgba(0, 0, 255, 0.5)");
+        assert_eq!(serialize(1), "color(srgb 0.5 0.5 1)");
+    }
+
+    #[test]
+    fn test_006_box_model() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px }
+            body { margin: 1em auto; padding: 5% 2rem; border: thick solid; \
+                border-left-style: none; border-top-width: 2em; box-sizing: border-box; \
+                width: fit-content(10em); max-height: min-content }",
+        );
+        let body = &styles[1];
+
+        let margin = body.margin();
+        assert_eq!(
+            margin.top,
+            Margin::LengthPercentage(Length::px(10.0).into())
+        );
+        assert_eq!(margin.left, Margin::Auto);
+
+        let padding = body.padding();
+        assert_eq!(padding.top, LengthPercentage::Percentage(5.0));
+        assert_eq!(padding.right, LengthPercentage::Length(Length::px(20.0)));
+        let context = LengthContext::default();
+        assert_eq!(padding.top.to_px(200.0, &context), 10.0);
+
+        // The sides without a border style have no border.
+        assert_eq!(
+            body.border_width(),
+            Edge {
+                left: Length::px(0),
+                right: Length::px(5),
+                top: Length::px(20.0),
+                bottom: Length::px(5),
+            }
+        );
+        assert_eq!(body.value(PropertyId::BorderLeftWidth).to_string(), "0px");
+        assert_eq!(styles[0].border_width().top, Length::px(0));
+
+        assert_eq!(body.box_sizing, BoxSizing::BorderBox);
+        assert_eq!(body.width, Size::FitContent(Some(Length::px(100.0).into())));
+        assert_eq!(body.max_height, MaxSize::Size(Size::MinContent));
+        assert_eq!(body.min_width, Size::Auto);
+    }
+
+    #[test]
+    fn test_007_logical_properties() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let p = doc.create(Some(body), "p", &[]);
+        doc.create(Some(p), "span", &[]);
+
+        let styles = doc.resolve(
+            "html { margin-left: 1px; margin-inline-start: 2px; padding-block: 3px 4px; \
+                inset-inline: 5px auto; border-block-start: 1px solid }
+            body { margin-inline-start: 2px; margin-left: 1px; direction: rtl }
+            p { margin-inline: 6px 7px; writing-mode: vertical-rl; inline-size: 10px; \
+                border-start-end-radius: 3px }
+            span { direction: initial; margin-block-end: 8px }",
+        );
+        // The margins clockwise from the top one.
+        let margin = |style: &Style| {
+            let margin = style.margin();
+            [margin.top, margin.right, margin.bottom, margin.left].map(|side| side.to_string())
+        };
+
+        // The later of a physical and a logical declaration wins.
+        let html = &styles[0];
+        assert_eq!(margin(html)[3], "2px");
+        assert_eq!(margin(&styles[1])[3], "1px");
+
+        assert_eq!(
+            html.padding(),
+            Edge {
+                left: LengthPercentage::zero(),
+                right: LengthPercentage::zero(),
+                top: LengthPercentage::Length(Length::px(3.0)),
+                bottom: LengthPercentage::Length(Length::px(4.0)),
+            }
+        );
+        assert_eq!(
+            html.inset().left,
+            Inset::LengthPercentage(Length::px(5.0).into())
+        );
+        assert_eq!(html.inset().right, Inset::Auto);
+        assert_eq!(html.border_width().top, Length::px(1.0));
+        assert_eq!(
+            html.value(PropertyId::MarginInlineStart),
+            html.value(PropertyId::MarginLeft)
+        );
+
+        // The inline start is on the right in right-to-left, and at the
+        // top, or at the bottom, in vertical writing modes.
+        let p = &styles[2];
+        assert_eq!(p.direction, Direction::Rtl);
+        assert_eq!(p.writing_mode, WritingMode::VerticalRl);
+        assert_eq!(margin(p), ["7px", "0px", "6px", "0px"]);
+        assert_eq!(p.height, Size::LengthPercentage(Length::px(10.0).into()));
+        assert_eq!(p.width, Size::Auto);
+        assert_eq!(p.value(PropertyId::InlineSize).to_string(), "10px");
+        assert_eq!(p.border_top_right_radius.to_string(), "3px");
+
+        let span = &styles[3];
+        assert_eq!(span.direction, Direction::Ltr);
+        assert_eq!(span.writing_mode, WritingMode::VerticalRl);
+        assert_eq!(margin(span), ["0px", "0px", "0px", "8px"]);
+    }
+
+    #[test]
+    fn test_008_font_properties() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let p = doc.create(Some(body), "p", &[]);
+        doc.create(Some(p), "span", &[]);
+
+        let styles = doc.resolve(
+            "html { font: italic 300 larger/150% serif }
+            body { font-weight: bolder; font-size: x-small; font-stretch: 80% }
+            p { font-weight: lighter; font-size: smaller; line-height: 2; \
+                font-variant: small-caps tabular-nums }
+            span { font-style: oblique -20deg; font-stretch: expanded; line-height: 1em }",
+        );
+
+        // The line height percentage is resolved against the font size of
+        // the element, 1.2 times the medium size of 16px.
+        let html = &styles[0];
+        assert_eq!(html.font_style, FontStyle::Italic);
+        assert_eq!(html.font_weight, 300.0);
+        assert_eq!(html.font_size, Length::px(19.2));
+        assert_eq!(
+            html.line_height,
+            LineHeight::LengthPercentage(Length::px(28.8).into())
+        );
+
+        let body = &styles[1];
+        assert_eq!(body.font_weight, 400.0);
+        assert_eq!(body.font_size, Length::px(12.0));
+        assert_eq!(body.font_stretch.to_string(), "80%");
+        assert_eq!(body.font_style, FontStyle::Italic);
+
+        let p = &styles[2];
+        assert_eq!(p.font_weight, 100.0);
+        assert_eq!(p.font_size, Length::px(10.0));
+        assert_eq!(p.line_height, LineHeight::Number(2.0));
+        assert_eq!(
+            p.value(PropertyId::FontVariantCaps).to_string(),
+            "small-caps"
+        );
+        assert_eq!(
+            p.value(PropertyId::FontVariantNumeric).to_string(),
+            "tabular-nums"
+        );
+
+        let span = &styles[3];
+        assert_eq!(span.font_style.to_string(), "oblique -20deg");
+        assert_eq!(span.font_stretch.to_string(), "125%");
+        assert_eq!(
+            span.line_height,
+            LineHeight::LengthPercentage(Length::px(10.0).into())
+        );
+        assert_eq!(span.font_variant_caps, FontVariantCaps::SmallCaps);
+    }
+
+    #[test]
+    fn test_009_text_properties() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let p = doc.create(Some(body), "p", &[]);
+        doc.create(Some(p), "span", &[]);
+
+        let styles = doc.resolve(
+            "html { text-align: end; letter-spacing: 0.5em; text-indent: 10% hanging; \
+                text-shadow: 1px 1px 2px red, blue 1em 0 }
+            body { direction: rtl; text-align: match-parent; word-spacing: normal; \
+                text-decoration: underline dotted; tab-size: 2em }
+            p { text-align: match-parent; text-transform: uppercase full-width; \
+                white-space: pre-line; font-size: 20px }
+            span { text-align-last: match-parent }",
+        );
+
+        let html = &styles[0];
+        assert_eq!(html.text_align, TextAlign::End);
+        assert_eq!(html.letter_spacing, Length::px(8.0).into());
+        assert_eq!(html.text_indent.to_string(), "10% hanging");
+        assert_eq!(
+            html.text_shadow.to_string(),
+            "1px 1px 2px red, 16px 0px blue"
+        );
+
+        // The start and end of the parent are made physical with its
+        // direction.
+        let body = &styles[1];
+        assert_eq!(body.text_align, TextAlign::Right);
+        assert_eq!(body.word_spacing, LengthPercentage::zero());
+        assert!(body.text_decoration_line.underline);
+        assert_eq!(body.text_decoration_style, TextDecorationStyle::Dotted);
+        assert_eq!(body.tab_size, TabSize::Length(Length::px(32.0)));
+
+        let p = &styles[2];
+        assert_eq!(p.text_align, TextAlign::Right);
+        assert_eq!(p.white_space_collapse, WhiteSpaceCollapse::PreserveBreaks);
+        assert_eq!(p.text_wrap, TextWrap::Wrap);
+        assert_eq!(p.text_transform.to_string(), "uppercase full-width");
+        assert!(!p.text_decoration_line.underline);
+        // The inherited letter spacing is the computed length.
+        assert_eq!(p.letter_spacing, Length::px(8.0).into());
+
+        let span = &styles[3];
+        assert_eq!(span.text_align, TextAlign::Right);
+        assert_eq!(span.text_align_last, TextAlignLast::Auto);
+        assert_eq!(span.text_transform.apply("hello"), "HELLO");
+    }
+
+    #[test]
+    fn test_010_positioning() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let div = doc.create(Some(body), "div", &[]);
+        doc.create(Some(div), "span", &[]);
+        doc.create(Some(div), "em", &[]);
+        doc.create(Some(div), "i", &[]);
+
+        let styles = doc.resolve(
+            "body { display: block; position: relative; overflow: clip visible }
+            div { display: inline; overflow-x: hidden }
+            span { position: absolute; float: right; z-index: 3 }
+            em { float: inline-start }
+            i { position: fixed }",
+        );
+        let [html, body, div, span, em, i] = [0, 1, 2, 3, 4, 5].map(|index| &styles[index]);
+        let block = Display::try_from(Value::from(Keyword::Block)).unwrap();
+
+        // The root element is blockified.
+        assert_eq!(html.display, block);
+        assert_eq!(html.containing_block([]), ContainingBlock::Initial);
+        assert_eq!(
+            body.containing_block([html]),
+            ContainingBlock::Ancestor {
+                depth: 0,
+                area: ContainingBlockArea::ContentBox
+            }
+        );
+
+        // An axis scrolling makes the other one scroll too.
+        assert_eq!(
+            (body.overflow_x, body.overflow_y),
+            (Overflow::Clip, Overflow::Visible)
+        );
+        assert_eq!(
+            (div.overflow_x, div.overflow_y),
+            (Overflow::Hidden, Overflow::Auto)
+        );
+        assert!(div.display.is_inline_flow());
+
+        // The absolutely positioned boxes don't float, and are contained by
+        // the padding box of their nearest positioned ancestor.
+        assert_eq!(span.position, PositionScheme::Absolute);
+        assert_eq!(span.float, Float::None);
+        assert_eq!(span.display, block);
+        assert_eq!(span.z_index, ZIndex::Integer(3));
+        assert_eq!(
+            span.containing_block([div, body, html]),
+            ContainingBlock::Ancestor {
+                depth: 1,
+                area: ContainingBlockArea::PaddingBox
+            }
+        );
+
+        // The inline boxes aren't containing blocks.
+        assert_eq!(em.float, Float::InlineStart);
+        assert_eq!(em.display, block);
+        assert_eq!(
+            em.containing_block([div, body, html]),
+            ContainingBlock::Ancestor {
+                depth: 1,
+                area: ContainingBlockArea::ContentBox
+            }
+        );
+        assert_eq!(
+            i.containing_block([div, body, html]),
+            ContainingBlock::Viewport
+        );
+        assert_eq!(i.z_index, ZIndex::Auto);
+    }
+
+    #[test]
+    fn test_011_flex_items() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "span", &[]);
+        doc.create(Some(body), "em", &[]);
+
+        let styles = doc.resolve(
+            "body { display: flex; flex-flow: column wrap; justify-content: safe center; \
+                align-items: last baseline; gap: 1em 5% }
+            span { flex: 2 3em; order: -1; align-self: flex-end }
+            em { display: contents }",
+        );
+        let [body, span, em] = [1, 2, 3].map(|index| &styles[index]);
+
+        assert!(body.display.is_flex_container());
+        assert!(body.flex_direction.is_column());
+        assert_eq!(body.flex_wrap, FlexWrap::Wrap);
+        assert_eq!(
+            *body.justify_content,
+            ContentAlignment::Position(Some(OverflowPosition::Safe), ContentPosition::Center)
+        );
+        assert_eq!(
+            *body.align_items,
+            SelfAlignment::Baseline(BaselinePosition::Last)
+        );
+        assert_eq!(
+            (body.row_gap.to_string(), body.column_gap.to_string()),
+            ("16px".to_string(), "5%".to_string())
+        );
+
+        // The flex items are blockified, unless they generate no box.
+        assert_eq!(
+            span.display,
+            Display::try_from(Value::from(Keyword::Block)).unwrap()
+        );
+        assert!(em.display.is_contents());
+        assert_eq!((span.flex_grow, span.flex_shrink), (2.0, 1.0));
+        assert_eq!(span.flex_basis.to_string(), "48px");
+        assert_eq!(span.order, -1);
+        assert_eq!(span.align_self.to_string(), "flex-end");
+        assert_eq!(*em.align_self, SelfAlignment::Auto);
+    }
+
+    #[test]
+    fn test_012_grid_items() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "span", &[]);
+
+        let styles = doc.resolve(
+            "body { display: grid; grid-template-columns: 2em minmax(min-content, 1fr); \
+                grid-template-areas: 'head head' 'nav main'; grid-auto-flow: column }
+            span { grid-area: nav; display: inline }",
+        );
+        let [body, span] = [1, 2].map(|index| &styles[index]);
+
+        assert!(body.display.is_grid_container());
+        assert_eq!(
+            body.grid_template_columns.to_string(),
+            "32px minmax(min-content, 1fr)"
+        );
+        let GridTemplateAreas::Areas(areas) = &body.grid_template_areas else {
+            panic!("expected named areas");
+        };
+        assert_eq!((areas.rows(), areas.columns()), (2, 2));
+        assert_eq!(
+            areas.area("head").map(|area| area.columns.clone()),
+            Some(0..2)
+        );
+        assert_eq!(areas.area("main").map(|area| area.rows.clone()), Some(1..2));
+        assert!(body.grid_auto_flow.column);
+
+        // The grid items are blockified.
+        assert_eq!(
+            span.display,
+            Display::try_from(Value::from(Keyword::Block)).unwrap()
+        );
+        assert_eq!(span.grid_row_start, GridLine::Ident("nav".to_string()));
+        assert_eq!(span.grid_column_end, GridLine::Ident("nav".to_string()));
+    }
+
+    #[test]
+    fn test_013_viewport_units() {
+        let author = Stylesheet::from(
+            "html { font-size: 2vw; width: 50vw; height: calc(50vh - 1em); \
+                text-indent: calc(50% + 1em) }
+            p { writing-mode: vertical-rl; width: 10vi; margin-left: 2ex; font-size: 1.5em; \
+                min-width: calc(100% - 1em) }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "p", &[]);
+
+        let styles = StyleResolver::new(&cascade)
+            .with_viewport(ViewportSize::new(800.0, 600.0))
+            .resolve_tree(&doc.element(html));
+        let value = |index: usize, id: PropertyId| styles[index].1.value(id).to_string();
+
+        assert_eq!(value(0, PropertyId::FontSize), "16px");
+        assert_eq!(value(0, PropertyId::Width), "400px");
+        assert_eq!(value(0, PropertyId::Height), "284px");
+        assert_eq!(value(0, PropertyId::TextIndent), "calc(50% + 16px)");
+
+        // The inline axis is vertical, and ex comes from the font metrics.
+        assert_eq!(value(1, PropertyId::FontSize), "24px");
+        assert_eq!(value(1, PropertyId::Width), "60px");
+        assert_eq!(value(1, PropertyId::MarginLeft), "25.359375px");
+
+        // The percentages are kept, the em being resolved before it is inherited.
+        assert_eq!(value(1, PropertyId::TextIndent), "calc(50% + 16px)");
+        assert_eq!(value(1, PropertyId::MinWidth), "calc(100% - 24px)");
+    }
+
+    #[test]
+    fn test_014_font_relative_units() {
+        let author = Stylesheet::from(
+            "html { font-size: 10px; line-height: 30px }
+            body { font-size: 12px; line-height: 2; margin: 1lh 1rlh 1cap 1ch }
+            p { line-height: 1.5lh; text-indent: 1lh; padding-left: 1ex }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        let styles = StyleResolver::new(&cascade).resolve_tree(&doc.element(html));
+        let value = |index: usize, id: PropertyId| styles[index].1.value(id).to_string();
+
+        // The line heights are the computed ones, of the element and the root.
+        assert_eq!(value(1, PropertyId::MarginTop), "24px");
+        assert_eq!(value(1, PropertyId::MarginRight), "30px");
+
+        // The other units come from the metrics of Liberation Sans.
+        assert_eq!(value(1, PropertyId::MarginBottom), "8.255859px");
+        assert_eq!(value(1, PropertyId::MarginLeft), "6.673828px");
+
+        // In line-height, lh refers to the line height of the parent.
+        assert_eq!(value(2, PropertyId::LineHeight), "36px");
+        assert_eq!(value(2, PropertyId::TextIndent), "36px");
+        assert_eq!(value(2, PropertyId::PaddingLeft), "6.3398438px");
+    }
+
+    #[test]
+    fn test_015_color() {
+        let author = Stylesheet::from(
+            "html { --brand: blue }
+            body { color: var(--brand, red); border-top-color: currentcolor }
+            p { color: var(--missing, green) }
+            em { color: currentcolor }
+            span { color: var(--brand) 12px }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+        doc.create(Some(body), "em", &[]);
+        doc.create(Some(body), "span", &[]);
+
+        let styles = StyleResolver::new(&cascade).resolve_tree(&doc.element(html));
+        let color = |index: usize| styles[index].1.value(PropertyId::Color).to_string();
+
+        assert_eq!(color(0), "canvastext");
+        assert_eq!(color(1), "blue");
+        assert_eq!(color(2), "green");
+        // currentcolor in color is inherited, and the invalid substitution too.
+        assert_eq!(color(3), "blue");
+        assert_eq!(color(4), "blue");
+
+        // The other properties keep currentcolor, resolved when used.
+        let body = &styles[1].1;
+        assert_eq!(
+            body.value(PropertyId::BorderTopColor).to_string(),
+            "currentcolor"
+        );
+        assert_eq!(
+            Color::from(body.border_top_color.clone()).to_rgba(&body.current_color()),
+            Rgba::new(0.0, 0.0, 1.0, 1.0)
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use crate::style::{
+    CascadedDeclaration, CascadedValues, ComponentValue, ComputeContext, ComputedStyle,
+    CssWideKeyword, Declaration, Direction, Longhand, Origin, PropertyRegistry, ShorthandId,
+    SpecProperty, SpecStyle, StyleError, Value, WritingMode,
+};
+
+use super::{contains_var, CustomProperties};
+
+/// The rank of the origins that `revert` rolls back, the animations and
+/// transitions being rolled back as the author origin.
+fn origin_rank(origin: Origin) -> u8 {
+    match origin {
+        Origin::UserAgent => 0,
+        Origin::User => 1,
+        Origin::Author | Origin::Animation | Origin::Transition => 2,
+    }
+}
+
+/// The specified value of a property, from its cascaded declarations.
+///
+/// `revert` and `revert-layer` roll back to the declarations of the previous
+/// origin or layer. Other declarations are parsed, the ones failing to parse
+/// being skipped. The property is unset if no declaration remains.
+pub fn specified_with<T, F>(candidates: &[CascadedDeclaration], parse: F) -> SpecProperty<T>
+where
+    F: Fn(&Declaration) -> Result<SpecProperty<T>, StyleError>,
+{
+    let mut remaining = candidates.iter().collect::<Vec<_>>();
+
+    while let Some(candidate) = remaining.first().copied() {
+        let keyword = Value::try_from(candidate.declaration.value.as_slice())
+            .ok()
+            .and_then(|value| CssWideKeyword::try_from(&value).ok());
+        let origin = candidate.origin();
+
+        match keyword {
+            Some(CssWideKeyword::Initial) => return SpecProperty::Initial,
+            Some(CssWideKeyword::Inherit) => return SpecProperty::Inherit,
+            Some(CssWideKeyword::Unset) => return SpecProperty::Unset,
+            Some(CssWideKeyword::RevertLayer)
+                if !matches!(origin, Origin::Animation | Origin::Transition) =>
+            {
+                remaining.retain(|c| {
+                    c.origin() != origin
+                        || c.layer != candidate.layer
+                        || c.priority.element_attached != candidate.priority.element_attached
+                });
+            }
+            Some(CssWideKeyword::Revert | CssWideKeyword::RevertLayer) => {
+                remaining.retain(|c| origin_rank(c.origin()) < origin_rank(origin));
+            }
+            _ => match parse(&candidate.declaration) {
+                Ok(specified) => return specified,
+                Err(_) => {
+                    remaining.remove(0);
+                }
+            },
+        }
+    }
+
+    SpecProperty::Unset
+}
+
+/// The specified value of a longhand property.
+///
+/// Shorthands are expanded, the value of the longhand being picked. Values
+/// referencing custom properties are substituted then parsed, the property
+/// being invalid at computed-value time, thus unset, if it fails.
+pub fn specified_value<P: Longhand>(
+    candidates: &[CascadedDeclaration],
+    custom: &CustomProperties,
+) -> SpecProperty<P> {
+    specified_physical_value(
+        candidates,
+        custom,
+        WritingMode::default(),
+        Direction::default(),
+    )
+}
+
+/// The specified value of a physical longhand, whose candidates may include
+/// the declarations of the logical longhands mapping to it, given the
+/// writing mode and direction.
+pub fn specified_physical_value<P: Longhand>(
+    candidates: &[CascadedDeclaration],
+    custom: &CustomProperties,
+    writing_mode: WritingMode,
+    direction: Direction,
+) -> SpecProperty<P> {
+    specified_with(candidates, |decl| {
+        let parse = |value: &[ComponentValue]| match ShorthandId::try_from(decl.name.as_str()) {
+            Ok(shorthand) => shorthand
+                .expand(value)?
+                .into_iter()
+                .find(|declaration| {
+                    declaration
+                        .id()
+                        .is_some_and(|id| id.to_physical(writing_mode, direction) == P::ID)
+                })
+                .and_then(|declaration| match declaration.logical_value() {
+                    Some(value) => P::parse(value).ok(),
+                    None => P::from_declaration(declaration),
+                })
+                .ok_or(StyleError::InvalidDeclaration),
+            Err(_) => Value::try_from(value).and_then(P::parse),
+        };
+
+        if !contains_var(&decl.value) {
+            return parse(&decl.value).map(SpecProperty::Value);
+        }
+
+        Ok(custom
+            .substitute(&decl.value)
+            .and_then(|value| parse(&value))
+            .map(SpecProperty::Value)
+            .unwrap_or(SpecProperty::Unset))
+    })
+}
+
+impl SpecStyle {
+    /// The specified style of an element, from its cascaded values and the
+    /// computed style of its parent.
+    ///
+    /// The writing mode and direction are resolved first, the declarations
+    /// of the logical longhands being mapped against them.
+    pub fn new(
+        values: &CascadedValues,
+        parent: Option<&ComputedStyle>,
+        registry: &PropertyRegistry,
+    ) -> Self {
+        let custom =
+            CustomProperties::compute(values, parent.map(|parent| &parent.custom), registry);
+
+        // Both are keywords, computed without context.
+        let context = ComputeContext::default();
+        let writing_mode =
+            specified_value::<WritingMode>(values.candidates("writing-mode"), &custom)
+                .compute(parent.map(|parent| &parent.writing_mode), &context);
+        let direction = specified_value::<Direction>(values.candidates("direction"), &custom)
+            .compute(parent.map(|parent| &parent.direction), &context);
+
+        Self::from_cascaded(
+            &values.to_physical(writing_mode, direction),
+            custom,
+            writing_mode,
+            direction,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::TestDocument, BackgroundAttachment, BackgroundClip, Cascade, CascadeInputs,
+        Display, FontSize, Layers, Origin, SpecProperty, Stylesheet,
+    };
+
+    use super::{specified_value, CustomProperties};
+
+    #[test]
+    fn test_001_revert_and_revert_layer() {
+        let ua = Stylesheet::from("p { display: block }");
+        let author = Stylesheet::from(
+            "@layer base { p { display: none; font-size: 2em } }
+            p { display: revert-layer; font-size: red }
+            p.reverted { display: revert }
+            p.unset { display: unset; font-size: inherit }",
+        );
+        let cascade = Cascade::new([(Origin::UserAgent, &ua), (Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[]);
+        let reverted = doc.create(None, "p", &[("class", "reverted")]);
+        let unset = doc.create(None, "p", &[("class", "unset")]);
+
+        let values =
+            |node| cascade.cascade(&doc.element(node), None, None, &CascadeInputs::default());
+
+        let custom = CustomProperties::default();
+        let display =
+            |node| specified_value::<Display>(values(node).candidates("display"), &custom);
+        assert!(matches!(display(p), SpecProperty::Value(d) if d.is_none()));
+        assert!(matches!(display(reverted), SpecProperty::Value(d) if !d.is_none()));
+        assert!(matches!(display(unset), SpecProperty::Unset));
+
+        // Invalid declarations are skipped.
+        let font_size =
+            |node| specified_value::<FontSize>(values(node).candidates("font-size"), &custom);
+        assert!(matches!(
+            font_size(p),
+            SpecProperty::Value(FontSize::Length(_))
+        ));
+        assert!(matches!(font_size(unset), SpecProperty::Inherit));
+    }
+
+    #[test]
+    fn test_002_shorthands() {
+        let author = Stylesheet::from(
+            "p { background-clip: text; background: fixed content-box }
+            p.reset { background: red; background-attachment: local }
+            p.inherit { background: inherit }",
+        );
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[]);
+        let reset = doc.create(None, "p", &[("class", "reset")]);
+        let inherit = doc.create(None, "p", &[("class", "inherit")]);
+
+        let values =
+            |node| cascade.cascade(&doc.element(node), None, None, &CascadeInputs::default());
+        let custom = CustomProperties::default();
+        let clip = |node| {
+            specified_value::<Layers<BackgroundClip>>(
+                values(node).candidates("background-clip"),
+                &custom,
+            )
+        };
+        let attachment = |node| {
+            specified_value::<Layers<BackgroundAttachment>>(
+                values(node).candidates("background-attachment"),
+                &custom,
+            )
+        };
+
+        assert!(matches!(
+            clip(p),
+            SpecProperty::Value(layers) if layers[..] == [BackgroundClip::ContentBox]
+        ));
+        assert!(matches!(
+            attachment(p),
+            SpecProperty::Value(layers) if layers[..] == [BackgroundAttachment::Fixed]
+        ));
+
+        // The omitted longhands are reset to their initial value.
+        assert!(matches!(
+            clip(reset),
+            SpecProperty::Value(layers) if layers[..] == [BackgroundClip::BorderBox]
+        ));
+        assert!(matches!(
+            attachment(reset),
+            SpecProperty::Value(layers) if layers[..] == [BackgroundAttachment::Local]
+        ));
+
+        assert!(matches!(clip(inherit), SpecProperty::Inherit));
+    }
+}
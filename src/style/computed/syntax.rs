@@ -0,0 +1,386 @@
+use crate::style::{
+    Angle, Color, ComponentValue, ComputeContext, Keyword, Length, LengthUnit, Number, Percentage,
+    Resolution, StyleError, Time, TokenKind, Value,
+};
+
+/// A data type of a syntax component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxDataType {
+    Length,
+    Number,
+    Percentage,
+    LengthPercentage,
+    Integer,
+    Angle,
+    Time,
+    Resolution,
+    Color,
+    Url,
+    String,
+    CustomIdent,
+}
+
+impl TryFrom<&str> for SyntaxDataType {
+    type Error = StyleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "length" => Ok(Self::Length),
+            "number" => Ok(Self::Number),
+            "percentage" => Ok(Self::Percentage),
+            "length-percentage" => Ok(Self::LengthPercentage),
+            "integer" => Ok(Self::Integer),
+            "angle" => Ok(Self::Angle),
+            "time" => Ok(Self::Time),
+            "resolution" => Ok(Self::Resolution),
+            "color" => Ok(Self::Color),
+            "url" => Ok(Self::Url),
+            "string" => Ok(Self::String),
+            "custom-ident" => Ok(Self::CustomIdent),
+            _ => Err(StyleError::InvalidValue(&["<syntax-type-name>"])),
+        }
+    }
+}
+
+impl std::fmt::Display for SyntaxDataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Length => "length",
+            Self::Number => "number",
+            Self::Percentage => "percentage",
+            Self::LengthPercentage => "length-percentage",
+            Self::Integer => "integer",
+            Self::Angle => "angle",
+            Self::Time => "time",
+            Self::Resolution => "resolution",
+            Self::Color => "color",
+            Self::Url => "url",
+            Self::String => "string",
+            Self::CustomIdent => "custom-ident",
+        };
+
+        write!(f, "<{}>", name)
+    }
+}
+
+/// What a syntax component matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxComponentName {
+    DataType(SyntaxDataType),
+    /// A literal identifier.
+    Ident(String),
+}
+
+/// How many times a syntax component is repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxMultiplier {
+    /// Exactly once.
+    One,
+    /// A space-separated list, +.
+    Space,
+    /// A comma-separated list, #.
+    Comma,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxComponent {
+    pub name: SyntaxComponentName,
+    pub multiplier: SyntaxMultiplier,
+}
+
+/// The syntax descriptor of a registered custom property.
+///
+/// # Grammar
+/// <syntax> = '*' | <syntax-component> [ '|' <syntax-component> ]*
+/// <syntax-component> = [ <syntax-type-name> | <ident> ] [ '+' | '#' ]?
+///
+/// [CSS Properties and Values API](https://drafts.css-houdini.org/css-properties-values-api/#syntax-strings)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertySyntax {
+    /// The universal syntax, any token list.
+    Universal,
+    /// The alternatives, tried in order.
+    Components(Vec<SyntaxComponent>),
+}
+
+impl TryFrom<&str> for PropertySyntax {
+    type Error = StyleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<syntax>"]);
+
+        if value.trim() == "*" {
+            return Ok(Self::Universal);
+        }
+
+        let components = value
+            .split('|')
+            .map(|component| {
+                let component = component.trim();
+
+                let (component, multiplier) = match component.as_bytes().last() {
+                    Some(b'+') => (&component[..component.len() - 1], SyntaxMultiplier::Space),
+                    Some(b'#') => (&component[..component.len() - 1], SyntaxMultiplier::Comma),
+                    _ => (component, SyntaxMultiplier::One),
+                };
+
+                let name = if let Some(name) = component
+                    .strip_prefix('<')
+                    .and_then(|name| name.strip_suffix('>'))
+                {
+                    SyntaxComponentName::DataType(SyntaxDataType::try_from(name)?)
+                } else if is_custom_ident(component) {
+                    SyntaxComponentName::Ident(component.to_string())
+                } else {
+                    return Err(invalid.clone());
+                };
+
+                Ok(SyntaxComponent { name, multiplier })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::Components(components))
+    }
+}
+
+impl std::fmt::Display for PropertySyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let components = match self {
+            Self::Universal => return write!(f, "*"),
+            Self::Components(components) => components,
+        };
+
+        let components = components
+            .iter()
+            .map(|component| {
+                let name = match &component.name {
+                    SyntaxComponentName::DataType(data_type) => data_type.to_string(),
+                    SyntaxComponentName::Ident(ident) => ident.clone(),
+                };
+
+                match component.multiplier {
+                    SyntaxMultiplier::One => name,
+                    SyntaxMultiplier::Space => format!("{}+", name),
+                    SyntaxMultiplier::Comma => format!("{}#", name),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        write!(f, "{}", components)
+    }
+}
+
+/// Checks if the identifier can be used as a custom identifier.
+fn is_custom_ident(ident: &str) -> bool {
+    let mut chars = ident.chars();
+    let starts_ident = match chars.next() {
+        Some('-') => matches!(chars.next(), Some(c) if c == '-' || c.is_alphabetic() || c == '_'),
+        Some(c) => c.is_alphabetic() || c == '_',
+        None => false,
+    };
+
+    starts_ident
+        && ident
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        && !matches!(
+            ident.to_ascii_lowercase().as_str(),
+            "initial" | "inherit" | "unset" | "revert" | "revert-layer" | "default"
+        )
+}
+
+/// A value matching the syntax of a registered custom property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyntaxValue {
+    Length(Length),
+    Number(Number),
+    Percentage(Percentage),
+    Integer(i32),
+    Angle(Angle),
+    Time(Time),
+    Resolution(Resolution),
+    Color(Color),
+    Url(String),
+    String(String),
+    Ident(String),
+    List(Vec<SyntaxValue>, SyntaxMultiplier),
+    /// A value of the universal syntax.
+    Tokens(Vec<ComponentValue>),
+}
+
+impl SyntaxValue {
+    /// Parse a value against the syntax.
+    pub fn parse(syntax: &PropertySyntax, value: &[ComponentValue]) -> Result<Self, StyleError> {
+        let components = match syntax {
+            PropertySyntax::Universal => return Ok(Self::Tokens(value.to_vec())),
+            PropertySyntax::Components(components) => components,
+        };
+
+        components
+            .iter()
+            .find_map(|component| Self::parse_component(component, value).ok())
+            .ok_or(StyleError::InvalidValue(&["<syntax>"]))
+    }
+
+    fn parse_component(
+        component: &SyntaxComponent,
+        value: &[ComponentValue],
+    ) -> Result<Self, StyleError> {
+        let invalid = StyleError::InvalidValue(&["<syntax>"]);
+
+        let items = match component.multiplier {
+            SyntaxMultiplier::One | SyntaxMultiplier::Space => value
+                .iter()
+                .filter(|cv| !cv.is_whitespace())
+                .map(std::slice::from_ref)
+                .collect::<Vec<_>>(),
+            SyntaxMultiplier::Comma => value.split(ComponentValue::is_comma).collect(),
+        };
+
+        let items = items
+            .into_iter()
+            .map(|item| {
+                let mut item = item.iter().filter(|cv| !cv.is_whitespace());
+                match (item.next(), item.next()) {
+                    (Some(cv), None) => Self::parse_item(&component.name, cv),
+                    _ => Err(invalid.clone()),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match (component.multiplier, items.len()) {
+            (_, 0) => Err(invalid),
+            (SyntaxMultiplier::One, 1) => Ok(items.into_iter().next().unwrap()),
+            (SyntaxMultiplier::One, _) => Err(invalid),
+            (multiplier, _) => Ok(Self::List(items, multiplier)),
+        }
+    }
+
+    fn parse_item(name: &SyntaxComponentName, cv: &ComponentValue) -> Result<Self, StyleError> {
+        let invalid = StyleError::InvalidValue(&["<syntax>"]);
+        let kind = cv.as_token_kind();
+
+        let data_type = match name {
+            SyntaxComponentName::Ident(ident) => {
+                return match kind {
+                    Some(TokenKind::Ident(value)) if value == ident => {
+                        Ok(Self::Ident(value.clone()))
+                    }
+                    _ => Err(invalid),
+                };
+            }
+            SyntaxComponentName::DataType(data_type) => *data_type,
+        };
+
+        // The functions, such as the color ones, are values.
+        match (data_type, kind) {
+            (SyntaxDataType::Url, Some(TokenKind::Url(url))) => return Ok(Self::Url(url.clone())),
+            (SyntaxDataType::String, Some(TokenKind::String(str))) => {
+                return Ok(Self::String(str.clone()))
+            }
+            (SyntaxDataType::CustomIdent, Some(TokenKind::Ident(ident)))
+                if is_custom_ident(ident) =>
+            {
+                return Ok(Self::Ident(ident.clone()))
+            }
+            _ => {}
+        }
+
+        match (data_type, Value::try_from(cv)?) {
+            (SyntaxDataType::Length | SyntaxDataType::LengthPercentage, Value::Length(length)) => {
+                Ok(Self::Length(length))
+            }
+            (SyntaxDataType::Length | SyntaxDataType::LengthPercentage, Value::Number(number))
+                if f32::from(number) == 0.0 =>
+            {
+                Ok(Self::Length(Length::px(0)))
+            }
+            (
+                SyntaxDataType::Percentage | SyntaxDataType::LengthPercentage,
+                Value::Percentage(percentage),
+            ) => Ok(Self::Percentage(percentage)),
+            (SyntaxDataType::Number, Value::Number(number)) => Ok(Self::Number(number)),
+            (SyntaxDataType::Integer, Value::Number(Number::Int(int))) => Ok(Self::Integer(int)),
+            (SyntaxDataType::Angle, Value::Angle(angle)) => Ok(Self::Angle(angle)),
+            (SyntaxDataType::Time, Value::Time(time)) => Ok(Self::Time(time)),
+            (SyntaxDataType::Resolution, Value::Resolution(resolution)) => {
+                Ok(Self::Resolution(resolution))
+            }
+            (SyntaxDataType::Color, Value::Color(color)) => Ok(Self::Color(color)),
+            _ => Err(invalid),
+        }
+    }
+
+    /// Checks if the value can be computed without the context of an element,
+    /// as required for initial values.
+    pub fn is_computationally_independent(&self) -> bool {
+        match self {
+            Self::Length(length) => !matches!(length.unit, LengthUnit::Relative(_)),
+            Self::List(items, _) => items.iter().all(Self::is_computationally_independent),
+            _ => true,
+        }
+    }
+
+    /// Compute the value, the relative lengths being resolved.
+    pub fn compute(&self, context: &ComputeContext) -> Self {
+        match self {
+            Self::Length(length) => Self::Length(length.resolve_relative(&context.length)),
+            Self::List(items, multiplier) => Self::List(
+                items.iter().map(|item| item.compute(context)).collect(),
+                *multiplier,
+            ),
+            value => value.clone(),
+        }
+    }
+
+    /// The value as a token list, as substituted by var().
+    pub fn to_component_values(&self) -> Vec<ComponentValue> {
+        match self {
+            Self::Tokens(tokens) => tokens.clone(),
+            value => crate::style::parse_component_values(&value.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for SyntaxValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Length(length) => write!(f, "{}", length),
+            Self::Number(number) => write!(f, "{}", number),
+            Self::Percentage(percentage) => write!(f, "{}", percentage),
+            Self::Integer(int) => write!(f, "{}", int),
+            Self::Angle(angle) => write!(f, "{}", angle),
+            Self::Time(time) => write!(f, "{}", time),
+            Self::Resolution(resolution) => write!(f, "{}", resolution),
+            Self::Color(color) => write!(f, "{}", color),
+            Self::Url(url) => write!(f, "url({})", url),
+            Self::String(str) => write!(f, "{}", Value::String(str.clone())),
+            Self::Ident(ident) => write!(f, "{}", ident),
+            Self::List(items, multiplier) => {
+                let separator = match multiplier {
+                    SyntaxMultiplier::Comma => ", ",
+                    _ => " ",
+                };
+                let items = items
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(separator);
+                write!(f, "{}", items)
+            }
+            Self::Tokens(tokens) => {
+                for token in tokens.iter() {
+                    write!(f, "{}", token)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<Keyword> for SyntaxValue {
+    fn from(value: Keyword) -> Self {
+        Self::Ident(value.to_string())
+    }
+}
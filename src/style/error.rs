@@ -1,3 +1,7 @@
+#[derive(Debug, Clone, PartialEq)]
 pub enum StyleError {
     InvalidValue(&'static [&'static str]),
+    InvalidSelector,
+    InvalidDeclaration,
+    UnexpectedEof,
 }
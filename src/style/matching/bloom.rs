@@ -0,0 +1,180 @@
+use super::Element;
+
+const KEY_SIZE: usize = 12;
+const ARRAY_SIZE: usize = 1 << KEY_SIZE;
+const KEY_MASK: u32 = (ARRAY_SIZE as u32) - 1;
+
+/// The maximum number of ancestor hashes kept per selector.
+pub const MAX_ANCESTOR_HASHES: usize = 4;
+
+/// Hash an identifier (FNV-1a).
+pub fn hash_ident(ident: &str) -> u32 {
+    let hash = ident.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+
+    // 0 is used as the empty slot of AncestorHashes.
+    hash.max(1)
+}
+
+/// A counting bloom filter with 8-bit counters, and two hash functions
+/// derived from a single 32-bit hash.
+///
+/// Saturated counters are never decremented, so removing an element can
+/// only lead to false positives.
+#[derive(Clone)]
+pub struct CountingBloomFilter {
+    counters: Box<[u8; ARRAY_SIZE]>,
+}
+
+impl Default for CountingBloomFilter {
+    fn default() -> Self {
+        Self {
+            counters: Box::new([0; ARRAY_SIZE]),
+        }
+    }
+}
+
+impl CountingBloomFilter {
+    #[inline]
+    fn first_slot(hash: u32) -> usize {
+        (hash & KEY_MASK) as usize
+    }
+
+    #[inline]
+    fn second_slot(hash: u32) -> usize {
+        ((hash >> KEY_SIZE) & KEY_MASK) as usize
+    }
+
+    pub fn insert_hash(&mut self, hash: u32) {
+        for slot in [Self::first_slot(hash), Self::second_slot(hash)] {
+            let counter = &mut self.counters[slot];
+            *counter = counter.saturating_add(1);
+        }
+    }
+
+    pub fn remove_hash(&mut self, hash: u32) {
+        for slot in [Self::first_slot(hash), Self::second_slot(hash)] {
+            let counter = &mut self.counters[slot];
+            if *counter != u8::MAX {
+                *counter = counter.saturating_sub(1);
+            }
+        }
+    }
+
+    pub fn might_contain_hash(&self, hash: u32) -> bool {
+        self.counters[Self::first_slot(hash)] != 0 && self.counters[Self::second_slot(hash)] != 0
+    }
+
+    pub fn clear(&mut self) {
+        self.counters.fill(0);
+    }
+}
+
+/// The hashes of the ids, classes and tags a selector requires on the
+/// ancestors of the subject.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AncestorHashes([u32; MAX_ANCESTOR_HASHES]);
+
+impl AncestorHashes {
+    pub fn push(&mut self, hash: u32) -> bool {
+        match self.0.iter_mut().find(|slot| **slot == 0) {
+            Some(slot) => {
+                *slot = hash;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0.iter().copied().take_while(|hash| *hash != 0)
+    }
+}
+
+/// A bloom filter of the ids, classes and tags of the ancestors of the
+/// element being styled.
+///
+/// It is maintained during a tree walk by pushing an element before
+/// styling its children, and popping it afterwards.
+#[derive(Default, Clone)]
+pub struct AncestorFilter {
+    filter: CountingBloomFilter,
+    pushed: Vec<Vec<u32>>,
+}
+
+impl AncestorFilter {
+    fn element_hashes<E: Element>(element: &E) -> Vec<u32> {
+        let mut hashes = vec![hash_ident(&element.local_name().to_ascii_lowercase())];
+        hashes.extend(element.id().map(hash_ident));
+        hashes.extend(element.classes().into_iter().map(hash_ident));
+        hashes
+    }
+
+    /// Build the filter for the ancestors of the element.
+    pub fn for_ancestors_of<E: Element>(element: &E) -> Self {
+        let mut ancestors = Vec::<E>::default();
+        let mut next = element.parent_element();
+
+        while let Some(parent) = next {
+            next = parent.parent_element();
+            ancestors.push(parent);
+        }
+
+        let mut filter = Self::default();
+        ancestors
+            .iter()
+            .rev()
+            .for_each(|ancestor| filter.push(ancestor));
+        filter
+    }
+
+    pub fn push<E: Element>(&mut self, element: &E) {
+        let hashes = Self::element_hashes(element);
+        hashes
+            .iter()
+            .for_each(|hash| self.filter.insert_hash(*hash));
+        self.pushed.push(hashes);
+    }
+
+    pub fn pop(&mut self) {
+        if let Some(hashes) = self.pushed.pop() {
+            hashes
+                .iter()
+                .for_each(|hash| self.filter.remove_hash(*hash));
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.pushed.len()
+    }
+
+    /// Returns false if the selector can't match, given its ancestor hashes.
+    pub fn might_match(&self, hashes: &AncestorHashes) -> bool {
+        hashes
+            .iter()
+            .all(|hash| self.filter.might_contain_hash(hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_ident, CountingBloomFilter};
+
+    #[test]
+    fn test_001_counting_bloom_filter() {
+        let mut filter = CountingBloomFilter::default();
+        let (foo, bar) = (hash_ident("foo"), hash_ident("bar"));
+
+        filter.insert_hash(foo);
+        filter.insert_hash(foo);
+        assert!(filter.might_contain_hash(foo));
+        assert!(!filter.might_contain_hash(bar));
+
+        filter.remove_hash(foo);
+        assert!(filter.might_contain_hash(foo));
+
+        filter.remove_hash(foo);
+        assert!(!filter.might_contain_hash(foo));
+    }
+}
@@ -0,0 +1,58 @@
+use crate::style::ElementState;
+
+/// An element of the document tree, as seen by the selector matching.
+///
+/// Implementors are expected to be cheap handles over the nodes of the document.
+pub trait Element: Clone {
+    /// The local name of the element, in lowercase for HTML elements.
+    fn local_name(&self) -> &str;
+
+    fn id(&self) -> Option<&str>;
+
+    fn classes(&self) -> Vec<&str>;
+
+    fn attribute(&self, name: &str) -> Option<&str>;
+
+    fn attribute_names(&self) -> Vec<&str>;
+
+    fn parent_element(&self) -> Option<Self>;
+
+    fn prev_sibling_element(&self) -> Option<Self>;
+
+    fn next_sibling_element(&self) -> Option<Self>;
+
+    fn first_child_element(&self) -> Option<Self>;
+
+    /// Checks if the element has neither child elements nor text content.
+    fn is_empty(&self) -> bool;
+
+    /// Checks the dynamic state of the element (:hover, :checked, ...).
+    fn is_in_state(&self, _state: ElementState) -> bool {
+        false
+    }
+
+    /// The declarations of the style attribute of the element.
+    fn style_attribute(&self) -> Option<&str> {
+        self.attribute("style")
+    }
+
+    fn has_class(&self, class: &str) -> bool {
+        self.classes().contains(&class)
+    }
+
+    fn is_root(&self) -> bool {
+        self.parent_element().is_none()
+    }
+
+    fn child_elements(&self) -> Vec<Self> {
+        let mut children = Vec::<Self>::default();
+        let mut next = self.first_child_element();
+
+        while let Some(child) = next {
+            next = child.next_sibling_element();
+            children.push(child);
+        }
+
+        children
+    }
+}
@@ -0,0 +1,14 @@
+//! Selector matching.
+//!
+//! Selectors are matched right to left against an [Element]. Style rules
+//! are indexed in a [RuleIndex], which only tests the selectors of the
+//! buckets an element belongs to, and fast-rejects descendant selectors
+//! with an [AncestorFilter] maintained during the tree walk.
+mod bloom;
+mod element;
+mod selector;
+mod selector_map;
+
+pub use bloom::*;
+pub use element::*;
+pub use selector_map::*;
@@ -0,0 +1,250 @@
+use crate::style::{
+    AttributeSelector, Combinator, ComplexSelector, CompoundSelector, Nth, PseudoClassSelector,
+    PseudoElement, SelectorList, SubclassSelector,
+};
+
+use super::Element;
+
+impl SelectorList {
+    /// Checks if any selector of the list matches the element.
+    pub fn matches<E: Element>(&self, element: &E) -> bool {
+        self.iter().any(|selector| selector.matches(element))
+    }
+}
+
+impl ComplexSelector {
+    /// Checks if the selector matches the element, from right to left.
+    pub fn matches<E: Element>(&self, element: &E) -> bool {
+        self.matches_for(element, None)
+    }
+
+    /// Checks if the selector matches the pseudo-element of the element.
+    ///
+    /// The rightmost compound must target the pseudo-element, and the rest of
+    /// the selector is matched against the originating element.
+    pub fn matches_for<E: Element>(&self, element: &E, pseudo: Option<PseudoElement>) -> bool {
+        match self {
+            Self::Root(compound) => compound.matches_for(element, pseudo),
+            Self::Combined(lhs, combinator, compound) => {
+                compound.matches_for(element, pseudo) && lhs.matches_relative(*combinator, element)
+            }
+        }
+    }
+
+    /// Checks if the selector matches an element related to the given one
+    /// through the combinator.
+    fn matches_relative<E: Element>(&self, combinator: Combinator, element: &E) -> bool {
+        match combinator {
+            Combinator::Child => element
+                .parent_element()
+                .map(|parent| self.matches(&parent))
+                .unwrap_or(false),
+            Combinator::Descendant => {
+                let mut next = element.parent_element();
+                while let Some(ancestor) = next {
+                    if self.matches(&ancestor) {
+                        return true;
+                    }
+                    next = ancestor.parent_element();
+                }
+                false
+            }
+            Combinator::NextSibling => element
+                .prev_sibling_element()
+                .map(|sibling| self.matches(&sibling))
+                .unwrap_or(false),
+            Combinator::SubsequentSibling => {
+                let mut next = element.prev_sibling_element();
+                while let Some(sibling) = next {
+                    if self.matches(&sibling) {
+                        return true;
+                    }
+                    next = sibling.prev_sibling_element();
+                }
+                false
+            }
+        }
+    }
+}
+
+impl CompoundSelector {
+    pub fn matches<E: Element>(&self, element: &E) -> bool {
+        self.matches_for(element, None)
+    }
+
+    pub fn matches_for<E: Element>(&self, element: &E, pseudo: Option<PseudoElement>) -> bool {
+        if self.pseudo() != pseudo {
+            return false;
+        }
+
+        if let Some(name) = self.local_name() {
+            if !element.local_name().eq_ignore_ascii_case(name) {
+                return false;
+            }
+        }
+
+        self.subclass_selectors
+            .iter()
+            .all(|selector| selector.matches(element))
+    }
+}
+
+impl SubclassSelector {
+    pub fn matches<E: Element>(&self, element: &E) -> bool {
+        match self {
+            Self::Id(id) => element.id() == Some(id.as_str()),
+            Self::Class(class) => element.has_class(class),
+            Self::Attribute(attr) => attr.matches(element),
+            Self::PseudoClass(pc) => pc.matches(element),
+        }
+    }
+}
+
+impl AttributeSelector {
+    pub fn matches<E: Element>(&self, element: &E) -> bool {
+        match (element.attribute(&self.name), &self.matcher) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(value), Some(matcher)) => {
+                matcher
+                    .operator
+                    .matches(value, &matcher.value, matcher.case_insensitive)
+            }
+        }
+    }
+}
+
+/// Position of the element among its siblings, 1-based.
+fn position<E: Element, F: Fn(&E) -> Option<E>>(element: &E, next: F, of_type: bool) -> i32 {
+    let mut position = 1;
+    let mut sibling = next(element);
+
+    while let Some(el) = sibling {
+        if !of_type || el.local_name() == element.local_name() {
+            position += 1;
+        }
+        sibling = next(&el);
+    }
+
+    position
+}
+
+fn matches_nth<E: Element>(element: &E, nth: &Nth, from_end: bool, of_type: bool) -> bool {
+    let position = if from_end {
+        position(element, E::next_sibling_element, of_type)
+    } else {
+        position(element, E::prev_sibling_element, of_type)
+    };
+
+    nth.matches(position)
+}
+
+impl PseudoClassSelector {
+    pub fn matches<E: Element>(&self, element: &E) -> bool {
+        let first = Nth::new(0, 1);
+
+        match self {
+            Self::Root => element.is_root(),
+            Self::Empty => element.is_empty(),
+            Self::FirstChild => matches_nth(element, &first, false, false),
+            Self::LastChild => matches_nth(element, &first, true, false),
+            Self::OnlyChild => {
+                matches_nth(element, &first, false, false)
+                    && matches_nth(element, &first, true, false)
+            }
+            Self::FirstOfType => matches_nth(element, &first, false, true),
+            Self::LastOfType => matches_nth(element, &first, true, true),
+            Self::OnlyOfType => {
+                matches_nth(element, &first, false, true)
+                    && matches_nth(element, &first, true, true)
+            }
+            Self::NthChild(nth) => matches_nth(element, nth, false, false),
+            Self::NthLastChild(nth) => matches_nth(element, nth, true, false),
+            Self::NthOfType(nth) => matches_nth(element, nth, false, true),
+            Self::NthLastOfType(nth) => matches_nth(element, nth, true, true),
+            Self::Not(list) => !list.matches(element),
+            Self::Is(list) | Self::Where(list) => list.matches(element),
+            Self::State(state) => element.is_in_state(*state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{testing::TestDocument, ElementState, PseudoElement, SelectorList};
+
+    fn matches(doc: &TestDocument, selector: &str, node: usize) -> bool {
+        SelectorList::try_from(selector)
+            .unwrap()
+            .matches(&doc.element(node))
+    }
+
+    #[test]
+    fn test_001_combinators() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[("class", "page dark")]);
+        let h1 = doc.create(Some(body), "h1", &[("id", "title")]);
+        let p1 = doc.create(Some(body), "p", &[]);
+        let p2 = doc.create(Some(body), "p", &[("lang", "en-US")]);
+
+        assert!(matches(&doc, "html body", body) && matches(&doc, "html h1", h1));
+        assert!(matches(&doc, "body > #title", h1));
+        assert!(!matches(&doc, "html > h1", h1));
+        assert!(matches(&doc, "h1 + p", p1) && !matches(&doc, "h1 + p", p2));
+        assert!(matches(&doc, "h1 ~ p", p2));
+        assert!(matches(&doc, ".dark p[lang|=en]", p2));
+        assert!(matches(&doc, "body.page.dark", body) && !matches(&doc, ".light", body));
+    }
+
+    #[test]
+    fn test_002_structural_pseudo_classes() {
+        let mut doc = TestDocument::default();
+        let ul = doc.create(None, "ul", &[]);
+        let items = (0..5)
+            .map(|_| doc.create(Some(ul), "li", &[]))
+            .collect::<Vec<_>>();
+
+        assert!(matches(&doc, ":root", ul));
+        assert!(matches(&doc, "li:first-child", items[0]));
+        assert!(matches(&doc, "li:last-of-type", items[4]));
+        assert!(matches(&doc, "li:nth-child(odd)", items[2]));
+        assert!(!matches(&doc, "li:nth-child(odd)", items[3]));
+        assert!(matches(&doc, "li:nth-last-child(2)", items[3]));
+        assert!(matches(&doc, "li:not(:first-child, :last-child)", items[1]));
+        assert!(matches(&doc, "li:empty", items[1]) && !matches(&doc, "ul:empty", ul));
+
+        doc.set_text(items[1], "text");
+        assert!(!matches(&doc, "li:empty", items[1]));
+    }
+
+    #[test]
+    fn test_003_element_state() {
+        let mut doc = TestDocument::default();
+        let a = doc.create(None, "a", &[("href", "#")]);
+        doc.set_state(a, ElementState::Hover);
+
+        assert!(matches(&doc, "a:hover", a));
+        assert!(!matches(&doc, "a:focus", a));
+    }
+
+    #[test]
+    fn test_004_pseudo_elements() {
+        let mut doc = TestDocument::default();
+        let div = doc.create(None, "div", &[("class", "note")]);
+        let p = doc.create(Some(div), "p", &[]);
+
+        let selector = SelectorList::try_from(".note > p::before")
+            .unwrap()
+            .0
+            .remove(0);
+        let element = doc.element(p);
+
+        assert!(!selector.matches(&element));
+        assert!(selector.matches_for(&element, Some(PseudoElement::Before)));
+        assert!(!selector.matches_for(&element, Some(PseudoElement::After)));
+
+        let selector = SelectorList::try_from("p").unwrap().0.remove(0);
+        assert!(!selector.matches_for(&element, Some(PseudoElement::Before)));
+    }
+}
@@ -0,0 +1,407 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::style::{
+    Combinator, ComplexSelector, PseudoElement, Rule, SelectorList, Specificity, Stylesheet,
+};
+
+use super::{hash_ident, AncestorFilter, AncestorHashes, Element};
+
+/// Compute the hashes of the ids, classes and tags that the ancestors of
+/// the subject must have for the selector to match.
+///
+/// Only the compounds linked by a descendant or child combinator to their
+/// right are ancestors of the subject, the ones linked by a sibling
+/// combinator are skipped.
+pub fn ancestor_hashes(selector: &ComplexSelector) -> AncestorHashes {
+    let mut hashes = AncestorHashes::default();
+
+    for (compound, combinator) in selector.iter_compounds() {
+        if !matches!(
+            combinator,
+            Some(Combinator::Child) | Some(Combinator::Descendant)
+        ) {
+            continue;
+        }
+
+        let idents = compound
+            .ids()
+            .map(ToString::to_string)
+            .chain(compound.classes().map(ToString::to_string))
+            .chain(compound.local_name().map(str::to_ascii_lowercase));
+
+        for ident in idents {
+            if !hashes.push(hash_ident(&ident)) {
+                return hashes;
+            }
+        }
+    }
+
+    hashes
+}
+
+/// An entry of the selector map.
+#[derive(Debug, Clone)]
+pub struct SelectorMapEntry<T> {
+    pub selector: ComplexSelector,
+    pub specificity: Specificity,
+    pub hashes: AncestorHashes,
+    pub data: T,
+}
+
+/// An index of selectors, bucketed by the rightmost id, class, attribute
+/// name or tag of the selector.
+///
+/// Looking up an element only tests the selectors of the buckets the
+/// element belongs to, instead of every selector.
+#[derive(Debug, Clone)]
+pub struct SelectorMap<T> {
+    entries: Vec<SelectorMapEntry<T>>,
+    ids: HashMap<String, Vec<usize>>,
+    classes: HashMap<String, Vec<usize>>,
+    attributes: HashMap<String, Vec<usize>>,
+    local_names: HashMap<String, Vec<usize>>,
+    universal: Vec<usize>,
+}
+
+impl<T> Default for SelectorMap<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::default(),
+            ids: HashMap::default(),
+            classes: HashMap::default(),
+            attributes: HashMap::default(),
+            local_names: HashMap::default(),
+            universal: Vec::default(),
+        }
+    }
+}
+
+impl<T> SelectorMap<T> {
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, SelectorMapEntry<T>> {
+        self.entries.iter()
+    }
+
+    /// Insert a selector, entries are kept in insertion order.
+    pub fn insert(&mut self, selector: ComplexSelector, data: T) {
+        let index = self.entries.len();
+        let rightmost = selector.rightmost();
+
+        let bucket = if let Some(id) = rightmost.ids().next() {
+            self.ids.entry(id.to_string()).or_default()
+        } else if let Some(class) = rightmost.classes().next() {
+            self.classes.entry(class.to_string()).or_default()
+        } else if let Some(attr) = rightmost.attributes().next() {
+            self.attributes.entry(attr.name.clone()).or_default()
+        } else if let Some(name) = rightmost.local_name() {
+            self.local_names
+                .entry(name.to_ascii_lowercase())
+                .or_default()
+        } else {
+            &mut self.universal
+        };
+
+        bucket.push(index);
+
+        self.entries.push(SelectorMapEntry {
+            specificity: selector.specificity(),
+            hashes: ancestor_hashes(&selector),
+            selector,
+            data,
+        });
+    }
+
+    /// Returns the entries whose selector matches the element, or one of its
+    /// pseudo-elements, in insertion order.
+    ///
+    /// If an ancestor filter is given, it must hold the ancestors of the element,
+    /// and is used to fast-reject the selectors with descendant or child combinators.
+    pub fn matching<E: Element>(
+        &self,
+        element: &E,
+        pseudo: Option<PseudoElement>,
+        filter: Option<&AncestorFilter>,
+    ) -> Vec<&SelectorMapEntry<T>> {
+        let mut candidates = Vec::<usize>::default();
+
+        if let Some(bucket) = element.id().and_then(|id| self.ids.get(id)) {
+            candidates.extend(bucket);
+        }
+
+        for class in element.classes() {
+            if let Some(bucket) = self.classes.get(class) {
+                candidates.extend(bucket);
+            }
+        }
+
+        for name in element.attribute_names() {
+            if let Some(bucket) = self.attributes.get(&name.to_ascii_lowercase()) {
+                candidates.extend(bucket);
+            }
+        }
+
+        if let Some(bucket) = self
+            .local_names
+            .get(&element.local_name().to_ascii_lowercase())
+        {
+            candidates.extend(bucket);
+        }
+
+        candidates.extend(&self.universal);
+
+        // An element can have the same class twice.
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .map(|index| &self.entries[index])
+            .filter(|entry| filter.map(|f| f.might_match(&entry.hashes)).unwrap_or(true))
+            .filter(|entry| entry.selector.matches_for(element, pseudo))
+            .collect()
+    }
+}
+
+/// Locate a style rule, and the selector of its selector list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RuleSource {
+    pub stylesheet: usize,
+    pub rule: usize,
+    pub selector: usize,
+}
+
+/// The index of the style rules of a set of stylesheets.
+///
+/// It is built once per stylesheet set, and looked up for every element.
+/// Rules targeting a pseudo-element are kept in a separate map per
+/// pseudo-element, so that each (element, pseudo-element) pair gets its
+/// own set of matched rules.
+#[derive(Debug, Clone, Default)]
+pub struct RuleIndex {
+    map: SelectorMap<RuleSource>,
+    pseudo_maps: BTreeMap<PseudoElement, SelectorMap<RuleSource>>,
+}
+
+impl RuleIndex {
+    /// Index the style rules of the stylesheets, rules with an invalid selector are dropped.
+    pub fn new<'a, I>(stylesheets: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Stylesheet>,
+    {
+        let mut index = Self::default();
+
+        for (stylesheet, sheet) in stylesheets.into_iter().enumerate() {
+            for (rule, qualified) in
+                sheet
+                    .rules
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, rule)| match rule {
+                        Rule::Qualified(qualified) => Some((i, qualified)),
+                        _ => None,
+                    })
+            {
+                if let Ok(selectors) = qualified.selectors() {
+                    index.insert(selectors, stylesheet, rule);
+                }
+            }
+        }
+
+        index
+    }
+
+    /// Index the selectors of a style rule.
+    pub fn insert(&mut self, selectors: SelectorList, stylesheet: usize, rule: usize) {
+        for (selector, complex) in selectors.0.into_iter().enumerate() {
+            let map = match complex.rightmost().pseudo() {
+                Some(pseudo) => self.pseudo_maps.entry(pseudo).or_default(),
+                None => &mut self.map,
+            };
+
+            map.insert(
+                complex,
+                RuleSource {
+                    stylesheet,
+                    rule,
+                    selector,
+                },
+            );
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+            + self
+                .pseudo_maps
+                .values()
+                .map(SelectorMap::len)
+                .sum::<usize>()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the selectors matching the element, or one of its
+    /// pseudo-elements, in source order.
+    pub fn matching<E: Element>(
+        &self,
+        element: &E,
+        pseudo: Option<PseudoElement>,
+        filter: Option<&AncestorFilter>,
+    ) -> Vec<&SelectorMapEntry<RuleSource>> {
+        match pseudo {
+            None => self.map.matching(element, None, filter),
+            Some(pseudo) => self
+                .pseudo_maps
+                .get(&pseudo)
+                .map(|map| map.matching(element, Some(pseudo), filter))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the pseudo-elements of the element targeted by at least one rule.
+    pub fn pseudo_elements<E: Element>(
+        &self,
+        element: &E,
+        filter: Option<&AncestorFilter>,
+    ) -> Vec<PseudoElement> {
+        self.pseudo_maps
+            .iter()
+            .filter(|(pseudo, map)| !map.matching(element, Some(**pseudo), filter).is_empty())
+            .map(|(pseudo, _)| *pseudo)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        matching::{AncestorFilter, Element},
+        testing::TestDocument,
+        PseudoElement, SelectorList, Stylesheet,
+    };
+
+    use super::{ancestor_hashes, RuleIndex, SelectorMap};
+
+    #[test]
+    fn test_001_buckets() {
+        let mut doc = TestDocument::default();
+        let div = doc.create(None, "div", &[("id", "main"), ("class", "a b")]);
+        let span = doc.create(Some(div), "span", &[("title", "x")]);
+
+        let mut map = SelectorMap::<&str>::default();
+        for selector in ["#main", ".b", "[title]", "span", "*", ".c", "div span.a"] {
+            let list = SelectorList::try_from(selector).unwrap();
+            map.insert(list.0.into_iter().next().unwrap(), selector);
+        }
+
+        let matched = |node: usize| {
+            map.matching(&doc.element(node), None, None)
+                .into_iter()
+                .map(|entry| entry.data)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matched(div), vec!["#main", ".b", "*"]);
+        assert_eq!(matched(span), vec!["[title]", "span", "*"]);
+    }
+
+    #[test]
+    fn test_002_ancestor_filter_rejects() {
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[("class", "dark")]);
+        let p = doc.create(Some(body), "p", &[]);
+
+        let selector = SelectorList::try_from(".light > section p")
+            .unwrap()
+            .0
+            .remove(0);
+        assert_eq!(ancestor_hashes(&selector).iter().count(), 2);
+
+        let filter = AncestorFilter::for_ancestors_of(&doc.element(p));
+        assert_eq!(filter.depth(), 2);
+        assert!(!filter.might_match(&ancestor_hashes(&selector)));
+
+        let selector = SelectorList::try_from(".dark p").unwrap().0.remove(0);
+        assert!(filter.might_match(&ancestor_hashes(&selector)));
+
+        // Siblings of the ancestors are not hashed.
+        let selector = SelectorList::try_from("h1 + body p").unwrap().0.remove(0);
+        assert_eq!(ancestor_hashes(&selector).iter().count(), 1);
+    }
+
+    #[test]
+    fn test_003_rule_index_tree_walk() {
+        let stylesheet = Stylesheet::from(
+            "p { color: red; }
+            .dark p, #intro { color: white; }
+            a:hover { color: blue; }
+            p:unknown { }",
+        );
+        let index = RuleIndex::new([&stylesheet]);
+        assert_eq!(index.len(), 4);
+
+        let mut doc = TestDocument::default();
+        let body = doc.create(None, "body", &[("class", "dark")]);
+        let intro = doc.create(Some(body), "p", &[("id", "intro")]);
+
+        let mut filter = AncestorFilter::default();
+        filter.push(&doc.element(body));
+
+        let matched = index
+            .matching(&doc.element(intro), None, Some(&filter))
+            .into_iter()
+            .map(|entry| (entry.data.rule, entry.data.selector))
+            .collect::<Vec<_>>();
+        assert_eq!(matched, vec![(0, 0), (1, 0), (1, 1)]);
+
+        filter.pop();
+        assert!(index
+            .matching(&doc.element(body), None, Some(&filter))
+            .is_empty());
+        assert!(doc.element(intro).parent_element().is_some());
+    }
+
+    #[test]
+    fn test_004_pseudo_element_rules() {
+        let stylesheet = Stylesheet::from(
+            "p { color: red; }
+            p::before { content: \"> \"; }
+            .intro::first-line { font-weight: bold; }
+            li::marker { color: blue; }",
+        );
+        let index = RuleIndex::new([&stylesheet]);
+        assert_eq!(index.len(), 4);
+
+        let mut doc = TestDocument::default();
+        let p = doc.create(None, "p", &[("class", "intro")]);
+        let element = doc.element(p);
+
+        assert_eq!(
+            index.pseudo_elements(&element, None),
+            vec![PseudoElement::Before, PseudoElement::FirstLine]
+        );
+
+        let rules = |pseudo| {
+            index
+                .matching(&element, pseudo, None)
+                .into_iter()
+                .map(|entry| entry.data.rule)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(rules(None), vec![0]);
+        assert_eq!(rules(Some(PseudoElement::Before)), vec![1]);
+        assert_eq!(rules(Some(PseudoElement::FirstLine)), vec![2]);
+        assert!(rules(Some(PseudoElement::Marker)).is_empty());
+    }
+}
@@ -1,66 +1,2027 @@
+mod cascade;
+mod computed;
 mod error;
+mod matching;
 mod parser;
+#[macro_use]
 mod property;
+mod user_agent;
 mod value;
 
+#[cfg(test)]
+pub(crate) mod testing;
+
+pub use cascade::*;
+pub use computed::*;
 pub use error::*;
+pub use matching::*;
+pub use parser::*;
 pub use property::*;
+pub use user_agent::*;
 pub use value::*;
-pub use parser::*;
+pub use value::{Dimension, Number};
 
-#[macro_export]
-macro_rules! lookup {
-    ($style:ident, "display") => {
-        $style.display
-    };
-    ($style:ident, "background") => {
-        $style.background
-    };
-    ($style:ident, "background-attachment") => {
-        $style.background.attachment
-    };
-    ($style: ident, "background-clip") => {
-        $style.background.clip
-    };
-    ($style:ident, "background-color") => {
-        $style.background.color
-    };
-    ($style:ident, "background-image") => {
-        $style.background.image
-    };
-    ($style:ident, "background-origin") => {
-        $style.background.origin
-    };
-    ($style:ident, "background-position") => {
-        $style.background.position
-    };
-    ($style:ident, "background-repeat") => {
-        $style.background.repeat
-    };
-    ($style:ident, "background-size") => {
-        $style.background.size
-    };
+// The property table, adding a longhand being adding an entry. The logical
+// longhands map to the physical ones of their group.
+longhands! {
+    physical {
+        Display display: Display => Display {
+            name: "display",
+            inherited: false,
+            initial: Display::default(),
+            grammar: "[ <display-outside> || <display-inside> ] | <display-listitem> | <display-internal> | <display-box> | <display-legacy>",
+            parse: Display::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        Color color: ForegroundColor => ForegroundColor {
+            name: "color",
+            inherited: true,
+            initial: ForegroundColor::default(),
+            grammar: "<color>",
+            parse: ForegroundColor::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        FontFamily font_family: FontFamily => FontFamily {
+            name: "font-family",
+            inherited: true,
+            initial: FontFamily::default(),
+            grammar: "[ <family-name> | <generic-family> ]#",
+            parse: FontFamily::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        FontSize font_size: FontSize => Length {
+            name: "font-size",
+            inherited: true,
+            initial: FontSize::medium(),
+            grammar: "<absolute-size> | <relative-size> | <length-percentage [0,∞]> | math",
+            parse: FontSize::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        FontStyle font_style: FontStyle => FontStyle {
+            name: "font-style",
+            inherited: true,
+            initial: FontStyle::default(),
+            grammar: "normal | italic | oblique <angle [-90deg,90deg]>?",
+            parse: FontStyle::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        FontWeight font_weight: FontWeight => f32 {
+            name: "font-weight",
+            inherited: true,
+            initial: FontWeight::NORMAL,
+            grammar: "<font-weight-absolute> | bolder | lighter",
+            parse: FontWeight::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        FontStretch font_stretch: FontStretch => Percentage {
+            name: "font-stretch",
+            inherited: true,
+            initial: Percentage::try_from(1.0).expect("finite"),
+            grammar: "normal | <percentage [0,∞]> | ultra-condensed | extra-condensed | condensed | semi-condensed | semi-expanded | expanded | extra-expanded | ultra-expanded",
+            parse: FontStretch::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        LineHeight line_height: LineHeight => LineHeight {
+            name: "line-height",
+            inherited: true,
+            initial: LineHeight::default(),
+            grammar: "normal | <number [0,∞]> | <length-percentage [0,∞]>",
+            parse: LineHeight::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        FontVariantLigatures font_variant_ligatures: FontVariantLigatures => FontVariantLigatures {
+            name: "font-variant-ligatures",
+            inherited: true,
+            initial: FontVariantLigatures::default(),
+            grammar: "normal | none | [ <common-lig-values> || <discretionary-lig-values> || <historical-lig-values> || <contextual-alt-values> ]",
+            parse: FontVariantLigatures::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        FontVariantCaps font_variant_caps: FontVariantCaps => FontVariantCaps {
+            name: "font-variant-caps",
+            inherited: true,
+            initial: FontVariantCaps::default(),
+            grammar: "normal | small-caps | all-small-caps | petite-caps | all-petite-caps | unicase | titling-caps",
+            parse: FontVariantCaps::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        FontVariantAlternates font_variant_alternates: FontVariantAlternates => FontVariantAlternates {
+            name: "font-variant-alternates",
+            inherited: true,
+            initial: FontVariantAlternates::default(),
+            grammar: "normal | historical-forms",
+            parse: FontVariantAlternates::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        FontVariantNumeric font_variant_numeric: FontVariantNumeric => FontVariantNumeric {
+            name: "font-variant-numeric",
+            inherited: true,
+            initial: FontVariantNumeric::default(),
+            grammar: "normal | [ <numeric-figure-values> || <numeric-spacing-values> || <numeric-fraction-values> || ordinal || slashed-zero ]",
+            parse: FontVariantNumeric::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        FontVariantEastAsian font_variant_east_asian: FontVariantEastAsian => FontVariantEastAsian {
+            name: "font-variant-east-asian",
+            inherited: true,
+            initial: FontVariantEastAsian::default(),
+            grammar: "normal | [ <east-asian-variant-values> || <east-asian-width-values> || ruby ]",
+            parse: FontVariantEastAsian::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        FontVariantPosition font_variant_position: FontVariantPosition => FontVariantPosition {
+            name: "font-variant-position",
+            inherited: true,
+            initial: FontVariantPosition::default(),
+            grammar: "normal | sub | super",
+            parse: FontVariantPosition::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        FontVariantEmoji font_variant_emoji: FontVariantEmoji => FontVariantEmoji {
+            name: "font-variant-emoji",
+            inherited: true,
+            initial: FontVariantEmoji::default(),
+            grammar: "normal | text | emoji | unicode",
+            parse: FontVariantEmoji::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        WritingMode writing_mode: WritingMode => WritingMode {
+            name: "writing-mode",
+            inherited: true,
+            initial: WritingMode::default(),
+            grammar: "horizontal-tb | vertical-rl | vertical-lr | sideways-rl | sideways-lr",
+            parse: WritingMode::try_from,
+            animation: NotAnimatable,
+            logical_group: None,
+        }
+        Direction direction: Direction => Direction {
+            name: "direction",
+            inherited: true,
+            initial: Direction::default(),
+            grammar: "ltr | rtl",
+            parse: Direction::try_from,
+            animation: NotAnimatable,
+            logical_group: None,
+        }
+        TextOrientation text_orientation: TextOrientation => TextOrientation {
+            name: "text-orientation",
+            inherited: true,
+            initial: TextOrientation::default(),
+            grammar: "mixed | upright | sideways",
+            parse: TextOrientation::try_from,
+            animation: NotAnimatable,
+            logical_group: None,
+        }
+        WhiteSpaceCollapse white_space_collapse: WhiteSpaceCollapse => WhiteSpaceCollapse {
+            name: "white-space-collapse",
+            inherited: true,
+            initial: WhiteSpaceCollapse::default(),
+            grammar: "collapse | discard | preserve | preserve-breaks | preserve-spaces | break-spaces",
+            parse: WhiteSpaceCollapse::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        TextWrap text_wrap: TextWrap => TextWrap {
+            name: "text-wrap",
+            inherited: true,
+            initial: TextWrap::default(),
+            grammar: "wrap | nowrap | balance | stable | pretty",
+            parse: TextWrap::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        TextAlign text_align: TextAlign => TextAlign {
+            name: "text-align",
+            inherited: true,
+            initial: TextAlign::default(),
+            grammar: "start | end | left | right | center | justify | match-parent | justify-all",
+            parse: TextAlign::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        TextAlignLast text_align_last: TextAlignLast => TextAlignLast {
+            name: "text-align-last",
+            inherited: true,
+            initial: TextAlignLast::default(),
+            grammar: "auto | start | end | left | right | center | justify | match-parent",
+            parse: TextAlignLast::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        TextJustify text_justify: TextJustify => TextJustify {
+            name: "text-justify",
+            inherited: true,
+            initial: TextJustify::default(),
+            grammar: "auto | none | inter-word | inter-character",
+            parse: TextJustify::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        TextTransform text_transform: TextTransform => TextTransform {
+            name: "text-transform",
+            inherited: true,
+            initial: TextTransform::default(),
+            grammar: "none | [ capitalize | uppercase | lowercase ] || full-width || full-size-kana",
+            parse: TextTransform::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        LetterSpacing letter_spacing: LetterSpacing => LengthPercentage {
+            name: "letter-spacing",
+            inherited: true,
+            initial: LengthPercentage::zero(),
+            grammar: "normal | <length-percentage>",
+            parse: LetterSpacing::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        WordSpacing word_spacing: WordSpacing => LengthPercentage {
+            name: "word-spacing",
+            inherited: true,
+            initial: LengthPercentage::zero(),
+            grammar: "normal | <length-percentage>",
+            parse: WordSpacing::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        TextIndent text_indent: TextIndent => TextIndent {
+            name: "text-indent",
+            inherited: true,
+            initial: TextIndent::default(),
+            grammar: "[ <length-percentage> ] && hanging? && each-line?",
+            parse: TextIndent::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        TextDecorationLine text_decoration_line: TextDecorationLine => TextDecorationLine {
+            name: "text-decoration-line",
+            inherited: false,
+            initial: TextDecorationLine::default(),
+            grammar: "none | [ underline || overline || line-through || blink ]",
+            parse: TextDecorationLine::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        TextDecorationStyle text_decoration_style: TextDecorationStyle => TextDecorationStyle {
+            name: "text-decoration-style",
+            inherited: false,
+            initial: TextDecorationStyle::default(),
+            grammar: "solid | double | dotted | dashed | wavy",
+            parse: TextDecorationStyle::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        TextDecorationColor text_decoration_color: TextDecorationColor => TextDecorationColor {
+            name: "text-decoration-color",
+            inherited: false,
+            initial: TextDecorationColor::default(),
+            grammar: "<color>",
+            parse: TextDecorationColor::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        TextDecorationThickness text_decoration_thickness: TextDecorationThickness => TextDecorationThickness {
+            name: "text-decoration-thickness",
+            inherited: false,
+            initial: TextDecorationThickness::default(),
+            grammar: "auto | from-font | <length-percentage>",
+            parse: TextDecorationThickness::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        TextShadow text_shadow: TextShadow => TextShadow {
+            name: "text-shadow",
+            inherited: true,
+            initial: TextShadow::default(),
+            grammar: "none | [ <color>? && <length>{2,3} ]#",
+            parse: TextShadow::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        OverflowWrap overflow_wrap: OverflowWrap => OverflowWrap {
+            name: "overflow-wrap",
+            inherited: true,
+            initial: OverflowWrap::default(),
+            grammar: "normal | break-word | anywhere",
+            parse: OverflowWrap::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        WordBreak word_break: WordBreak => WordBreak {
+            name: "word-break",
+            inherited: true,
+            initial: WordBreak::default(),
+            grammar: "normal | keep-all | break-all | break-word",
+            parse: WordBreak::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        Hyphens hyphens: Hyphens => Hyphens {
+            name: "hyphens",
+            inherited: true,
+            initial: Hyphens::default(),
+            grammar: "none | manual | auto",
+            parse: Hyphens::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        TabSize tab_size: TabSize => TabSize {
+            name: "tab-size",
+            inherited: true,
+            initial: TabSize::default(),
+            grammar: "<number [0,∞]> | <length [0,∞]>",
+            parse: TabSize::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        BackgroundImage background_image: Layers<BackgroundImage> => Layers<BackgroundImage> {
+            name: "background-image",
+            inherited: false,
+            initial: Layers::default(),
+            grammar: "<bg-image>#",
+            parse: Layers::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        BackgroundPosition background_position: Layers<BackgroundPosition> => Layers<BackgroundPosition> {
+            name: "background-position",
+            inherited: false,
+            initial: Layers::default(),
+            grammar: "<bg-position>#",
+            parse: Layers::try_from,
+            animation: RepeatableList,
+            logical_group: None,
+        }
+        BackgroundSize background_size: Layers<BackgroundSize> => Layers<BackgroundSize> {
+            name: "background-size",
+            inherited: false,
+            initial: Layers::default(),
+            grammar: "<bg-size>#",
+            parse: Layers::try_from,
+            animation: RepeatableList,
+            logical_group: None,
+        }
+        BackgroundRepeat background_repeat: Layers<BackgroundRepeat> => Layers<BackgroundRepeat> {
+            name: "background-repeat",
+            inherited: false,
+            initial: Layers::default(),
+            grammar: "<repeat-style>#",
+            parse: Layers::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        BackgroundAttachment background_attachment: Layers<BackgroundAttachment> => Layers<BackgroundAttachment> {
+            name: "background-attachment",
+            inherited: false,
+            initial: Layers::default(),
+            grammar: "<attachment>#",
+            parse: Layers::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        BackgroundOrigin background_origin: Layers<BackgroundOrigin> => Layers<BackgroundOrigin> {
+            name: "background-origin",
+            inherited: false,
+            initial: Layers::default(),
+            grammar: "<visual-box>#",
+            parse: Layers::try_from,
+            animation: RepeatableList,
+            logical_group: None,
+        }
+        BackgroundClip background_clip: Layers<BackgroundClip> => Layers<BackgroundClip> {
+            name: "background-clip",
+            inherited: false,
+            initial: Layers::default(),
+            grammar: "<bg-clip>#",
+            parse: Layers::try_from,
+            animation: RepeatableList,
+            logical_group: None,
+        }
+        BackgroundColor background_color: BackgroundColor => BackgroundColor {
+            name: "background-color",
+            inherited: false,
+            initial: BackgroundColor::default(),
+            grammar: "<color>",
+            parse: BackgroundColor::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        BackgroundBlendMode background_blend_mode: Layers<BlendMode> => Layers<BlendMode> {
+            name: "background-blend-mode",
+            inherited: false,
+            initial: Layers::default(),
+            grammar: "<blend-mode>#",
+            parse: Layers::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        BoxSizing box_sizing: BoxSizing => BoxSizing {
+            name: "box-sizing",
+            inherited: false,
+            initial: BoxSizing::default(),
+            grammar: "content-box | border-box",
+            parse: BoxSizing::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        Width width: Width => Size {
+            name: "width",
+            inherited: false,
+            initial: Size::default(),
+            grammar: "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: Width::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Size),
+        }
+        Height height: Height => Size {
+            name: "height",
+            inherited: false,
+            initial: Size::default(),
+            grammar: "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: Height::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Size),
+        }
+        MinWidth min_width: MinWidth => Size {
+            name: "min-width",
+            inherited: false,
+            initial: Size::default(),
+            grammar: "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: MinWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::MinSize),
+        }
+        MinHeight min_height: MinHeight => Size {
+            name: "min-height",
+            inherited: false,
+            initial: Size::default(),
+            grammar: "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: MinHeight::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::MinSize),
+        }
+        MaxWidth max_width: MaxWidth => MaxSize {
+            name: "max-width",
+            inherited: false,
+            initial: MaxSize::default(),
+            grammar: "none | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: MaxWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::MaxSize),
+        }
+        MaxHeight max_height: MaxHeight => MaxSize {
+            name: "max-height",
+            inherited: false,
+            initial: MaxSize::default(),
+            grammar: "none | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: MaxHeight::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::MaxSize),
+        }
+        MarginTop margin_top: MarginTop => Margin {
+            name: "margin-top",
+            inherited: false,
+            initial: Margin::default(),
+            grammar: "auto | <length-percentage>",
+            parse: MarginTop::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Margin),
+        }
+        MarginRight margin_right: MarginRight => Margin {
+            name: "margin-right",
+            inherited: false,
+            initial: Margin::default(),
+            grammar: "auto | <length-percentage>",
+            parse: MarginRight::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Margin),
+        }
+        MarginBottom margin_bottom: MarginBottom => Margin {
+            name: "margin-bottom",
+            inherited: false,
+            initial: Margin::default(),
+            grammar: "auto | <length-percentage>",
+            parse: MarginBottom::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Margin),
+        }
+        MarginLeft margin_left: MarginLeft => Margin {
+            name: "margin-left",
+            inherited: false,
+            initial: Margin::default(),
+            grammar: "auto | <length-percentage>",
+            parse: MarginLeft::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Margin),
+        }
+        PaddingTop padding_top: PaddingTop => Padding {
+            name: "padding-top",
+            inherited: false,
+            initial: Padding::default(),
+            grammar: "<length-percentage [0,∞]>",
+            parse: PaddingTop::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Padding),
+        }
+        PaddingRight padding_right: PaddingRight => Padding {
+            name: "padding-right",
+            inherited: false,
+            initial: Padding::default(),
+            grammar: "<length-percentage [0,∞]>",
+            parse: PaddingRight::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Padding),
+        }
+        PaddingBottom padding_bottom: PaddingBottom => Padding {
+            name: "padding-bottom",
+            inherited: false,
+            initial: Padding::default(),
+            grammar: "<length-percentage [0,∞]>",
+            parse: PaddingBottom::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Padding),
+        }
+        PaddingLeft padding_left: PaddingLeft => Padding {
+            name: "padding-left",
+            inherited: false,
+            initial: Padding::default(),
+            grammar: "<length-percentage [0,∞]>",
+            parse: PaddingLeft::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Padding),
+        }
+        BorderTopWidth border_top_width: BorderTopWidth => Length {
+            name: "border-top-width",
+            inherited: false,
+            initial: BorderWidth::medium(),
+            grammar: "<line-width>",
+            parse: BorderTopWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderWidth),
+        }
+        BorderRightWidth border_right_width: BorderRightWidth => Length {
+            name: "border-right-width",
+            inherited: false,
+            initial: BorderWidth::medium(),
+            grammar: "<line-width>",
+            parse: BorderRightWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderWidth),
+        }
+        BorderBottomWidth border_bottom_width: BorderBottomWidth => Length {
+            name: "border-bottom-width",
+            inherited: false,
+            initial: BorderWidth::medium(),
+            grammar: "<line-width>",
+            parse: BorderBottomWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderWidth),
+        }
+        BorderLeftWidth border_left_width: BorderLeftWidth => Length {
+            name: "border-left-width",
+            inherited: false,
+            initial: BorderWidth::medium(),
+            grammar: "<line-width>",
+            parse: BorderLeftWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderWidth),
+        }
+        BorderTopStyle border_top_style: BorderTopStyle => BorderStyle {
+            name: "border-top-style",
+            inherited: false,
+            initial: BorderStyle::default(),
+            grammar: "<line-style>",
+            parse: BorderTopStyle::try_from,
+            animation: Discrete,
+            logical_group: Some(LogicalGroup::BorderStyle),
+        }
+        BorderRightStyle border_right_style: BorderRightStyle => BorderStyle {
+            name: "border-right-style",
+            inherited: false,
+            initial: BorderStyle::default(),
+            grammar: "<line-style>",
+            parse: BorderRightStyle::try_from,
+            animation: Discrete,
+            logical_group: Some(LogicalGroup::BorderStyle),
+        }
+        BorderBottomStyle border_bottom_style: BorderBottomStyle => BorderStyle {
+            name: "border-bottom-style",
+            inherited: false,
+            initial: BorderStyle::default(),
+            grammar: "<line-style>",
+            parse: BorderBottomStyle::try_from,
+            animation: Discrete,
+            logical_group: Some(LogicalGroup::BorderStyle),
+        }
+        BorderLeftStyle border_left_style: BorderLeftStyle => BorderStyle {
+            name: "border-left-style",
+            inherited: false,
+            initial: BorderStyle::default(),
+            grammar: "<line-style>",
+            parse: BorderLeftStyle::try_from,
+            animation: Discrete,
+            logical_group: Some(LogicalGroup::BorderStyle),
+        }
+        BorderTopColor border_top_color: BorderTopColor => BorderColor {
+            name: "border-top-color",
+            inherited: false,
+            initial: BorderColor::default(),
+            grammar: "<color>",
+            parse: BorderTopColor::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderColor),
+        }
+        BorderRightColor border_right_color: BorderRightColor => BorderColor {
+            name: "border-right-color",
+            inherited: false,
+            initial: BorderColor::default(),
+            grammar: "<color>",
+            parse: BorderRightColor::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderColor),
+        }
+        BorderBottomColor border_bottom_color: BorderBottomColor => BorderColor {
+            name: "border-bottom-color",
+            inherited: false,
+            initial: BorderColor::default(),
+            grammar: "<color>",
+            parse: BorderBottomColor::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderColor),
+        }
+        BorderLeftColor border_left_color: BorderLeftColor => BorderColor {
+            name: "border-left-color",
+            inherited: false,
+            initial: BorderColor::default(),
+            grammar: "<color>",
+            parse: BorderLeftColor::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderColor),
+        }
+        BorderTopLeftRadius border_top_left_radius: BorderTopLeftRadius => BorderRadius {
+            name: "border-top-left-radius",
+            inherited: false,
+            initial: BorderRadius::default(),
+            grammar: "<length-percentage [0,∞]>{1,2}",
+            parse: BorderTopLeftRadius::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderRadius),
+        }
+        BorderTopRightRadius border_top_right_radius: BorderTopRightRadius => BorderRadius {
+            name: "border-top-right-radius",
+            inherited: false,
+            initial: BorderRadius::default(),
+            grammar: "<length-percentage [0,∞]>{1,2}",
+            parse: BorderTopRightRadius::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderRadius),
+        }
+        BorderBottomRightRadius border_bottom_right_radius: BorderBottomRightRadius => BorderRadius {
+            name: "border-bottom-right-radius",
+            inherited: false,
+            initial: BorderRadius::default(),
+            grammar: "<length-percentage [0,∞]>{1,2}",
+            parse: BorderBottomRightRadius::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderRadius),
+        }
+        BorderBottomLeftRadius border_bottom_left_radius: BorderBottomLeftRadius => BorderRadius {
+            name: "border-bottom-left-radius",
+            inherited: false,
+            initial: BorderRadius::default(),
+            grammar: "<length-percentage [0,∞]>{1,2}",
+            parse: BorderBottomLeftRadius::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderRadius),
+        }
+        Position position: PositionScheme => PositionScheme {
+            name: "position",
+            inherited: false,
+            initial: PositionScheme::default(),
+            grammar: "static | relative | absolute | fixed | sticky",
+            parse: PositionScheme::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        Top top: Top => Inset {
+            name: "top",
+            inherited: false,
+            initial: Inset::default(),
+            grammar: "auto | <length-percentage>",
+            parse: Top::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Inset),
+        }
+        Right right: Right => Inset {
+            name: "right",
+            inherited: false,
+            initial: Inset::default(),
+            grammar: "auto | <length-percentage>",
+            parse: Right::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Inset),
+        }
+        Bottom bottom: Bottom => Inset {
+            name: "bottom",
+            inherited: false,
+            initial: Inset::default(),
+            grammar: "auto | <length-percentage>",
+            parse: Bottom::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Inset),
+        }
+        Left left: Left => Inset {
+            name: "left",
+            inherited: false,
+            initial: Inset::default(),
+            grammar: "auto | <length-percentage>",
+            parse: Left::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Inset),
+        }
+        ZIndex z_index: ZIndex => ZIndex {
+            name: "z-index",
+            inherited: false,
+            initial: ZIndex::default(),
+            grammar: "auto | <integer>",
+            parse: ZIndex::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        Float float: Float => Float {
+            name: "float",
+            inherited: false,
+            initial: Float::default(),
+            grammar: "none | left | right | inline-start | inline-end",
+            parse: Float::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        Clear clear: Clear => Clear {
+            name: "clear",
+            inherited: false,
+            initial: Clear::default(),
+            grammar: "none | left | right | inline-start | inline-end | both",
+            parse: Clear::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        Visibility visibility: Visibility => Visibility {
+            name: "visibility",
+            inherited: true,
+            initial: Visibility::default(),
+            grammar: "visible | hidden | collapse",
+            parse: Visibility::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        OverflowX overflow_x: OverflowX => Overflow {
+            name: "overflow-x",
+            inherited: false,
+            initial: Overflow::default(),
+            grammar: "visible | hidden | clip | scroll | auto",
+            parse: OverflowX::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        OverflowY overflow_y: OverflowY => Overflow {
+            name: "overflow-y",
+            inherited: false,
+            initial: Overflow::default(),
+            grammar: "visible | hidden | clip | scroll | auto",
+            parse: OverflowY::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        OverflowClipMargin overflow_clip_margin: OverflowClipMargin => OverflowClipMargin {
+            name: "overflow-clip-margin",
+            inherited: false,
+            initial: OverflowClipMargin::default(),
+            grammar: "<visual-box> || <length [0,∞]>",
+            parse: OverflowClipMargin::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        FlexDirection flex_direction: FlexDirection => FlexDirection {
+            name: "flex-direction",
+            inherited: false,
+            initial: FlexDirection::default(),
+            grammar: "row | row-reverse | column | column-reverse",
+            parse: FlexDirection::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        FlexWrap flex_wrap: FlexWrap => FlexWrap {
+            name: "flex-wrap",
+            inherited: false,
+            initial: FlexWrap::default(),
+            grammar: "nowrap | wrap | wrap-reverse",
+            parse: FlexWrap::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        FlexGrow flex_grow: FlexGrow => f32 {
+            name: "flex-grow",
+            inherited: false,
+            initial: 0.0,
+            grammar: "<number [0,∞]>",
+            parse: FlexGrow::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        FlexShrink flex_shrink: FlexShrink => f32 {
+            name: "flex-shrink",
+            inherited: false,
+            initial: 1.0,
+            grammar: "<number [0,∞]>",
+            parse: FlexShrink::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        FlexBasis flex_basis: FlexBasis => FlexBasis {
+            name: "flex-basis",
+            inherited: false,
+            initial: FlexBasis::default(),
+            grammar: "content | <'width'>",
+            parse: FlexBasis::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        Order order: Order => i32 {
+            name: "order",
+            inherited: false,
+            initial: 0,
+            grammar: "<integer>",
+            parse: Order::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        JustifyContent justify_content: JustifyContent => JustifyContent {
+            name: "justify-content",
+            inherited: false,
+            initial: JustifyContent::default(),
+            grammar: "normal | <content-distribution> | <overflow-position>? [ <content-position> | left | right ]",
+            parse: JustifyContent::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        AlignContent align_content: AlignContent => AlignContent {
+            name: "align-content",
+            inherited: false,
+            initial: AlignContent::default(),
+            grammar: "normal | <baseline-position> | <content-distribution> | <overflow-position>? <content-position>",
+            parse: AlignContent::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        AlignItems align_items: AlignItems => AlignItems {
+            name: "align-items",
+            inherited: false,
+            initial: AlignItems::default(),
+            grammar: "normal | stretch | <baseline-position> | <overflow-position>? <self-position>",
+            parse: AlignItems::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        AlignSelf align_self: AlignSelf => AlignSelf {
+            name: "align-self",
+            inherited: false,
+            initial: AlignSelf::default(),
+            grammar: "auto | normal | stretch | <baseline-position> | <overflow-position>? <self-position>",
+            parse: AlignSelf::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        RowGap row_gap: RowGap => Gap {
+            name: "row-gap",
+            inherited: false,
+            initial: Gap::default(),
+            grammar: "normal | <length-percentage [0,∞]>",
+            parse: RowGap::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        ColumnGap column_gap: ColumnGap => Gap {
+            name: "column-gap",
+            inherited: false,
+            initial: Gap::default(),
+            grammar: "normal | <length-percentage [0,∞]>",
+            parse: ColumnGap::try_from,
+            animation: ByComputedValue,
+            logical_group: None,
+        }
+        GridTemplateColumns grid_template_columns: GridTemplateColumns => GridTemplate {
+            name: "grid-template-columns",
+            inherited: false,
+            initial: GridTemplate::default(),
+            grammar: "none | <track-list> | <auto-track-list> | subgrid <line-name-list>?",
+            parse: GridTemplateColumns::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        GridTemplateRows grid_template_rows: GridTemplateRows => GridTemplate {
+            name: "grid-template-rows",
+            inherited: false,
+            initial: GridTemplate::default(),
+            grammar: "none | <track-list> | <auto-track-list> | subgrid <line-name-list>?",
+            parse: GridTemplateRows::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        GridTemplateAreas grid_template_areas: GridTemplateAreas => GridTemplateAreas {
+            name: "grid-template-areas",
+            inherited: false,
+            initial: GridTemplateAreas::default(),
+            grammar: "none | <string>+",
+            parse: GridTemplateAreas::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        GridAutoColumns grid_auto_columns: GridAutoColumns => GridAutoTracks {
+            name: "grid-auto-columns",
+            inherited: false,
+            initial: GridAutoTracks::default(),
+            grammar: "<track-size>+",
+            parse: GridAutoColumns::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        GridAutoRows grid_auto_rows: GridAutoRows => GridAutoTracks {
+            name: "grid-auto-rows",
+            inherited: false,
+            initial: GridAutoTracks::default(),
+            grammar: "<track-size>+",
+            parse: GridAutoRows::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        GridAutoFlow grid_auto_flow: GridAutoFlow => GridAutoFlow {
+            name: "grid-auto-flow",
+            inherited: false,
+            initial: GridAutoFlow::default(),
+            grammar: "[ row | column ] || dense",
+            parse: GridAutoFlow::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        GridRowStart grid_row_start: GridRowStart => GridLine {
+            name: "grid-row-start",
+            inherited: false,
+            initial: GridLine::default(),
+            grammar: "<grid-line>",
+            parse: GridRowStart::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        GridRowEnd grid_row_end: GridRowEnd => GridLine {
+            name: "grid-row-end",
+            inherited: false,
+            initial: GridLine::default(),
+            grammar: "<grid-line>",
+            parse: GridRowEnd::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        GridColumnStart grid_column_start: GridColumnStart => GridLine {
+            name: "grid-column-start",
+            inherited: false,
+            initial: GridLine::default(),
+            grammar: "<grid-line>",
+            parse: GridColumnStart::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+        GridColumnEnd grid_column_end: GridColumnEnd => GridLine {
+            name: "grid-column-end",
+            inherited: false,
+            initial: GridLine::default(),
+            grammar: "<grid-line>",
+            parse: GridColumnEnd::try_from,
+            animation: Discrete,
+            logical_group: None,
+        }
+    }
+    logical {
+        MarginBlockStart: MarginBlockStart {
+            name: "margin-block-start",
+            inherited: false,
+            initial: Margin::default(),
+            grammar: "auto | <length-percentage>",
+            parse: MarginBlockStart::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Margin),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockStart),
+        }
+        MarginBlockEnd: MarginBlockEnd {
+            name: "margin-block-end",
+            inherited: false,
+            initial: Margin::default(),
+            grammar: "auto | <length-percentage>",
+            parse: MarginBlockEnd::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Margin),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockEnd),
+        }
+        MarginInlineStart: MarginInlineStart {
+            name: "margin-inline-start",
+            inherited: false,
+            initial: Margin::default(),
+            grammar: "auto | <length-percentage>",
+            parse: MarginInlineStart::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Margin),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineStart),
+        }
+        MarginInlineEnd: MarginInlineEnd {
+            name: "margin-inline-end",
+            inherited: false,
+            initial: Margin::default(),
+            grammar: "auto | <length-percentage>",
+            parse: MarginInlineEnd::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Margin),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineEnd),
+        }
+        PaddingBlockStart: PaddingBlockStart {
+            name: "padding-block-start",
+            inherited: false,
+            initial: Padding::default(),
+            grammar: "<length-percentage [0,∞]>",
+            parse: PaddingBlockStart::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Padding),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockStart),
+        }
+        PaddingBlockEnd: PaddingBlockEnd {
+            name: "padding-block-end",
+            inherited: false,
+            initial: Padding::default(),
+            grammar: "<length-percentage [0,∞]>",
+            parse: PaddingBlockEnd::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Padding),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockEnd),
+        }
+        PaddingInlineStart: PaddingInlineStart {
+            name: "padding-inline-start",
+            inherited: false,
+            initial: Padding::default(),
+            grammar: "<length-percentage [0,∞]>",
+            parse: PaddingInlineStart::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Padding),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineStart),
+        }
+        PaddingInlineEnd: PaddingInlineEnd {
+            name: "padding-inline-end",
+            inherited: false,
+            initial: Padding::default(),
+            grammar: "<length-percentage [0,∞]>",
+            parse: PaddingInlineEnd::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Padding),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineEnd),
+        }
+        InsetBlockStart: InsetBlockStart {
+            name: "inset-block-start",
+            inherited: false,
+            initial: Inset::default(),
+            grammar: "auto | <length-percentage>",
+            parse: InsetBlockStart::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Inset),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockStart),
+        }
+        InsetBlockEnd: InsetBlockEnd {
+            name: "inset-block-end",
+            inherited: false,
+            initial: Inset::default(),
+            grammar: "auto | <length-percentage>",
+            parse: InsetBlockEnd::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Inset),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockEnd),
+        }
+        InsetInlineStart: InsetInlineStart {
+            name: "inset-inline-start",
+            inherited: false,
+            initial: Inset::default(),
+            grammar: "auto | <length-percentage>",
+            parse: InsetInlineStart::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Inset),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineStart),
+        }
+        InsetInlineEnd: InsetInlineEnd {
+            name: "inset-inline-end",
+            inherited: false,
+            initial: Inset::default(),
+            grammar: "auto | <length-percentage>",
+            parse: InsetInlineEnd::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Inset),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineEnd),
+        }
+        BorderBlockStartWidth: BorderBlockStartWidth {
+            name: "border-block-start-width",
+            inherited: false,
+            initial: BorderWidth::medium(),
+            grammar: "<line-width>",
+            parse: BorderBlockStartWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderWidth),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockStart),
+        }
+        BorderBlockEndWidth: BorderBlockEndWidth {
+            name: "border-block-end-width",
+            inherited: false,
+            initial: BorderWidth::medium(),
+            grammar: "<line-width>",
+            parse: BorderBlockEndWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderWidth),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockEnd),
+        }
+        BorderInlineStartWidth: BorderInlineStartWidth {
+            name: "border-inline-start-width",
+            inherited: false,
+            initial: BorderWidth::medium(),
+            grammar: "<line-width>",
+            parse: BorderInlineStartWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderWidth),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineStart),
+        }
+        BorderInlineEndWidth: BorderInlineEndWidth {
+            name: "border-inline-end-width",
+            inherited: false,
+            initial: BorderWidth::medium(),
+            grammar: "<line-width>",
+            parse: BorderInlineEndWidth::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderWidth),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineEnd),
+        }
+        BorderBlockStartStyle: BorderBlockStartStyle {
+            name: "border-block-start-style",
+            inherited: false,
+            initial: BorderStyle::default(),
+            grammar: "<line-style>",
+            parse: BorderBlockStartStyle::try_from,
+            animation: Discrete,
+            logical_group: Some(LogicalGroup::BorderStyle),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockStart),
+        }
+        BorderBlockEndStyle: BorderBlockEndStyle {
+            name: "border-block-end-style",
+            inherited: false,
+            initial: BorderStyle::default(),
+            grammar: "<line-style>",
+            parse: BorderBlockEndStyle::try_from,
+            animation: Discrete,
+            logical_group: Some(LogicalGroup::BorderStyle),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockEnd),
+        }
+        BorderInlineStartStyle: BorderInlineStartStyle {
+            name: "border-inline-start-style",
+            inherited: false,
+            initial: BorderStyle::default(),
+            grammar: "<line-style>",
+            parse: BorderInlineStartStyle::try_from,
+            animation: Discrete,
+            logical_group: Some(LogicalGroup::BorderStyle),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineStart),
+        }
+        BorderInlineEndStyle: BorderInlineEndStyle {
+            name: "border-inline-end-style",
+            inherited: false,
+            initial: BorderStyle::default(),
+            grammar: "<line-style>",
+            parse: BorderInlineEndStyle::try_from,
+            animation: Discrete,
+            logical_group: Some(LogicalGroup::BorderStyle),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineEnd),
+        }
+        BorderBlockStartColor: BorderBlockStartColor {
+            name: "border-block-start-color",
+            inherited: false,
+            initial: BorderColor::default(),
+            grammar: "<color>",
+            parse: BorderBlockStartColor::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderColor),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockStart),
+        }
+        BorderBlockEndColor: BorderBlockEndColor {
+            name: "border-block-end-color",
+            inherited: false,
+            initial: BorderColor::default(),
+            grammar: "<color>",
+            parse: BorderBlockEndColor::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderColor),
+            flow_relative: FlowRelative::Side(LogicalSide::BlockEnd),
+        }
+        BorderInlineStartColor: BorderInlineStartColor {
+            name: "border-inline-start-color",
+            inherited: false,
+            initial: BorderColor::default(),
+            grammar: "<color>",
+            parse: BorderInlineStartColor::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderColor),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineStart),
+        }
+        BorderInlineEndColor: BorderInlineEndColor {
+            name: "border-inline-end-color",
+            inherited: false,
+            initial: BorderColor::default(),
+            grammar: "<color>",
+            parse: BorderInlineEndColor::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderColor),
+            flow_relative: FlowRelative::Side(LogicalSide::InlineEnd),
+        }
+        BorderStartStartRadius: BorderStartStartRadius {
+            name: "border-start-start-radius",
+            inherited: false,
+            initial: BorderRadius::default(),
+            grammar: "<length-percentage [0,∞]>{1,2}",
+            parse: BorderStartStartRadius::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderRadius),
+            flow_relative: FlowRelative::Corner(LogicalSide::BlockStart, LogicalSide::InlineStart),
+        }
+        BorderStartEndRadius: BorderStartEndRadius {
+            name: "border-start-end-radius",
+            inherited: false,
+            initial: BorderRadius::default(),
+            grammar: "<length-percentage [0,∞]>{1,2}",
+            parse: BorderStartEndRadius::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderRadius),
+            flow_relative: FlowRelative::Corner(LogicalSide::BlockStart, LogicalSide::InlineEnd),
+        }
+        BorderEndStartRadius: BorderEndStartRadius {
+            name: "border-end-start-radius",
+            inherited: false,
+            initial: BorderRadius::default(),
+            grammar: "<length-percentage [0,∞]>{1,2}",
+            parse: BorderEndStartRadius::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderRadius),
+            flow_relative: FlowRelative::Corner(LogicalSide::BlockEnd, LogicalSide::InlineStart),
+        }
+        BorderEndEndRadius: BorderEndEndRadius {
+            name: "border-end-end-radius",
+            inherited: false,
+            initial: BorderRadius::default(),
+            grammar: "<length-percentage [0,∞]>{1,2}",
+            parse: BorderEndEndRadius::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::BorderRadius),
+            flow_relative: FlowRelative::Corner(LogicalSide::BlockEnd, LogicalSide::InlineEnd),
+        }
+        InlineSize: InlineSize {
+            name: "inline-size",
+            inherited: false,
+            initial: Size::default(),
+            grammar: "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: InlineSize::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Size),
+            flow_relative: FlowRelative::Axis(LogicalAxis::Inline),
+        }
+        BlockSize: BlockSize {
+            name: "block-size",
+            inherited: false,
+            initial: Size::default(),
+            grammar: "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: BlockSize::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::Size),
+            flow_relative: FlowRelative::Axis(LogicalAxis::Block),
+        }
+        MinInlineSize: MinInlineSize {
+            name: "min-inline-size",
+            inherited: false,
+            initial: Size::default(),
+            grammar: "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: MinInlineSize::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::MinSize),
+            flow_relative: FlowRelative::Axis(LogicalAxis::Inline),
+        }
+        MinBlockSize: MinBlockSize {
+            name: "min-block-size",
+            inherited: false,
+            initial: Size::default(),
+            grammar: "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: MinBlockSize::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::MinSize),
+            flow_relative: FlowRelative::Axis(LogicalAxis::Block),
+        }
+        MaxInlineSize: MaxInlineSize {
+            name: "max-inline-size",
+            inherited: false,
+            initial: MaxSize::default(),
+            grammar: "none | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: MaxInlineSize::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::MaxSize),
+            flow_relative: FlowRelative::Axis(LogicalAxis::Inline),
+        }
+        MaxBlockSize: MaxBlockSize {
+            name: "max-block-size",
+            inherited: false,
+            initial: MaxSize::default(),
+            grammar: "none | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>)",
+            parse: MaxBlockSize::try_from,
+            animation: ByComputedValue,
+            logical_group: Some(LogicalGroup::MaxSize),
+            flow_relative: FlowRelative::Axis(LogicalAxis::Block),
+        }
+    }
 }
 
-#[derive(Default)]
-pub struct Style {
-    pub display: Display,
-    pub background: Background,
+// The shorthand table, each setting the listed longhands.
+shorthands! {
+    Background {
+        name: "background",
+        longhands: [
+            BackgroundImage,
+            BackgroundPosition,
+            BackgroundSize,
+            BackgroundRepeat,
+            BackgroundAttachment,
+            BackgroundOrigin,
+            BackgroundClip,
+            BackgroundColor,
+        ],
+        parse: parse_background,
+        serialize: serialize_background,
+    }
+    Font {
+        name: "font",
+        longhands: [
+            FontStyle,
+            FontVariantCaps,
+            FontWeight,
+            FontStretch,
+            FontSize,
+            LineHeight,
+            FontFamily,
+            FontVariantLigatures,
+            FontVariantAlternates,
+            FontVariantNumeric,
+            FontVariantEastAsian,
+            FontVariantPosition,
+            FontVariantEmoji,
+        ],
+        parse: parse_font,
+        serialize: serialize_font,
+    }
+    FontVariant {
+        name: "font-variant",
+        longhands: [
+            FontVariantLigatures,
+            FontVariantCaps,
+            FontVariantAlternates,
+            FontVariantNumeric,
+            FontVariantEastAsian,
+            FontVariantPosition,
+            FontVariantEmoji,
+        ],
+        parse: parse_font_variant,
+        serialize: serialize_font_variant,
+    }
+    WhiteSpace {
+        name: "white-space",
+        longhands: [WhiteSpaceCollapse, TextWrap],
+        parse: parse_white_space,
+        serialize: serialize_white_space,
+    }
+    TextDecoration {
+        name: "text-decoration",
+        longhands: [
+            TextDecorationLine,
+            TextDecorationThickness,
+            TextDecorationStyle,
+            TextDecorationColor,
+        ],
+        parse: parse_text_decoration,
+        serialize: serialize_text_decoration,
+    }
+    Overflow {
+        name: "overflow",
+        longhands: [OverflowX, OverflowY],
+        parse: parse_overflow,
+        serialize: serialize_overflow,
+    }
+    FlexFlow {
+        name: "flex-flow",
+        longhands: [FlexDirection, FlexWrap],
+        parse: parse_flex_flow,
+        serialize: serialize_flex_flow,
+    }
+    Flex {
+        name: "flex",
+        longhands: [FlexGrow, FlexShrink, FlexBasis],
+        parse: parse_flex,
+        serialize: serialize_flex,
+    }
+    Gap {
+        name: "gap",
+        longhands: [RowGap, ColumnGap],
+        parse: parse_gap,
+        serialize: serialize_gap,
+    }
+    GridTemplate {
+        name: "grid-template",
+        longhands: [GridTemplateRows, GridTemplateColumns, GridTemplateAreas],
+        parse: parse_grid_template,
+        serialize: serialize_grid_template,
+    }
+    Grid {
+        name: "grid",
+        longhands: [
+            GridTemplateRows,
+            GridTemplateColumns,
+            GridTemplateAreas,
+            GridAutoRows,
+            GridAutoColumns,
+            GridAutoFlow,
+        ],
+        parse: parse_grid,
+        serialize: serialize_grid,
+    }
+    GridRow {
+        name: "grid-row",
+        longhands: [GridRowStart, GridRowEnd],
+        parse: parse_grid_row,
+        serialize: serialize_grid_row,
+    }
+    GridColumn {
+        name: "grid-column",
+        longhands: [GridColumnStart, GridColumnEnd],
+        parse: parse_grid_column,
+        serialize: serialize_grid_column,
+    }
+    GridArea {
+        name: "grid-area",
+        longhands: [GridRowStart, GridColumnStart, GridRowEnd, GridColumnEnd],
+        parse: parse_grid_area,
+        serialize: serialize_grid_area,
+    }
+    Margin {
+        name: "margin",
+        longhands: [
+            MarginTop,
+            MarginRight,
+            MarginBottom,
+            MarginLeft,
+        ],
+        parse: parse_margin,
+        serialize: serialize_margin,
+    }
+    Padding {
+        name: "padding",
+        longhands: [
+            PaddingTop,
+            PaddingRight,
+            PaddingBottom,
+            PaddingLeft,
+        ],
+        parse: parse_padding,
+        serialize: serialize_padding,
+    }
+    BorderWidth {
+        name: "border-width",
+        longhands: [
+            BorderTopWidth,
+            BorderRightWidth,
+            BorderBottomWidth,
+            BorderLeftWidth,
+        ],
+        parse: parse_border_width,
+        serialize: serialize_border_width,
+    }
+    BorderStyle {
+        name: "border-style",
+        longhands: [
+            BorderTopStyle,
+            BorderRightStyle,
+            BorderBottomStyle,
+            BorderLeftStyle,
+        ],
+        parse: parse_border_style,
+        serialize: serialize_border_style,
+    }
+    BorderColor {
+        name: "border-color",
+        longhands: [
+            BorderTopColor,
+            BorderRightColor,
+            BorderBottomColor,
+            BorderLeftColor,
+        ],
+        parse: parse_border_color,
+        serialize: serialize_border_color,
+    }
+    BorderTop {
+        name: "border-top",
+        longhands: [
+            BorderTopWidth,
+            BorderTopStyle,
+            BorderTopColor,
+        ],
+        parse: parse_border_top,
+        serialize: serialize_border_top,
+    }
+    BorderRight {
+        name: "border-right",
+        longhands: [
+            BorderRightWidth,
+            BorderRightStyle,
+            BorderRightColor,
+        ],
+        parse: parse_border_right,
+        serialize: serialize_border_right,
+    }
+    BorderBottom {
+        name: "border-bottom",
+        longhands: [
+            BorderBottomWidth,
+            BorderBottomStyle,
+            BorderBottomColor,
+        ],
+        parse: parse_border_bottom,
+        serialize: serialize_border_bottom,
+    }
+    BorderLeft {
+        name: "border-left",
+        longhands: [
+            BorderLeftWidth,
+            BorderLeftStyle,
+            BorderLeftColor,
+        ],
+        parse: parse_border_left,
+        serialize: serialize_border_left,
+    }
+    Border {
+        name: "border",
+        longhands: [
+            BorderTopWidth,
+            BorderRightWidth,
+            BorderBottomWidth,
+            BorderLeftWidth,
+            BorderTopStyle,
+            BorderRightStyle,
+            BorderBottomStyle,
+            BorderLeftStyle,
+            BorderTopColor,
+            BorderRightColor,
+            BorderBottomColor,
+            BorderLeftColor,
+        ],
+        parse: parse_border,
+        serialize: serialize_border,
+    }
+    BorderRadius {
+        name: "border-radius",
+        longhands: [
+            BorderTopLeftRadius,
+            BorderTopRightRadius,
+            BorderBottomRightRadius,
+            BorderBottomLeftRadius,
+        ],
+        parse: parse_border_radius,
+        serialize: serialize_border_radius,
+    }
+    Inset {
+        name: "inset",
+        longhands: [
+            Top,
+            Right,
+            Bottom,
+            Left,
+        ],
+        parse: parse_inset,
+        serialize: serialize_inset,
+    }
+    MarginBlock {
+        name: "margin-block",
+        longhands: [
+            MarginBlockStart,
+            MarginBlockEnd,
+        ],
+        parse: parse_margin_block,
+        serialize: serialize_margin_block,
+    }
+    MarginInline {
+        name: "margin-inline",
+        longhands: [
+            MarginInlineStart,
+            MarginInlineEnd,
+        ],
+        parse: parse_margin_inline,
+        serialize: serialize_margin_inline,
+    }
+    PaddingBlock {
+        name: "padding-block",
+        longhands: [
+            PaddingBlockStart,
+            PaddingBlockEnd,
+        ],
+        parse: parse_padding_block,
+        serialize: serialize_padding_block,
+    }
+    PaddingInline {
+        name: "padding-inline",
+        longhands: [
+            PaddingInlineStart,
+            PaddingInlineEnd,
+        ],
+        parse: parse_padding_inline,
+        serialize: serialize_padding_inline,
+    }
+    InsetBlock {
+        name: "inset-block",
+        longhands: [
+            InsetBlockStart,
+            InsetBlockEnd,
+        ],
+        parse: parse_inset_block,
+        serialize: serialize_inset_block,
+    }
+    InsetInline {
+        name: "inset-inline",
+        longhands: [
+            InsetInlineStart,
+            InsetInlineEnd,
+        ],
+        parse: parse_inset_inline,
+        serialize: serialize_inset_inline,
+    }
+    BorderBlockWidth {
+        name: "border-block-width",
+        longhands: [
+            BorderBlockStartWidth,
+            BorderBlockEndWidth,
+        ],
+        parse: parse_border_block_width,
+        serialize: serialize_border_block_width,
+    }
+    BorderBlockStyle {
+        name: "border-block-style",
+        longhands: [
+            BorderBlockStartStyle,
+            BorderBlockEndStyle,
+        ],
+        parse: parse_border_block_style,
+        serialize: serialize_border_block_style,
+    }
+    BorderBlockColor {
+        name: "border-block-color",
+        longhands: [
+            BorderBlockStartColor,
+            BorderBlockEndColor,
+        ],
+        parse: parse_border_block_color,
+        serialize: serialize_border_block_color,
+    }
+    BorderBlockStart {
+        name: "border-block-start",
+        longhands: [
+            BorderBlockStartWidth,
+            BorderBlockStartStyle,
+            BorderBlockStartColor,
+        ],
+        parse: parse_border_block_start,
+        serialize: serialize_border_block_start,
+    }
+    BorderBlockEnd {
+        name: "border-block-end",
+        longhands: [
+            BorderBlockEndWidth,
+            BorderBlockEndStyle,
+            BorderBlockEndColor,
+        ],
+        parse: parse_border_block_end,
+        serialize: serialize_border_block_end,
+    }
+    BorderBlock {
+        name: "border-block",
+        longhands: [
+            BorderBlockStartWidth,
+            BorderBlockEndWidth,
+            BorderBlockStartStyle,
+            BorderBlockEndStyle,
+            BorderBlockStartColor,
+            BorderBlockEndColor,
+        ],
+        parse: parse_border_block,
+        serialize: serialize_border_block,
+    }
+    BorderInlineWidth {
+        name: "border-inline-width",
+        longhands: [
+            BorderInlineStartWidth,
+            BorderInlineEndWidth,
+        ],
+        parse: parse_border_inline_width,
+        serialize: serialize_border_inline_width,
+    }
+    BorderInlineStyle {
+        name: "border-inline-style",
+        longhands: [
+            BorderInlineStartStyle,
+            BorderInlineEndStyle,
+        ],
+        parse: parse_border_inline_style,
+        serialize: serialize_border_inline_style,
+    }
+    BorderInlineColor {
+        name: "border-inline-color",
+        longhands: [
+            BorderInlineStartColor,
+            BorderInlineEndColor,
+        ],
+        parse: parse_border_inline_color,
+        serialize: serialize_border_inline_color,
+    }
+    BorderInlineStart {
+        name: "border-inline-start",
+        longhands: [
+            BorderInlineStartWidth,
+            BorderInlineStartStyle,
+            BorderInlineStartColor,
+        ],
+        parse: parse_border_inline_start,
+        serialize: serialize_border_inline_start,
+    }
+    BorderInlineEnd {
+        name: "border-inline-end",
+        longhands: [
+            BorderInlineEndWidth,
+            BorderInlineEndStyle,
+            BorderInlineEndColor,
+        ],
+        parse: parse_border_inline_end,
+        serialize: serialize_border_inline_end,
+    }
+    BorderInline {
+        name: "border-inline",
+        longhands: [
+            BorderInlineStartWidth,
+            BorderInlineEndWidth,
+            BorderInlineStartStyle,
+            BorderInlineEndStyle,
+            BorderInlineStartColor,
+            BorderInlineEndColor,
+        ],
+        parse: parse_border_inline,
+        serialize: serialize_border_inline,
+    }
 }
 
+pub type ComputedStyle = Style;
+
 #[cfg(test)]
 mod tests {
-    use crate::style::BackgroundAttachment;
+    use std::collections::HashSet;
+
+    use crate::style::{
+        parse_component_values, BackgroundAttachment, Direction, Layers, Longhand,
+        PropertyDeclaration, PropertyId, ShorthandId, Value, WritingMode,
+    };
 
     use super::Style;
 
     #[test]
-    pub fn test_lookup() {
+    pub fn test_001_property_table() {
         let style = Style::default();
 
+        let id = PropertyId::try_from("Background-Attachment").unwrap();
+        assert_eq!(id, Layers::<BackgroundAttachment>::ID);
+        assert_eq!(id.name(), "background-attachment");
+        assert!(!id.is_inherited());
         assert_eq!(
-            lookup!(style, "background-attachment"),
-            BackgroundAttachment::default()
+            style.value(id),
+            Value::from(BackgroundAttachment::default())
         );
+        assert_eq!(style.value(id), id.initial_value());
+
+        assert!(PropertyId::FontFamily.is_inherited());
+        // The shorthands aren't longhands.
+        assert!(PropertyId::try_from("background").is_err());
+        assert!(PropertyId::ALL
+            .iter()
+            .all(|id| PropertyId::try_from(id.name()) == Ok(*id)));
+    }
+
+    #[test]
+    pub fn test_002_names_and_ids() {
+        for id in PropertyId::ALL {
+            assert_eq!(PropertyId::try_from(id.name()), Ok(*id));
+            assert_eq!(
+                PropertyId::try_from(id.name().to_ascii_uppercase().as_str()),
+                Ok(*id)
+            );
+            assert_eq!(id.to_string(), id.name());
+            assert!(ShorthandId::try_from(id.name()).is_err(), "{}", id);
+        }
+
+        for shorthand in ShorthandId::ALL {
+            assert_eq!(ShorthandId::try_from(shorthand.name()), Ok(*shorthand));
+            assert_eq!(
+                ShorthandId::try_from(shorthand.name().to_ascii_uppercase().as_str()),
+                Ok(*shorthand)
+            );
+            assert_eq!(shorthand.to_string(), shorthand.name());
+        }
+
+        // The names are unique across the longhands and the shorthands.
+        let names = PropertyId::ALL
+            .iter()
+            .map(PropertyId::name)
+            .chain(ShorthandId::ALL.iter().map(ShorthandId::name))
+            .collect::<HashSet<_>>();
+        assert_eq!(names.len(), PropertyId::ALL.len() + ShorthandId::ALL.len());
+    }
+
+    #[test]
+    pub fn test_003_inherited_flags() {
+        let inherited = [
+            "color",
+            "font-family",
+            "font-size",
+            "font-style",
+            "font-weight",
+            "font-stretch",
+            "line-height",
+            "font-variant-ligatures",
+            "font-variant-caps",
+            "font-variant-alternates",
+            "font-variant-numeric",
+            "font-variant-east-asian",
+            "font-variant-position",
+            "font-variant-emoji",
+            "writing-mode",
+            "direction",
+            "text-orientation",
+            "white-space-collapse",
+            "text-wrap",
+            "text-align",
+            "text-align-last",
+            "text-justify",
+            "text-transform",
+            "letter-spacing",
+            "word-spacing",
+            "text-indent",
+            "text-shadow",
+            "overflow-wrap",
+            "word-break",
+            "hyphens",
+            "tab-size",
+            "visibility",
+        ];
+
+        for id in PropertyId::ALL {
+            assert_eq!(id.is_inherited(), inherited.contains(&id.name()), "{}", id);
+
+            // The logical longhands are inherited as the physical ones they map to.
+            let physical = id.to_physical(WritingMode::VerticalRl, Direction::Rtl);
+            assert_eq!(id.is_inherited(), physical.is_inherited(), "{}", id);
+        }
+    }
+
+    #[test]
+    pub fn test_004_initial_values() {
+        let style = Style::default();
+
+        for id in PropertyId::ALL {
+            assert_eq!(style.value(*id), id.initial_value(), "{}", id);
+        }
+    }
+
+    #[test]
+    pub fn test_005_shorthand_longhands() {
+        let values = [
+            ("background", "red"),
+            ("font", "12px serif"),
+            ("font-variant", "small-caps"),
+            ("white-space", "pre"),
+            ("text-decoration", "underline"),
+            ("overflow", "hidden"),
+            ("flex-flow", "row wrap"),
+            ("flex", "1"),
+            ("gap", "1px"),
+            ("grid-template", "none"),
+            ("grid", "none"),
+            ("grid-row", "1"),
+            ("grid-column", "1"),
+            ("grid-area", "1"),
+            ("margin", "1px"),
+            ("padding", "1px"),
+            ("border-width", "1px"),
+            ("border-style", "solid"),
+            ("border-color", "red"),
+            ("border-top", "1px solid"),
+            ("border-right", "1px solid"),
+            ("border-bottom", "1px solid"),
+            ("border-left", "1px solid"),
+            ("border", "1px solid"),
+            ("border-radius", "1px"),
+            ("inset", "1px"),
+            ("margin-block", "1px"),
+            ("margin-inline", "1px"),
+            ("padding-block", "1px"),
+            ("padding-inline", "1px"),
+            ("inset-block", "1px"),
+            ("inset-inline", "1px"),
+            ("border-block-width", "1px"),
+            ("border-block-style", "solid"),
+            ("border-block-color", "red"),
+            ("border-block-start", "1px solid"),
+            ("border-block-end", "1px solid"),
+            ("border-block", "1px solid"),
+            ("border-inline-width", "1px"),
+            ("border-inline-style", "solid"),
+            ("border-inline-color", "red"),
+            ("border-inline-start", "1px solid"),
+            ("border-inline-end", "1px solid"),
+            ("border-inline", "1px solid"),
+        ];
+        assert_eq!(values.len(), ShorthandId::ALL.len());
+
+        // Expanding a shorthand sets all its longhands, once and in order.
+        for (name, value) in values {
+            let shorthand = ShorthandId::try_from(name).unwrap();
+            let longhands = shorthand.longhands();
+            assert_eq!(
+                longhands.iter().collect::<HashSet<_>>().len(),
+                longhands.len(),
+                "{}",
+                name
+            );
+
+            let ids = PropertyDeclaration::parse_all(name, &parse_component_values(value))
+                .unwrap()
+                .iter()
+                .map(PropertyDeclaration::id)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                ids,
+                longhands.iter().copied().map(Some).collect::<Vec<_>>(),
+                "{}",
+                name
+            );
+
+            // The flow-relative shorthands only set logical longhands.
+            let logical = name.contains("-block") || name.contains("-inline");
+            assert!(
+                longhands.iter().all(|id| id.is_logical() == logical),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_006_nested_shorthands() {
+        let pairs = [
+            ("border", "border-width"),
+            ("border", "border-style"),
+            ("border", "border-color"),
+            ("border", "border-left"),
+            ("border-block", "border-block-start"),
+            ("border-inline", "border-inline-color"),
+            ("font", "font-variant"),
+            ("grid", "grid-template"),
+        ];
+
+        // The longhands of a sub-shorthand are all longhands of its parent.
+        for (outer, inner) in pairs {
+            let outer = ShorthandId::try_from(outer).unwrap().longhands();
+            let inner = ShorthandId::try_from(inner).unwrap().longhands();
+            assert!(inner.iter().all(|id| outer.contains(id)), "{:?}", inner);
+        }
     }
 }
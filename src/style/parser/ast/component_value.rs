@@ -5,7 +5,7 @@ use crate::style::{
 
 use super::{Function, SimpleBlock};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum ComponentValue {
     Block(SimpleBlock),
     Function(Function),
@@ -23,6 +23,39 @@ impl ComponentValue {
             _ => None,
         }
     }
+
+    pub fn as_token_kind(&self) -> Option<&TokenKind> {
+        match self {
+            Self::Token(tok) => Some(&tok.kind),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn is_whitespace(&self) -> bool {
+        self.if_token(Token::is_whitespace).unwrap_or(false)
+    }
+
+    #[inline]
+    pub fn is_delim_value<S: AsRef<str>>(&self, value: S) -> bool {
+        self.if_token(|tok| tok.is_delim_value(value.as_ref()))
+            .unwrap_or(false)
+    }
+
+    #[inline]
+    pub fn is_comma(&self) -> bool {
+        matches!(self.as_token_kind(), Some(TokenKind::Comma))
+    }
+}
+
+impl std::fmt::Display for ComponentValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComponentValue::Block(block) => write!(f, "{}", block),
+            ComponentValue::Function(function) => write!(f, "{}", function),
+            ComponentValue::Token(token) => write!(f, "{}", token),
+        }
+    }
 }
 
 impl From<Token> for ComponentValue {
@@ -32,6 +65,9 @@ impl From<Token> for ComponentValue {
 }
 
 impl Parser<Token> for ComponentValue {
+    /// Parse a component value.
+    ///
+    /// It expects the lexer's current token to be the first token of the component value.
     fn parse<L: Lexer<Token>>(lexer: &mut L) -> Self {
         let token = lexer.current().unwrap();
 
@@ -41,7 +77,6 @@ impl Parser<Token> for ComponentValue {
                 | TokenKind::OpeningParenthesis
                 | TokenKind::OpeningCurlyBracket
         ) {
-            lexer.rewind();
             ComponentValue::Block(SimpleBlock::parse(lexer))
         } else if matches!(token.kind, TokenKind::Function(_)) {
             lexer.rewind();
@@ -51,3 +86,86 @@ impl Parser<Token> for ComponentValue {
         }
     }
 }
+
+/// Parse a list of component values.
+pub fn parse_component_values(source: &str) -> Vec<ComponentValue> {
+    let mut lexer = crate::style::Lexer::new(source);
+    let mut values = Vec::<ComponentValue>::default();
+
+    while lexer.next().is_some() {
+        values.push(ComponentValue::parse(&mut lexer));
+    }
+
+    values
+}
+
+/// A lexer over an already parsed sequence of component values.
+pub struct ComponentValueStream<'a> {
+    values: &'a [ComponentValue],
+    cursor: isize,
+}
+
+impl<'a> ComponentValueStream<'a> {
+    pub fn new(values: &'a [ComponentValue]) -> Self {
+        Self { values, cursor: -1 }
+    }
+}
+
+impl Iterator for ComponentValueStream<'_> {
+    type Item = ComponentValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor < self.values.len() as isize {
+            self.cursor += 1;
+        }
+
+        self.current()
+    }
+}
+
+impl Lexer<ComponentValue> for ComponentValueStream<'_> {
+    fn current(&self) -> Option<Self::Item> {
+        usize::try_from(self.cursor)
+            .ok()
+            .and_then(|cursor| self.values.get(cursor))
+            .cloned()
+    }
+
+    fn rewind(&mut self) {
+        if self.cursor >= 0 {
+            self.cursor -= 1;
+        }
+    }
+}
+
+/// Helpers shared by the parsers working on component values.
+pub trait ComponentValueLexer: Lexer<ComponentValue> {
+    /// Returns the next component value without consuming it.
+    fn peek(&mut self) -> Option<ComponentValue> {
+        let value = self.next();
+
+        if value.is_some() {
+            self.rewind();
+        }
+
+        value
+    }
+
+    /// Consume whitespaces, returns true if any was consumed.
+    fn skip_whitespace(&mut self) -> bool {
+        let mut skipped = false;
+
+        while let Some(value) = self.next() {
+            if !value.is_whitespace() {
+                self.rewind();
+                break;
+            }
+
+            skipped = true;
+        }
+
+        skipped
+    }
+}
+
+impl<L: Lexer<ComponentValue>> ComponentValueLexer for L {}
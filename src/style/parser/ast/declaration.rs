@@ -0,0 +1,244 @@
+use crate::style::{
+    traits::{ComponentValueLexer, Lexer, TryParser},
+    StyleError, TokenKind,
+};
+
+use super::{parse_component_values, ComponentValue, ComponentValueStream, SimpleBlock};
+
+/// A property declaration, as written in a style rule or a style attribute.
+///
+/// # Grammar
+/// <declaration> = <ident-token> : <declaration-value>? [ ! important ]?
+#[derive(Debug, Clone, PartialEq)]
+pub struct Declaration {
+    /// The property name, in lowercase unless it is a custom property.
+    pub name: String,
+    pub value: Vec<ComponentValue>,
+    pub important: bool,
+}
+
+impl Declaration {
+    pub fn new<S: ToString, I: IntoIterator<Item = ComponentValue>>(
+        name: S,
+        value: I,
+        important: bool,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.into_iter().collect(),
+            important,
+        }
+    }
+
+    pub fn is_custom_property(&self) -> bool {
+        self.name.starts_with("--")
+    }
+
+    /// Removes the trailing "! important" from the value, returns true if found.
+    fn strip_important(value: &mut Vec<ComponentValue>) -> bool {
+        let significant = value
+            .iter()
+            .enumerate()
+            .filter(|(_, cv)| !cv.is_whitespace())
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        if let [.., bang, important] = significant.as_slice() {
+            let is_important = matches!(
+                value[*important].as_token_kind(),
+                Some(TokenKind::Ident(ident)) if ident.eq_ignore_ascii_case("important")
+            );
+
+            if is_important && value[*bang].is_delim_value("!") {
+                value.truncate(*bang);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn trim(value: &mut Vec<ComponentValue>) {
+        while value
+            .last()
+            .map(ComponentValue::is_whitespace)
+            .unwrap_or(false)
+        {
+            value.pop();
+        }
+
+        let leading = value.iter().take_while(|cv| cv.is_whitespace()).count();
+        value.drain(..leading);
+    }
+}
+
+impl TryParser<ComponentValue> for Declaration {
+    /// Parse a declaration, up to the next semicolon or the end of the input.
+    fn try_parse<L: Lexer<ComponentValue>>(lexer: &mut L) -> Result<Self, StyleError> {
+        lexer.skip_whitespace();
+
+        let name = match lexer.next().and_then(|cv| cv.as_token_kind().cloned()) {
+            Some(TokenKind::Ident(name)) if name.starts_with("--") => name,
+            Some(TokenKind::Ident(name)) => name.to_ascii_lowercase(),
+            _ => {
+                lexer.rewind();
+                return Err(StyleError::InvalidDeclaration);
+            }
+        };
+
+        lexer.skip_whitespace();
+
+        if !matches!(
+            lexer
+                .next()
+                .as_ref()
+                .and_then(ComponentValue::as_token_kind),
+            Some(TokenKind::Colon)
+        ) {
+            lexer.rewind();
+            return Err(StyleError::InvalidDeclaration);
+        }
+
+        let mut value = Vec::<ComponentValue>::default();
+
+        while let Some(cv) = lexer.next() {
+            if matches!(cv.as_token_kind(), Some(TokenKind::Semicolon)) {
+                lexer.rewind();
+                break;
+            }
+
+            value.push(cv);
+        }
+
+        let important = Self::strip_important(&mut value);
+        Self::trim(&mut value);
+
+        if value.is_empty() && !name.starts_with("--") {
+            return Err(StyleError::InvalidDeclaration);
+        }
+
+        Ok(Self {
+            name,
+            value,
+            important,
+        })
+    }
+}
+
+impl std::fmt::Display for Declaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: ", self.name)?;
+
+        for value in self.value.iter() {
+            write!(f, "{}", value)?;
+        }
+
+        if self.important {
+            write!(f, " !important")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A list of declarations, such as the content of a style rule or a style attribute.
+///
+/// Invalid declarations are dropped while parsing.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct DeclarationBlock(pub Vec<Declaration>);
+
+impl DeclarationBlock {
+    pub fn iter(&self) -> std::slice::Iter<'_, Declaration> {
+        self.0.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The last declaration of the property, the one which wins within the block.
+    pub fn get(&self, name: &str) -> Option<&Declaration> {
+        self.iter().rev().find(|decl| decl.name == name)
+    }
+}
+
+impl From<&[ComponentValue]> for DeclarationBlock {
+    fn from(value: &[ComponentValue]) -> Self {
+        let mut lexer = ComponentValueStream::new(value);
+        let mut declarations = Vec::<Declaration>::default();
+
+        loop {
+            lexer.skip_whitespace();
+
+            if lexer.peek().is_none() {
+                break;
+            }
+
+            match Declaration::try_parse(&mut lexer) {
+                Ok(declaration) => declarations.push(declaration),
+                // Consume the remnants of the invalid declaration.
+                Err(_) => {
+                    while let Some(cv) = lexer.next() {
+                        if matches!(cv.as_token_kind(), Some(TokenKind::Semicolon)) {
+                            lexer.rewind();
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // Consume the semicolon
+            lexer.next();
+        }
+
+        Self(declarations)
+    }
+}
+
+impl From<&SimpleBlock> for DeclarationBlock {
+    fn from(value: &SimpleBlock) -> Self {
+        Self::from(value.values.as_slice())
+    }
+}
+
+impl From<&str> for DeclarationBlock {
+    /// Parse the content of a style attribute.
+    fn from(value: &str) -> Self {
+        Self::from(parse_component_values(value).as_slice())
+    }
+}
+
+impl std::fmt::Display for DeclarationBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let declarations = self
+            .iter()
+            .map(|decl| format!("{};", decl))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        write!(f, "{}", declarations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeclarationBlock;
+
+    #[test]
+    fn test_001_declaration_block() {
+        let block = DeclarationBlock::from(
+            "color: red; Margin : 0 auto !important; invalid; --Brand: #0af ;; width:",
+        );
+
+        assert_eq!(
+            block.to_string(),
+            "color: red; margin: 0 auto !important; --Brand: #0af;"
+        );
+        assert!(block.get("margin").unwrap().important);
+        assert!(block.get("--Brand").unwrap().is_custom_property());
+    }
+}
@@ -5,17 +5,56 @@ use crate::style::{
 
 use super::ComponentValue;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Function {
     pub name: String,
     pub value: Vec<ComponentValue>,
 }
 
+impl Function {
+    pub fn new<S: ToString, I: IntoIterator<Item = ComponentValue>>(name: S, value: I) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.into_iter().collect(),
+        }
+    }
+
+    /// Checks the function name, ASCII case-insensitively.
+    pub fn is(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+}
+
+impl std::fmt::Display for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for value in self.value.iter() {
+            write!(f, "{}", value)?;
+        }
+        write!(f, ")")
+    }
+}
+
 impl Parser<Token> for Function {
+    /// Parse a function.
+    ///
+    /// It expects the lexer's next token to be the <function-token>.
     fn parse<L: Lexer<Token>>(lexer: &mut L) -> Self {
-        let name = lexer.next().unwrap().kind.expect_ident().to_string();
+        let name = match lexer.next().unwrap().kind {
+            TokenKind::Function(name) => name,
+            kind => kind.expect_ident().to_string(),
+        };
+
         let mut value = Vec::<ComponentValue>::default();
 
+        // The lexer emits the opening parenthesis as a separate token,
+        // except for url( which consumes it.
+        if let Some(token) = lexer.next() {
+            if !matches!(token.kind, TokenKind::OpeningParenthesis) {
+                lexer.rewind();
+            }
+        }
+
         while let Some(token) = lexer.next() {
             if matches!(token.kind, TokenKind::ClosingParenthesis) {
                 return Self { name, value };
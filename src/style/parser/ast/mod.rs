@@ -1,4 +1,5 @@
 mod component_value;
+mod declaration;
 mod function;
 mod rule;
 mod selector;
@@ -6,6 +7,7 @@ mod simple_block;
 mod stylesheet;
 
 pub use component_value::*;
+pub use declaration::*;
 pub use function::*;
 pub use rule::*;
 pub use selector::*;
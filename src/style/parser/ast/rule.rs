@@ -1,12 +1,36 @@
 use crate::style::{
-    traits::{Lexer, Parser},
-    Token, TokenKind,
+    traits::{Lexer, Parser, TryParser},
+    StyleError, Token, TokenKind,
 };
 
-use super::{ComponentValue, SimpleBlock};
+use super::{BlockDelimiter, ComponentValue, SelectorList, SimpleBlock};
 
+#[derive(Default, Debug, PartialEq, Clone)]
 pub struct Rules(Vec<Rule>);
 
+impl Rules {
+    pub fn iter(&self) -> std::slice::Iter<'_, Rule> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a Rules {
+    type Item = &'a Rule;
+    type IntoIter = std::slice::Iter<'a, Rule>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl Parser<Token> for Rules {
     fn parse<L: Lexer<Token>>(lexer: &mut L) -> Self {
         let mut rules = Vec::<Rule>::default();
@@ -25,7 +49,9 @@ impl Parser<Token> for Rules {
                 rules.push(Rule::At(AtRule::parse(lexer)));
             } else {
                 lexer.rewind();
-                rules.push(Rule::Qualified(QualifiedRule::parse(lexer)));
+                if let Ok(rule) = QualifiedRule::try_parse(lexer) {
+                    rules.push(Rule::Qualified(rule));
+                }
             }
         }
 
@@ -33,12 +59,53 @@ impl Parser<Token> for Rules {
     }
 }
 
+impl From<&[ComponentValue]> for Rules {
+    /// Parse the rules nested in a block (@layer, @scope, @media, ...).
+    fn from(value: &[ComponentValue]) -> Self {
+        let mut rules = Vec::<Rule>::default();
+        let mut prelude = Vec::<ComponentValue>::default();
+
+        for cv in value.iter() {
+            let is_at_rule = matches!(
+                prelude.first().and_then(ComponentValue::as_token_kind),
+                Some(TokenKind::AtKeyword(_))
+            );
+
+            match cv {
+                ComponentValue::Token(tok) if prelude.is_empty() && tok.is_whitespace() => {}
+                ComponentValue::Token(tok)
+                    if is_at_rule && matches!(tok.kind, TokenKind::Semicolon) =>
+                {
+                    rules.push(Rule::At(AtRule {
+                        prelude: std::mem::take(&mut prelude),
+                        block: SimpleBlock::default(),
+                    }));
+                }
+                ComponentValue::Block(block) if block.is_delimited_by(BlockDelimiter::CurlyBracket) => {
+                    let prelude = std::mem::take(&mut prelude);
+                    let block = block.clone();
+
+                    rules.push(if is_at_rule {
+                        Rule::At(AtRule { prelude, block })
+                    } else {
+                        Rule::Qualified(QualifiedRule { prelude, block })
+                    });
+                }
+                cv => prelude.push(cv.clone()),
+            }
+        }
+
+        Self(rules)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum Rule {
     At(AtRule),
     Qualified(QualifiedRule),
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Default, Debug, PartialEq, Clone)]
 pub struct AtRule {
     prelude: Vec<ComponentValue>,
     block: SimpleBlock,
@@ -58,9 +125,30 @@ impl AtRule {
             block,
         }
     }
+
+    /// The at-keyword of the rule, without the @.
+    pub fn name(&self) -> Option<&str> {
+        self.prelude.first().and_then(|cv| match cv.as_token_kind() {
+            Some(TokenKind::AtKeyword(name)) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The prelude of the rule, without the at-keyword.
+    pub fn prelude(&self) -> &[ComponentValue] {
+        match self.name() {
+            Some(_) => &self.prelude[1..],
+            None => &self.prelude,
+        }
+    }
+
+    pub fn block(&self) -> &SimpleBlock {
+        &self.block
+    }
 }
 
 impl Parser<Token> for AtRule {
+    /// Parse an at-rule, an at-rule still open at EOF being returned as is.
     fn parse<L: Lexer<Token>>(lexer: &mut L) -> Self {
         let mut rule = Self::default();
 
@@ -71,16 +159,17 @@ impl Parser<Token> for AtRule {
 
             if matches!(token.kind, TokenKind::OpeningCurlyBracket) {
                 rule.block = SimpleBlock::parse(lexer);
+                return rule;
             } else {
                 rule.prelude.push(ComponentValue::parse(lexer));
             }
         }
 
-        panic!("unexpected eof");
+        rule
     }
 }
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, PartialEq, Clone)]
 pub struct QualifiedRule {
     prelude: Vec<ComponentValue>,
     block: SimpleBlock,
@@ -97,21 +186,35 @@ impl QualifiedRule {
             block,
         }
     }
+
+    pub fn prelude(&self) -> &[ComponentValue] {
+        &self.prelude
+    }
+
+    pub fn block(&self) -> &SimpleBlock {
+        &self.block
+    }
+
+    /// Parse the prelude as a selector list.
+    pub fn selectors(&self) -> Result<SelectorList, StyleError> {
+        SelectorList::try_from(self.prelude.as_slice())
+    }
 }
 
-impl Parser<Token> for QualifiedRule {
-    fn parse<L: Lexer<Token>>(lexer: &mut L) -> Self {
+impl TryParser<Token> for QualifiedRule {
+    /// Parse a qualified rule, which is dropped when EOF comes before its block.
+    fn try_parse<L: Lexer<Token>>(lexer: &mut L) -> Result<Self, StyleError> {
         let mut rule = Self::default();
 
         while let Some(token) = lexer.next() {
             if matches!(token.kind, TokenKind::OpeningCurlyBracket) {
                 rule.block = SimpleBlock::parse(lexer);
-                return rule;
+                return Ok(rule);
             } else {
                 rule.prelude.push(ComponentValue::parse(lexer));
             }
         }
 
-        panic!("unexpected eof");
+        Err(StyleError::UnexpectedEof)
     }
 }
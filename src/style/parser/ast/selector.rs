@@ -1,15 +1,142 @@
 use crate::style::{
-    traits::{Lexer, Parser},
-    TokenKind,
+    traits::{ComponentValueLexer, Lexer, TryParser},
+    StyleError, TokenKind,
 };
 
-use super::ComponentValue;
+use super::{BlockDelimiter, ComponentValue, ComponentValueStream, Function, SimpleBlock};
+
+/// The specificity of a selector, compared lexicographically.
+///
+/// [Selectors Level 4](https://drafts.csswg.org/selectors/#specificity-rules)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Specificity {
+    pub ids: u32,
+    pub classes: u32,
+    pub types: u32,
+}
+
+impl Specificity {
+    pub fn new(ids: u32, classes: u32, types: u32) -> Self {
+        Self {
+            ids,
+            classes,
+            types,
+        }
+    }
+}
+
+impl std::ops::Add for Specificity {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            ids: self.ids + rhs.ids,
+            classes: self.classes + rhs.classes,
+            types: self.types + rhs.types,
+        }
+    }
+}
 
 ///
 ///
 /// # Grammar
 /// <selector-list> = <complex-selector-list>
-pub struct SelectorList();
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectorList(pub Vec<ComplexSelector>);
+
+impl SelectorList {
+    pub fn iter(&self) -> std::slice::Iter<'_, ComplexSelector> {
+        self.0.iter()
+    }
+
+    /// The greatest specificity among the selectors of the list.
+    pub fn max_specificity(&self) -> Specificity {
+        self.iter()
+            .map(ComplexSelector::specificity)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl TryParser<ComponentValue> for SelectorList {
+    fn try_parse<L: Lexer<ComponentValue>>(lexer: &mut L) -> Result<Self, StyleError> {
+        let mut selectors = Vec::<ComplexSelector>::default();
+
+        loop {
+            lexer.skip_whitespace();
+            selectors.push(ComplexSelector::try_parse(lexer)?);
+            lexer.skip_whitespace();
+
+            match lexer.next() {
+                None => break,
+                Some(cv) if cv.is_comma() => continue,
+                Some(_) => return Err(StyleError::InvalidSelector),
+            }
+        }
+
+        Ok(Self(selectors))
+    }
+}
+
+impl TryFrom<&[ComponentValue]> for SelectorList {
+    type Error = StyleError;
+
+    fn try_from(value: &[ComponentValue]) -> Result<Self, Self::Error> {
+        Self::try_parse(&mut ComponentValueStream::new(value))
+    }
+}
+
+impl TryFrom<&str> for SelectorList {
+    type Error = StyleError;
+
+    /// Parse a selector list from its source.
+    ///
+    /// ```
+    /// use marennes::style::SelectorList;
+    ///
+    /// let list = SelectorList::try_from("div > p.note, #main").unwrap();
+    /// assert_eq!(list.to_string(), "div > p.note, #main");
+    /// ```
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let values = super::parse_component_values(value);
+        Self::try_from(values.as_slice())
+    }
+}
+
+impl std::fmt::Display for SelectorList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let selectors = self
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(f, "{}", selectors)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    /// A B
+    Descendant,
+    /// A > B
+    Child,
+    /// A + B
+    NextSibling,
+    /// A ~ B
+    SubsequentSibling,
+}
+
+impl std::fmt::Display for Combinator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Combinator::Descendant => write!(f, " "),
+            Combinator::Child => write!(f, " > "),
+            Combinator::NextSibling => write!(f, " + "),
+            Combinator::SubsequentSibling => write!(f, " ~ "),
+        }
+    }
+}
 
 ///
 ///
@@ -19,64 +146,1183 @@ pub struct SelectorList();
 /// <complex-selector> = <compound-selector>
 ///                    | <complex-selector> <cobminator> <compound-selector>
 ///                    | <complex-selector> <whitespace-token> <compound-selector>
+#[derive(Debug, Clone, PartialEq)]
 pub enum ComplexSelector {
     Root(CompoundSelector),
-    Combined(Box<ComplexSelector>, Option<String>, CompoundSelector),
+    Combined(Box<ComplexSelector>, Combinator, CompoundSelector),
+}
+
+impl ComplexSelector {
+    /// The compound selector matched against the subject of the selector.
+    pub fn rightmost(&self) -> &CompoundSelector {
+        match self {
+            Self::Root(compound) => compound,
+            Self::Combined(_, _, compound) => compound,
+        }
+    }
+
+    pub fn specificity(&self) -> Specificity {
+        match self {
+            Self::Root(compound) => compound.specificity(),
+            Self::Combined(lhs, _, compound) => lhs.specificity() + compound.specificity(),
+        }
+    }
+
+    /// Iterate over the compound selectors from right to left, with the
+    /// combinator which links each compound to the one on its right.
+    pub fn iter_compounds(&self) -> impl Iterator<Item = (&CompoundSelector, Option<Combinator>)> {
+        let mut compounds = Vec::default();
+        let mut next = Some(self);
+        let mut right_combinator = None;
+
+        while let Some(selector) = next {
+            match selector {
+                Self::Root(compound) => {
+                    compounds.push((compound, right_combinator));
+                    next = None;
+                }
+                Self::Combined(lhs, combinator, compound) => {
+                    compounds.push((compound, right_combinator));
+                    right_combinator = Some(*combinator);
+                    next = Some(lhs);
+                }
+            }
+        }
+
+        compounds.into_iter()
+    }
+}
+
+impl TryParser<ComponentValue> for ComplexSelector {
+    fn try_parse<L: Lexer<ComponentValue>>(lexer: &mut L) -> Result<Self, StyleError> {
+        let mut selector = Self::Root(CompoundSelector::try_parse(lexer)?);
+
+        loop {
+            let whitespace = lexer.skip_whitespace();
+
+            let combinator = match lexer.peek() {
+                None => break,
+                Some(cv) if cv.is_comma() => break,
+                Some(cv) if cv.is_delim_value(">") => Combinator::Child,
+                Some(cv) if cv.is_delim_value("+") => Combinator::NextSibling,
+                Some(cv) if cv.is_delim_value("~") => Combinator::SubsequentSibling,
+                Some(_) if whitespace => Combinator::Descendant,
+                Some(_) => return Err(StyleError::InvalidSelector),
+            };
+
+            // A pseudo-element is only allowed in the last compound.
+            if selector.rightmost().pseudo_element.is_some() {
+                return Err(StyleError::InvalidSelector);
+            }
+
+            if combinator != Combinator::Descendant {
+                lexer.next();
+                lexer.skip_whitespace();
+            }
+
+            let compound = CompoundSelector::try_parse(lexer)?;
+            selector = Self::Combined(Box::new(selector), combinator, compound);
+        }
+
+        Ok(selector)
+    }
+}
+
+impl std::fmt::Display for ComplexSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Root(compound) => write!(f, "{}", compound),
+            Self::Combined(lhs, combinator, compound) => {
+                write!(f, "{}{}{}", lhs, combinator, compound)
+            }
+        }
+    }
 }
 
 ///
 ///
 /// # Grammar
 /// <compound-selector> = [ <type-selector>? <subclass-selector>* [ <pseudo-element-selector> <pseudo-class-selector>* ]* ]!
-pub struct CompoundSelector{
-    type_selector: Option<TypeSelector>,
-    subclass_selectors: Vec<SubclassSelector>,
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CompoundSelector {
+    pub type_selector: Option<TypeSelector>,
+    pub subclass_selectors: Vec<SubclassSelector>,
+    pub pseudo_element: Option<PseudoElementSelector>,
+}
 
-};
+impl CompoundSelector {
+    pub fn is_empty(&self) -> bool {
+        self.type_selector.is_none()
+            && self.subclass_selectors.is_empty()
+            && self.pseudo_element.is_none()
+    }
 
+    pub fn specificity(&self) -> Specificity {
+        let mut specificity = Specificity::default();
 
-pub struct TypeSelector;
+        if let Some(TypeSelector { name: Some(_), .. }) = &self.type_selector {
+            specificity.types += 1;
+        }
 
-pub struct SubclassSelector;
+        if self.pseudo_element.is_some() {
+            specificity.types += 1;
+        }
 
-pub struct NsPrefix(String);
+        self.subclass_selectors
+            .iter()
+            .fold(specificity, |acc, sel| acc + sel.specificity())
+    }
 
-/// A pseudo-element selector
+    /// The pseudo-element targeted by the compound, if any.
+    pub fn pseudo(&self) -> Option<PseudoElement> {
+        self.pseudo_element.as_ref().map(|pe| pe.0)
+    }
+
+    /// The local name required by the type selector, if any.
+    pub fn local_name(&self) -> Option<&str> {
+        self.type_selector
+            .as_ref()
+            .and_then(|ty| ty.name.as_deref())
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.subclass_selectors.iter().filter_map(|sel| match sel {
+            SubclassSelector::Id(id) => Some(id.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.subclass_selectors.iter().filter_map(|sel| match sel {
+            SubclassSelector::Class(class) => Some(class.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn attributes(&self) -> impl Iterator<Item = &AttributeSelector> {
+        self.subclass_selectors.iter().filter_map(|sel| match sel {
+            SubclassSelector::Attribute(attr) => Some(attr),
+            _ => None,
+        })
+    }
+}
+
+impl TryParser<ComponentValue> for CompoundSelector {
+    fn try_parse<L: Lexer<ComponentValue>>(lexer: &mut L) -> Result<Self, StyleError> {
+        let mut compound = Self::default();
+
+        if let Some(cv) = lexer.peek() {
+            if matches!(cv.as_token_kind(), Some(TokenKind::Ident(_)))
+                || cv.is_delim_value("*")
+                || cv.is_delim_value("|")
+            {
+                compound.type_selector = Some(TypeSelector::try_parse(lexer)?);
+            }
+        }
+
+        while let Some(cv) = lexer.next() {
+            // A pseudo-element ends the compound: another pseudo-element or a
+            // pseudo-class, which would apply to the originating element, is invalid.
+            if compound.pseudo_element.is_some() {
+                if matches!(cv.as_token_kind(), Some(TokenKind::Colon)) {
+                    return Err(StyleError::InvalidSelector);
+                }
+
+                lexer.rewind();
+                break;
+            }
+
+            match cv {
+                ComponentValue::Token(tok) => match tok.kind {
+                    TokenKind::Hash(id) => {
+                        compound.subclass_selectors.push(SubclassSelector::Id(id))
+                    }
+                    TokenKind::Delim(del) if del == "." => match lexer.next() {
+                        Some(ComponentValue::Token(tok)) => match tok.kind {
+                            TokenKind::Ident(class) => compound
+                                .subclass_selectors
+                                .push(SubclassSelector::Class(class)),
+                            _ => return Err(StyleError::InvalidSelector),
+                        },
+                        _ => return Err(StyleError::InvalidSelector),
+                    },
+                    TokenKind::Colon => match lexer.next() {
+                        Some(cv) if matches!(cv.as_token_kind(), Some(TokenKind::Colon)) => {
+                            let pseudo_element = PseudoElementSelector::try_parse(lexer)?;
+                            compound.pseudo_element = Some(pseudo_element);
+                        }
+                        Some(cv) => {
+                            lexer.rewind();
+
+                            // Legacy single-colon pseudo-elements.
+                            if let Some(TokenKind::Ident(name)) = cv.as_token_kind() {
+                                if PseudoElementSelector::is_legacy(name) {
+                                    let pseudo_element = PseudoElementSelector::try_parse(lexer)?;
+                                    compound.pseudo_element = Some(pseudo_element);
+                                    continue;
+                                }
+                            }
+
+                            let pseudo_class = PseudoClassSelector::try_parse(lexer)?;
+                            compound
+                                .subclass_selectors
+                                .push(SubclassSelector::PseudoClass(pseudo_class));
+                        }
+                        None => return Err(StyleError::InvalidSelector),
+                    },
+                    _ => {
+                        lexer.rewind();
+                        break;
+                    }
+                },
+                ComponentValue::Block(block)
+                    if block.is_delimited_by(BlockDelimiter::SquareBracket) =>
+                {
+                    compound
+                        .subclass_selectors
+                        .push(SubclassSelector::Attribute(AttributeSelector::try_from(
+                            &block,
+                        )?));
+                }
+                _ => {
+                    lexer.rewind();
+                    break;
+                }
+            }
+        }
+
+        if compound.is_empty() {
+            return Err(StyleError::InvalidSelector);
+        }
+
+        Ok(compound)
+    }
+}
+
+impl std::fmt::Display for CompoundSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(ty) = &self.type_selector {
+            write!(f, "{}", ty)?;
+        }
+
+        for sel in self.subclass_selectors.iter() {
+            write!(f, "{}", sel)?;
+        }
+
+        if let Some(pe) = &self.pseudo_element {
+            write!(f, "{}", pe)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A namespace prefix, * stands for any namespace, and the empty string for no namespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NsPrefix(pub String);
+
+/// A type selector, the name is None for the universal selector.
 ///
-/// # Exemple
-/// ::is("value")
-pub struct PseudoElementSelector(PseudoClassSelector);
+/// # Grammar
+/// <type-selector> = <wq-name> | <ns-prefix>? '*'
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeSelector {
+    pub prefix: Option<NsPrefix>,
+    pub name: Option<String>,
+}
+
+impl TypeSelector {
+    fn name_from(cv: Option<ComponentValue>) -> Result<Option<String>, StyleError> {
+        match cv {
+            Some(ComponentValue::Token(tok)) => match tok.kind {
+                TokenKind::Ident(name) => Ok(Some(name.to_ascii_lowercase())),
+                TokenKind::Delim(del) if del == "*" => Ok(None),
+                _ => Err(StyleError::InvalidSelector),
+            },
+            _ => Err(StyleError::InvalidSelector),
+        }
+    }
+}
+
+impl TryParser<ComponentValue> for TypeSelector {
+    fn try_parse<L: Lexer<ComponentValue>>(lexer: &mut L) -> Result<Self, StyleError> {
+        // |name, no namespace.
+        if lexer
+            .peek()
+            .map(|cv| cv.is_delim_value("|"))
+            .unwrap_or(false)
+        {
+            lexer.next();
+            return Ok(Self {
+                prefix: Some(NsPrefix(String::default())),
+                name: Self::name_from(lexer.next())?,
+            });
+        }
+
+        let first = lexer.next();
+        let first_str = first.as_ref().map(ToString::to_string).unwrap_or_default();
+        let name = Self::name_from(first)?;
 
-impl Parser<ComponentValue> for PseudoElementSelector {
-    fn parse<L: Lexer<ComponentValue>>(lexer: &mut L) -> Self {
         if lexer
-            .next()
-            .and_then(|cv| cv.if_token(|tok| tok.is_delim_value(":")))
+            .peek()
+            .map(|cv| cv.is_delim_value("|"))
             .unwrap_or(false)
         {
-            return Self(PseudoClassSelector::parse(lexer));
+            lexer.next();
+            return Ok(Self {
+                prefix: Some(NsPrefix(first_str)),
+                name: Self::name_from(lexer.next())?,
+            });
+        }
+
+        Ok(Self { prefix: None, name })
+    }
+}
+
+impl std::fmt::Display for TypeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(NsPrefix(prefix)) = &self.prefix {
+            write!(f, "{}|", prefix)?;
+        }
+
+        match &self.name {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "*"),
+        }
+    }
+}
+
+///
+///
+/// # Grammar
+/// <subclass-selector> = <id-selector> | <class-selector> | <attribute-selector> | <pseudo-class-selector>
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubclassSelector {
+    Id(String),
+    Class(String),
+    Attribute(AttributeSelector),
+    PseudoClass(PseudoClassSelector),
+}
+
+impl SubclassSelector {
+    pub fn specificity(&self) -> Specificity {
+        match self {
+            Self::Id(_) => Specificity::new(1, 0, 0),
+            Self::Class(_) | Self::Attribute(_) => Specificity::new(0, 1, 0),
+            Self::PseudoClass(pc) => pc.specificity(),
         }
+    }
+}
 
-        panic!("parsing error");
+impl std::fmt::Display for SubclassSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Id(id) => write!(f, "#{}", id),
+            Self::Class(class) => write!(f, ".{}", class),
+            Self::Attribute(attr) => write!(f, "{}", attr),
+            Self::PseudoClass(pc) => write!(f, "{}", pc),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOperator {
+    /// [attr=value]
+    Equal,
+    /// [attr~=value]
+    Includes,
+    /// [attr|=value]
+    DashMatch,
+    /// [attr^=value]
+    Prefix,
+    /// [attr$=value]
+    Suffix,
+    /// [attr*=value]
+    Substring,
+}
+
+impl AttributeOperator {
+    /// Checks if the attribute value matches the selector value.
+    pub fn matches(&self, attribute: &str, value: &str, case_insensitive: bool) -> bool {
+        let (attribute, value) = if case_insensitive {
+            (attribute.to_lowercase(), value.to_lowercase())
+        } else {
+            (attribute.to_string(), value.to_string())
+        };
+
+        match self {
+            Self::Equal => attribute == value,
+            Self::Includes => {
+                !value.is_empty() && attribute.split_ascii_whitespace().any(|v| v == value)
+            }
+            Self::DashMatch => attribute == value || attribute.starts_with(&format!("{}-", value)),
+            Self::Prefix => !value.is_empty() && attribute.starts_with(&value),
+            Self::Suffix => !value.is_empty() && attribute.ends_with(&value),
+            Self::Substring => !value.is_empty() && attribute.contains(&value),
+        }
+    }
+}
+
+impl std::fmt::Display for AttributeOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Equal => write!(f, "="),
+            Self::Includes => write!(f, "~="),
+            Self::DashMatch => write!(f, "|="),
+            Self::Prefix => write!(f, "^="),
+            Self::Suffix => write!(f, "$="),
+            Self::Substring => write!(f, "*="),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeMatcher {
+    pub operator: AttributeOperator,
+    pub value: String,
+    pub case_insensitive: bool,
+}
+
+/// An attribute selector.
+///
+/// # Grammar
+/// <attribute-selector> = '[' <wq-name> ']' |
+///                        '[' <wq-name> <attr-matcher> [ <string-token> | <ident-token> ] <attr-modifier>? ']'
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeSelector {
+    pub name: String,
+    pub matcher: Option<AttributeMatcher>,
+}
+
+impl TryFrom<&SimpleBlock> for AttributeSelector {
+    type Error = StyleError;
+
+    fn try_from(value: &SimpleBlock) -> Result<Self, Self::Error> {
+        let mut lexer = ComponentValueStream::new(&value.values);
+
+        lexer.skip_whitespace();
+        let name = match lexer.next().and_then(|cv| cv.as_token_kind().cloned()) {
+            Some(TokenKind::Ident(name)) => name.to_ascii_lowercase(),
+            _ => return Err(StyleError::InvalidSelector),
+        };
+        lexer.skip_whitespace();
+
+        let operator = match lexer.next() {
+            None => {
+                return Ok(Self {
+                    name,
+                    matcher: None,
+                })
+            }
+            Some(cv) if cv.is_delim_value("=") => AttributeOperator::Equal,
+            Some(cv) => {
+                let operator = match cv.as_token_kind() {
+                    Some(TokenKind::Delim(del)) => match del.as_str() {
+                        "~" => AttributeOperator::Includes,
+                        "|" => AttributeOperator::DashMatch,
+                        "^" => AttributeOperator::Prefix,
+                        "$" => AttributeOperator::Suffix,
+                        "*" => AttributeOperator::Substring,
+                        _ => return Err(StyleError::InvalidSelector),
+                    },
+                    _ => return Err(StyleError::InvalidSelector),
+                };
+
+                if !lexer
+                    .next()
+                    .map(|cv| cv.is_delim_value("="))
+                    .unwrap_or(false)
+                {
+                    return Err(StyleError::InvalidSelector);
+                }
+
+                operator
+            }
+        };
+
+        lexer.skip_whitespace();
+        let value = match lexer.next().and_then(|cv| cv.as_token_kind().cloned()) {
+            Some(TokenKind::Ident(value)) | Some(TokenKind::String(value)) => value,
+            _ => return Err(StyleError::InvalidSelector),
+        };
+        lexer.skip_whitespace();
+
+        let case_insensitive = match lexer.next().and_then(|cv| cv.as_token_kind().cloned()) {
+            None => false,
+            Some(TokenKind::Ident(modifier)) if modifier.eq_ignore_ascii_case("i") => true,
+            Some(TokenKind::Ident(modifier)) if modifier.eq_ignore_ascii_case("s") => false,
+            _ => return Err(StyleError::InvalidSelector),
+        };
+        lexer.skip_whitespace();
+
+        if lexer.next().is_some() {
+            return Err(StyleError::InvalidSelector);
+        }
+
+        Ok(Self {
+            name,
+            matcher: Some(AttributeMatcher {
+                operator,
+                value,
+                case_insensitive,
+            }),
+        })
+    }
+}
+
+impl std::fmt::Display for AttributeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.matcher {
+            None => write!(f, "[{}]", self.name),
+            Some(matcher) => {
+                write!(f, "[{}{}\"{}\"", self.name, matcher.operator, matcher.value)?;
+                if matcher.case_insensitive {
+                    write!(f, " i")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// The An+B microsyntax.
+///
+/// [CSS Syntax Level 3](https://drafts.csswg.org/css-syntax/#anb-microsyntax)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nth {
+    pub a: i32,
+    pub b: i32,
+}
+
+impl Nth {
+    pub fn new(a: i32, b: i32) -> Self {
+        Self { a, b }
+    }
+
+    /// Checks if the 1-based position matches An+B for some n >= 0.
+    pub fn matches(&self, position: i32) -> bool {
+        if self.a == 0 {
+            return position == self.b;
+        }
+
+        let diff = position - self.b;
+        diff % self.a == 0 && diff / self.a >= 0
+    }
+}
+
+/// The value of an integer token, and whether it has an explicit sign.
+fn integer(kind: &TokenKind) -> Option<(i32, bool)> {
+    match kind {
+        TokenKind::Number(number) if number.is_integer() => {
+            Some((number.to_f32() as i32, number.is_signed()))
+        }
+        _ => None,
+    }
+}
+
+impl TryFrom<&[ComponentValue]> for Nth {
+    type Error = StyleError;
+
+    /// Parse An+B from its tokens, whitespace being allowed everywhere but
+    /// between a leading + and the n.
+    fn try_from(value: &[ComponentValue]) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidSelector;
+
+        let tokens = value
+            .iter()
+            .map(|cv| cv.as_token_kind().ok_or(invalid.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut tokens = tokens
+            .into_iter()
+            .skip_while(|kind| matches!(kind, TokenKind::Whitespace));
+
+        let first = tokens.next().ok_or(invalid.clone())?;
+
+        // odd, even and <integer> stand alone.
+        let standalone = match first {
+            TokenKind::Ident(ident) if ident.eq_ignore_ascii_case("odd") => Some(Self::new(2, 1)),
+            TokenKind::Ident(ident) if ident.eq_ignore_ascii_case("even") => Some(Self::new(2, 0)),
+            kind => integer(kind).map(|(b, _)| Self::new(0, b)),
+        };
+
+        if let Some(nth) = standalone {
+            return match tokens.all(|kind| matches!(kind, TokenKind::Whitespace)) {
+                true => Ok(nth),
+                false => Err(invalid),
+            };
+        }
+
+        // The A coefficient, and the unit or ident which starts with the n.
+        let (a, unit) = match first {
+            TokenKind::Dimension(dimension) if dimension.number.is_integer() => (
+                dimension.number.to_f32() as i32,
+                dimension.unit.to_ascii_lowercase(),
+            ),
+            TokenKind::Ident(ident) => match ident.to_ascii_lowercase().strip_prefix('-') {
+                Some(unit) => (-1, unit.to_string()),
+                None => (1, ident.to_ascii_lowercase()),
+            },
+            TokenKind::Delim(delim) if delim == "+" => match tokens.next() {
+                Some(TokenKind::Ident(ident)) if !ident.starts_with('-') => {
+                    (1, ident.to_ascii_lowercase())
+                }
+                _ => return Err(invalid),
+            },
+            _ => return Err(invalid),
+        };
+
+        let rest = tokens
+            .filter(|kind| !matches!(kind, TokenKind::Whitespace))
+            .collect::<Vec<_>>();
+
+        let suffix = unit.strip_prefix('n').ok_or(invalid.clone())?;
+
+        let b = match (suffix, rest.as_slice()) {
+            ("", []) => 0,
+            ("", [b]) => match integer(b) {
+                Some((b, true)) => b,
+                _ => return Err(invalid),
+            },
+            ("", [TokenKind::Delim(sign), b]) if sign == "+" || sign == "-" => match integer(b) {
+                Some((b, false)) if sign == "-" => -b,
+                Some((b, false)) => b,
+                _ => return Err(invalid),
+            },
+            ("-", [b]) => match integer(b) {
+                Some((b, false)) => -b,
+                _ => return Err(invalid),
+            },
+            (suffix, []) => match suffix.strip_prefix('-') {
+                Some(digits) if digits.bytes().all(|c| c.is_ascii_digit()) => {
+                    -digits.parse::<i32>().map_err(|_| invalid.clone())?
+                }
+                _ => return Err(invalid),
+            },
+            _ => return Err(invalid),
+        };
+
+        Ok(Self::new(a, b))
+    }
+}
+
+impl std::fmt::Display for Nth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.a, self.b) {
+            (0, b) => write!(f, "{}", b),
+            (a, b) => {
+                match a {
+                    1 => write!(f, "n")?,
+                    -1 => write!(f, "-n")?,
+                    a => write!(f, "{}n", a)?,
+                };
+
+                match b {
+                    0 => Ok(()),
+                    b if b > 0 => write!(f, "+{}", b),
+                    b => write!(f, "{}", b),
+                }
+            }
+        }
+    }
+}
+
+/// The dynamic state of an element, as tested by user-action and input pseudo-classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElementState {
+    Hover,
+    Active,
+    Focus,
+    FocusWithin,
+    FocusVisible,
+    Link,
+    Visited,
+    AnyLink,
+    Target,
+    Checked,
+    Indeterminate,
+    Disabled,
+    Enabled,
+    Required,
+    Optional,
+    ReadOnly,
+    ReadWrite,
+    PlaceholderShown,
+    Default,
+    Valid,
+    Invalid,
+}
+
+impl TryFrom<&str> for ElementState {
+    type Error = StyleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "hover" => Ok(Self::Hover),
+            "active" => Ok(Self::Active),
+            "focus" => Ok(Self::Focus),
+            "focus-within" => Ok(Self::FocusWithin),
+            "focus-visible" => Ok(Self::FocusVisible),
+            "link" => Ok(Self::Link),
+            "visited" => Ok(Self::Visited),
+            "any-link" => Ok(Self::AnyLink),
+            "target" => Ok(Self::Target),
+            "checked" => Ok(Self::Checked),
+            "indeterminate" => Ok(Self::Indeterminate),
+            "disabled" => Ok(Self::Disabled),
+            "enabled" => Ok(Self::Enabled),
+            "required" => Ok(Self::Required),
+            "optional" => Ok(Self::Optional),
+            "read-only" => Ok(Self::ReadOnly),
+            "read-write" => Ok(Self::ReadWrite),
+            "placeholder-shown" => Ok(Self::PlaceholderShown),
+            "default" => Ok(Self::Default),
+            "valid" => Ok(Self::Valid),
+            "invalid" => Ok(Self::Invalid),
+            _ => Err(StyleError::InvalidSelector),
+        }
+    }
+}
+
+impl std::fmt::Display for ElementState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hover => write!(f, "hover"),
+            Self::Active => write!(f, "active"),
+            Self::Focus => write!(f, "focus"),
+            Self::FocusWithin => write!(f, "focus-within"),
+            Self::FocusVisible => write!(f, "focus-visible"),
+            Self::Link => write!(f, "link"),
+            Self::Visited => write!(f, "visited"),
+            Self::AnyLink => write!(f, "any-link"),
+            Self::Target => write!(f, "target"),
+            Self::Checked => write!(f, "checked"),
+            Self::Indeterminate => write!(f, "indeterminate"),
+            Self::Disabled => write!(f, "disabled"),
+            Self::Enabled => write!(f, "enabled"),
+            Self::Required => write!(f, "required"),
+            Self::Optional => write!(f, "optional"),
+            Self::ReadOnly => write!(f, "read-only"),
+            Self::ReadWrite => write!(f, "read-write"),
+            Self::PlaceholderShown => write!(f, "placeholder-shown"),
+            Self::Default => write!(f, "default"),
+            Self::Valid => write!(f, "valid"),
+            Self::Invalid => write!(f, "invalid"),
+        }
+    }
+}
+
+/// The pseudo-elements supported by the style system.
+///
+/// [CSS Pseudo-Elements Level 4](https://drafts.csswg.org/css-pseudo-4/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PseudoElement {
+    Before,
+    After,
+    Marker,
+    FirstLine,
+    FirstLetter,
+    Placeholder,
+}
+
+/// Properties, or property prefixes when ending with a dash, applying to ::first-line.
+const FIRST_LINE_PROPERTIES: &[&str] = &[
+    "font",
+    "font-",
+    "color",
+    "opacity",
+    "background",
+    "background-",
+    "word-spacing",
+    "letter-spacing",
+    "text-decoration",
+    "text-decoration-",
+    "text-emphasis",
+    "text-emphasis-",
+    "text-transform",
+    "text-shadow",
+    "line-height",
+    "vertical-align",
+    "ruby-position",
+    "--",
+];
+
+/// Properties applying to ::first-letter, besides the ::first-line ones.
+const FIRST_LETTER_PROPERTIES: &[&str] = &[
+    "margin",
+    "margin-",
+    "padding",
+    "padding-",
+    "border",
+    "border-",
+    "box-shadow",
+    "float",
+    "initial-letter",
+    "initial-letter-",
+];
+
+/// Properties applying to ::marker.
+const MARKER_PROPERTIES: &[&str] = &[
+    "font",
+    "font-",
+    "color",
+    "content",
+    "white-space",
+    "white-space-",
+    "text-combine-upright",
+    "unicode-bidi",
+    "direction",
+    "animation",
+    "animation-",
+    "transition",
+    "transition-",
+    "--",
+];
+
+fn is_property_in(property: &str, properties: &[&str]) -> bool {
+    properties.iter().any(|allowed| {
+        if allowed.ends_with('-') {
+            property.starts_with(allowed)
+        } else {
+            property == *allowed
+        }
+    })
+}
+
+impl PseudoElement {
+    pub const ALL: [Self; 6] = [
+        Self::Before,
+        Self::After,
+        Self::Marker,
+        Self::FirstLine,
+        Self::FirstLetter,
+        Self::Placeholder,
+    ];
+
+    /// Typographic pseudo-elements style a fragment of the originating
+    /// element's inline content, during the inline layout.
+    pub fn is_typographic(&self) -> bool {
+        matches!(self, Self::FirstLine | Self::FirstLetter)
+    }
+
+    /// Pseudo-elements which can be written with a single colon.
+    pub fn is_legacy(&self) -> bool {
+        matches!(
+            self,
+            Self::Before | Self::After | Self::FirstLine | Self::FirstLetter
+        )
+    }
+
+    /// Checks if the property applies to the pseudo-element, declarations
+    /// of other properties are ignored.
+    pub fn allows_property(&self, property: &str) -> bool {
+        let property = property.to_ascii_lowercase();
+
+        match self {
+            Self::Before | Self::After => true,
+            Self::FirstLine | Self::Placeholder => is_property_in(&property, FIRST_LINE_PROPERTIES),
+            Self::FirstLetter => {
+                is_property_in(&property, FIRST_LINE_PROPERTIES)
+                    || is_property_in(&property, FIRST_LETTER_PROPERTIES)
+            }
+            Self::Marker => is_property_in(&property, MARKER_PROPERTIES),
+        }
+    }
+}
+
+impl TryFrom<&str> for PseudoElement {
+    type Error = StyleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "before" => Ok(Self::Before),
+            "after" => Ok(Self::After),
+            "marker" => Ok(Self::Marker),
+            "first-line" => Ok(Self::FirstLine),
+            "first-letter" => Ok(Self::FirstLetter),
+            "placeholder" => Ok(Self::Placeholder),
+            _ => Err(StyleError::InvalidSelector),
+        }
+    }
+}
+
+impl std::fmt::Display for PseudoElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Before => write!(f, "before"),
+            Self::After => write!(f, "after"),
+            Self::Marker => write!(f, "marker"),
+            Self::FirstLine => write!(f, "first-line"),
+            Self::FirstLetter => write!(f, "first-letter"),
+            Self::Placeholder => write!(f, "placeholder"),
+        }
+    }
+}
+
+/// A pseudo-element selector
+///
+/// # Exemple
+/// ::before
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PseudoElementSelector(pub PseudoElement);
+
+impl PseudoElementSelector {
+    /// Checks if the ident is a pseudo-element which can be written with a single colon.
+    fn is_legacy(name: &str) -> bool {
+        PseudoElement::try_from(name)
+            .map(|pe| pe.is_legacy())
+            .unwrap_or(false)
+    }
+}
+
+impl TryParser<ComponentValue> for PseudoElementSelector {
+    /// Parse the pseudo-element name, the colons being already consumed.
+    fn try_parse<L: Lexer<ComponentValue>>(lexer: &mut L) -> Result<Self, StyleError> {
+        match lexer.next().and_then(|cv| cv.as_token_kind().cloned()) {
+            Some(TokenKind::Ident(name)) => PseudoElement::try_from(name.as_str()).map(Self),
+            _ => Err(StyleError::InvalidSelector),
+        }
+    }
+}
+
+impl std::fmt::Display for PseudoElementSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "::{}", self.0)
     }
 }
 
 /// A pseudo-class selector
 ///
 /// # Example
-/// :nth(0)
-pub struct PseudoClassSelector(ComponentValue);
+/// :nth-child(2n+1)
+#[derive(Debug, Clone, PartialEq)]
+pub enum PseudoClassSelector {
+    Root,
+    Empty,
+    FirstChild,
+    LastChild,
+    OnlyChild,
+    FirstOfType,
+    LastOfType,
+    OnlyOfType,
+    NthChild(Nth),
+    NthLastChild(Nth),
+    NthOfType(Nth),
+    NthLastOfType(Nth),
+    Not(SelectorList),
+    Is(SelectorList),
+    Where(SelectorList),
+    State(ElementState),
+}
 
-impl Parser<ComponentValue> for PseudoClassSelector {
-    fn parse<L: Lexer<ComponentValue>>(lexer: &mut L) -> Self {
-        if lexer
-            .next()
-            .and_then(|cv| cv.if_token(|tok| tok.is_delim_value(":")))
-            .unwrap_or(false)
-        {
-            return Self(lexer.next().unwrap());
+impl PseudoClassSelector {
+    pub fn specificity(&self) -> Specificity {
+        match self {
+            Self::Not(list) | Self::Is(list) => list.max_specificity(),
+            Self::Where(_) => Specificity::default(),
+            _ => Specificity::new(0, 1, 0),
+        }
+    }
+
+    fn from_ident(name: &str) -> Result<Self, StyleError> {
+        match name.to_ascii_lowercase().as_str() {
+            "root" => Ok(Self::Root),
+            "empty" => Ok(Self::Empty),
+            "first-child" => Ok(Self::FirstChild),
+            "last-child" => Ok(Self::LastChild),
+            "only-child" => Ok(Self::OnlyChild),
+            "first-of-type" => Ok(Self::FirstOfType),
+            "last-of-type" => Ok(Self::LastOfType),
+            "only-of-type" => Ok(Self::OnlyOfType),
+            name => ElementState::try_from(name).map(Self::State),
+        }
+    }
+
+    fn from_function(function: &Function) -> Result<Self, StyleError> {
+        let args = function.value.as_slice();
+
+        match function.name.to_ascii_lowercase().as_str() {
+            "nth-child" => Nth::try_from(args).map(Self::NthChild),
+            "nth-last-child" => Nth::try_from(args).map(Self::NthLastChild),
+            "nth-of-type" => Nth::try_from(args).map(Self::NthOfType),
+            "nth-last-of-type" => Nth::try_from(args).map(Self::NthLastOfType),
+            "not" => SelectorList::try_from(args).map(Self::Not),
+            "is" | "matches" => SelectorList::try_from(args).map(Self::Is),
+            "where" => SelectorList::try_from(args).map(Self::Where),
+            _ => Err(StyleError::InvalidSelector),
+        }
+    }
+}
+
+impl TryParser<ComponentValue> for PseudoClassSelector {
+    /// Parse the pseudo-class, the colon being already consumed.
+    fn try_parse<L: Lexer<ComponentValue>>(lexer: &mut L) -> Result<Self, StyleError> {
+        match lexer.next() {
+            Some(ComponentValue::Token(tok)) => match tok.kind {
+                TokenKind::Ident(name) => Self::from_ident(&name),
+                _ => Err(StyleError::InvalidSelector),
+            },
+            Some(ComponentValue::Function(function)) => Self::from_function(&function),
+            _ => Err(StyleError::InvalidSelector),
+        }
+    }
+}
+
+impl std::fmt::Display for PseudoClassSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Root => write!(f, ":root"),
+            Self::Empty => write!(f, ":empty"),
+            Self::FirstChild => write!(f, ":first-child"),
+            Self::LastChild => write!(f, ":last-child"),
+            Self::OnlyChild => write!(f, ":only-child"),
+            Self::FirstOfType => write!(f, ":first-of-type"),
+            Self::LastOfType => write!(f, ":last-of-type"),
+            Self::OnlyOfType => write!(f, ":only-of-type"),
+            Self::NthChild(nth) => write!(f, ":nth-child({})", nth),
+            Self::NthLastChild(nth) => write!(f, ":nth-last-child({})", nth),
+            Self::NthOfType(nth) => write!(f, ":nth-of-type({})", nth),
+            Self::NthLastOfType(nth) => write!(f, ":nth-last-of-type({})", nth),
+            Self::Not(list) => write!(f, ":not({})", list),
+            Self::Is(list) => write!(f, ":is({})", list),
+            Self::Where(list) => write!(f, ":where({})", list),
+            Self::State(state) => write!(f, ":{}", state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Nth, PseudoElement, SelectorList, Specificity};
+    use crate::style::parse_component_values;
+
+    #[test]
+    fn test_001_parse_and_serialize() {
+        for source in [
+            "div",
+            "*",
+            "#main",
+            "div.note.warning",
+            "ul > li + li ~ li",
+            "article p",
+            "a[href^=\"https\"]",
+            "input[type=\"checkbox\" i]:checked",
+            "li:nth-child(2n+1)",
+            ":not(.a, .b)",
+            "p::first-line",
+        ] {
+            let list = SelectorList::try_from(source).unwrap();
+            assert_eq!(list.to_string(), source);
+        }
+    }
+
+    #[test]
+    fn test_002_invalid_selectors() {
+        for source in [
+            "",
+            "div,",
+            "..a",
+            "a >",
+            ":unknown-pseudo",
+            "[=a]",
+            "p::unknown",
+            ":marker",
+            "p::before::after",
+            "p:before::after",
+            "p::before:hover",
+            "::before a",
+            "p::before > a",
+            "li::marker + li",
+            "p:first-line ~ p",
+        ] {
+            assert!(SelectorList::try_from(source).is_err(), "{}", source);
         }
+    }
+
+    #[test]
+    fn test_003_specificity() {
+        let specificity = |source: &str| SelectorList::try_from(source).unwrap().max_specificity();
+
+        assert_eq!(specificity("*"), Specificity::new(0, 0, 0));
+        assert_eq!(specificity("li"), Specificity::new(0, 0, 1));
+        assert_eq!(specificity("ul li.red"), Specificity::new(0, 1, 2));
+        assert_eq!(specificity("#x[href]:hover"), Specificity::new(1, 2, 0));
+        assert_eq!(specificity(":is(#a, .b) p"), Specificity::new(1, 0, 1));
+        assert_eq!(specificity(":where(#a) p"), Specificity::new(0, 0, 1));
+        assert_eq!(specificity("p::before"), Specificity::new(0, 0, 2));
+    }
+
+    #[test]
+    fn test_004_nth() {
+        let odd = Nth::new(2, 1);
+        assert!(odd.matches(1) && odd.matches(3) && !odd.matches(2));
+
+        let first_three = Nth::new(-1, 3);
+        assert!(first_three.matches(1) && first_three.matches(3) && !first_three.matches(4));
+
+        let list = SelectorList::try_from("li:nth-child(-n+3)").unwrap();
+        assert_eq!(list.to_string(), "li:nth-child(-n+3)");
+
+        let nth = |source: &str| Nth::try_from(parse_component_values(source).as_slice());
+
+        for (source, a, b) in [
+            ("odd", 2, 1),
+            (" EVEN ", 2, 0),
+            ("+5", 0, 5),
+            ("-7", 0, -7),
+            ("2n+1", 2, 1),
+            ("2N-1", 2, -1),
+            ("2n + 1", 2, 1),
+            ("2n- 1", 2, -1),
+            ("-2n+ 3", -2, 3),
+            ("n -1", 1, -1),
+            ("+n", 1, 0),
+            ("-n-12", -1, -12),
+        ] {
+            assert_eq!(nth(source), Ok(Nth::new(a, b)), "{}", source);
+        }
+
+        for source in [
+            "", "2n1", "2n 1", "+ n", "+-n", "1.5n", "2.0", "n-", "n--1", "2n + +1", "2n - -1",
+            "odd 1", "3 n", "2px", "n-1a",
+        ] {
+            assert!(nth(source).is_err(), "{}", source);
+        }
+        assert!(SelectorList::try_from("li:nth-child(2n1)").is_err());
+    }
+
+    #[test]
+    fn test_005_pseudo_elements() {
+        let list = SelectorList::try_from("p:first-line, li::marker, a:hover::before").unwrap();
+        let pseudos = list
+            .iter()
+            .map(|sel| sel.rightmost().pseudo())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            pseudos,
+            vec![
+                Some(PseudoElement::FirstLine),
+                Some(PseudoElement::Marker),
+                Some(PseudoElement::Before)
+            ]
+        );
+        assert_eq!(
+            list.to_string(),
+            "p::first-line, li::marker, a:hover::before"
+        );
+
+        let list = SelectorList::try_from("ul > li::marker , p::after ").unwrap();
+        assert_eq!(list.to_string(), "ul > li::marker, p::after");
+    }
 
-        panic!("parsing error");
+    #[test]
+    fn test_006_restricted_properties() {
+        assert!(PseudoElement::FirstLine.allows_property("font-weight"));
+        assert!(PseudoElement::FirstLine.allows_property("--brand"));
+        assert!(!PseudoElement::FirstLine.allows_property("margin-left"));
+        assert!(PseudoElement::FirstLetter.allows_property("margin-left"));
+        assert!(PseudoElement::FirstLetter.allows_property("float"));
+        assert!(!PseudoElement::Marker.allows_property("background-color"));
+        assert!(PseudoElement::Before.allows_property("display"));
     }
 }
@@ -5,45 +5,116 @@ use crate::style::{
 
 use super::ComponentValue;
 
-#[derive(Default, Debug, PartialEq)]
-pub struct SimpleBlock(Vec<ComponentValue>);
+/// The opening code point of a simple block.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BlockDelimiter {
+    /// {}
+    #[default]
+    CurlyBracket,
+    /// ()
+    Parenthesis,
+    /// []
+    SquareBracket,
+}
+
+impl BlockDelimiter {
+    fn from_token_kind(kind: &TokenKind) -> Option<Self> {
+        match kind {
+            TokenKind::OpeningCurlyBracket => Some(Self::CurlyBracket),
+            TokenKind::OpeningParenthesis => Some(Self::Parenthesis),
+            TokenKind::OpeningSquareBracket => Some(Self::SquareBracket),
+            _ => None,
+        }
+    }
+
+    fn is_closing(&self, kind: &TokenKind) -> bool {
+        matches!(
+            (self, kind),
+            (Self::CurlyBracket, TokenKind::ClosingCurlyBracket)
+                | (Self::Parenthesis, TokenKind::ClosingParenthesis)
+                | (Self::SquareBracket, TokenKind::ClosingSquareBracket)
+        )
+    }
+
+    pub fn opening(&self) -> char {
+        match self {
+            Self::CurlyBracket => '{',
+            Self::Parenthesis => '(',
+            Self::SquareBracket => '[',
+        }
+    }
+
+    pub fn closing(&self) -> char {
+        match self {
+            Self::CurlyBracket => '}',
+            Self::Parenthesis => ')',
+            Self::SquareBracket => ']',
+        }
+    }
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SimpleBlock {
+    pub delimiter: BlockDelimiter,
+    pub values: Vec<ComponentValue>,
+}
 
 impl SimpleBlock {
+    /// Creates a {}-block.
     pub fn new<T, I>(values: I) -> Self
     where
         ComponentValue: From<T>,
         I: IntoIterator<Item = T>,
     {
-        Self(values.into_iter().map(ComponentValue::from).collect())
+        Self::with_delimiter(BlockDelimiter::CurlyBracket, values)
+    }
+
+    pub fn with_delimiter<T, I>(delimiter: BlockDelimiter, values: I) -> Self
+    where
+        ComponentValue: From<T>,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            delimiter,
+            values: values.into_iter().map(ComponentValue::from).collect(),
+        }
+    }
+
+    pub fn is_delimited_by(&self, delimiter: BlockDelimiter) -> bool {
+        self.delimiter == delimiter
+    }
+}
+
+impl std::fmt::Display for SimpleBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.delimiter.opening())?;
+        for value in self.values.iter() {
+            write!(f, "{}", value)?;
+        }
+        write!(f, "{}", self.delimiter.closing())
     }
 }
 
 impl Parser<Token> for SimpleBlock {
     /// Parse a {}, [] or () block.
     ///
-    /// It expects the lexer's current token to be the opening.
+    /// It expects the lexer's current token to be the opening. A block still
+    /// open at EOF is closed.
     fn parse<L: Lexer<Token>>(lexer: &mut L) -> Self {
         let del = lexer.current().unwrap();
-        let mut block = SimpleBlock::default();
+        let mut block = SimpleBlock {
+            delimiter: BlockDelimiter::from_token_kind(&del.kind).unwrap_or_default(),
+            values: Vec::default(),
+        };
 
         while let Some(token) = lexer.next() {
-            if matches!(
-                (&del.kind, token.kind),
-                (
-                    TokenKind::OpeningCurlyBracket,
-                    TokenKind::ClosingCurlyBracket
-                ) | (TokenKind::OpeningParenthesis, TokenKind::ClosingParenthesis)
-                    | (
-                        TokenKind::OpeningSquareBracket,
-                        TokenKind::ClosingSquareBracket
-                    )
-            ) {
+            if block.delimiter.is_closing(&token.kind) {
                 return block;
             } else {
-                block.0.push(ComponentValue::parse(lexer));
+                block.values.push(ComponentValue::parse(lexer));
             }
         }
 
-        panic!("unexpected eof")
+        block
     }
 }
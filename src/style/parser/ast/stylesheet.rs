@@ -5,6 +5,7 @@ use crate::style::{
 
 use super::Rules;
 
+#[derive(Default, Debug, Clone)]
 pub struct Stylesheet {
     pub rules: Rules,
 }
@@ -16,3 +17,9 @@ impl Parser<Token> for Stylesheet {
         }
     }
 }
+
+impl From<&str> for Stylesheet {
+    fn from(value: &str) -> Self {
+        Self::parse(&mut crate::style::Lexer::new(value))
+    }
+}
@@ -79,9 +79,11 @@ impl Lexer<'_> {
     /// Consume an ident sequence
     ///
     /// ```
+    /// use marennes::style::lexer::{Lexer, TokenKind};
+    ///
     /// let mut lexer = Lexer::new("background-repeat 123456");
-    /// let ident = lexer.consume_ident_sequence();
-    /// assert_eq!(ident, "background-repeat");
+    /// let token = lexer.next().unwrap();
+    /// assert_eq!(token.kind, TokenKind::Ident("background-repeat".to_string()));
     /// ```
     fn consume_ident_sequence(&mut self) -> String {
         let mut buf = String::default();
@@ -106,10 +108,11 @@ impl Lexer<'_> {
     /// point.
     ///
     /// ```
+    /// use marennes::style::lexer::{Dimension, Lexer, TokenKind};
+    ///
     /// let mut lexer = Lexer::new("123456px");
-    /// let digits = lexer.consume_digits();
-    /// assert_eq!(digits, "123456".to_string());
-    /// assert_eq!(lexer.stream.next(), Some('p'));
+    /// let token = lexer.next().unwrap();
+    /// assert_eq!(token.kind, TokenKind::Dimension(Dimension::new("123456", "px")));
     /// ```
     pub(self) fn consume_digits(&mut self) -> String {
         let mut buf = String::default();
@@ -132,9 +135,11 @@ impl Lexer<'_> {
     /// Example of number are -123.456e-789
     ///
     /// ```
+    /// use marennes::style::lexer::{Lexer, Number, TokenKind};
+    ///
     /// let mut lexer = Lexer::new("-123.456e-789");
-    /// let number = lexer.consume_number();
-    /// assert_eq!(number, Number::new("-123", "456", "789"));
+    /// let token = lexer.next().unwrap();
+    /// assert_eq!(token.kind, TokenKind::Number(Number::new("-123", "456", "-789")));
     /// ```
     fn consume_number(&mut self) -> Number {
         let mut sel = 0;
@@ -182,7 +187,7 @@ impl Lexer<'_> {
     }
 
     fn consume_remnants_of_bad_url(&mut self) {
-        while let Some(c) = self.stream.next() {
+        for c in self.stream.by_ref() {
             if c == ')' {
                 return;
             }
@@ -229,12 +234,7 @@ impl Lexer<'_> {
                 self.stream.next();
                 self.consume_whitespace();
 
-                if self
-                    .stream
-                    .current()
-                    .map(|c| c == '\'' || c == '"')
-                    .unwrap_or(false)
-                {
+                if matches!(self.stream.peek::<1, 1>(), "'" | "\"") {
                     return Token::function(seq, location);
                 }
 
@@ -441,7 +441,11 @@ impl Lexer<'_> {
             }
             // Consume either a delim, or a hash-token.
             if c == '#' {
-                if Self::is_ident_sequence_start(self.stream.peek::<1, 3>()) {
+                let next = self.stream.peek::<1, 3>();
+
+                if next.chars().next().map(Self::is_ident_code_point).unwrap_or(false)
+                    || Self::is_valid_escape(next)
+                {
                     let value = self.consume_ident_sequence();
                     return Some(Token::hash(value, location));
                 }
@@ -478,6 +482,12 @@ impl Lexer<'_> {
                     return Some(Token::cdc(location));
                 }
 
+                // Consume an ident-token starting with an hyphen (-webkit-, --custom-property).
+                if c == '-' && Self::is_ident_sequence_start(self.stream.peek::<0, 3>()) {
+                    self.stream.rewind();
+                    return Some(self.consume_ident_token());
+                }
+
                 return Some(Token::delim(c, location));
             }
 
@@ -831,6 +841,28 @@ mod test {
         assert_eq!(token, expected_token)
     }
 
+    #[test]
+    fn test_026_hyphen_ident() {
+        let lexer = Lexer::new("--brand -webkit-box -1");
+        let tokens = lexer.collect::<Vec<_>>();
+        let expected_tokens = vec![
+            Token::ident("--brand", SourceLocation::new(1, 1)),
+            Token::whitespace(SourceLocation::new(1, 8)),
+            Token::ident("-webkit-box", SourceLocation::new(1, 9)),
+            Token::whitespace(SourceLocation::new(1, 20)),
+            Token::number("-1", SourceLocation::new(1, 21)),
+        ];
+        assert_eq!(tokens, expected_tokens);
+    }
+
+    #[test]
+    fn test_027_hash_token_starting_with_digit() {
+        let mut lexer = Lexer::new("#0af");
+        let token = lexer.next().unwrap();
+        let expected_token = Token::hash("0af", SourceLocation::new(1, 1));
+        assert_eq!(token, expected_token)
+    }
+
     #[test]
     fn test_100_complex_sequence() {
         let lexer = Lexer::new("background-repeat @at-keyword 123.45pt func(test, 10)");
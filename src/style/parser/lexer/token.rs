@@ -15,6 +15,32 @@ impl Number {
             exponent: exponent.to_string(),
         }
     }
+
+    pub fn is_integer(&self) -> bool {
+        self.decimal.is_empty() && self.exponent.is_empty()
+    }
+
+    /// Checks if the number starts with an explicit + or - sign.
+    pub fn is_signed(&self) -> bool {
+        self.integer.starts_with(['+', '-'])
+    }
+
+    pub fn to_f32(&self) -> f32 {
+        let integer = match self.integer.as_str() {
+            "" | "+" | "-" => format!("{}0", self.integer),
+            integer => integer.to_string(),
+        };
+
+        let mut number = integer;
+        if !self.decimal.is_empty() {
+            number = format!("{}.{}", number, self.decimal);
+        }
+        if !self.exponent.is_empty() {
+            number = format!("{}e{}", number, self.exponent);
+        }
+
+        number.parse().unwrap_or_default()
+    }
 }
 
 impl From<&str> for Number {
@@ -77,6 +103,12 @@ impl Dimension {
     }
 }
 
+impl std::fmt::Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.number, self.unit)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum TokenKind {
     String(String),
@@ -120,12 +152,43 @@ pub enum TokenKind {
 impl TokenKind {
     pub fn expect_ident(&self) -> &str {
         match self {
-            Self::Ident(ident) => &ident,
+            Self::Ident(ident) => ident,
             _ => panic!("not an ident token"),
         }
     }
 }
 
+impl std::fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenKind::String(str) | TokenKind::BadString(str) => {
+                write!(f, "\"{}\"", str.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+            TokenKind::AtKeyword(kw) => write!(f, "@{}", kw),
+            TokenKind::Ident(ident) => write!(f, "{}", ident),
+            TokenKind::Delim(del) => write!(f, "{}", del),
+            TokenKind::Number(number) => write!(f, "{}", number),
+            TokenKind::Dimension(dimension) => write!(f, "{}", dimension),
+            TokenKind::Percentage(number) => write!(f, "{}%", number),
+            TokenKind::Function(name) => write!(f, "{}(", name),
+            TokenKind::Url(url) | TokenKind::BadUrl(url) => write!(f, "url({})", url),
+            TokenKind::Whitespace => write!(f, " "),
+            TokenKind::Hash(hash) => write!(f, "#{}", hash),
+            TokenKind::OpeningParenthesis => write!(f, "("),
+            TokenKind::ClosingParenthesis => write!(f, ")"),
+            TokenKind::OpeningCurlyBracket => write!(f, "{{"),
+            TokenKind::ClosingCurlyBracket => write!(f, "}}"),
+            TokenKind::OpeningSquareBracket => write!(f, "["),
+            TokenKind::ClosingSquareBracket => write!(f, "]"),
+            TokenKind::Comma => write!(f, ","),
+            TokenKind::CDO => write!(f, "<!--"),
+            TokenKind::CDC => write!(f, "-->"),
+            TokenKind::Colon => write!(f, ":"),
+            TokenKind::Semicolon => write!(f, ";"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct Token {
     pub location: SourceLocation,
@@ -329,6 +392,11 @@ impl Token {
         }
     }
 
+    #[inline]
+    pub fn is_whitespace(&self) -> bool {
+        matches!(self.kind, TokenKind::Whitespace)
+    }
+
     #[inline]
     pub fn is_delim_value<S: AsRef<str>>(&self, value: S) -> bool {
         match &self.kind {
@@ -337,3 +405,9 @@ impl Token {
         }
     }
 }
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
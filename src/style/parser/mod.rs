@@ -5,11 +5,20 @@ pub use ast::*;
 pub use lexer::*;
 
 pub mod traits {
+    pub use super::ast::ComponentValueLexer;
     pub use super::lexer::traits::Lexer;
 
+    use crate::style::StyleError;
+
     pub trait Parser<T> {
         fn parse<L: Lexer<T>>(lexer: &mut L) -> Self;
     }
+
+    /// A parser which can fail, used when the grammar allows to drop invalid
+    /// constructs (selectors, declarations, ...).
+    pub trait TryParser<T>: Sized {
+        fn try_parse<L: Lexer<T>>(lexer: &mut L) -> Result<Self, StyleError>;
+    }
 }
 
 #[cfg(test)]
@@ -18,7 +27,7 @@ mod test {
 
     use super::{
         ast::{AtRule, QualifiedRule, SimpleBlock},
-        traits::Parser,
+        traits::{Parser, TryParser},
     };
 
     #[test]
@@ -46,7 +55,7 @@ mod test {
         ",
         );
 
-        let rule = QualifiedRule::parse(&mut lexer);
+        let rule = QualifiedRule::try_parse(&mut lexer).unwrap();
         let expected_rule = QualifiedRule::new(
             [
                 Token::ident("p", SourceLocation::new(1, 1)),
@@ -0,0 +1,498 @@
+//! The alignment of the boxes in their containers, and the gutters between
+//! them.
+//!
+//! [CSS Box Alignment 3](https://drafts.csswg.org/css-align-3/)
+use crate::style::{ComputeContext, Keyword, LengthPercentage, StyleError, ToComputedValue, Value};
+
+/// Whether an alignment is kept when the content overflows its container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPosition {
+    /// The content is aligned to the start rather than overflowing it.
+    Safe,
+    Unsafe,
+}
+
+/// The baseline of the alignment baseline set of the boxes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BaselinePosition {
+    #[default]
+    First,
+    Last,
+}
+
+/// The distribution of the extra space between the boxes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentDistribution {
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+    Stretch,
+}
+
+/// The position of the content of a container, left and right being only
+/// valid in the inline axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentPosition {
+    Center,
+    Start,
+    End,
+    FlexStart,
+    FlexEnd,
+    Left,
+    Right,
+}
+
+/// The position of a box in its alignment container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfPosition {
+    Center,
+    Start,
+    End,
+    SelfStart,
+    SelfEnd,
+    FlexStart,
+    FlexEnd,
+}
+
+/// The alignment of the content of a container as a whole.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ContentAlignment {
+    /// The default alignment of the layout.
+    #[default]
+    Normal,
+    Baseline(BaselinePosition),
+    Distribution(ContentDistribution),
+    Position(Option<OverflowPosition>, ContentPosition),
+}
+
+/// The alignment of a box in its alignment container.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelfAlignment {
+    /// The alignment given by the align-items of the parent.
+    Auto,
+    /// The default alignment of the layout.
+    #[default]
+    Normal,
+    Stretch,
+    Baseline(BaselinePosition),
+    Position(Option<OverflowPosition>, SelfPosition),
+}
+
+/// The keywords of an alignment value.
+fn keywords(value: &Value) -> Option<Vec<Keyword>> {
+    value
+        .iter()
+        .map(|value| match value {
+            Value::Keyword(keyword) => Some(*keyword),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parse a baseline position, first by default.
+fn baseline(keywords: &[Keyword]) -> Option<BaselinePosition> {
+    match keywords {
+        [Keyword::Baseline] | [Keyword::First, Keyword::Baseline] => Some(BaselinePosition::First),
+        [Keyword::Last, Keyword::Baseline] => Some(BaselinePosition::Last),
+        _ => None,
+    }
+}
+
+/// Split the optional overflow position off a positional alignment.
+fn overflow_position(keywords: &[Keyword]) -> Option<(Option<OverflowPosition>, Keyword)> {
+    match keywords {
+        [Keyword::Safe, keyword] => Some((Some(OverflowPosition::Safe), *keyword)),
+        [Keyword::Unsafe, keyword] => Some((Some(OverflowPosition::Unsafe), *keyword)),
+        [keyword] => Some((None, *keyword)),
+        _ => None,
+    }
+}
+
+impl ContentAlignment {
+    fn parse(value: &Value) -> Option<Self> {
+        let keywords = keywords(value)?;
+        if let Some(baseline) = baseline(&keywords) {
+            return Some(Self::Baseline(baseline));
+        }
+
+        let distribution = match keywords.as_slice() {
+            [Keyword::Normal] => return Some(Self::Normal),
+            [Keyword::SpaceBetween] => Some(ContentDistribution::SpaceBetween),
+            [Keyword::SpaceAround] => Some(ContentDistribution::SpaceAround),
+            [Keyword::SpaceEvenly] => Some(ContentDistribution::SpaceEvenly),
+            [Keyword::Stretch] => Some(ContentDistribution::Stretch),
+            _ => None,
+        };
+        if let Some(distribution) = distribution {
+            return Some(Self::Distribution(distribution));
+        }
+
+        let (overflow, keyword) = overflow_position(&keywords)?;
+        let position = match keyword {
+            Keyword::Center => ContentPosition::Center,
+            Keyword::Start => ContentPosition::Start,
+            Keyword::End => ContentPosition::End,
+            Keyword::FlexStart => ContentPosition::FlexStart,
+            Keyword::FlexEnd => ContentPosition::FlexEnd,
+            Keyword::Left => ContentPosition::Left,
+            Keyword::Right => ContentPosition::Right,
+            _ => return None,
+        };
+
+        Some(Self::Position(overflow, position))
+    }
+}
+
+impl From<ContentAlignment> for Value {
+    fn from(value: ContentAlignment) -> Self {
+        let keywords = match value {
+            ContentAlignment::Normal => vec![Keyword::Normal],
+            ContentAlignment::Baseline(baseline) => baseline_keywords(baseline),
+            ContentAlignment::Distribution(distribution) => vec![match distribution {
+                ContentDistribution::SpaceBetween => Keyword::SpaceBetween,
+                ContentDistribution::SpaceAround => Keyword::SpaceAround,
+                ContentDistribution::SpaceEvenly => Keyword::SpaceEvenly,
+                ContentDistribution::Stretch => Keyword::Stretch,
+            }],
+            ContentAlignment::Position(overflow, position) => {
+                let position = match position {
+                    ContentPosition::Center => Keyword::Center,
+                    ContentPosition::Start => Keyword::Start,
+                    ContentPosition::End => Keyword::End,
+                    ContentPosition::FlexStart => Keyword::FlexStart,
+                    ContentPosition::FlexEnd => Keyword::FlexEnd,
+                    ContentPosition::Left => Keyword::Left,
+                    ContentPosition::Right => Keyword::Right,
+                };
+                overflow_keywords(overflow, position)
+            }
+        };
+
+        keywords_value(keywords)
+    }
+}
+
+impl SelfAlignment {
+    fn parse(value: &Value) -> Option<Self> {
+        let keywords = keywords(value)?;
+        if let Some(baseline) = baseline(&keywords) {
+            return Some(Self::Baseline(baseline));
+        }
+
+        match keywords.as_slice() {
+            [Keyword::Auto] => return Some(Self::Auto),
+            [Keyword::Normal] => return Some(Self::Normal),
+            [Keyword::Stretch] => return Some(Self::Stretch),
+            _ => {}
+        }
+
+        let (overflow, keyword) = overflow_position(&keywords)?;
+        let position = match keyword {
+            Keyword::Center => SelfPosition::Center,
+            Keyword::Start => SelfPosition::Start,
+            Keyword::End => SelfPosition::End,
+            Keyword::SelfStart => SelfPosition::SelfStart,
+            Keyword::SelfEnd => SelfPosition::SelfEnd,
+            Keyword::FlexStart => SelfPosition::FlexStart,
+            Keyword::FlexEnd => SelfPosition::FlexEnd,
+            _ => return None,
+        };
+
+        Some(Self::Position(overflow, position))
+    }
+}
+
+impl From<SelfAlignment> for Value {
+    fn from(value: SelfAlignment) -> Self {
+        let keywords = match value {
+            SelfAlignment::Auto => vec![Keyword::Auto],
+            SelfAlignment::Normal => vec![Keyword::Normal],
+            SelfAlignment::Stretch => vec![Keyword::Stretch],
+            SelfAlignment::Baseline(baseline) => baseline_keywords(baseline),
+            SelfAlignment::Position(overflow, position) => {
+                let position = match position {
+                    SelfPosition::Center => Keyword::Center,
+                    SelfPosition::Start => Keyword::Start,
+                    SelfPosition::End => Keyword::End,
+                    SelfPosition::SelfStart => Keyword::SelfStart,
+                    SelfPosition::SelfEnd => Keyword::SelfEnd,
+                    SelfPosition::FlexStart => Keyword::FlexStart,
+                    SelfPosition::FlexEnd => Keyword::FlexEnd,
+                };
+                overflow_keywords(overflow, position)
+            }
+        };
+
+        keywords_value(keywords)
+    }
+}
+
+/// The keywords of a baseline position, the first baseline serializing as
+/// baseline.
+fn baseline_keywords(baseline: BaselinePosition) -> Vec<Keyword> {
+    match baseline {
+        BaselinePosition::First => vec![Keyword::Baseline],
+        BaselinePosition::Last => vec![Keyword::Last, Keyword::Baseline],
+    }
+}
+
+fn overflow_keywords(overflow: Option<OverflowPosition>, position: Keyword) -> Vec<Keyword> {
+    match overflow {
+        Some(OverflowPosition::Safe) => vec![Keyword::Safe, position],
+        Some(OverflowPosition::Unsafe) => vec![Keyword::Unsafe, position],
+        None => vec![position],
+    }
+}
+
+fn keywords_value(keywords: Vec<Keyword>) -> Value {
+    match keywords.as_slice() {
+        [keyword] => Value::Keyword(*keyword),
+        _ => Value::Array(keywords.into_iter().map(Value::Keyword).collect()),
+    }
+}
+
+/// Generate an alignment longhand, which accepts some of the values of its
+/// alignment type.
+macro_rules! alignment_longhand {
+    (
+        $(#[$meta:meta])*
+        $name:ident($inner:ident) = $initial:expr,
+        $allowed:expr,
+        $expected:expr
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name(pub $inner);
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self($initial)
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = $inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl TryFrom<Value> for $name {
+            type Error = StyleError;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                let allowed: fn(&$inner) -> bool = $allowed;
+
+                $inner::parse(&value)
+                    .filter(allowed)
+                    .map(Self)
+                    .ok_or(StyleError::InvalidValue($expected))
+            }
+        }
+
+        impl From<$name> for Value {
+            fn from(value: $name) -> Self {
+                Self::from(value.0)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", Value::from(*self))
+            }
+        }
+
+        impl ToComputedValue for $name {
+            type Computed = Self;
+
+            fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+                *self
+            }
+        }
+    };
+}
+
+alignment_longhand!(
+    /// The alignment of the content of a container in its inline axis, or
+    /// in the main axis of a flex container.
+    JustifyContent(ContentAlignment) = ContentAlignment::Normal,
+    |alignment| !matches!(alignment, ContentAlignment::Baseline(_)),
+    &[
+        "normal",
+        "<content-distribution>",
+        "<overflow-position>? [ <content-position> | left | right ]",
+    ]
+);
+alignment_longhand!(
+    /// The alignment of the content of a container in its block axis, or
+    /// of the lines of a multi-line flex container in its cross axis.
+    AlignContent(ContentAlignment) = ContentAlignment::Normal,
+    |alignment| {
+        !matches!(
+            alignment,
+            ContentAlignment::Position(_, ContentPosition::Left | ContentPosition::Right)
+        )
+    },
+    &[
+        "normal",
+        "<baseline-position>",
+        "<content-distribution>",
+        "<overflow-position>? <content-position>",
+    ]
+);
+alignment_longhand!(
+    /// The default alignment of the items of a container in its block
+    /// axis, or in the cross axis of a flex container.
+    AlignItems(SelfAlignment) = SelfAlignment::Normal,
+    |alignment| *alignment != SelfAlignment::Auto,
+    &[
+        "normal",
+        "stretch",
+        "<baseline-position>",
+        "<overflow-position>? <self-position>",
+    ]
+);
+alignment_longhand!(
+    /// The alignment of a box in its container, overriding the
+    /// align-items of the container unless auto.
+    AlignSelf(SelfAlignment) = SelfAlignment::Auto,
+    |_| true,
+    &[
+        "auto",
+        "normal",
+        "stretch",
+        "<baseline-position>",
+        "<overflow-position>? <self-position>",
+    ]
+);
+
+/// The gutter between the rows or columns of a container.
+///
+/// [CSS Box Alignment 3](https://drafts.csswg.org/css-align-3/#column-row-gap)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Gap {
+    /// No gutter, except in multi-column containers.
+    #[default]
+    Normal,
+    LengthPercentage(LengthPercentage),
+}
+
+impl TryFrom<Value> for Gap {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Normal) => Ok(Self::Normal),
+            value => match LengthPercentage::try_from(value) {
+                Ok(gap) if !gap.is_negative() => Ok(Self::LengthPercentage(gap)),
+                _ => Err(StyleError::InvalidValue(&[
+                    "normal",
+                    "<length-percentage [0,∞]>",
+                ])),
+            },
+        }
+    }
+}
+
+impl From<Gap> for Value {
+    fn from(value: Gap) -> Self {
+        match value {
+            Gap::Normal => Self::Keyword(Keyword::Normal),
+            Gap::LengthPercentage(gap) => Self::from(gap),
+        }
+    }
+}
+
+impl std::fmt::Display for Gap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "normal"),
+            Self::LengthPercentage(gap) => write!(f, "{}", gap),
+        }
+    }
+}
+
+impl ToComputedValue for Gap {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::Normal => Self::Normal,
+            Self::LengthPercentage(gap) => {
+                Self::LengthPercentage(gap.resolve_non_negative(&context.length))
+            }
+        }
+    }
+}
+
+longhand_newtypes!(Gap: RowGap, ColumnGap);
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_alignment() {
+        for (name, source, expected) in [
+            ("justify-content", "safe center", "safe center"),
+            ("justify-content", "space-between", "space-between"),
+            ("justify-content", "left", "left"),
+            ("align-content", "first baseline", "baseline"),
+            ("align-content", "last baseline", "last baseline"),
+            ("align-items", "normal", "normal"),
+            ("align-items", "last baseline", "last baseline"),
+            ("align-self", "unsafe flex-end", "unsafe flex-end"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("justify-content", "baseline"),
+            ("justify-content", "safe space-between"),
+            ("align-content", "left"),
+            ("align-items", "auto"),
+            ("align-self", "safe safe center"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve("html { align-items: center; justify-content: safe end }");
+        let value = |id| styles[1].value(id).to_string();
+        assert_eq!(value(PropertyId::AlignItems), "normal");
+        assert_eq!(value(PropertyId::JustifyContent), "normal");
+    }
+
+    #[test]
+    fn test_002_gaps() {
+        for (name, source, expected) in [
+            ("row-gap", "normal", "normal"),
+            ("column-gap", "10%", "10%"),
+            ("gap", "1em 5%", "1em 5%"),
+            ("gap", "1px 1px", "1px"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [("row-gap", "-1px"), ("gap", "1px 2px 3px"), ("gap", "auto")] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve("html { font-size: 10px; gap: calc(1px - 1em) calc(10% - 1em) }");
+        let gap = |index: usize, id| styles[index].value(id).to_string();
+
+        // The negative expressions are clamped once resolved.
+        assert_eq!(gap(0, PropertyId::RowGap), "0px");
+        assert_eq!(gap(0, PropertyId::ColumnGap), "calc(10% - 10px)");
+        assert_eq!(gap(1, PropertyId::RowGap), "normal");
+    }
+}
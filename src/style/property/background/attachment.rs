@@ -1,9 +1,10 @@
-use crate::style::{Keyword, StyleError, Value};
+use crate::style::{ComputeContext, Keyword, StyleError, ToComputedValue, Value};
 
 const ALLOWED_KWS_ATTACHMENT: &[Keyword] = &[Keyword::Scroll, Keyword::Fixed, Keyword::Local];
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
 pub enum BackgroundAttachment {
+    #[default]
     Scroll,
     Fixed,
     Local,
@@ -46,6 +47,12 @@ impl From<BackgroundAttachment> for Keyword {
     }
 }
 
+impl std::fmt::Display for BackgroundAttachment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Keyword::from(*self))
+    }
+}
+
 impl From<BackgroundAttachment> for Value {
     fn from(value: BackgroundAttachment) -> Self {
         let kw: Keyword = Keyword::from(value);
@@ -53,8 +60,10 @@ impl From<BackgroundAttachment> for Value {
     }
 }
 
-impl Default for BackgroundAttachment {
-    fn default() -> Self {
-        Self::Scroll
+impl ToComputedValue for BackgroundAttachment {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
     }
 }
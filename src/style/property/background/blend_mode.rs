@@ -0,0 +1,106 @@
+use crate::style::{ComputeContext, Keyword, StyleError, ToComputedValue, Value};
+
+/// How a background layer is blended with the layers below it and the
+/// background color.
+///
+/// [Compositing 1](https://drafts.fxtf.org/compositing-1/#ltblendmodegt)
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+impl TryFrom<Keyword> for BlendMode {
+    type Error = StyleError;
+
+    fn try_from(value: Keyword) -> Result<Self, Self::Error> {
+        match value {
+            Keyword::Normal => Ok(Self::Normal),
+            Keyword::Multiply => Ok(Self::Multiply),
+            Keyword::Screen => Ok(Self::Screen),
+            Keyword::Overlay => Ok(Self::Overlay),
+            Keyword::Darken => Ok(Self::Darken),
+            Keyword::Lighten => Ok(Self::Lighten),
+            Keyword::ColorDodge => Ok(Self::ColorDodge),
+            Keyword::ColorBurn => Ok(Self::ColorBurn),
+            Keyword::HardLight => Ok(Self::HardLight),
+            Keyword::SoftLight => Ok(Self::SoftLight),
+            Keyword::Difference => Ok(Self::Difference),
+            Keyword::Exclusion => Ok(Self::Exclusion),
+            Keyword::Hue => Ok(Self::Hue),
+            Keyword::Saturation => Ok(Self::Saturation),
+            Keyword::Color => Ok(Self::Color),
+            Keyword::Luminosity => Ok(Self::Luminosity),
+            _ => Err(StyleError::InvalidValue(&["<blend-mode>"])),
+        }
+    }
+}
+
+impl TryFrom<Value> for BlendMode {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(kw) => Self::try_from(kw),
+            _ => Err(StyleError::InvalidValue(&["<blend-mode>"])),
+        }
+    }
+}
+
+impl From<BlendMode> for Keyword {
+    fn from(value: BlendMode) -> Self {
+        match value {
+            BlendMode::Normal => Self::Normal,
+            BlendMode::Multiply => Self::Multiply,
+            BlendMode::Screen => Self::Screen,
+            BlendMode::Overlay => Self::Overlay,
+            BlendMode::Darken => Self::Darken,
+            BlendMode::Lighten => Self::Lighten,
+            BlendMode::ColorDodge => Self::ColorDodge,
+            BlendMode::ColorBurn => Self::ColorBurn,
+            BlendMode::HardLight => Self::HardLight,
+            BlendMode::SoftLight => Self::SoftLight,
+            BlendMode::Difference => Self::Difference,
+            BlendMode::Exclusion => Self::Exclusion,
+            BlendMode::Hue => Self::Hue,
+            BlendMode::Saturation => Self::Saturation,
+            BlendMode::Color => Self::Color,
+            BlendMode::Luminosity => Self::Luminosity,
+        }
+    }
+}
+
+impl std::fmt::Display for BlendMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Keyword::from(*self))
+    }
+}
+
+impl From<BlendMode> for Value {
+    fn from(value: BlendMode) -> Self {
+        Self::Keyword(Keyword::from(value))
+    }
+}
+
+impl ToComputedValue for BlendMode {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
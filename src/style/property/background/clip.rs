@@ -1,4 +1,4 @@
-use crate::style::{Keyword, StyleError, Value};
+use crate::style::{ComputeContext, Keyword, StyleError, ToComputedValue, Value};
 
 const ALLOWED_KWS: &[Keyword] = &[
     Keyword::BorderBox,
@@ -7,8 +7,9 @@ const ALLOWED_KWS: &[Keyword] = &[
     Keyword::Text,
 ];
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
 pub enum BackgroundClip {
+    #[default]
     BorderBox,
     PaddingBox,
     ContentBox,
@@ -64,6 +65,12 @@ impl From<BackgroundClip> for Keyword {
     }
 }
 
+impl std::fmt::Display for BackgroundClip {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Keyword::from(*self))
+    }
+}
+
 impl From<BackgroundClip> for Value {
     fn from(value: BackgroundClip) -> Self {
         let kw: Keyword = Keyword::from(value);
@@ -71,8 +78,10 @@ impl From<BackgroundClip> for Value {
     }
 }
 
-impl Default for BackgroundClip {
-    fn default() -> Self {
-        Self::BorderBox
+impl ToComputedValue for BackgroundClip {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
     }
 }
@@ -1,7 +1,8 @@
 use std::ops::Deref;
 
-use crate::style::{Color, StyleError, Value};
+use crate::style::{Color, ComputeContext, StyleError, ToComputedValue, Value};
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct BackgroundColor(Color);
 
 impl Deref for BackgroundColor {
@@ -43,8 +44,22 @@ impl From<BackgroundColor> for Color {
     }
 }
 
+impl std::fmt::Display for BackgroundColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl From<BackgroundColor> for Value {
     fn from(value: BackgroundColor) -> Self {
         Self::Color(value.into())
     }
 }
+
+impl ToComputedValue for BackgroundColor {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        self.clone()
+    }
+}
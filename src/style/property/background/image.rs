@@ -1,8 +1,15 @@
-use crate::style::{Image, Keyword, StyleError, Value};
+use crate::style::{ComputeContext, Image, Keyword, StyleError, ToComputedValue, Value};
 
-#[derive(Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct BackgroundImage(Option<Image>);
 
+impl BackgroundImage {
+    /// The image of the layer, none for `none`.
+    pub fn image(&self) -> Option<&Image> {
+        self.0.as_ref()
+    }
+}
+
 impl From<Image> for BackgroundImage {
     fn from(value: Image) -> Self {
         Self(Some(value))
@@ -45,3 +52,20 @@ impl From<BackgroundImage> for Value {
         }
     }
 }
+
+impl std::fmt::Display for BackgroundImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(image) => write!(f, "{}", image),
+            None => write!(f, "none"),
+        }
+    }
+}
+
+impl ToComputedValue for BackgroundImage {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        self.clone()
+    }
+}
@@ -0,0 +1,217 @@
+use std::ops::Deref;
+
+use crate::style::{
+    BackgroundAttachment, BackgroundClip, BackgroundImage, BackgroundOrigin, BackgroundPosition,
+    BackgroundRepeat, BackgroundSize, BlendMode, ComputeContext, Style, StyleError,
+    ToComputedValue, Value,
+};
+
+/// The comma-separated values of a property, one per background layer.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#layering)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layers<T>(Vec<T>);
+
+impl<T> Layers<T> {
+    /// The value of a layer, the list being repeated as needed.
+    pub fn layer(&self, index: usize) -> &T {
+        &self.0[index % self.0.len()]
+    }
+}
+
+impl<T> Deref for Layers<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Default> Default for Layers<T> {
+    fn default() -> Self {
+        Self(vec![T::default()])
+    }
+}
+
+impl<T> From<Vec<T>> for Layers<T> {
+    /// The layers of the values, which mustn't be empty.
+    fn from(value: Vec<T>) -> Self {
+        debug_assert!(!value.is_empty());
+        Self(value)
+    }
+}
+
+impl<T: TryFrom<Value, Error = StyleError>> TryFrom<Value> for Layers<T> {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::List(values) => values
+                .into_iter()
+                .map(T::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Self),
+            value => T::try_from(value).map(|layer| Self(vec![layer])),
+        }
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Layers<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, layer) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", layer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> From<Layers<T>> for Value
+where
+    Value: From<T>,
+{
+    fn from(value: Layers<T>) -> Self {
+        let mut values = value.0.into_iter().map(Value::from).collect::<Vec<_>>();
+        match values.len() {
+            1 => values.remove(0),
+            _ => Self::List(values),
+        }
+    }
+}
+
+impl<T: ToComputedValue> ToComputedValue for Layers<T> {
+    type Computed = Layers<T::Computed>;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        Layers(self.0.iter().map(|layer| layer.compute(context)).collect())
+    }
+}
+
+/// The computed values of a background layer, the color being painted under
+/// the final layer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundLayer {
+    pub image: BackgroundImage,
+    pub position: BackgroundPosition,
+    pub size: BackgroundSize,
+    pub repeat: BackgroundRepeat,
+    pub attachment: BackgroundAttachment,
+    pub origin: BackgroundOrigin,
+    pub clip: BackgroundClip,
+    pub blend_mode: BlendMode,
+}
+
+impl Style {
+    /// The background layers, from the topmost, as many as the images: the
+    /// other lists are repeated or truncated to that count.
+    ///
+    /// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#layering)
+    pub fn background_layers(&self) -> Vec<BackgroundLayer> {
+        (0..self.background_image.len())
+            .map(|index| BackgroundLayer {
+                image: self.background_image.layer(index).clone(),
+                position: self.background_position.layer(index).clone(),
+                size: self.background_size.layer(index).clone(),
+                repeat: *self.background_repeat.layer(index),
+                attachment: *self.background_attachment.layer(index),
+                origin: *self.background_origin.layer(index),
+                clip: *self.background_clip.layer(index),
+                blend_mode: *self.background_blend_mode.layer(index),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        parse_component_values, BackgroundClip, BackgroundPosition, BackgroundRepeat,
+        BackgroundSize, BlendMode, Layers, RepeatStyle, Style, StyleError, Value,
+    };
+
+    fn parse<T>(source: &str) -> Result<Layers<T>, StyleError>
+    where
+        T: TryFrom<Value, Error = StyleError>,
+    {
+        Layers::try_from(Value::try_from(parse_component_values(source).as_slice())?)
+    }
+
+    fn serialize<T>(source: &str) -> String
+    where
+        T: TryFrom<Value, Error = StyleError> + std::fmt::Display,
+    {
+        parse::<T>(source).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_001_layered_longhands() {
+        assert_eq!(
+            serialize::<BackgroundPosition>("left, right 10px top, bottom 10% right 2em"),
+            "left center, right 10px top, right 2em bottom 10%"
+        );
+        assert_eq!(serialize::<BackgroundPosition>("10px"), "10px center");
+        assert_eq!(
+            serialize::<BackgroundPosition>("center bottom 5px"),
+            "center bottom 5px"
+        );
+        for invalid in ["left 10px 20px", "center 10px top", "left right", "10px top 5px"] {
+            assert!(parse::<BackgroundPosition>(invalid).is_err(), "{}", invalid);
+        }
+
+        assert_eq!(
+            serialize::<BackgroundRepeat>("repeat no-repeat, no-repeat repeat, space round"),
+            "repeat-x, repeat-y, space round"
+        );
+        assert_eq!(serialize::<BackgroundRepeat>("round round"), "round");
+        assert_eq!(
+            parse::<BackgroundRepeat>("space").map(|layers| layers[0]),
+            Ok(BackgroundRepeat {
+                horizontal: RepeatStyle::Space,
+                vertical: RepeatStyle::Space
+            })
+        );
+        assert!(parse::<BackgroundRepeat>("repeat-x repeat").is_err());
+
+        assert_eq!(
+            serialize::<BackgroundSize>("cover, 50% auto, auto 2em, contain"),
+            "cover, 50%, auto 2em, contain"
+        );
+        assert!(parse::<BackgroundSize>("-1px").is_err());
+        assert!(parse::<BackgroundSize>("cover auto").is_err());
+
+        assert_eq!(
+            parse::<BlendMode>("multiply, color-dodge").map(|layers| layers.to_vec()),
+            Ok(vec![BlendMode::Multiply, BlendMode::ColorDodge])
+        );
+        assert!(parse::<BackgroundClip>("border-box,").is_err());
+    }
+
+    #[test]
+    fn test_002_layer_count() {
+        let style = Style {
+            background_image: parse("url(a.png), none, url(b.png)").unwrap(),
+            background_repeat: parse("no-repeat, space").unwrap(),
+            background_size: parse("cover, contain, auto, 10px").unwrap(),
+            ..Style::default()
+        };
+
+        // The lists are repeated or truncated to the count of the images.
+        let layers = style.background_layers();
+        assert_eq!(layers.len(), 3);
+        assert_eq!(
+            layers
+                .iter()
+                .map(|layer| layer.repeat.to_string())
+                .collect::<Vec<_>>(),
+            ["no-repeat", "space", "no-repeat"]
+        );
+        assert_eq!(layers[2].size, BackgroundSize::default());
+        assert_eq!(layers[1].position, BackgroundPosition::default());
+        assert!(layers[1].image.image().is_none());
+
+        assert_eq!(Style::default().background_layers().len(), 1);
+    }
+}
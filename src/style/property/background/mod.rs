@@ -1,25 +1,21 @@
 mod attachment;
+mod blend_mode;
 mod clip;
 mod color;
 mod image;
+mod layers;
+mod origin;
+mod position;
+mod repeat;
+mod size;
 
 pub use attachment::*;
+pub use blend_mode::*;
 pub use clip::*;
 pub use color::*;
 pub use image::*;
-
-use super::SpecProperty;
-
-#[derive(Default)]
-pub struct Background {
-    pub attachment: BackgroundAttachment,
-    pub clip: BackgroundClip,
-    pub color: BackgroundColor,
-}
-
-#[derive(Default)]
-pub struct SpecBackground {
-    pub attachment: SpecProperty<BackgroundAttachment>,
-    pub clip: SpecProperty<BackgroundClip>,
-    pub color: SpecProperty<BackgroundColor>,
-}
+pub use layers::*;
+pub use origin::*;
+pub use position::*;
+pub use repeat::*;
+pub use size::*;
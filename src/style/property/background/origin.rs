@@ -0,0 +1,77 @@
+use crate::style::{ComputeContext, Keyword, StyleError, ToComputedValue, Value};
+
+const ALLOWED_KWS: &[Keyword] = &[Keyword::BorderBox, Keyword::PaddingBox, Keyword::ContentBox];
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum BackgroundOrigin {
+    BorderBox,
+    #[default]
+    PaddingBox,
+    ContentBox,
+}
+
+impl TryFrom<Keyword> for BackgroundOrigin {
+    type Error = StyleError;
+
+    fn try_from(value: Keyword) -> Result<Self, Self::Error> {
+        match value {
+            Keyword::BorderBox => Ok(Self::BorderBox),
+            Keyword::PaddingBox => Ok(Self::PaddingBox),
+            Keyword::ContentBox => Ok(Self::ContentBox),
+            _ => Err(StyleError::InvalidValue(&[
+                "border-box",
+                "padding-box",
+                "content-box",
+            ])),
+        }
+    }
+}
+
+impl TryFrom<Value> for BackgroundOrigin {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let kw = value
+            .iter_keywords()
+            .find(Keyword::is_either_func(ALLOWED_KWS))
+            .cloned()
+            .ok_or(StyleError::InvalidValue(&[
+                "border-box",
+                "padding-box",
+                "content-box",
+            ]))?;
+
+        Self::try_from(kw)
+    }
+}
+
+impl From<BackgroundOrigin> for Keyword {
+    fn from(value: BackgroundOrigin) -> Self {
+        match value {
+            BackgroundOrigin::BorderBox => Self::BorderBox,
+            BackgroundOrigin::PaddingBox => Self::PaddingBox,
+            BackgroundOrigin::ContentBox => Self::ContentBox,
+        }
+    }
+}
+
+impl std::fmt::Display for BackgroundOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Keyword::from(*self))
+    }
+}
+
+impl From<BackgroundOrigin> for Value {
+    fn from(value: BackgroundOrigin) -> Self {
+        let kw: Keyword = Keyword::from(value);
+        Self::from(kw)
+    }
+}
+
+impl ToComputedValue for BackgroundOrigin {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
@@ -0,0 +1,61 @@
+use crate::style::{
+    ComputeContext, LengthPercentage, Position, PositionComponent, StyleError, ToComputedValue,
+    Value,
+};
+
+/// The position of a background image, relative to its positioning area.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#background-position)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundPosition(Position);
+
+impl BackgroundPosition {
+    pub fn position(&self) -> &Position {
+        &self.0
+    }
+}
+
+impl Default for BackgroundPosition {
+    /// The top left corner, `0% 0%`.
+    fn default() -> Self {
+        Self(Position {
+            horizontal: PositionComponent::Offset(LengthPercentage::Percentage(0.0)),
+            vertical: PositionComponent::Offset(LengthPercentage::Percentage(0.0)),
+        })
+    }
+}
+
+impl From<Position> for BackgroundPosition {
+    fn from(value: Position) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<Value> for BackgroundPosition {
+    type Error = StyleError;
+
+    /// Parse a position of one to four values.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Position::try_from_background(value).map(Self)
+    }
+}
+
+impl std::fmt::Display for BackgroundPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<BackgroundPosition> for Value {
+    fn from(value: BackgroundPosition) -> Self {
+        Self::from(value.0)
+    }
+}
+
+impl ToComputedValue for BackgroundPosition {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        Self(self.0.resolve_relative(&context.length))
+    }
+}
@@ -0,0 +1,123 @@
+use crate::style::{ComputeContext, Keyword, StyleError, ToComputedValue, Value};
+
+/// How a background image is tiled along an axis.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum RepeatStyle {
+    #[default]
+    Repeat,
+    /// Repeated as often as it fits, the space being distributed between the images.
+    Space,
+    /// Repeated as often as it fits, the images being rescaled to fill the area.
+    Round,
+    NoRepeat,
+}
+
+impl TryFrom<Keyword> for RepeatStyle {
+    type Error = StyleError;
+
+    fn try_from(value: Keyword) -> Result<Self, Self::Error> {
+        match value {
+            Keyword::Repeat => Ok(Self::Repeat),
+            Keyword::Space => Ok(Self::Space),
+            Keyword::Round => Ok(Self::Round),
+            Keyword::NoRepeat => Ok(Self::NoRepeat),
+            _ => Err(StyleError::InvalidValue(&[
+                "repeat",
+                "space",
+                "round",
+                "no-repeat",
+            ])),
+        }
+    }
+}
+
+impl From<RepeatStyle> for Keyword {
+    fn from(value: RepeatStyle) -> Self {
+        match value {
+            RepeatStyle::Repeat => Self::Repeat,
+            RepeatStyle::Space => Self::Space,
+            RepeatStyle::Round => Self::Round,
+            RepeatStyle::NoRepeat => Self::NoRepeat,
+        }
+    }
+}
+
+/// The tiling of a background image, along each axis.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#background-repeat)
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct BackgroundRepeat {
+    pub horizontal: RepeatStyle,
+    pub vertical: RepeatStyle,
+}
+
+impl BackgroundRepeat {
+    /// The keywords of its shortest form, `repeat-x` and `repeat-y` included.
+    fn to_kws(self) -> Vec<Keyword> {
+        match (self.horizontal, self.vertical) {
+            (RepeatStyle::Repeat, RepeatStyle::NoRepeat) => vec![Keyword::RepeatX],
+            (RepeatStyle::NoRepeat, RepeatStyle::Repeat) => vec![Keyword::RepeatY],
+            (horizontal, vertical) if horizontal == vertical => vec![horizontal.into()],
+            (horizontal, vertical) => vec![horizontal.into(), vertical.into()],
+        }
+    }
+}
+
+impl TryFrom<Value> for BackgroundRepeat {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let style = |value: &Value| match value {
+            Value::Keyword(kw) => RepeatStyle::try_from(*kw),
+            _ => Err(StyleError::InvalidValue(&["<repeat-style>"])),
+        };
+
+        match &value {
+            Value::Keyword(Keyword::RepeatX) => Ok(Self {
+                horizontal: RepeatStyle::Repeat,
+                vertical: RepeatStyle::NoRepeat,
+            }),
+            Value::Keyword(Keyword::RepeatY) => Ok(Self {
+                horizontal: RepeatStyle::NoRepeat,
+                vertical: RepeatStyle::Repeat,
+            }),
+            Value::Array(values) if values.len() == 2 => Ok(Self {
+                horizontal: style(&values[0])?,
+                vertical: style(&values[1])?,
+            }),
+            value => style(value).map(|style| Self {
+                horizontal: style,
+                vertical: style,
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for BackgroundRepeat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kws = self.to_kws();
+        match kws.as_slice() {
+            [kw] => write!(f, "{}", kw),
+            [horizontal, vertical] => write!(f, "{} {}", horizontal, vertical),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<BackgroundRepeat> for Value {
+    fn from(value: BackgroundRepeat) -> Self {
+        let mut kws = value.to_kws();
+        match kws.len() {
+            1 => Self::Keyword(kws.remove(0)),
+            _ => Self::Array(kws.into_iter().map(Self::Keyword).collect()),
+        }
+    }
+}
+
+impl ToComputedValue for BackgroundRepeat {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
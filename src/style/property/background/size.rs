@@ -0,0 +1,129 @@
+use crate::style::{ComputeContext, Keyword, LengthPercentage, StyleError, ToComputedValue, Value};
+
+/// The size of a background image along an axis.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum SizeComponent {
+    /// The size given by the other axis and the intrinsic ratio of the image.
+    #[default]
+    Auto,
+    LengthPercentage(LengthPercentage),
+}
+
+impl TryFrom<Value> for SizeComponent {
+    type Error = StyleError;
+
+    /// Parse `auto` or a non-negative length or percentage.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["auto", "<length-percentage [0,∞]>"]);
+
+        match value {
+            Value::Keyword(Keyword::Auto) => Ok(Self::Auto),
+            value => match LengthPercentage::try_from(value) {
+                Ok(size) if !size.is_negative() => Ok(Self::LengthPercentage(size)),
+                _ => Err(invalid),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for SizeComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::LengthPercentage(size) => write!(f, "{}", size),
+        }
+    }
+}
+
+impl From<SizeComponent> for Value {
+    fn from(value: SizeComponent) -> Self {
+        match value {
+            SizeComponent::Auto => Self::Keyword(Keyword::Auto),
+            SizeComponent::LengthPercentage(size) => Self::from(size),
+        }
+    }
+}
+
+/// The size of a background image.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#background-size)
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackgroundSize {
+    /// The largest size at which the image covers the positioning area.
+    Cover,
+    /// The largest size at which the image fits in the positioning area.
+    Contain,
+    /// The width and the height.
+    Explicit(SizeComponent, SizeComponent),
+}
+
+impl Default for BackgroundSize {
+    fn default() -> Self {
+        Self::Explicit(SizeComponent::Auto, SizeComponent::Auto)
+    }
+}
+
+impl TryFrom<Value> for BackgroundSize {
+    type Error = StyleError;
+
+    /// Parse `cover`, `contain`, or a width optionally followed by a height.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Cover) => Ok(Self::Cover),
+            Value::Keyword(Keyword::Contain) => Ok(Self::Contain),
+            Value::Array(values) => match <[Value; 2]>::try_from(values) {
+                Ok([width, height]) => Ok(Self::Explicit(
+                    SizeComponent::try_from(width)?,
+                    SizeComponent::try_from(height)?,
+                )),
+                Err(_) => Err(StyleError::InvalidValue(&["<bg-size>"])),
+            },
+            value => Ok(Self::Explicit(
+                SizeComponent::try_from(value)?,
+                SizeComponent::Auto,
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for BackgroundSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cover => write!(f, "cover"),
+            Self::Contain => write!(f, "contain"),
+            Self::Explicit(width, SizeComponent::Auto) => write!(f, "{}", width),
+            Self::Explicit(width, height) => write!(f, "{} {}", width, height),
+        }
+    }
+}
+
+impl From<BackgroundSize> for Value {
+    fn from(value: BackgroundSize) -> Self {
+        match value {
+            BackgroundSize::Cover => Self::Keyword(Keyword::Cover),
+            BackgroundSize::Contain => Self::Keyword(Keyword::Contain),
+            BackgroundSize::Explicit(width, SizeComponent::Auto) => Self::from(width),
+            BackgroundSize::Explicit(width, height) => {
+                Self::Array(vec![Self::from(width), Self::from(height)])
+            }
+        }
+    }
+}
+
+impl ToComputedValue for BackgroundSize {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        let resolve = |size: &SizeComponent| match size {
+            SizeComponent::Auto => SizeComponent::Auto,
+            SizeComponent::LengthPercentage(size) => {
+                SizeComponent::LengthPercentage(size.resolve_relative(&context.length))
+            }
+        };
+
+        match self {
+            Self::Explicit(width, height) => Self::Explicit(resolve(width), resolve(height)),
+            other => other.clone(),
+        }
+    }
+}
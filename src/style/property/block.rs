@@ -0,0 +1,718 @@
+use std::collections::HashSet;
+
+use crate::style::{DeclarationBlock, PropertyDeclaration, PropertyId, ShorthandId};
+
+/// A declaration block whose declarations are parsed, the shorthands being
+/// expanded into their longhands.
+///
+/// [CSSOM](https://drafts.csswg.org/cssom/#css-declaration-blocks)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropertyDeclarationBlock {
+    /// The declarations, in their order of appearance, and their importance.
+    declarations: Vec<(PropertyDeclaration, bool)>,
+}
+
+impl PropertyDeclarationBlock {
+    /// Add a declaration, replacing the previous one of the same property
+    /// unless only that one is important.
+    pub fn push(&mut self, declaration: PropertyDeclaration, important: bool) {
+        let previous = self
+            .declarations
+            .iter()
+            .position(|(previous, _)| previous.name() == declaration.name());
+
+        if let Some(previous) = previous {
+            if self.declarations[previous].1 && !important {
+                return;
+            }
+            self.declarations.remove(previous);
+        }
+
+        self.declarations.push((declaration, important));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&PropertyDeclaration, bool)> {
+        self.declarations
+            .iter()
+            .map(|(declaration, important)| (declaration, *important))
+    }
+
+    pub fn len(&self) -> usize {
+        self.declarations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+
+    /// The declaration of a longhand, and its importance.
+    pub fn get(&self, id: PropertyId) -> Option<(&PropertyDeclaration, bool)> {
+        self.iter()
+            .find(|(declaration, _)| declaration.id() == Some(id))
+    }
+
+    /// The serialized value of a property, empty if it isn't declared or,
+    /// for a shorthand, if its longhands can't be represented by it.
+    pub fn property_value(&self, name: &str) -> String {
+        match ShorthandId::try_from(name) {
+            Ok(shorthand) => self.serialize_shorthand(shorthand).unwrap_or_default(),
+            Err(_) => self
+                .iter()
+                .find(|(declaration, _)| declaration.name() == name)
+                .map(|(declaration, _)| declaration.value_to_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Serialize a shorthand, from the declarations of all its longhands
+    /// which must share their importance.
+    fn serialize_shorthand(&self, shorthand: ShorthandId) -> Option<String> {
+        let declarations = shorthand
+            .longhands()
+            .iter()
+            .map(|id| self.get(*id))
+            .collect::<Option<Vec<_>>>()?;
+
+        let important = declarations.first()?.1;
+        if declarations.iter().any(|(_, other)| *other != important) {
+            return None;
+        }
+
+        let declarations = declarations
+            .into_iter()
+            .map(|(declaration, _)| declaration)
+            .collect::<Vec<_>>();
+        shorthand.serialize(&declarations)
+    }
+}
+
+impl From<&DeclarationBlock> for PropertyDeclarationBlock {
+    /// Parse the declarations, the invalid ones being dropped.
+    fn from(value: &DeclarationBlock) -> Self {
+        let mut block = Self::default();
+
+        for declaration in value.iter() {
+            let Ok(declarations) =
+                PropertyDeclaration::parse_all(&declaration.name, &declaration.value)
+            else {
+                continue;
+            };
+
+            for parsed in declarations {
+                block.push(parsed, declaration.important);
+            }
+        }
+
+        block
+    }
+}
+
+impl From<&str> for PropertyDeclarationBlock {
+    fn from(value: &str) -> Self {
+        Self::from(&DeclarationBlock::from(value))
+    }
+}
+
+impl std::fmt::Display for PropertyDeclarationBlock {
+    /// Serialize the declarations, the longhands being collapsed into the
+    /// shorthands setting the most of them.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut serialized = HashSet::<PropertyId>::default();
+        let mut declarations = Vec::<String>::default();
+        let importance = |important: bool| match important {
+            true => " !important",
+            false => "",
+        };
+
+        for (declaration, important) in self.iter() {
+            let Some(id) = declaration.id() else {
+                declarations.push(format!("{}{};", declaration, importance(important)));
+                continue;
+            };
+
+            if serialized.contains(&id) {
+                continue;
+            }
+
+            let mut shorthands = ShorthandId::ALL
+                .iter()
+                .filter(|shorthand| shorthand.longhands().contains(&id))
+                .collect::<Vec<_>>();
+            shorthands.sort_by_key(|shorthand| std::cmp::Reverse(shorthand.longhands().len()));
+
+            let collapsed = shorthands.into_iter().find_map(|shorthand| {
+                let longhands = shorthand.longhands();
+                if longhands.iter().any(|id| serialized.contains(id)) {
+                    return None;
+                }
+
+                self.serialize_shorthand(*shorthand)
+                    .map(|value| (shorthand, longhands, value))
+            });
+
+            match collapsed {
+                Some((shorthand, longhands, value)) => {
+                    declarations.push(format!(
+                        "{}: {}{};",
+                        shorthand,
+                        value,
+                        importance(important)
+                    ));
+                    serialized.extend(longhands.iter().copied());
+                }
+                None => {
+                    declarations.push(format!("{}{};", declaration, importance(important)));
+                    serialized.insert(id);
+                }
+            }
+        }
+
+        write!(f, "{}", declarations.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        BackgroundAttachment, BackgroundClip, Layers, PropertyDeclaration,
+        PropertyDeclarationBlock, PropertyId,
+    };
+
+    #[test]
+    fn test_001_expand_shorthands() {
+        let block = PropertyDeclarationBlock::from("background: fixed content-box red");
+
+        assert_eq!(block.len(), 8);
+        assert_eq!(
+            block.get(PropertyId::BackgroundAttachment),
+            Some((
+                &PropertyDeclaration::BackgroundAttachment(Layers::from(vec![
+                    BackgroundAttachment::Fixed
+                ])),
+                false
+            ))
+        );
+        assert_eq!(
+            block.get(PropertyId::BackgroundClip),
+            Some((
+                &PropertyDeclaration::BackgroundClip(Layers::from(vec![
+                    BackgroundClip::ContentBox
+                ])),
+                false
+            ))
+        );
+        assert_eq!(block.property_value("background-color"), "red");
+
+        // The omitted longhands are reset to their initial value.
+        let block = PropertyDeclarationBlock::from(
+            "background-clip: text; background: blue; font: 2em \"Inter\", serif",
+        );
+        assert_eq!(block.property_value("background-clip"), "border-box");
+        assert_eq!(block.property_value("font-size"), "2em");
+        assert_eq!(block.property_value("font-family"), "\"Inter\", serif");
+
+        for invalid in [
+            "background: red blue",
+            "background: fixed scroll",
+            "background: text padding-box",
+            "background: border-box padding-box content-box",
+            "font: 12px",
+            "font: serif 12px",
+        ] {
+            assert!(
+                PropertyDeclarationBlock::from(invalid).is_empty(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_002_collapse_shorthands() {
+        let serialize = |source: &str| PropertyDeclarationBlock::from(source).to_string();
+
+        assert_eq!(
+            serialize("background: padding-box content-box"),
+            "background: padding-box content-box;"
+        );
+        assert_eq!(
+            serialize("background: none; background-color: red; font-size: 2em"),
+            "background: red; font-size: 2em;"
+        );
+        assert_eq!(serialize("background: initial"), "background: initial;");
+        assert_eq!(
+            serialize("background: var(--bg); font-family: serif"),
+            "background: var(--bg); font-family: serif;"
+        );
+
+        // The longhands of different importance, or CSS-wide keywords mixed
+        // with values, can't be collapsed.
+        assert_eq!(
+            serialize("background: none; background-color: red !important"),
+            "background-image: none; background-position: 0% 0%; background-size: auto; \
+             background-repeat: repeat; background-attachment: scroll; \
+             background-origin: padding-box; background-clip: border-box; \
+             background-color: red !important;"
+        );
+        assert_eq!(
+            serialize("background: red; background-clip: inherit"),
+            "background-image: none; background-position: 0% 0%; background-size: auto; \
+             background-repeat: repeat; background-attachment: scroll; \
+             background-origin: padding-box; background-color: red; \
+             background-clip: inherit;"
+        );
+
+        let block = PropertyDeclarationBlock::from("font: 1em serif; background-color: red");
+        assert_eq!(block.property_value("font"), "1em serif");
+        assert_eq!(block.property_value("background"), "");
+    }
+
+    #[test]
+    fn test_003_background_layers() {
+        let block = PropertyDeclarationBlock::from(
+            "background: url(a.png) right 10px top / cover no-repeat fixed, \
+             center / 50% auto border-box content-box red",
+        );
+        assert_eq!(
+            block.property_value("background-image"),
+            "url(\"a.png\"), none"
+        );
+        assert_eq!(
+            block.property_value("background-position"),
+            "right 10px top, center center"
+        );
+        assert_eq!(block.property_value("background-size"), "cover, 50%");
+        assert_eq!(
+            block.property_value("background-repeat"),
+            "no-repeat, repeat"
+        );
+        assert_eq!(
+            block.property_value("background-origin"),
+            "padding-box, border-box"
+        );
+        assert_eq!(
+            block.property_value("background-clip"),
+            "border-box, content-box"
+        );
+        assert_eq!(block.property_value("background-color"), "red");
+        assert_eq!(
+            block.property_value("background"),
+            "url(\"a.png\") right 10px top / cover no-repeat fixed, \
+             center center / 50% border-box content-box red"
+        );
+
+        // The lists of different lengths can't be collapsed.
+        let block = PropertyDeclarationBlock::from(
+            "background: url(a.png), url(b.png); background-repeat: space",
+        );
+        assert_eq!(block.property_value("background"), "");
+
+        for invalid in [
+            "background: red, url(a.png)",
+            "background: url(a.png),",
+            "background: / cover",
+            "background: left / red",
+            "background: no-repeat repeat-x",
+        ] {
+            assert!(
+                PropertyDeclarationBlock::from(invalid).is_empty(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_004_box_shorthands() {
+        let block = PropertyDeclarationBlock::from(
+            "margin: 1px auto 2px; padding: 1em 2em; border: thin dashed; \
+             border-radius: 1px 2px / 3px",
+        );
+        assert_eq!(block.property_value("margin-left"), "auto");
+        assert_eq!(block.property_value("margin-bottom"), "2px");
+        assert_eq!(block.property_value("padding-left"), "2em");
+        assert_eq!(block.property_value("border-left-style"), "dashed");
+        assert_eq!(block.property_value("border-right-color"), "currentcolor");
+        assert_eq!(
+            block.property_value("border-bottom-right-radius"),
+            "1px 3px"
+        );
+        assert_eq!(block.property_value("border-top-right-radius"), "2px 3px");
+        assert_eq!(
+            block.to_string(),
+            "margin: 1px auto 2px; padding: 1em 2em; border: thin dashed; \
+             border-radius: 1px 2px / 3px;"
+        );
+
+        let serialize = |source: &str| PropertyDeclarationBlock::from(source).to_string();
+        assert_eq!(serialize("margin: 0 0 0 0"), "margin: 0px;");
+        assert_eq!(serialize("border-radius: 5% / 5%"), "border-radius: 5%;");
+        assert_eq!(
+            serialize("border: 1px solid red; border-top-color: blue"),
+            "border-width: 1px; border-style: solid; border-color: blue red red;"
+        );
+        assert_eq!(serialize("border-top: 1px solid"), "border-top: 1px solid;");
+
+        for invalid in [
+            "margin: 1px 2px 3px 4px 5px",
+            "padding: -1px",
+            "border: solid solid",
+            "border-width: 10%",
+            "border-radius: 1px / 2px / 3px",
+            "border-radius: -1px",
+            "width: auto auto",
+            "max-width: auto",
+            "height: fit-content(1px, 2px)",
+        ] {
+            assert!(
+                PropertyDeclarationBlock::from(invalid).is_empty(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_005_logical_shorthands() {
+        let block = PropertyDeclarationBlock::from(
+            "margin-inline: 1px auto; padding-block: 2px; inset: 1px 2px; \
+             border-block: thin dashed; border-inline-color: red blue",
+        );
+        assert_eq!(block.property_value("margin-inline-end"), "auto");
+        assert_eq!(block.property_value("padding-block-end"), "2px");
+        assert_eq!(block.property_value("left"), "2px");
+        assert_eq!(block.property_value("border-block-end-style"), "dashed");
+        assert_eq!(block.property_value("border-inline-end-color"), "blue");
+        assert_eq!(
+            block.to_string(),
+            "margin-inline: 1px auto; padding-block: 2px; inset: 1px 2px; \
+             border-block: thin dashed; border-inline-color: red blue;"
+        );
+
+        let serialize = |source: &str| PropertyDeclarationBlock::from(source).to_string();
+        assert_eq!(
+            serialize("border-block-start: 1px solid; border-block-end: 1px solid"),
+            "border-block: 1px solid;"
+        );
+        assert_eq!(
+            serialize("inset-inline: auto; inset-block: auto"),
+            "inset-inline: auto; inset-block: auto;"
+        );
+
+        for invalid in [
+            "margin-block: 1px 2px 3px",
+            "padding-inline: -1px",
+            "inset-block: none",
+            "border-inline: solid solid",
+            "writing-mode: vertical",
+            "direction: auto",
+        ] {
+            assert!(
+                PropertyDeclarationBlock::from(invalid).is_empty(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_006_font_shorthands() {
+        let block = PropertyDeclarationBlock::from(
+            "font: italic small-caps bold condensed 12px/1.5 \"Inter\", serif",
+        );
+        assert_eq!(block.property_value("font-style"), "italic");
+        assert_eq!(block.property_value("font-variant-caps"), "small-caps");
+        assert_eq!(block.property_value("font-weight"), "bold");
+        assert_eq!(block.property_value("font-stretch"), "condensed");
+        assert_eq!(block.property_value("font-size"), "12px");
+        assert_eq!(block.property_value("line-height"), "1.5");
+        assert_eq!(
+            block.property_value("font"),
+            "italic small-caps bold condensed 12px/1.5 \"Inter\", serif"
+        );
+
+        // The family names may be unquoted, of one or more identifiers.
+        let block = PropertyDeclarationBlock::from("font: 12px Georgia");
+        assert_eq!(block.property_value("font-family"), "Georgia");
+        let block = PropertyDeclarationBlock::from(
+            "font: italic small-caps bold condensed 12px/30px Times New Roman, serif",
+        );
+        assert_eq!(block.property_value("line-height"), "30px");
+        assert_eq!(
+            block.property_value("font-family"),
+            "Times New Roman, serif"
+        );
+        assert_eq!(
+            block.property_value("font"),
+            "italic small-caps bold condensed 12px/30px Times New Roman, serif"
+        );
+
+        let block = PropertyDeclarationBlock::from("font: oblique 10deg 300 larger serif");
+        assert_eq!(block.property_value("font-style"), "oblique 10deg");
+        assert_eq!(block.property_value("font-weight"), "300");
+        assert_eq!(block.property_value("font-size"), "larger");
+
+        // The font shorthand can't represent most of the variants.
+        let block = PropertyDeclarationBlock::from("font: 1em serif; font-variant: slashed-zero");
+        assert_eq!(block.property_value("font"), "");
+        assert_eq!(block.property_value("font-variant"), "slashed-zero");
+
+        let block = PropertyDeclarationBlock::from(
+            "font-variant: no-common-ligatures small-caps oldstyle-nums jis78 super",
+        );
+        assert_eq!(
+            block.property_value("font-variant-ligatures"),
+            "no-common-ligatures"
+        );
+        assert_eq!(
+            block.property_value("font-variant-numeric"),
+            "oldstyle-nums"
+        );
+        assert_eq!(block.property_value("font-variant-east-asian"), "jis78");
+        assert_eq!(block.property_value("font-variant-position"), "super");
+        assert_eq!(
+            block.property_value("font-variant"),
+            "no-common-ligatures small-caps oldstyle-nums jis78 super"
+        );
+        assert_eq!(
+            PropertyDeclarationBlock::from("font-variant: none").property_value("font-variant"),
+            "none"
+        );
+
+        for invalid in [
+            "font: bold bold 12px serif",
+            "font: 12px/ serif",
+            "font: italic oblique 12px serif",
+            "font: 12px Georgia, inherit",
+            "font: 12px Georgia,",
+            "font-family: Arial, initial",
+            "font-variant: normal small-caps",
+            "font-variant: lining-nums oldstyle-nums",
+        ] {
+            assert!(
+                PropertyDeclarationBlock::from(invalid).is_empty(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_007_text_shorthands() {
+        let white_space = |source: &str| {
+            let block = PropertyDeclarationBlock::from(source);
+            (
+                block.property_value("white-space-collapse"),
+                block.property_value("text-wrap"),
+                block.property_value("white-space"),
+            )
+        };
+
+        assert_eq!(
+            white_space("white-space: pre"),
+            ("preserve".into(), "nowrap".into(), "pre".into())
+        );
+        assert_eq!(
+            white_space("white-space: nowrap"),
+            ("collapse".into(), "nowrap".into(), "nowrap".into())
+        );
+        assert_eq!(
+            white_space("white-space: break-spaces"),
+            ("break-spaces".into(), "wrap".into(), "break-spaces".into())
+        );
+        assert_eq!(
+            white_space("white-space: nowrap preserve-spaces"),
+            (
+                "preserve-spaces".into(),
+                "nowrap".into(),
+                "preserve-spaces nowrap".into()
+            )
+        );
+        assert_eq!(white_space("white-space: normal; text-wrap: balance").2, "");
+
+        let block =
+            PropertyDeclarationBlock::from("text-decoration: red underline 2px overline wavy");
+        assert_eq!(
+            block.property_value("text-decoration-line"),
+            "underline overline"
+        );
+        assert_eq!(block.property_value("text-decoration-thickness"), "2px");
+        assert_eq!(block.property_value("text-decoration-style"), "wavy");
+        assert_eq!(block.property_value("text-decoration-color"), "red");
+        assert_eq!(
+            block.property_value("text-decoration"),
+            "underline overline 2px wavy red"
+        );
+        assert_eq!(
+            PropertyDeclarationBlock::from("text-decoration: none")
+                .property_value("text-decoration"),
+            "none"
+        );
+
+        for invalid in [
+            "white-space: pre nowrap",
+            "white-space: wrap nowrap",
+            "white-space: balance",
+            "text-decoration: underline underline",
+            "text-decoration: none underline",
+            "text-decoration: solid dashed",
+            "text-shadow: 1px",
+            "text-shadow: 1px red 1px",
+            "text-shadow: 1px 1px -1px",
+            "text-indent: hanging",
+            "text-transform: uppercase lowercase",
+            "tab-size: -1",
+        ] {
+            assert!(
+                PropertyDeclarationBlock::from(invalid).is_empty(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_008_overflow_shorthand() {
+        let block = PropertyDeclarationBlock::from("overflow: hidden");
+        assert_eq!(block.property_value("overflow-y"), "hidden");
+        assert_eq!(block.property_value("overflow"), "hidden");
+
+        let block = PropertyDeclarationBlock::from("overflow: clip scroll");
+        assert_eq!(block.property_value("overflow-x"), "clip");
+        assert_eq!(block.property_value("overflow"), "clip scroll");
+
+        for invalid in ["overflow: auto auto auto", "overflow: none"] {
+            assert!(
+                PropertyDeclarationBlock::from(invalid).is_empty(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_009_flex_shorthands() {
+        let flex = |source: &str| {
+            let block = PropertyDeclarationBlock::from(format!("flex: {}", source).as_str());
+            block.property_value("flex")
+        };
+
+        assert_eq!(flex("none"), "0 0 auto");
+        assert_eq!(flex("auto"), "1 1 auto");
+        assert_eq!(flex("initial"), "initial");
+        assert_eq!(flex("2"), "2 1 0%");
+        assert_eq!(flex("2 3"), "2 3 0%");
+        assert_eq!(flex("10px 2"), "2 1 10px");
+        assert_eq!(flex("0 0 0"), "0 0 0px");
+        assert_eq!(flex("content"), "1 1 content");
+
+        let block = PropertyDeclarationBlock::from("flex-flow: wrap-reverse; gap: 2px");
+        assert_eq!(block.property_value("flex-direction"), "row");
+        assert_eq!(block.property_value("flex-flow"), "wrap-reverse");
+        assert_eq!(block.property_value("column-gap"), "2px");
+        assert_eq!(block.property_value("gap"), "2px");
+
+        for invalid in [
+            "flex: 1 10px 1",
+            "flex: 1 2 3",
+            "flex: -1",
+            "flex-flow: row column",
+            "gap: -1px",
+            "justify-content: baseline",
+            "align-content: left",
+            "align-items: auto",
+            "align-self: safe stretch",
+            "order: 1.5",
+        ] {
+            assert!(
+                PropertyDeclarationBlock::from(invalid).is_empty(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_010_grid_shorthands() {
+        let value = |source: &str, property: &str| {
+            PropertyDeclarationBlock::from(source).property_value(property)
+        };
+
+        assert_eq!(
+            value(
+                "grid-template-columns: [a] repeat(auto-fill, minmax(10px, 1fr)) [b] 20%",
+                "grid-template-columns"
+            ),
+            "[a] repeat(auto-fill, minmax(10px, 1fr)) [b] 20%"
+        );
+        assert_eq!(
+            value(
+                "grid-template-rows: subgrid [a] repeat(2, [b] [c])",
+                "grid-template-rows"
+            ),
+            "subgrid [a] repeat(2, [b] [c])"
+        );
+        assert_eq!(
+            value(
+                "grid-template-areas: 'a a .' 'b b ...'",
+                "grid-template-areas"
+            ),
+            "\"a a .\" \"b b .\""
+        );
+
+        let template = "grid-template: [top] 'a a' 10px [mid] 'b c' [bottom] / 1fr 2fr";
+        assert_eq!(
+            value(template, "grid-template-rows"),
+            "[top] 10px [mid] auto [bottom]"
+        );
+        assert_eq!(value(template, "grid-template-columns"), "1fr 2fr");
+        assert_eq!(
+            value(template, "grid-template"),
+            "[top] \"a a\" 10px [mid] \"b c\" [bottom] / 1fr 2fr"
+        );
+        assert_eq!(
+            value("grid-template: 10px / auto", "grid-template"),
+            "10px / auto"
+        );
+
+        let grid = "grid: auto-flow dense 50px / repeat(3, 1fr)";
+        assert_eq!(value(grid, "grid-auto-flow"), "dense");
+        assert_eq!(value(grid, "grid-auto-rows"), "50px");
+        assert_eq!(value(grid, "grid"), "auto-flow dense 50px / repeat(3, 1fr)");
+        assert_eq!(value("grid: none / auto-flow", "grid-auto-flow"), "column");
+        assert_eq!(value("grid: 'a'", "grid"), "\"a\"");
+
+        assert_eq!(value("grid-row: a", "grid-row-end"), "a");
+        assert_eq!(value("grid-row: 2 / span 3 b", "grid-row"), "2 / span 3 b");
+        assert_eq!(value("grid-column: span foo", "grid-column-end"), "auto");
+        assert_eq!(value("grid-area: main", "grid-column-end"), "main");
+        assert_eq!(value("grid-area: main", "grid-area"), "main");
+        assert_eq!(value("grid-area: 1 / 2 / -1", "grid-area"), "1 / 2 / -1");
+
+        for invalid in [
+            "grid-template-columns: repeat(auto-fill, 1fr)",
+            "grid-template-columns: repeat(auto-fill, 10px) repeat(auto-fit, 10px)",
+            "grid-template-columns: repeat(auto-fit, 10px) auto",
+            "grid-template-columns: minmax(1fr, 10px)",
+            "grid-template-columns: [a] [b] 10px",
+            "grid-template-rows: -1fr",
+            "grid-template-areas: 'a b' 'b a'",
+            "grid-template-areas: 'a a' 'b'",
+            "grid-template-areas: 'a #'",
+            "grid-template: 'a' / repeat(2, 1fr)",
+            "grid: auto-flow / auto-flow",
+            "grid-auto-flow: row column",
+            "grid-row: 0",
+            "grid-row: span -1",
+            "grid-row: 1 span a",
+            "grid-column: span",
+            "grid-area: 1 / 2 / 3 / 4 / 5",
+        ] {
+            assert!(
+                PropertyDeclarationBlock::from(invalid).is_empty(),
+                "{}",
+                invalid
+            );
+        }
+    }
+}
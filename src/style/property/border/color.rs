@@ -0,0 +1,59 @@
+use crate::style::{Color, ComputeContext, StyleError, ToComputedValue, Value};
+
+/// The color of a side of a border.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#border-color)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BorderColor(Color);
+
+impl Default for BorderColor {
+    fn default() -> Self {
+        Self(Color::CurrentColor)
+    }
+}
+
+impl From<Color> for BorderColor {
+    fn from(value: Color) -> Self {
+        Self(value)
+    }
+}
+
+impl From<BorderColor> for Color {
+    fn from(value: BorderColor) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<Value> for BorderColor {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Color(color) => Ok(Self(color)),
+            _ => Err(StyleError::InvalidValue(&["<color>"])),
+        }
+    }
+}
+
+impl std::fmt::Display for BorderColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<BorderColor> for Value {
+    fn from(value: BorderColor) -> Self {
+        Self::Color(value.0)
+    }
+}
+
+impl ToComputedValue for BorderColor {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        self.clone()
+    }
+}
+
+longhand_newtypes!(BorderColor: BorderTopColor, BorderRightColor, BorderBottomColor, BorderLeftColor);
+longhand_newtypes!(BorderColor: BorderBlockStartColor, BorderBlockEndColor, BorderInlineStartColor, BorderInlineEndColor);
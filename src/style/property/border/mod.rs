@@ -0,0 +1,84 @@
+mod color;
+mod radius;
+mod style;
+mod width;
+
+pub use color::*;
+pub use radius::*;
+pub use style::*;
+pub use width::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_border_longhands() {
+        for (name, source, expected) in [
+            ("border-top-width", "thin", "thin"),
+            ("border-top-width", "0", "0px"),
+            ("border-top-style", "DASHED", "dashed"),
+            ("border-top-color", "currentColor", "currentcolor"),
+            ("border-top-left-radius", "1px 2px", "1px 2px"),
+            ("border-top-left-radius", "10% 10%", "10%"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("border-top-width", "-1px"),
+            ("border-top-width", "10%"),
+            ("border-top-style", "auto"),
+            ("border-top-color", "1px"),
+            ("border-top-left-radius", "-1px"),
+            ("border-top-left-radius", "1px 2px 3px"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px }
+            body { border: thin solid; border-left-width: 1em; border-bottom-style: hidden; \
+                border-top-left-radius: calc(1px - 1em) 2em }",
+        );
+        let border = |index: usize, id| styles[index].value(id).to_string();
+
+        assert_eq!(border(1, PropertyId::BorderTopWidth), "1px");
+        assert_eq!(border(1, PropertyId::BorderLeftWidth), "10px");
+        // The sides without a border have no width.
+        assert_eq!(border(1, PropertyId::BorderBottomWidth), "0px");
+        assert_eq!(border(1, PropertyId::BorderTopLeftRadius), "0px 20px");
+        assert_eq!(border(2, PropertyId::BorderTopStyle), "none");
+        assert_eq!(border(2, PropertyId::BorderTopWidth), "0px");
+    }
+
+    #[test]
+    fn test_002_border_shorthands() {
+        for (name, source, expected) in [
+            ("border", "1px solid red", "1px solid red"),
+            ("border", "dotted thick", "thick dotted"),
+            ("border-top", "2px", "2px"),
+            ("border-width", "1px 2px 1px", "1px 2px"),
+            ("border-style", "none dashed", "none dashed"),
+            ("border-color", "red blue green", "red blue green"),
+            ("border-radius", "1px 2px / 3px", "1px 2px / 3px"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("border", "solid solid"),
+            ("border", "1px solid red blue"),
+            ("border-width", "1px 2px 3px 4px 5px"),
+            ("border-radius", "1px / 2px / 3px"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::style::{ComputeContext, LengthPercentage, StyleError, ToComputedValue, Value};
+
+/// The radii of the ellipse rounding a corner of a border.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#border-radius)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BorderRadius {
+    pub horizontal: LengthPercentage,
+    pub vertical: LengthPercentage,
+}
+
+impl BorderRadius {
+    /// The circular corner of the radius.
+    pub fn circular(radius: LengthPercentage) -> Self {
+        Self {
+            horizontal: radius.clone(),
+            vertical: radius,
+        }
+    }
+}
+
+impl Default for BorderRadius {
+    fn default() -> Self {
+        Self::circular(LengthPercentage::zero())
+    }
+}
+
+impl TryFrom<Value> for BorderRadius {
+    type Error = StyleError;
+
+    /// Parse the horizontal radius, optionally followed by the vertical one.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let radius = |value: Value| match LengthPercentage::try_from(value) {
+            Ok(radius) if !radius.is_negative() => Ok(radius),
+            _ => Err(StyleError::InvalidValue(&["<length-percentage [0,∞]>"])),
+        };
+
+        match value {
+            Value::Array(values) => match <[Value; 2]>::try_from(values) {
+                Ok([horizontal, vertical]) => Ok(Self {
+                    horizontal: radius(horizontal)?,
+                    vertical: radius(vertical)?,
+                }),
+                Err(_) => Err(StyleError::InvalidValue(&[
+                    "<length-percentage [0,∞]>{1,2}",
+                ])),
+            },
+            value => radius(value).map(Self::circular),
+        }
+    }
+}
+
+impl From<BorderRadius> for Value {
+    fn from(value: BorderRadius) -> Self {
+        match value.horizontal == value.vertical {
+            true => Self::from(value.horizontal),
+            false => Self::Array(vec![
+                Self::from(value.horizontal),
+                Self::from(value.vertical),
+            ]),
+        }
+    }
+}
+
+impl std::fmt::Display for BorderRadius {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.horizontal == self.vertical {
+            true => write!(f, "{}", self.horizontal),
+            false => write!(f, "{} {}", self.horizontal, self.vertical),
+        }
+    }
+}
+
+impl ToComputedValue for BorderRadius {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        let resolve = |radius: &LengthPercentage| radius.resolve_non_negative(&context.length);
+
+        Self {
+            horizontal: resolve(&self.horizontal),
+            vertical: resolve(&self.vertical),
+        }
+    }
+}
+
+longhand_newtypes!(
+    BorderRadius: BorderTopLeftRadius,
+    BorderTopRightRadius,
+    BorderBottomRightRadius,
+    BorderBottomLeftRadius,
+);
+longhand_newtypes!(
+    BorderRadius: BorderStartStartRadius,
+    BorderStartEndRadius,
+    BorderEndStartRadius,
+    BorderEndEndRadius,
+);
@@ -0,0 +1,111 @@
+use crate::style::{ComputeContext, Keyword, StyleError, ToComputedValue, Value};
+
+const ALLOWED_KWS: &[Keyword] = &[
+    Keyword::None,
+    Keyword::Hidden,
+    Keyword::Dotted,
+    Keyword::Dashed,
+    Keyword::Solid,
+    Keyword::Double,
+    Keyword::Groove,
+    Keyword::Ridge,
+    Keyword::Inset,
+    Keyword::Outset,
+];
+
+/// The line style of a side of a border.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#border-style)
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum BorderStyle {
+    #[default]
+    None,
+    /// No border, winning over the other borders in table border conflicts.
+    Hidden,
+    Dotted,
+    Dashed,
+    Solid,
+    Double,
+    Groove,
+    Ridge,
+    Inset,
+    Outset,
+}
+
+impl BorderStyle {
+    /// Checks if the side has a border, whose width is then the declared one.
+    pub fn has_border(&self) -> bool {
+        !matches!(self, Self::None | Self::Hidden)
+    }
+}
+
+impl TryFrom<Keyword> for BorderStyle {
+    type Error = StyleError;
+
+    fn try_from(value: Keyword) -> Result<Self, Self::Error> {
+        match value {
+            Keyword::None => Ok(Self::None),
+            Keyword::Hidden => Ok(Self::Hidden),
+            Keyword::Dotted => Ok(Self::Dotted),
+            Keyword::Dashed => Ok(Self::Dashed),
+            Keyword::Solid => Ok(Self::Solid),
+            Keyword::Double => Ok(Self::Double),
+            Keyword::Groove => Ok(Self::Groove),
+            Keyword::Ridge => Ok(Self::Ridge),
+            Keyword::Inset => Ok(Self::Inset),
+            Keyword::Outset => Ok(Self::Outset),
+            _ => Err(StyleError::InvalidValue(&["<line-style>"])),
+        }
+    }
+}
+
+impl TryFrom<Value> for BorderStyle {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(kw) if kw.is_either(ALLOWED_KWS) => Self::try_from(kw),
+            _ => Err(StyleError::InvalidValue(&["<line-style>"])),
+        }
+    }
+}
+
+impl From<BorderStyle> for Keyword {
+    fn from(value: BorderStyle) -> Self {
+        match value {
+            BorderStyle::None => Self::None,
+            BorderStyle::Hidden => Self::Hidden,
+            BorderStyle::Dotted => Self::Dotted,
+            BorderStyle::Dashed => Self::Dashed,
+            BorderStyle::Solid => Self::Solid,
+            BorderStyle::Double => Self::Double,
+            BorderStyle::Groove => Self::Groove,
+            BorderStyle::Ridge => Self::Ridge,
+            BorderStyle::Inset => Self::Inset,
+            BorderStyle::Outset => Self::Outset,
+        }
+    }
+}
+
+impl std::fmt::Display for BorderStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Keyword::from(*self))
+    }
+}
+
+impl From<BorderStyle> for Value {
+    fn from(value: BorderStyle) -> Self {
+        Self::Keyword(Keyword::from(value))
+    }
+}
+
+impl ToComputedValue for BorderStyle {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+longhand_newtypes!(BorderStyle: BorderTopStyle, BorderRightStyle, BorderBottomStyle, BorderLeftStyle);
+longhand_newtypes!(BorderStyle: BorderBlockStartStyle, BorderBlockEndStyle, BorderInlineStartStyle, BorderInlineEndStyle);
@@ -0,0 +1,120 @@
+use crate::{
+    geometry::Edge,
+    style::{ComputeContext, Keyword, Length, Style, StyleError, ToComputedValue, Value},
+};
+
+/// The width of a side of a border, computed into an absolute length.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#border-width)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum BorderWidth {
+    Thin,
+    #[default]
+    Medium,
+    Thick,
+    Length(Length),
+}
+
+impl BorderWidth {
+    /// The initial border width, medium.
+    pub fn medium() -> Length {
+        Length::px(3)
+    }
+}
+
+impl TryFrom<Value> for BorderWidth {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Thin) => Ok(Self::Thin),
+            Value::Keyword(Keyword::Medium) => Ok(Self::Medium),
+            Value::Keyword(Keyword::Thick) => Ok(Self::Thick),
+            Value::Length(length) if f32::from(length.quantity) >= 0.0 => Ok(Self::Length(length)),
+            // The unitless zero is a length.
+            Value::Number(number) if f32::from(number) == 0.0 => Ok(Self::Length(Length::px(0))),
+            _ => Err(StyleError::InvalidValue(&[
+                "<length [0,∞]>",
+                "thin",
+                "medium",
+                "thick",
+            ])),
+        }
+    }
+}
+
+impl From<BorderWidth> for Value {
+    fn from(value: BorderWidth) -> Self {
+        match value {
+            BorderWidth::Thin => Self::Keyword(Keyword::Thin),
+            BorderWidth::Medium => Self::Keyword(Keyword::Medium),
+            BorderWidth::Thick => Self::Keyword(Keyword::Thick),
+            BorderWidth::Length(length) => Self::Length(length),
+        }
+    }
+}
+
+impl std::fmt::Display for BorderWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Thin => write!(f, "thin"),
+            Self::Medium => write!(f, "medium"),
+            Self::Thick => write!(f, "thick"),
+            Self::Length(length) => write!(f, "{}", length),
+        }
+    }
+}
+
+impl ToComputedValue for BorderWidth {
+    type Computed = Length;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::Thin => Length::px(1),
+            Self::Medium => Self::medium(),
+            Self::Thick => Length::px(5),
+            Self::Length(length) => length.resolve_relative(&context.length),
+        }
+    }
+}
+
+longhand_newtypes!(BorderWidth: BorderTopWidth, BorderRightWidth, BorderBottomWidth, BorderLeftWidth);
+longhand_newtypes!(BorderWidth: BorderBlockStartWidth, BorderBlockEndWidth, BorderInlineStartWidth, BorderInlineEndWidth);
+
+impl Style {
+    /// The computed border widths, zero on the sides without a border style.
+    pub fn border_width(&self) -> Edge<Length> {
+        let width = |width: &Length, style| match style {
+            true => width.clone(),
+            false => Length::px(0),
+        };
+
+        Edge {
+            left: width(&self.border_left_width, self.border_left_style.has_border()),
+            right: width(
+                &self.border_right_width,
+                self.border_right_style.has_border(),
+            ),
+            top: width(&self.border_top_width, self.border_top_style.has_border()),
+            bottom: width(
+                &self.border_bottom_width,
+                self.border_bottom_style.has_border(),
+            ),
+        }
+    }
+
+    /// Zero the computed widths of the sides without a border style.
+    pub(crate) fn compute_border_widths(&mut self) {
+        let Edge {
+            left,
+            right,
+            top,
+            bottom,
+        } = self.border_width();
+
+        self.border_left_width = left;
+        self.border_right_width = right;
+        self.border_top_width = top;
+        self.border_bottom_width = bottom;
+    }
+}
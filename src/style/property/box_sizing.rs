@@ -0,0 +1,78 @@
+use crate::style::{ComputeContext, Keyword, StyleError, Value};
+
+use super::ToComputedValue;
+
+/// The box the width and height properties size.
+///
+/// [CSS Box Sizing 3](https://drafts.csswg.org/css-sizing-3/#box-sizing)
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum BoxSizing {
+    #[default]
+    ContentBox,
+    /// The padding and the border are included in the size.
+    BorderBox,
+}
+
+impl TryFrom<Value> for BoxSizing {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::ContentBox) => Ok(Self::ContentBox),
+            Value::Keyword(Keyword::BorderBox) => Ok(Self::BorderBox),
+            _ => Err(StyleError::InvalidValue(&["content-box", "border-box"])),
+        }
+    }
+}
+
+impl From<BoxSizing> for Keyword {
+    fn from(value: BoxSizing) -> Self {
+        match value {
+            BoxSizing::ContentBox => Self::ContentBox,
+            BoxSizing::BorderBox => Self::BorderBox,
+        }
+    }
+}
+
+impl std::fmt::Display for BoxSizing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Keyword::from(*self))
+    }
+}
+
+impl From<BoxSizing> for Value {
+    fn from(value: BoxSizing) -> Self {
+        Self::Keyword(Keyword::from(value))
+    }
+}
+
+impl ToComputedValue for BoxSizing {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        BoxSizing,
+    };
+
+    #[test]
+    fn test_001_box_sizing() {
+        assert_eq!(round_trip("box-sizing", "BORDER-BOX"), "border-box");
+        assert_eq!(round_trip("box-sizing", "content-box"), "content-box");
+        assert_eq!(round_trip("box-sizing", "padding-box"), "");
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve("html { box-sizing: border-box }");
+        assert_eq!(styles[0].box_sizing, BoxSizing::BorderBox);
+        assert_eq!(styles[1].box_sizing, BoxSizing::ContentBox);
+    }
+}
@@ -0,0 +1,85 @@
+use std::ops::Deref;
+
+use crate::style::{Color, ComputeContext, Rgba, Style, StyleError, SystemColor, Value};
+
+use super::ToComputedValue;
+
+/// The foreground color of the text, which currentcolor refers to.
+///
+/// [CSS Color 4](https://drafts.csswg.org/css-color-4/#the-color-property)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForegroundColor(Color);
+
+impl Deref for ForegroundColor {
+    type Target = Color;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for ForegroundColor {
+    fn default() -> Self {
+        Self(Color::System(SystemColor::CanvasText))
+    }
+}
+
+impl From<Color> for ForegroundColor {
+    fn from(value: Color) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<Value> for ForegroundColor {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Color(color) => Ok(Self(color)),
+            _ => Err(StyleError::InvalidValue(&["<color>"])),
+        }
+    }
+}
+
+impl From<ForegroundColor> for Color {
+    fn from(value: ForegroundColor) -> Self {
+        value.0
+    }
+}
+
+impl std::fmt::Display for ForegroundColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<ForegroundColor> for Value {
+    fn from(value: ForegroundColor) -> Self {
+        Self::Color(value.0)
+    }
+}
+
+impl ToComputedValue for ForegroundColor {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        self.clone()
+    }
+}
+
+impl Style {
+    /// Resolve currentcolor in the color property to the color of the
+    /// parent, as if inherited.
+    pub(crate) fn compute_color(&mut self, parent: Option<&Style>) {
+        if *self.color == Color::CurrentColor {
+            self.color =
+                parent.map_or_else(ForegroundColor::default, |parent| parent.color.clone());
+        }
+    }
+
+    /// The used value of currentcolor, the colors of the other properties
+    /// being resolved against it.
+    pub fn current_color(&self) -> Rgba {
+        self.color.to_rgba(&Rgba::new(0.0, 0.0, 0.0, 1.0))
+    }
+}
@@ -0,0 +1,215 @@
+use crate::style::{
+    contains_var, ComponentValue, Declaration, Keyword, PropertyDeclaration, PropertyId,
+    ShorthandId, StyleError, Value,
+};
+
+/// The keywords every property accepts.
+///
+/// [CSS Cascade 5](https://drafts.csswg.org/css-cascade-5/#defaulting-keywords)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CssWideKeyword {
+    Initial,
+    Inherit,
+    Unset,
+    Revert,
+    RevertLayer,
+}
+
+impl TryFrom<&Value> for CssWideKeyword {
+    type Error = StyleError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Initial) => Ok(Self::Initial),
+            Value::Keyword(Keyword::Inherit) => Ok(Self::Inherit),
+            Value::Keyword(Keyword::Unset) => Ok(Self::Unset),
+            Value::Keyword(Keyword::Revert) => Ok(Self::Revert),
+            Value::Keyword(Keyword::RevertLayer) => Ok(Self::RevertLayer),
+            _ => Err(StyleError::InvalidValue(&[
+                "initial",
+                "inherit",
+                "unset",
+                "revert",
+                "revert-layer",
+            ])),
+        }
+    }
+}
+
+impl From<CssWideKeyword> for Keyword {
+    fn from(value: CssWideKeyword) -> Self {
+        match value {
+            CssWideKeyword::Initial => Self::Initial,
+            CssWideKeyword::Inherit => Self::Inherit,
+            CssWideKeyword::Unset => Self::Unset,
+            CssWideKeyword::Revert => Self::Revert,
+            CssWideKeyword::RevertLayer => Self::RevertLayer,
+        }
+    }
+}
+
+impl std::fmt::Display for CssWideKeyword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Keyword::from(*self))
+    }
+}
+
+/// The CSS-wide keyword a value consists of, if any.
+fn css_wide_keyword(value: &[ComponentValue]) -> Option<CssWideKeyword> {
+    Value::try_from(value)
+        .ok()
+        .and_then(|value| CssWideKeyword::try_from(&value).ok())
+}
+
+impl PropertyDeclaration {
+    /// Parse the value of the named longhand or custom property.
+    pub fn parse(name: &str, value: &[ComponentValue]) -> Result<Self, StyleError> {
+        if name.starts_with("--") {
+            return Ok(Self::Custom(name.to_string(), value.to_vec()));
+        }
+
+        let id = PropertyId::try_from(name)?;
+
+        if contains_var(value) {
+            return Ok(Self::WithVariables(id, None, value.to_vec()));
+        }
+
+        match css_wide_keyword(value) {
+            Some(keyword) => Ok(Self::CssWide(id, keyword)),
+            None => id.parse(value),
+        }
+    }
+
+    /// Parse the value of the named property, shorthands being expanded into
+    /// the declarations of their longhands.
+    pub fn parse_all(name: &str, value: &[ComponentValue]) -> Result<Vec<Self>, StyleError> {
+        let Ok(shorthand) = ShorthandId::try_from(name) else {
+            return Self::parse(name, value).map(|declaration| vec![declaration]);
+        };
+
+        let longhands = shorthand.longhands().iter();
+        if contains_var(value) {
+            return Ok(longhands
+                .map(|id| Self::WithVariables(*id, Some(shorthand), value.to_vec()))
+                .collect());
+        }
+
+        match css_wide_keyword(value) {
+            Some(keyword) => Ok(longhands.map(|id| Self::CssWide(*id, keyword)).collect()),
+            None => shorthand.expand(value),
+        }
+    }
+
+    /// The name of the declared property.
+    pub fn name(&self) -> &str {
+        match (self, self.id()) {
+            (Self::Custom(name, _), _) => name,
+            (_, Some(id)) => id.name(),
+            (_, None) => unreachable!(),
+        }
+    }
+}
+
+impl TryFrom<&Declaration> for PropertyDeclaration {
+    type Error = StyleError;
+
+    fn try_from(value: &Declaration) -> Result<Self, Self::Error> {
+        Self::parse(&value.name, &value.value)
+    }
+}
+
+impl std::fmt::Display for PropertyDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name(), self.value_to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        parse_component_values, BackgroundClip, Color, CssWideKeyword, Layers, PropertyDeclaration,
+        PropertyId, StyleError, Url, Value,
+    };
+
+    fn parse(name: &str, source: &str) -> Result<PropertyDeclaration, StyleError> {
+        PropertyDeclaration::parse(name, &parse_component_values(source))
+    }
+
+    #[test]
+    fn test_001_values() {
+        let value = |source: &str| Value::try_from(parse_component_values(source).as_slice());
+
+        assert_eq!(value("BLOCK"), value("block"));
+        assert_eq!(
+            value("RED"),
+            Ok(Value::Color(Color::try_from("red").unwrap()))
+        );
+        assert_eq!(value("url(a.png)"), Ok(Value::Url(Url::new("a.png"))));
+        assert_eq!(value("url(\"a.png\")"), Ok(Value::Url(Url::new("a.png"))));
+        assert_eq!(value("src('a.png')"), Ok(Value::Url(Url::new("a.png"))));
+        assert_eq!(value("10DEG").map(|v| v.to_string()), Ok("10deg".into()));
+        assert!(value("10foo").is_err());
+
+        assert_eq!(value("150%").map(|v| v.to_string()), Ok("150%".into()));
+        assert_eq!(value("-10%").map(|v| v.to_string()), Ok("-10%".into()));
+    }
+
+    #[test]
+    fn test_002_property_declarations() {
+        assert_eq!(
+            parse("background-clip", "Padding-Box"),
+            Ok(PropertyDeclaration::BackgroundClip(Layers::from(vec![
+                BackgroundClip::PaddingBox
+            ])))
+        );
+        assert_eq!(
+            parse("DISPLAY", "inherit"),
+            Ok(PropertyDeclaration::CssWide(
+                PropertyId::Display,
+                CssWideKeyword::Inherit
+            ))
+        );
+        assert!(matches!(
+            parse("font-size", "var(--size)"),
+            Ok(PropertyDeclaration::WithVariables(
+                PropertyId::FontSize,
+                None,
+                _
+            ))
+        ));
+        assert!(matches!(
+            parse("--Size", " 1em"),
+            Ok(PropertyDeclaration::Custom(name, _)) if name == "--Size"
+        ));
+
+        assert_eq!(
+            parse("background-attachment", "FIXED").map(|decl| decl.to_string()),
+            Ok("background-attachment: fixed".into())
+        );
+        assert_eq!(
+            parse("font-size", "2em").map(|decl| decl.to_string()),
+            Ok("font-size: 2em".into())
+        );
+        assert_eq!(
+            parse("font-family", "serif, \"Arial\"").map(|decl| decl.to_string()),
+            Ok("font-family: serif, \"Arial\"".into())
+        );
+        assert_eq!(
+            parse("background-color", "#ff0000").map(|decl| decl.to_string()),
+            Ok("background-color: rgb(255, 0, 0)".into())
+        );
+
+        assert_eq!(
+            parse("font-size", "red"),
+            Err(StyleError::InvalidValue(&["<length>", "<percentage>"]))
+        );
+        assert_eq!(
+            parse("font-family", "12px, serif"),
+            Err(StyleError::InvalidValue(&[
+                "<family-name>",
+                "<generic-family>"
+            ]))
+        );
+        assert_eq!(parse("colour", "red"), Err(StyleError::InvalidDeclaration));
+    }
+}
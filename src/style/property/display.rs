@@ -1,12 +1,6 @@
-use crate::style::{Keyword, Value};
-
-const ALLOWED_KWS: &[Keyword] = &[
-    Keyword::Inline,
-    Keyword::Block,
-    Keyword::Inline,
-    Keyword::None,
-    Keyword::Contents,
-];
+use crate::style::{ComputeContext, Keyword, StyleError, Value};
+
+use super::ToComputedValue;
 
 const BOX_MASK: i32 = 0b11;
 const NONE: i32 = 0b10;
@@ -33,175 +27,114 @@ const TABLE_ROW_GROUP: i32 = 0b000100000000;
 const TABLE_HEADER_GROUP: i32 = 0b001000000000;
 const TABLE_FOOTER_GROUP: i32 = 0b001100000000;
 const TABLE_ROW: i32 = 0b010000000000;
-const TABLE_CELL: i32 = 0b010000000000;
-const TABLE_COLUMN_GROUP: i32 = 0b010100000000;
-const TABLE_CAPTION: i32 = 0b011000000000;
-const RUBY_BASE: i32 = 0b011100000000;
-const RUBY_BASE_CONTAINER: i32 = 0b100000000000;
-const RUBY_TEXT_CONTAINER: i32 = 0b100100000000;
-
-/// Transform a keyword into a display flag.
+const TABLE_CELL: i32 = 0b010100000000;
+const TABLE_COLUMN_GROUP: i32 = 0b011000000000;
+const TABLE_COLUMN: i32 = 0b011100000000;
+const TABLE_CAPTION: i32 = 0b100000000000;
+const RUBY_BASE: i32 = 0b100100000000;
+const RUBY_TEXT: i32 = 0b101000000000;
+const RUBY_BASE_CONTAINER: i32 = 0b101100000000;
+const RUBY_TEXT_CONTAINER: i32 = 0b110000000000;
+
+/// The outer display types.
+const OUTSIDE_KWS: &[(Keyword, i32)] = &[
+    (Keyword::Block, BLOCK),
+    (Keyword::Inline, INLINE),
+    (Keyword::RunIn, RUN_IN),
+];
+
+/// The inner display types.
+const INSIDE_KWS: &[(Keyword, i32)] = &[
+    (Keyword::Flow, FLOW),
+    (Keyword::FlowRoot, FLOW_ROOT),
+    (Keyword::Table, TABLE),
+    (Keyword::Flex, FLEX),
+    (Keyword::Grid, GRID),
+    (Keyword::Ruby, RUBY),
+];
+
+/// The keywords which can't be combined with any other: the box generation,
+/// layout-internal and legacy ones.
+const STANDALONE_KWS: &[(Keyword, i32)] = &[
+    (Keyword::None, NONE),
+    (Keyword::Contents, CONTENTS),
+    (Keyword::TableRowGroup, TABLE_ROW_GROUP),
+    (Keyword::TableHeaderGroup, TABLE_HEADER_GROUP),
+    (Keyword::TableFooterGroup, TABLE_FOOTER_GROUP),
+    (Keyword::TableRow, TABLE_ROW),
+    (Keyword::TableCell, TABLE_CELL),
+    (Keyword::TableColumnGroup, TABLE_COLUMN_GROUP),
+    (Keyword::TableColumn, TABLE_COLUMN),
+    (Keyword::TableCaption, TABLE_CAPTION),
+    (Keyword::RubyBase, RUBY_BASE),
+    (Keyword::RubyText, RUBY_TEXT),
+    (Keyword::RubyBaseContainer, RUBY_BASE_CONTAINER),
+    (Keyword::RubyTextContainer, RUBY_TEXT_CONTAINER),
+    (Keyword::InlineBlock, INLINE | FLOW_ROOT),
+    (Keyword::InlineTable, INLINE | TABLE),
+    (Keyword::InlineFlex, INLINE | FLEX),
+    (Keyword::InlineGrid, INLINE | GRID),
+];
+
+/// Transform a keyword into a display flag, zero if it isn't a display one.
 fn from_kw(kw: &Keyword) -> i32 {
-    match kw {
-        Keyword::Block => BLOCK,
-        Keyword::Inline => INLINE,
-        Keyword::RunIn => RUN_IN,
-
-        Keyword::Flow => FLOW,
-        Keyword::FlowRoot => FLOW_ROOT,
-        Keyword::Table => TABLE,
-        Keyword::Flex => FLEX,
-        Keyword::Grid => GRID,
-        Keyword::Ruby => RUBY,
-
-        Keyword::ListItem => LIST_ITEM,
-
-        Keyword::TableRowGroup => TABLE_ROW_GROUP,
-        Keyword::TableHeaderGroup => TABLE_HEADER_GROUP,
-        Keyword::TableFooterGroup => TABLE_FOOTER_GROUP,
-        Keyword::TableRow => TABLE_ROW,
-        Keyword::TableCell => TABLE_CELL,
-        Keyword::TableColumnGroup => TABLE_COLUMN_GROUP,
-        Keyword::TableCaption => TABLE_CAPTION,
-        Keyword::RubyBase => RUBY_BASE,
-        Keyword::RubyBaseContainer => RUBY_BASE_CONTAINER,
-        Keyword::RubyTextContainer => RUBY_TEXT_CONTAINER,
-
-        Keyword::None => NONE,
-        Keyword::Contents => CONTENTS,
-
-        _ => 0,
-    }
+    [
+        OUTSIDE_KWS,
+        INSIDE_KWS,
+        STANDALONE_KWS,
+        &[(Keyword::ListItem, LIST_ITEM)],
+    ]
+    .into_iter()
+    .flatten()
+    .find(|(keyword, _)| keyword == kw)
+    .map_or(0, |(_, flag)| *flag)
 }
 
-// Transform a display flag into a list of keywords.
-fn to_kws(flags: i32) -> Vec<Keyword> {
-    let box_display = flags & BOX_MASK;
+/// The keyword of a display flag among the given ones.
+fn to_kw(flag: i32, kws: &[(Keyword, i32)]) -> Option<Keyword> {
+    kws.iter()
+        .find(|(_, kw_flag)| *kw_flag == flag)
+        .map(|(keyword, _)| *keyword)
+}
 
-    if box_display == NONE {
-        return vec![Keyword::None];
+/// Transform display flags into their shortest list of keywords, the omitted
+/// display types being the ones they default to.
+fn to_kws(flags: i32) -> Vec<Keyword> {
+    if let Some(keyword) = to_kw(flags, STANDALONE_KWS) {
+        return vec![keyword];
     }
 
-    if box_display == CONTENTS {
-        return vec![Keyword::Contents];
-    }
+    let (outside, inside) = (flags & OUTSIDE_MASK, flags & INSIDE_MASK);
+    let list_item = flags & LIST_ITEM_MASK == LIST_ITEM;
+    let default_outside = match inside {
+        RUBY => INLINE,
+        _ => BLOCK,
+    };
 
     let mut kws = Vec::<Keyword>::default();
-
-    let inside_display = flags & INSIDE_MASK;
-    if inside_display == INLINE {
-        kws.push(Keyword::Inline);
-    } else if inside_display == BLOCK {
-        kws.push(Keyword::Block);
-    } else if inside_display == RUN_IN {
-        kws.push(Keyword::RunIn)
-    }
-
-    let outside_display = flags & OUTSIDE_MASK;
-    if outside_display == FLOW {
-        kws.push(Keyword::Flow)
-    } else if outside_display == FLOW_ROOT {
-        kws.push(Keyword::FlowRoot)
-    } else if outside_display == TABLE {
-        kws.push(Keyword::Table)
-    } else if outside_display == FLEX {
-        kws.push(Keyword::Flex)
-    } else if outside_display == GRID {
-        kws.push(Keyword::Grid)
-    } else if outside_display == RUBY {
-        kws.push(Keyword::Ruby)
+    if outside != default_outside || (inside == FLOW && !list_item) {
+        kws.extend(to_kw(outside, OUTSIDE_KWS));
     }
-
-    let list_item_display = flags & LIST_ITEM_MASK;
-    if list_item_display == LIST_ITEM {
-        kws.push(Keyword::ListItem)
+    if inside != FLOW || (kws.is_empty() && !list_item) {
+        kws.extend(to_kw(inside, INSIDE_KWS));
     }
-
-    let internal_display = flags & INTERNAL_MASK;
-    if internal_display == TABLE_ROW_GROUP {
-        kws.push(Keyword::TableRowGroup);
-    } else if internal_display == TABLE_HEADER_GROUP {
-        kws.push(Keyword::TableHeaderGroup);
-    } else if internal_display == TABLE_FOOTER_GROUP {
-        kws.push(Keyword::TableFooterGroup);
-    } else if internal_display == TABLE_ROW {
-        kws.push(Keyword::TableRow);
-    } else if internal_display == TABLE_CELL {
-        kws.push(Keyword::TableCell)
-    } else if internal_display == TABLE_COLUMN_GROUP {
-        kws.push(Keyword::TableColumnGroup)
-    } else if internal_display == TABLE_CAPTION {
-        kws.push(Keyword::TableCaption)
-    } else if internal_display == RUBY_BASE {
-        kws.push(Keyword::RubyBase)
-    } else if internal_display == RUBY_BASE_CONTAINER {
-        kws.push(Keyword::RubyBaseContainer)
-    } else if internal_display == RUBY_TEXT_CONTAINER {
-        kws.push(Keyword::RubyTextContainer)
+    if list_item {
+        kws.push(Keyword::ListItem);
     }
     kws
 }
 
-/// Correct the display flags.
-/// If a box display is set, remove all other flags.
-/// If an internal display, remove all other flags.
-fn correct_flags(mut flags: i32) -> i32 {
-    if flags == 0 {
-        flags = INLINE
-    }
-
-    if BOX_MASK & flags > 0 {
-        flags &= BOX_MASK;
-    }
-
-    if INTERNAL_MASK & flags > 0 {
-        flags &= INTERNAL_MASK;
-    }
-
-    // The inside values range from 001 to 110
-    // > 110 fallbacks to INLINE.
-    if INSIDE_MASK & flags > RUBY {
-        flags = INLINE;
-    }
-
-    // The internal values range from 0001 to 1001.
-    // So > 1001 fallbacks to INLINE.
-    if INTERNAL_MASK & flags > RUBY_TEXT_CONTAINER {
-        flags = INLINE;
-    }
-
-    // List item can also have inside display and outside display.
-    if LIST_ITEM & flags > 0 {
-        flags &= LIST_ITEM | INSIDE_MASK | OUTSIDE_MASK;
-
-        // > If no inner display type value is specified,
-        // >     the principal box’s inner display type defaults to *flow*.
-        // > If no outer display type value is specified, the
-        // >    principal box’s outer display type defaults to *block*.
-        // [CSS Display Module Level 3](https://drafts.csswg.org/css-display/#list-items)
-        if flags & INSIDE_MASK == 0 {
-            flags |= FLOW;
-        }
-
-        if flags & OUTSIDE_MASK == 0 {
-            flags |= BLOCK;
-        }
-    }
-
-    if BOX_MASK & flags == 0 || INTERNAL_MASK & flags == 0 {
-        if INSIDE_MASK & flags == 0 {
-            flags |= FLOW;
-        }
-
-        if OUTSIDE_MASK & flags == 0 {
-            flags |= INLINE;
-        }
-    }
-
-    flags
+/// Whether the flag is one of the values of the mask.
+fn is_within(flag: i32, mask: i32) -> bool {
+    flag != 0 && flag & !mask == 0
 }
 
-#[derive(Clone, Copy)]
+/// The display type of a box: its outer and inner display types and whether
+/// it is a list item, a layout-internal display type, or the absence of a
+/// box.
+///
+/// [CSS Display 3](https://drafts.csswg.org/css-display-3/#the-display-properties)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Display(i32);
 
 impl std::fmt::Display for Display {
@@ -212,15 +145,11 @@ impl std::fmt::Display for Display {
             .collect::<Vec<_>>()
             .join(" ");
 
-        write!(f, "display: {}", kws)
+        write!(f, "{}", kws)
     }
 }
 
 impl Display {
-    fn new_from_flags(flags: i32) -> Self {
-        Self(correct_flags(flags))
-    }
-
     #[inline]
     pub fn is_none(&self) -> bool {
         self.0 & BOX_MASK == NONE
@@ -230,6 +159,75 @@ impl Display {
     pub fn is_contents(&self) -> bool {
         self.0 & BOX_MASK == CONTENTS
     }
+
+    #[inline]
+    pub fn is_list_item(&self) -> bool {
+        self.0 & LIST_ITEM_MASK == LIST_ITEM
+    }
+
+    /// Whether it is a layout-internal display type, only meaningful inside a
+    /// table or a ruby container.
+    #[inline]
+    pub fn is_internal(&self) -> bool {
+        self.0 & INTERNAL_MASK != 0
+    }
+
+    /// Whether the box participates in a block formatting context.
+    #[inline]
+    pub fn is_block_level(&self) -> bool {
+        self.0 & OUTSIDE_MASK == BLOCK
+    }
+
+    /// Whether the box participates in an inline formatting context.
+    #[inline]
+    pub fn is_inline_level(&self) -> bool {
+        self.0 & OUTSIDE_MASK == INLINE
+    }
+
+    /// Whether it generates a flex container, whose children are flex items.
+    pub fn is_flex_container(&self) -> bool {
+        self.0 & INSIDE_MASK == FLEX
+    }
+
+    /// Whether it generates a grid container, whose children are grid items.
+    pub fn is_grid_container(&self) -> bool {
+        self.0 & INSIDE_MASK == GRID
+    }
+
+    /// Whether it generates an inline box, which can't contain blocks.
+    pub fn is_inline_flow(&self) -> bool {
+        self.0 == INLINE | FLOW
+    }
+
+    /// The block-level equivalent of the display, the layout-internal boxes
+    /// becoming block containers.
+    ///
+    /// [CSS Display 3](https://drafts.csswg.org/css-display-3/#blockify)
+    pub fn blockify(&self) -> Self {
+        if self.0 & BOX_MASK != 0 {
+            return *self;
+        }
+
+        match self.is_internal() {
+            true => Self(BLOCK | FLOW),
+            false => Self((self.0 & !OUTSIDE_MASK) | BLOCK),
+        }
+    }
+
+    /// The inline-level equivalent of the display, the block containers
+    /// staying block containers. The layout-internal boxes are unchanged.
+    ///
+    /// [CSS Display 3](https://drafts.csswg.org/css-display-3/#inlinify)
+    pub fn inlinify(&self) -> Self {
+        if self.0 & (BOX_MASK | INTERNAL_MASK) != 0 || self.is_inline_level() {
+            return *self;
+        }
+
+        match self.0 & INSIDE_MASK {
+            FLOW => Self((self.0 & !(OUTSIDE_MASK | INSIDE_MASK)) | INLINE | FLOW_ROOT),
+            _ => Self((self.0 & !OUTSIDE_MASK) | INLINE),
+        }
+    }
 }
 
 impl From<Display> for Value {
@@ -240,20 +238,70 @@ impl From<Display> for Value {
 
 impl Default for Display {
     fn default() -> Self {
-        Display(INLINE)
+        Display(INLINE | FLOW)
+    }
+}
+
+impl TryFrom<Value> for Display {
+    type Error = StyleError;
+
+    /// Parse the display types, the omitted outer one being block, or inline
+    /// for ruby, and the omitted inner one flow.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&[
+            "[ <display-outside> || <display-inside> ]",
+            "<display-listitem>",
+            "<display-internal>",
+            "<display-box>",
+            "<display-legacy>",
+        ]);
+        if let Value::List(_) = value {
+            return Err(invalid);
+        }
+        let kws = value
+            .into_iter()
+            .map(|value| match value {
+                Value::Keyword(kw) => Ok(kw),
+                _ => Err(invalid.clone()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let [kw] = kws.as_slice() {
+            if let Some((_, flag)) = STANDALONE_KWS.iter().find(|(keyword, _)| keyword == kw) {
+                return Ok(Self(*flag));
+            }
+        }
+
+        let (mut outside, mut inside, mut list_item) = (0, 0, 0);
+        for kw in &kws {
+            match from_kw(kw) {
+                flag if is_within(flag, OUTSIDE_MASK) && outside == 0 => outside = flag,
+                flag if is_within(flag, INSIDE_MASK) && inside == 0 => inside = flag,
+                LIST_ITEM if list_item == 0 => list_item = LIST_ITEM,
+                _ => return Err(invalid),
+            }
+        }
+
+        // A list item can only contain flow layout.
+        if list_item != 0 && !matches!(inside, 0 | FLOW | FLOW_ROOT) {
+            return Err(invalid);
+        }
+        if outside == 0 {
+            outside = if inside == RUBY { INLINE } else { BLOCK };
+        }
+        if inside == 0 {
+            inside = FLOW;
+        }
+
+        Ok(Self(outside | inside | list_item))
     }
 }
 
-impl From<Value> for Display {
-    fn from(value: Value) -> Self {
-        let flags = value
-            .iter_keywords()
-            .filter(Keyword::is_either_func(ALLOWED_KWS))
-            .map(from_kw)
-            .reduce(|a, b| a | b)
-            .unwrap_or(INLINE);
+impl ToComputedValue for Display {
+    type Computed = Self;
 
-        Self::new_from_flags(flags)
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
     }
 }
 
@@ -264,6 +312,16 @@ mod tests {
     use crate::style::property::display::{CONTENTS, FLEX, FLOW_ROOT, GRID, NONE, RUBY, TABLE};
     use crate::style::{Keyword, Value};
 
+    /// Parse a display value from its keywords.
+    fn display(source: &str) -> Result<Display, ()> {
+        source
+            .split_whitespace()
+            .map(|kw| Keyword::try_from(kw).map(Value::from))
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|kws| Display::try_from(Value::Array(kws)))
+            .map_err(|_| ())
+    }
+
     #[test]
     fn test_001_from_kw() {
         let mut flags = from_kw(&Keyword::None);
@@ -295,8 +353,89 @@ mod tests {
     fn test_002_display_from_value() {
         let value: Value = [Keyword::None].into_iter().collect();
 
-        let display = Display::from(value);
+        let display = Display::try_from(value).unwrap();
         assert!(display.is_none(), "{}", display);
     }
-}
 
+    #[test]
+    fn test_003_round_trip() {
+        for (source, expected) in [
+            ("block", "block"),
+            ("block flow", "block"),
+            ("flow", "block"),
+            ("inline", "inline"),
+            ("run-in", "run-in"),
+            ("flow-root", "flow-root"),
+            ("inline flow-root", "inline-block"),
+            ("inline-block", "inline-block"),
+            ("table", "table"),
+            ("inline table", "inline-table"),
+            ("flex block", "flex"),
+            ("inline-flex", "inline-flex"),
+            ("grid", "grid"),
+            ("inline grid", "inline-grid"),
+            ("ruby", "ruby"),
+            ("block ruby", "block ruby"),
+            ("run-in flex", "run-in flex"),
+            ("list-item", "list-item"),
+            ("list-item block flow", "list-item"),
+            ("inline list-item", "inline list-item"),
+            ("flow-root list-item", "flow-root list-item"),
+            ("list-item flow-root inline", "inline flow-root list-item"),
+            ("table-row", "table-row"),
+            ("table-cell", "table-cell"),
+            ("table-column", "table-column"),
+            ("ruby-text", "ruby-text"),
+            ("ruby-text-container", "ruby-text-container"),
+            ("contents", "contents"),
+        ] {
+            let display = display(source).unwrap();
+            assert_eq!(display.to_string(), expected, "{}", source);
+            assert_eq!(Display::try_from(Value::from(display)), Ok(display));
+        }
+
+        for invalid in [
+            "block inline",
+            "flex grid",
+            "list-item list-item",
+            "flex list-item",
+            "none block",
+            "table-row block",
+            "inline-block list-item",
+            "inherit",
+        ] {
+            assert!(display(invalid).is_err(), "{}", invalid);
+        }
+
+        assert_eq!(Display::default().to_string(), "inline");
+        assert_ne!(display("table-row"), display("table-cell"));
+    }
+
+    #[test]
+    fn test_004_blockify_inlinify() {
+        let blockify = |source: &str| display(source).unwrap().blockify().to_string();
+        let inlinify = |source: &str| display(source).unwrap().inlinify().to_string();
+
+        assert_eq!(blockify("inline"), "block");
+        assert_eq!(blockify("inline-flex"), "flex");
+        assert_eq!(blockify("inline-block"), "flow-root");
+        assert_eq!(blockify("ruby"), "block ruby");
+        assert_eq!(blockify("inline list-item"), "list-item");
+        assert_eq!(blockify("table-cell"), "block");
+        assert_eq!(blockify("none"), "none");
+
+        assert_eq!(inlinify("block"), "inline-block");
+        assert_eq!(inlinify("grid"), "inline-grid");
+        assert_eq!(inlinify("list-item"), "inline flow-root list-item");
+        assert_eq!(inlinify("inline"), "inline");
+        assert_eq!(inlinify("table-row"), "table-row");
+        assert_eq!(inlinify("contents"), "contents");
+
+        let flex = display("flex").unwrap();
+        assert!(flex.is_flex_container() && flex.is_block_level());
+        assert!(display("inline-grid").unwrap().is_grid_container());
+        assert!(display("inline").unwrap().is_inline_flow());
+        assert!(!display("inline-block").unwrap().is_inline_flow());
+        assert!(display("table-caption").unwrap().is_internal());
+    }
+}
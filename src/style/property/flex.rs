@@ -0,0 +1,252 @@
+//! The layout of the items of a flex container along its main axis.
+//!
+//! [CSS Flexbox 1](https://drafts.csswg.org/css-flexbox-1/)
+use crate::style::{
+    ComputeContext, Keyword, Number, Size, Style, StyleError, ToComputedValue, Value,
+};
+
+keyword_longhand!(
+    /// The direction of the main axis of a flex container.
+    FlexDirection {
+        Row,
+        RowReverse,
+        Column,
+        ColumnReverse,
+    },
+    &["row", "row-reverse", "column", "column-reverse"]
+);
+
+impl FlexDirection {
+    /// Whether the main axis is the block axis.
+    pub fn is_column(&self) -> bool {
+        matches!(self, Self::Column | Self::ColumnReverse)
+    }
+
+    /// Whether the items are laid out from the end of the main axis.
+    pub fn is_reverse(&self) -> bool {
+        matches!(self, Self::RowReverse | Self::ColumnReverse)
+    }
+}
+
+keyword_longhand!(
+    /// Whether the items of a flex container wrap onto several lines, and
+    /// the direction the lines are stacked in.
+    FlexWrap { Nowrap, Wrap, WrapReverse },
+    &["nowrap", "wrap", "wrap-reverse"]
+);
+
+/// Generate a flex factor longhand, a non-negative number.
+macro_rules! flex_factor {
+    ($(#[$meta:meta])* $name:ident = $initial:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name(pub f32);
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self($initial)
+            }
+        }
+
+        impl TryFrom<Value> for $name {
+            type Error = StyleError;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                match value {
+                    Value::Number(number) if f32::from(number) >= 0.0 => Ok(Self(number.into())),
+                    _ => Err(StyleError::InvalidValue(&["<number [0,∞]>"])),
+                }
+            }
+        }
+
+        impl From<$name> for Value {
+            fn from(value: $name) -> Self {
+                Self::from(value.0)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl ToComputedValue for $name {
+            type Computed = f32;
+
+            fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+                self.0
+            }
+        }
+    };
+}
+
+flex_factor!(
+    /// The share of the positive free space a flex item grows by.
+    FlexGrow = 0.0
+);
+flex_factor!(
+    /// The share of the negative free space a flex item shrinks by, scaled
+    /// by its flex basis.
+    FlexShrink = 1.0
+);
+
+/// The initial main size of a flex item, before the free space is
+/// distributed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FlexBasis {
+    /// The size of the content.
+    Content,
+    /// The main size of the item, auto using its width or height.
+    Size(Size),
+}
+
+impl Default for FlexBasis {
+    fn default() -> Self {
+        Self::Size(Size::Auto)
+    }
+}
+
+impl TryFrom<Value> for FlexBasis {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Content) => Ok(Self::Content),
+            value => Size::try_from(value)
+                .map(Self::Size)
+                .map_err(|_| StyleError::InvalidValue(&["content", "<'width'>"])),
+        }
+    }
+}
+
+impl From<FlexBasis> for Value {
+    fn from(value: FlexBasis) -> Self {
+        match value {
+            FlexBasis::Content => Self::Keyword(Keyword::Content),
+            FlexBasis::Size(size) => Self::from(size),
+        }
+    }
+}
+
+impl std::fmt::Display for FlexBasis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Content => write!(f, "content"),
+            Self::Size(size) => write!(f, "{}", size),
+        }
+    }
+}
+
+impl ToComputedValue for FlexBasis {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::Content => Self::Content,
+            Self::Size(size) => Self::Size(size.compute(context)),
+        }
+    }
+}
+
+/// The position of an item in the layout order of its container, which
+/// doesn't change its painting or speech order.
+///
+/// [CSS Display 4](https://drafts.csswg.org/css-display-4/#order-property)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Order(pub i32);
+
+impl TryFrom<Value> for Order {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(Number::Int(order)) => Ok(Self(order)),
+            _ => Err(StyleError::InvalidValue(&["<integer>"])),
+        }
+    }
+}
+
+impl From<Order> for Value {
+    fn from(value: Order) -> Self {
+        Self::Number(Number::Int(value.0))
+    }
+}
+
+impl std::fmt::Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ToComputedValue for Order {
+    type Computed = i32;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        self.0
+    }
+}
+
+impl Style {
+    /// Blockify the items of a flex or grid container.
+    ///
+    /// [CSS Flexbox 1](https://drafts.csswg.org/css-flexbox-1/#flex-items)
+    /// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#grid-item-display)
+    pub(crate) fn compute_item_display(&mut self, parent: Option<&Style>) {
+        if parent.is_some_and(|parent| {
+            parent.display.is_flex_container() || parent.display.is_grid_container()
+        }) {
+            self.display = self.display.blockify();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_flex_longhands() {
+        for (name, source) in [
+            ("flex-direction", "column-reverse"),
+            ("flex-wrap", "wrap-reverse"),
+            ("flex-grow", "2"),
+            ("flex-shrink", "0.5"),
+            ("flex-basis", "content"),
+            ("flex-basis", "10%"),
+            ("order", "-1"),
+        ] {
+            assert_eq!(round_trip(name, source), source);
+        }
+        for (name, invalid) in [
+            ("flex-direction", "column row"),
+            ("flex-grow", "-1"),
+            ("flex-shrink", "1px"),
+            ("flex-basis", "-1px"),
+            ("order", "1.5"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px; flex-basis: calc(1px - 1em); flex-grow: 2; order: 3; \
+                flex-wrap: wrap }",
+        );
+        let value = |index: usize, id| styles[index].value(id).to_string();
+
+        // The negative expressions are clamped once resolved, and the flex
+        // properties aren't inherited.
+        assert_eq!(value(0, PropertyId::FlexBasis), "0px");
+        assert_eq!(value(0, PropertyId::Order), "3");
+        assert_eq!(value(1, PropertyId::FlexBasis), "auto");
+        assert_eq!(value(1, PropertyId::FlexGrow), "0");
+        assert_eq!(value(1, PropertyId::FlexWrap), "nowrap");
+    }
+}
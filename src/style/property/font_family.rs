@@ -1,6 +1,8 @@
 use std::fmt::Display;
 
-use crate::style::{Keyword, Value};
+use crate::style::{Color, ComputeContext, CssWideKeyword, Keyword, StyleError, Value};
+
+use super::ToComputedValue;
 
 const ALLOWED_KWS: &[Keyword] = &[
     Keyword::Serif,
@@ -18,33 +20,78 @@ const ALLOWED_KWS: &[Keyword] = &[
     Keyword::Fangsong,
 ];
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FontFamily(Vec<Value>);
 
-impl From<Value> for FontFamily {
-    fn from(value: Value) -> FontFamily {
-        let families: Vec<Value> = value
-            .into_iter()
-            .filter(|v| v.is_either(ALLOWED_KWS) | v.is_string())
-            .collect();
+impl FontFamily {
+    /// The families, in order of preference: names or generic keywords.
+    pub fn iter(&self) -> impl Iterator<Item = &Value> {
+        self.0.iter()
+    }
+}
 
-        if families.is_empty() {
-            return Self::default();
-        }
+/// The identifier a value was parsed from, if any, a family name being made
+/// of identifiers which may also be keywords or named colors, those being
+/// lowercase as the names are matched ASCII case-insensitively.
+fn ident(value: &Value) -> Option<String> {
+    match value {
+        Value::CustomIdent(ident) => Some(ident.clone()),
+        Value::Keyword(_) if CssWideKeyword::try_from(value).is_ok() => None,
+        Value::Keyword(keyword) => Some(keyword.to_string()),
+        Value::Color(
+            color @ (Color::Named(_) | Color::System(_) | Color::CurrentColor | Color::Transparent),
+        ) => Some(color.to_string()),
+        _ => None,
+    }
+}
 
-        Self(families)
+/// Parse a family: a generic family, a quoted name, or an unquoted name made
+/// of identifiers, kept as a single identifier joined by spaces.
+///
+/// [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#family-name-syntax)
+fn parse_family(value: Value) -> Option<Value> {
+    if value.is_either(ALLOWED_KWS) || value.is_string() {
+        return Some(value);
+    }
+
+    let idents = value
+        .iter()
+        .map(ident)
+        .collect::<Option<Vec<_>>>()?
+        .join(" ");
+    Some(Value::CustomIdent(idents))
+}
+
+impl TryFrom<Value> for FontFamily {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let families = match value {
+            Value::List(families) => families,
+            family => vec![family],
+        };
+
+        families
+            .into_iter()
+            .map(parse_family)
+            .collect::<Option<Vec<_>>>()
+            .map(Self)
+            .ok_or(StyleError::InvalidValue(&[
+                "<family-name>",
+                "<generic-family>",
+            ]))
     }
 }
 
 impl From<FontFamily> for Value {
     fn from(value: FontFamily) -> Self {
-        value.0.into_iter().collect()
+        Self::List(value.0)
     }
 }
 
 impl Default for FontFamily {
     fn default() -> Self {
-        Self::from(Value::from(Keyword::SystemUi))
+        Self(vec![Value::from(Keyword::SystemUi)])
     }
 }
 
@@ -53,13 +100,52 @@ impl Display for FontFamily {
         let families = self
             .0
             .iter()
-            .cloned()
-            .map(Value::from)
             .map(|v| v.to_string())
             .collect::<Vec<_>>()
             .join(", ");
 
-        write!(f, "font-family: {}", families)
+        write!(f, "{}", families)
+    }
+}
+
+impl ToComputedValue for FontFamily {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        self.clone()
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, FontFamily, StyleError, Value};
+
+    fn parse(source: &str) -> Result<String, StyleError> {
+        Value::try_from(parse_component_values(source).as_slice())
+            .and_then(FontFamily::try_from)
+            .map(|family| family.to_string())
+    }
+
+    #[test]
+    fn test_001_family_names() {
+        assert_eq!(parse("Arial"), Ok("Arial".into()));
+        assert_eq!(parse("Arial, sans-serif"), Ok("Arial, sans-serif".into()));
+        assert_eq!(
+            parse("Times  New Roman, serif"),
+            Ok("Times New Roman, serif".into())
+        );
+        assert_eq!(
+            parse("\"Inter\", Red Hat Display, system-ui"),
+            Ok("\"Inter\", red Hat Display, system-ui".into())
+        );
+        // A generic family is only one when it is the whole name.
+        assert_eq!(
+            parse("Source Serif, serif"),
+            Ok("Source serif, serif".into())
+        );
+
+        for invalid in ["a, inherit", "initial", "Arial, 12px", "Arial,", "\"a\" b"] {
+            assert!(parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
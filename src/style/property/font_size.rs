@@ -0,0 +1,244 @@
+use crate::style::{
+    BaseType, Calc, CalcContext, CalcValue, ComputeContext, Keyword, Length, LengthContext, Number,
+    Percentage, StyleError, Unit, Value,
+};
+
+use super::ToComputedValue;
+
+/// The keywords of the absolute font sizes, scaling the medium one.
+///
+/// [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#absolute-size-mapping)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbsoluteSize {
+    XxSmall,
+    XSmall,
+    Small,
+    Medium,
+    Large,
+    XLarge,
+    XxLarge,
+    XxxLarge,
+}
+
+impl AbsoluteSize {
+    /// The factor applied to the medium font size.
+    pub fn scale(&self) -> f32 {
+        match self {
+            Self::XxSmall => 3.0 / 5.0,
+            Self::XSmall => 3.0 / 4.0,
+            Self::Small => 8.0 / 9.0,
+            Self::Medium => 1.0,
+            Self::Large => 6.0 / 5.0,
+            Self::XLarge => 3.0 / 2.0,
+            Self::XxLarge => 2.0,
+            Self::XxxLarge => 3.0,
+        }
+    }
+}
+
+impl TryFrom<Keyword> for AbsoluteSize {
+    type Error = StyleError;
+
+    fn try_from(value: Keyword) -> Result<Self, Self::Error> {
+        match value {
+            Keyword::XxSmall => Ok(Self::XxSmall),
+            Keyword::XSmall => Ok(Self::XSmall),
+            Keyword::Small => Ok(Self::Small),
+            Keyword::Medium => Ok(Self::Medium),
+            Keyword::Large => Ok(Self::Large),
+            Keyword::XLarge => Ok(Self::XLarge),
+            Keyword::XxLarge => Ok(Self::XxLarge),
+            Keyword::XxxLarge => Ok(Self::XxxLarge),
+            _ => Err(StyleError::InvalidValue(&["<absolute-size>"])),
+        }
+    }
+}
+
+impl From<AbsoluteSize> for Keyword {
+    fn from(value: AbsoluteSize) -> Self {
+        match value {
+            AbsoluteSize::XxSmall => Self::XxSmall,
+            AbsoluteSize::XSmall => Self::XSmall,
+            AbsoluteSize::Small => Self::Small,
+            AbsoluteSize::Medium => Self::Medium,
+            AbsoluteSize::Large => Self::Large,
+            AbsoluteSize::XLarge => Self::XLarge,
+            AbsoluteSize::XxLarge => Self::XxLarge,
+            AbsoluteSize::XxxLarge => Self::XxxLarge,
+        }
+    }
+}
+
+/// The keywords of the font sizes relative to the parent's one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeSize {
+    Larger,
+    Smaller,
+}
+
+impl RelativeSize {
+    /// The ratio between two consecutive sizes.
+    const RATIO: f32 = 1.2;
+}
+
+impl From<RelativeSize> for Keyword {
+    fn from(value: RelativeSize) -> Self {
+        match value {
+            RelativeSize::Larger => Self::Larger,
+            RelativeSize::Smaller => Self::Smaller,
+        }
+    }
+}
+
+/// The font-size property, computed into an absolute length.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSize {
+    Absolute(AbsoluteSize),
+    Relative(RelativeSize),
+    Length(Length),
+    /// A percentage of the font size of the parent element.
+    Percentage(Percentage),
+    /// A math expression resolving to a length.
+    Calc(Calc),
+}
+
+impl FontSize {
+    /// The initial font size, medium.
+    pub fn medium() -> Length {
+        Length::px(16)
+    }
+}
+
+impl Default for FontSize {
+    fn default() -> Self {
+        Self::Length(Self::medium())
+    }
+}
+
+impl TryFrom<Value> for FontSize {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Larger) => Ok(Self::Relative(RelativeSize::Larger)),
+            Value::Keyword(Keyword::Smaller) => Ok(Self::Relative(RelativeSize::Smaller)),
+            Value::Keyword(keyword) => {
+                AbsoluteSize::try_from(keyword)
+                    .map(Self::Absolute)
+                    .map_err(|_| {
+                        StyleError::InvalidValue(&[
+                            "<absolute-size>",
+                            "<relative-size>",
+                            "<length>",
+                            "<percentage>",
+                        ])
+                    })
+            }
+            Value::Length(length) if f32::from(length.quantity) >= 0.0 => Ok(Self::Length(length)),
+            Value::Percentage(percentage) if f32::from(percentage) >= 0.0 => {
+                Ok(Self::Percentage(percentage))
+            }
+            Value::Calc(calc) if calc.ty().matches(BaseType::Length, true) => Ok(Self::Calc(calc)),
+            _ => Err(StyleError::InvalidValue(&["<length>", "<percentage>"])),
+        }
+    }
+}
+
+impl From<FontSize> for Value {
+    fn from(value: FontSize) -> Self {
+        match value {
+            FontSize::Absolute(size) => Self::Keyword(size.into()),
+            FontSize::Relative(size) => Self::Keyword(size.into()),
+            FontSize::Length(length) => Self::Length(length),
+            FontSize::Percentage(percentage) => Self::Percentage(percentage),
+            FontSize::Calc(calc) => Self::Calc(calc),
+        }
+    }
+}
+
+impl std::fmt::Display for FontSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontSize::Absolute(size) => write!(f, "{}", Keyword::from(*size)),
+            FontSize::Relative(size) => write!(f, "{}", Keyword::from(*size)),
+            FontSize::Length(length) => write!(f, "{}", length),
+            FontSize::Percentage(percentage) => write!(f, "{}", percentage),
+            FontSize::Calc(calc) => write!(f, "{}", calc),
+        }
+    }
+}
+
+/// Scale a font size, keeping its unit.
+fn scale(font_size: &Length, factor: f32) -> Length {
+    Length {
+        quantity: Number::from(f32::from(font_size.quantity) * factor),
+        unit: font_size.unit,
+    }
+}
+
+impl ToComputedValue for FontSize {
+    type Computed = Length;
+
+    /// The em unit and percentages refer to the font size of the parent.
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        let parent = &context.parent_font_size;
+        let length_context = LengthContext {
+            font: context.parent_font,
+            ..context.length
+        };
+
+        match self {
+            Self::Absolute(size) => scale(&FontSize::medium(), size.scale()),
+            Self::Relative(RelativeSize::Larger) => scale(parent, RelativeSize::RATIO),
+            Self::Relative(RelativeSize::Smaller) => scale(parent, 1.0 / RelativeSize::RATIO),
+            Self::Length(length) => length.resolve_relative(&length_context),
+            Self::Percentage(percentage) => scale(parent, f32::from(*percentage)),
+            // Unresolvable expressions fall back to the parent's font size,
+            // and negative results are clamped to zero.
+            Self::Calc(calc) => {
+                let context = CalcContext {
+                    percentage_basis: Some(CalcValue::dimension(
+                        length_context.font.font_size,
+                        Unit::from(FontSize::medium().unit),
+                    )),
+                    length: Some(length_context),
+                };
+
+                calc.resolve_length(&context)
+                    .map(|length| Length::new(f32::from(length.quantity).max(0.0), length.unit))
+                    .unwrap_or_else(|_| parent.clone())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        parse_component_values, ComputeContext, FontSize, Length, StyleError, ToComputedValue,
+        Value,
+    };
+
+    fn parse(source: &str) -> Result<FontSize, StyleError> {
+        Value::try_from(parse_component_values(source).as_slice()).and_then(FontSize::try_from)
+    }
+
+    #[test]
+    fn test_001_negative_font_sizes() {
+        assert_eq!(parse("0px").map(|size| size.to_string()), Ok("0px".into()));
+        assert!(parse("-1px").is_err());
+        assert!(parse("-10%").is_err());
+
+        let context = ComputeContext::default();
+        let compute = |source: &str| parse(source).unwrap().compute(&context).to_string();
+
+        assert_eq!(compute("calc(10px - 2em)"), "0px");
+        assert_eq!(compute("calc(2em - 10px)"), "22px");
+        assert_eq!(compute("calc(NaN * 1px)"), "0px");
+        assert_eq!(compute("calc(-infinity * 1px)"), "0px");
+        assert_eq!(
+            parse("calc(infinity * 1px)").unwrap().compute(&context),
+            Length::px(f32::MAX)
+        );
+    }
+}
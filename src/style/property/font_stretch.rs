@@ -0,0 +1,140 @@
+use crate::style::{ComputeContext, Keyword, Percentage, StyleError, Value};
+
+use super::ToComputedValue;
+
+/// The width of the glyphs, computed into a percentage of the normal width.
+///
+/// [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#font-stretch-prop)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum FontStretch {
+    UltraCondensed,
+    ExtraCondensed,
+    Condensed,
+    SemiCondensed,
+    #[default]
+    Normal,
+    SemiExpanded,
+    Expanded,
+    ExtraExpanded,
+    UltraExpanded,
+    Percentage(Percentage),
+}
+
+impl FontStretch {
+    /// The width, as a fraction of the normal one.
+    pub fn to_fraction(&self) -> f32 {
+        match self {
+            Self::UltraCondensed => 0.5,
+            Self::ExtraCondensed => 0.625,
+            Self::Condensed => 0.75,
+            Self::SemiCondensed => 0.875,
+            Self::Normal => 1.0,
+            Self::SemiExpanded => 1.125,
+            Self::Expanded => 1.25,
+            Self::ExtraExpanded => 1.5,
+            Self::UltraExpanded => 2.0,
+            Self::Percentage(percentage) => f32::from(*percentage),
+        }
+    }
+
+    /// Parse one of the keywords, the only values of the font shorthand.
+    pub fn from_keyword(keyword: Keyword) -> Option<Self> {
+        match keyword {
+            Keyword::UltraCondensed => Some(Self::UltraCondensed),
+            Keyword::ExtraCondensed => Some(Self::ExtraCondensed),
+            Keyword::Condensed => Some(Self::Condensed),
+            Keyword::SemiCondensed => Some(Self::SemiCondensed),
+            Keyword::Normal => Some(Self::Normal),
+            Keyword::SemiExpanded => Some(Self::SemiExpanded),
+            Keyword::Expanded => Some(Self::Expanded),
+            Keyword::ExtraExpanded => Some(Self::ExtraExpanded),
+            Keyword::UltraExpanded => Some(Self::UltraExpanded),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<Value> for FontStretch {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(keyword) => Self::from_keyword(keyword),
+            Value::Percentage(percentage) if f32::from(percentage) >= 0.0 => {
+                Some(Self::Percentage(percentage))
+            }
+            _ => None,
+        }
+        .ok_or(StyleError::InvalidValue(&[
+            "normal",
+            "<percentage [0,∞]>",
+            "ultra-condensed",
+            "extra-condensed",
+            "condensed",
+            "semi-condensed",
+            "semi-expanded",
+            "expanded",
+            "extra-expanded",
+            "ultra-expanded",
+        ]))
+    }
+}
+
+impl From<FontStretch> for Value {
+    fn from(value: FontStretch) -> Self {
+        match value {
+            FontStretch::UltraCondensed => Self::Keyword(Keyword::UltraCondensed),
+            FontStretch::ExtraCondensed => Self::Keyword(Keyword::ExtraCondensed),
+            FontStretch::Condensed => Self::Keyword(Keyword::Condensed),
+            FontStretch::SemiCondensed => Self::Keyword(Keyword::SemiCondensed),
+            FontStretch::Normal => Self::Keyword(Keyword::Normal),
+            FontStretch::SemiExpanded => Self::Keyword(Keyword::SemiExpanded),
+            FontStretch::Expanded => Self::Keyword(Keyword::Expanded),
+            FontStretch::ExtraExpanded => Self::Keyword(Keyword::ExtraExpanded),
+            FontStretch::UltraExpanded => Self::Keyword(Keyword::UltraExpanded),
+            FontStretch::Percentage(percentage) => Self::Percentage(percentage),
+        }
+    }
+}
+
+impl std::fmt::Display for FontStretch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(*self))
+    }
+}
+
+impl ToComputedValue for FontStretch {
+    type Computed = Percentage;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        Percentage::try_from(self.to_fraction()).expect("the widths are finite")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::testing::{round_trip, TestDocument};
+
+    #[test]
+    fn test_001_font_stretches() {
+        for (source, expected) in [
+            ("CONDENSED", "condensed"),
+            ("normal", "normal"),
+            ("50%", "50%"),
+            ("1000%", "1000%"),
+        ] {
+            assert_eq!(round_trip("font-stretch", source), expected, "{}", source);
+        }
+        for invalid in ["-1%", "50", "narrower"] {
+            assert_eq!(round_trip("font-stretch", invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        // The keywords are computed into percentages, which are inherited.
+        let styles = doc.resolve("html { font-stretch: ultra-expanded }");
+        assert_eq!(styles[1].font_stretch.to_string(), "200%");
+    }
+}
@@ -0,0 +1,115 @@
+use crate::style::{Angle, ComputeContext, Keyword, StyleError, Value};
+
+use super::ToComputedValue;
+
+/// Whether the glyphs are italic or slanted.
+///
+/// [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#font-style-prop)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum FontStyle {
+    #[default]
+    Normal,
+    Italic,
+    /// The glyphs slanted by an angle between -90deg and 90deg.
+    Oblique(Angle),
+}
+
+impl FontStyle {
+    /// The angle of `oblique`, when omitted.
+    pub fn default_oblique_angle() -> Angle {
+        Angle::deg(14)
+    }
+}
+
+impl TryFrom<Value> for FontStyle {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid =
+            StyleError::InvalidValue(&["normal", "italic", "oblique <angle [-90deg,90deg]>?"]);
+
+        match value {
+            Value::Keyword(Keyword::Normal) => Ok(Self::Normal),
+            Value::Keyword(Keyword::Italic) => Ok(Self::Italic),
+            Value::Keyword(Keyword::Oblique) => Ok(Self::Oblique(Self::default_oblique_angle())),
+            Value::Array(values) => match values.as_slice() {
+                [Value::Keyword(Keyword::Oblique), angle] => {
+                    let angle = Angle::try_from(angle.clone()).map_err(|_| invalid.clone())?;
+                    match (-90.0..=90.0).contains(&angle.to_deg()) {
+                        true => Ok(Self::Oblique(angle)),
+                        false => Err(invalid),
+                    }
+                }
+                _ => Err(invalid),
+            },
+            _ => Err(invalid),
+        }
+    }
+}
+
+impl From<FontStyle> for Value {
+    fn from(value: FontStyle) -> Self {
+        match value {
+            FontStyle::Normal => Self::Keyword(Keyword::Normal),
+            FontStyle::Italic => Self::Keyword(Keyword::Italic),
+            FontStyle::Oblique(angle) if angle == FontStyle::default_oblique_angle() => {
+                Self::Keyword(Keyword::Oblique)
+            }
+            FontStyle::Oblique(angle) => {
+                Self::Array(vec![Self::Keyword(Keyword::Oblique), Self::Angle(angle)])
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for FontStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "normal"),
+            Self::Italic => write!(f, "italic"),
+            Self::Oblique(angle) if *angle == Self::default_oblique_angle() => write!(f, "oblique"),
+            Self::Oblique(angle) => write!(f, "oblique {}", angle),
+        }
+    }
+}
+
+impl ToComputedValue for FontStyle {
+    type Computed = Self;
+
+    /// The angle is computed in degrees.
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::Oblique(angle) => Self::Oblique(Angle::deg(angle.to_deg())),
+            other => *other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::testing::{round_trip, TestDocument};
+
+    #[test]
+    fn test_001_font_styles() {
+        for (source, expected) in [
+            ("ITALIC", "italic"),
+            ("oblique", "oblique"),
+            ("oblique 10deg", "oblique 10deg"),
+            ("oblique -90deg", "oblique -90deg"),
+            // The default angle is omitted.
+            ("oblique 14deg", "oblique"),
+        ] {
+            assert_eq!(round_trip("font-style", source), expected, "{}", source);
+        }
+        for invalid in ["oblique 91deg", "italic 10deg", "oblique 10px"] {
+            assert_eq!(round_trip("font-style", invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve("html { font-style: oblique 0.25turn }");
+        assert_eq!(styles[1].font_style.to_string(), "oblique 90deg");
+    }
+}
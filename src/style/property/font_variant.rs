@@ -0,0 +1,411 @@
+//! The alternate glyphs of a font.
+//!
+//! [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#font-rend-props)
+use crate::style::{ComputeContext, Keyword, StyleError, Value};
+
+use super::ToComputedValue;
+
+keyword_longhand!(
+    /// The capital letters glyphs.
+    FontVariantCaps {
+        Normal,
+        SmallCaps,
+        AllSmallCaps,
+        PetiteCaps,
+        AllPetiteCaps,
+        Unicase,
+        TitlingCaps,
+    },
+    &[
+        "normal",
+        "small-caps",
+        "all-small-caps",
+        "petite-caps",
+        "all-petite-caps",
+        "unicase",
+        "titling-caps",
+    ]
+);
+keyword_longhand!(
+    /// The subscript and superscript glyphs.
+    FontVariantPosition { Normal, Sub, Super },
+    &["normal", "sub", "super"]
+);
+keyword_longhand!(
+    /// The alternate glyphs, of which only the historical forms are supported.
+    FontVariantAlternates { Normal, HistoricalForms },
+    &["normal", "historical-forms"]
+);
+keyword_longhand!(
+    /// The presentation of the emoji.
+    FontVariantEmoji { Normal, Text, Emoji, Unicode },
+    &["normal", "text", "emoji", "unicode"]
+);
+
+/// The keywords of a value, which is a keyword or an array of keywords.
+fn keywords(value: &Value) -> Option<Vec<Keyword>> {
+    value
+        .iter()
+        .map(|value| match value {
+            Value::Keyword(keyword) => Some(*keyword),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The value of the keywords set, normal if none is.
+fn keywords_value(keywords: Vec<Keyword>) -> Value {
+    match keywords.as_slice() {
+        [] => Value::Keyword(Keyword::Normal),
+        [keyword] => Value::Keyword(*keyword),
+        _ => Value::Array(keywords.into_iter().map(Value::Keyword).collect()),
+    }
+}
+
+/// Set an optional value of a group of keywords, failing if it is already set.
+fn set<T>(field: &mut Option<T>, value: T) -> bool {
+    field.replace(value).is_none()
+}
+
+/// The ligatures and contextual forms, each enabled, disabled or left to
+/// the font.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FontVariantLigatures {
+    /// All the ligatures are disabled.
+    pub none: bool,
+    pub common: Option<bool>,
+    pub discretionary: Option<bool>,
+    pub historical: Option<bool>,
+    pub contextual: Option<bool>,
+}
+
+impl FontVariantLigatures {
+    /// Add a keyword to the values, failing if it isn't one of them or if
+    /// its group is already set.
+    pub fn add(&mut self, keyword: Keyword) -> bool {
+        match keyword {
+            Keyword::CommonLigatures => set(&mut self.common, true),
+            Keyword::NoCommonLigatures => set(&mut self.common, false),
+            Keyword::DiscretionaryLigatures => set(&mut self.discretionary, true),
+            Keyword::NoDiscretionaryLigatures => set(&mut self.discretionary, false),
+            Keyword::HistoricalLigatures => set(&mut self.historical, true),
+            Keyword::NoHistoricalLigatures => set(&mut self.historical, false),
+            Keyword::Contextual => set(&mut self.contextual, true),
+            Keyword::NoContextual => set(&mut self.contextual, false),
+            _ => false,
+        }
+    }
+
+    fn keywords(&self) -> Vec<Keyword> {
+        let groups = [
+            (
+                self.common,
+                Keyword::CommonLigatures,
+                Keyword::NoCommonLigatures,
+            ),
+            (
+                self.discretionary,
+                Keyword::DiscretionaryLigatures,
+                Keyword::NoDiscretionaryLigatures,
+            ),
+            (
+                self.historical,
+                Keyword::HistoricalLigatures,
+                Keyword::NoHistoricalLigatures,
+            ),
+            (self.contextual, Keyword::Contextual, Keyword::NoContextual),
+        ];
+
+        groups
+            .into_iter()
+            .filter_map(|(enabled, on, off)| enabled.map(|enabled| if enabled { on } else { off }))
+            .collect()
+    }
+}
+
+impl TryFrom<Value> for FontVariantLigatures {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&[
+            "normal",
+            "none",
+            "<common-lig-values>",
+            "<discretionary-lig-values>",
+            "<historical-lig-values>",
+            "<contextual-alt-values>",
+        ]);
+
+        match keywords(&value).ok_or(invalid.clone())?.as_slice() {
+            [Keyword::Normal] => Ok(Self::default()),
+            [Keyword::None] => Ok(Self {
+                none: true,
+                ..Self::default()
+            }),
+            keywords => {
+                let mut ligatures = Self::default();
+                match keywords.iter().all(|keyword| ligatures.add(*keyword)) {
+                    true => Ok(ligatures),
+                    false => Err(invalid),
+                }
+            }
+        }
+    }
+}
+
+impl From<FontVariantLigatures> for Value {
+    fn from(value: FontVariantLigatures) -> Self {
+        let keywords = match value.none {
+            true => vec![Keyword::None],
+            false => value.keywords(),
+        };
+
+        keywords_value(keywords)
+    }
+}
+
+impl std::fmt::Display for FontVariantLigatures {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(*self))
+    }
+}
+
+impl ToComputedValue for FontVariantLigatures {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+/// The glyphs of the numbers, fractions and ordinal markers, the keywords
+/// of each group being kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FontVariantNumeric {
+    /// Lining or old-style figures.
+    pub figure: Option<Keyword>,
+    /// Proportional or tabular figures.
+    pub spacing: Option<Keyword>,
+    /// Diagonal or stacked fractions.
+    pub fraction: Option<Keyword>,
+    pub ordinal: bool,
+    pub slashed_zero: bool,
+}
+
+impl FontVariantNumeric {
+    /// Add a keyword to the values, failing if it isn't one of them or if
+    /// its group is already set.
+    pub fn add(&mut self, keyword: Keyword) -> bool {
+        match keyword {
+            Keyword::LiningNums | Keyword::OldstyleNums => set(&mut self.figure, keyword),
+            Keyword::ProportionalNums | Keyword::TabularNums => set(&mut self.spacing, keyword),
+            Keyword::DiagonalFractions | Keyword::StackedFractions => {
+                set(&mut self.fraction, keyword)
+            }
+            Keyword::Ordinal => !std::mem::replace(&mut self.ordinal, true),
+            Keyword::SlashedZero => !std::mem::replace(&mut self.slashed_zero, true),
+            _ => false,
+        }
+    }
+
+    fn keywords(&self) -> Vec<Keyword> {
+        let mut keywords = [self.figure, self.spacing, self.fraction]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if self.ordinal {
+            keywords.push(Keyword::Ordinal);
+        }
+        if self.slashed_zero {
+            keywords.push(Keyword::SlashedZero);
+        }
+        keywords
+    }
+}
+
+impl TryFrom<Value> for FontVariantNumeric {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&[
+            "normal",
+            "<numeric-figure-values>",
+            "<numeric-spacing-values>",
+            "<numeric-fraction-values>",
+            "ordinal",
+            "slashed-zero",
+        ]);
+
+        match keywords(&value).ok_or(invalid.clone())?.as_slice() {
+            [Keyword::Normal] => Ok(Self::default()),
+            keywords => {
+                let mut numeric = Self::default();
+                match keywords.iter().all(|keyword| numeric.add(*keyword)) {
+                    true => Ok(numeric),
+                    false => Err(invalid),
+                }
+            }
+        }
+    }
+}
+
+impl From<FontVariantNumeric> for Value {
+    fn from(value: FontVariantNumeric) -> Self {
+        keywords_value(value.keywords())
+    }
+}
+
+impl std::fmt::Display for FontVariantNumeric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(*self))
+    }
+}
+
+impl ToComputedValue for FontVariantNumeric {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+/// The glyphs of the East Asian scripts, the keywords of each group being kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FontVariantEastAsian {
+    /// The JIS standard, or the simplified or traditional forms.
+    pub variant: Option<Keyword>,
+    /// Full-width or proportional-width glyphs.
+    pub width: Option<Keyword>,
+    pub ruby: bool,
+}
+
+impl FontVariantEastAsian {
+    /// Add a keyword to the values, failing if it isn't one of them or if
+    /// its group is already set.
+    pub fn add(&mut self, keyword: Keyword) -> bool {
+        match keyword {
+            Keyword::Jis78
+            | Keyword::Jis83
+            | Keyword::Jis90
+            | Keyword::Jis04
+            | Keyword::Simplified
+            | Keyword::Traditional => set(&mut self.variant, keyword),
+            Keyword::FullWidth | Keyword::ProportionalWidth => set(&mut self.width, keyword),
+            Keyword::Ruby => !std::mem::replace(&mut self.ruby, true),
+            _ => false,
+        }
+    }
+
+    fn keywords(&self) -> Vec<Keyword> {
+        let mut keywords = [self.variant, self.width]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if self.ruby {
+            keywords.push(Keyword::Ruby);
+        }
+        keywords
+    }
+}
+
+impl TryFrom<Value> for FontVariantEastAsian {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&[
+            "normal",
+            "<east-asian-variant-values>",
+            "<east-asian-width-values>",
+            "ruby",
+        ]);
+
+        match keywords(&value).ok_or(invalid.clone())?.as_slice() {
+            [Keyword::Normal] => Ok(Self::default()),
+            keywords => {
+                let mut east_asian = Self::default();
+                match keywords.iter().all(|keyword| east_asian.add(*keyword)) {
+                    true => Ok(east_asian),
+                    false => Err(invalid),
+                }
+            }
+        }
+    }
+}
+
+impl From<FontVariantEastAsian> for Value {
+    fn from(value: FontVariantEastAsian) -> Self {
+        keywords_value(value.keywords())
+    }
+}
+
+impl std::fmt::Display for FontVariantEastAsian {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(*self))
+    }
+}
+
+impl ToComputedValue for FontVariantEastAsian {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_font_variants() {
+        for (name, source, expected) in [
+            ("font-variant-caps", "ALL-PETITE-CAPS", "all-petite-caps"),
+            (
+                "font-variant-numeric",
+                "oldstyle-nums tabular-nums",
+                "oldstyle-nums tabular-nums",
+            ),
+            ("font-variant-ligatures", "none", "none"),
+            (
+                "font-variant-east-asian",
+                "jis78 full-width",
+                "jis78 full-width",
+            ),
+            ("font-variant-position", "sub", "sub"),
+            ("font-variant", "normal", "normal"),
+            ("font-variant", "none", "none"),
+            (
+                "font-variant",
+                "small-caps slashed-zero",
+                "small-caps slashed-zero",
+            ),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("font-variant-caps", "smallcaps"),
+            ("font-variant-numeric", "oldstyle-nums lining-nums"),
+            (
+                "font-variant-ligatures",
+                "common-ligatures no-common-ligatures",
+            ),
+            ("font-variant-east-asian", "jis78 jis83"),
+            ("font-variant", "none small-caps"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve("html { font-variant: small-caps ordinal }");
+        let variant = |id| styles[1].value(id).to_string();
+        assert_eq!(variant(PropertyId::FontVariantCaps), "small-caps");
+        assert_eq!(variant(PropertyId::FontVariantNumeric), "ordinal");
+        assert_eq!(variant(PropertyId::FontVariantLigatures), "normal");
+    }
+}
@@ -0,0 +1,133 @@
+use crate::style::{ComputeContext, Keyword, StyleError, Value};
+
+use super::ToComputedValue;
+
+/// The weight of the glyphs, computed into a number between 1 and 1000.
+///
+/// [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#font-weight-prop)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum FontWeight {
+    #[default]
+    Normal,
+    Bold,
+    Number(f32),
+    /// A weight bolder than the parent's one.
+    Bolder,
+    /// A weight lighter than the parent's one.
+    Lighter,
+}
+
+impl FontWeight {
+    pub const NORMAL: f32 = 400.0;
+    pub const BOLD: f32 = 700.0;
+
+    /// The weight relative to the parent's one.
+    ///
+    /// [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#relative-weights)
+    fn relative(&self, parent: f32) -> f32 {
+        match self {
+            Self::Normal => Self::NORMAL,
+            Self::Bold => Self::BOLD,
+            Self::Number(weight) => *weight,
+            Self::Bolder if parent < 350.0 => 400.0,
+            Self::Bolder if parent < 550.0 => 700.0,
+            Self::Bolder => parent.max(900.0),
+            Self::Lighter if parent < 100.0 => parent,
+            Self::Lighter if parent < 550.0 => 100.0,
+            Self::Lighter if parent < 750.0 => 400.0,
+            Self::Lighter => 700.0,
+        }
+    }
+}
+
+impl TryFrom<Value> for FontWeight {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Normal) => Ok(Self::Normal),
+            Value::Keyword(Keyword::Bold) => Ok(Self::Bold),
+            Value::Keyword(Keyword::Bolder) => Ok(Self::Bolder),
+            Value::Keyword(Keyword::Lighter) => Ok(Self::Lighter),
+            Value::Number(weight) if (1.0..=1000.0).contains(&f32::from(weight)) => {
+                Ok(Self::Number(weight.into()))
+            }
+            _ => Err(StyleError::InvalidValue(&[
+                "normal",
+                "bold",
+                "bolder",
+                "lighter",
+                "<number [1,1000]>",
+            ])),
+        }
+    }
+}
+
+impl From<FontWeight> for Value {
+    fn from(value: FontWeight) -> Self {
+        match value {
+            FontWeight::Normal => Self::Keyword(Keyword::Normal),
+            FontWeight::Bold => Self::Keyword(Keyword::Bold),
+            FontWeight::Number(weight) => Self::from(weight),
+            FontWeight::Bolder => Self::Keyword(Keyword::Bolder),
+            FontWeight::Lighter => Self::Keyword(Keyword::Lighter),
+        }
+    }
+}
+
+impl std::fmt::Display for FontWeight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "normal"),
+            Self::Bold => write!(f, "bold"),
+            Self::Number(weight) => write!(f, "{}", weight),
+            Self::Bolder => write!(f, "bolder"),
+            Self::Lighter => write!(f, "lighter"),
+        }
+    }
+}
+
+impl ToComputedValue for FontWeight {
+    type Computed = f32;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        self.relative(context.parent_font_weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::testing::{round_trip, TestDocument};
+
+    #[test]
+    fn test_001_font_weights() {
+        for (source, expected) in [
+            ("BOLD", "bold"),
+            ("1", "1"),
+            ("1000", "1000"),
+            ("bolder", "bolder"),
+        ] {
+            assert_eq!(round_trip("font-weight", source), expected, "{}", source);
+        }
+        for invalid in ["0", "1001", "0.5", "400px", "heavy"] {
+            assert_eq!(round_trip("font-weight", invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let p = doc.create(Some(body), "p", &[]);
+        doc.create(Some(p), "b", &[]);
+
+        let styles = doc.resolve(
+            "html { font-weight: 950 } body { font-weight: lighter } b { font-weight: bolder }",
+        );
+        let weight = |index: usize| styles[index].font_weight.to_string();
+
+        // The relative weights are computed from the inherited one.
+        assert_eq!(weight(0), "950");
+        assert_eq!(weight(1), "700");
+        assert_eq!(weight(2), "700");
+        assert_eq!(weight(3), "900");
+    }
+}
@@ -0,0 +1,225 @@
+use std::ops::Range;
+
+use crate::style::{ComputeContext, Keyword, StyleError, ToComputedValue, Value};
+
+/// A named area of a grid template, the cells it spans being given by the
+/// zero-based ranges of its rows and columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedArea {
+    pub name: String,
+    pub rows: Range<usize>,
+    pub columns: Range<usize>,
+}
+
+/// The cells of a grid template, each of them being named or null, and the
+/// rectangular areas formed by the cells sharing a name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateAreas {
+    cells: Vec<Vec<Option<String>>>,
+    areas: Vec<NamedArea>,
+}
+
+impl TemplateAreas {
+    pub fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.cells[0].len()
+    }
+
+    pub fn areas(&self) -> &[NamedArea] {
+        &self.areas
+    }
+
+    pub fn area(&self, name: &str) -> Option<&NamedArea> {
+        self.areas.iter().find(|area| area.name == name)
+    }
+
+    /// The rows, as they are written in the template.
+    pub fn row_strings(&self) -> impl Iterator<Item = String> + '_ {
+        self.cells.iter().map(|row| {
+            row.iter()
+                .map(|cell| cell.as_deref().unwrap_or("."))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    }
+}
+
+/// Tokenize a row of a grid template into named and null cells.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#valdef-grid-template-areas-string)
+fn tokenize_row(row: &str) -> Result<Vec<Option<String>>, StyleError> {
+    let is_name = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii();
+    let mut cells = Vec::default();
+    let mut chars = row.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_ascii_whitespace() {
+            continue;
+        }
+        if c == '.' {
+            while chars.next_if_eq(&'.').is_some() {}
+            cells.push(None);
+        } else if is_name(c) {
+            let mut name = c.to_string();
+            while let Some(c) = chars.next_if(|c| is_name(*c)) {
+                name.push(c);
+            }
+            cells.push(Some(name));
+        } else {
+            return Err(StyleError::InvalidValue(&["<string>+"]));
+        }
+    }
+
+    Ok(cells)
+}
+
+impl TryFrom<&[String]> for TemplateAreas {
+    type Error = StyleError;
+
+    /// Parse the rows of a grid template, which must all have the same number
+    /// of cells, each name forming a single rectangle.
+    fn try_from(rows: &[String]) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<string>+"]);
+        let cells = rows
+            .iter()
+            .map(|row| tokenize_row(row))
+            .collect::<Result<Vec<_>, _>>()?;
+        let columns = cells.first().map_or(0, Vec::len);
+        if columns == 0 || cells.iter().any(|row| row.len() != columns) {
+            return Err(invalid);
+        }
+
+        let mut areas = Vec::<NamedArea>::default();
+        for (row, cell) in cells.iter().enumerate() {
+            for (column, name) in cell.iter().enumerate() {
+                let Some(name) = name else { continue };
+                match areas.iter_mut().find(|area| area.name == *name) {
+                    Some(area) => {
+                        area.rows.end = area.rows.end.max(row + 1);
+                        area.columns.start = area.columns.start.min(column);
+                        area.columns.end = area.columns.end.max(column + 1);
+                    }
+                    None => areas.push(NamedArea {
+                        name: name.clone(),
+                        rows: row..row + 1,
+                        columns: column..column + 1,
+                    }),
+                }
+            }
+        }
+
+        let is_rectangle = |area: &NamedArea| {
+            let count = cells
+                .iter()
+                .flatten()
+                .filter(|cell| cell.as_ref() == Some(&area.name))
+                .count();
+            let filled = cells[area.rows.clone()].iter().all(|row| {
+                row[area.columns.clone()]
+                    .iter()
+                    .all(|cell| cell.as_ref() == Some(&area.name))
+            });
+            filled && count == area.rows.len() * area.columns.len()
+        };
+        match areas.iter().all(is_rectangle) {
+            true => Ok(Self { cells, areas }),
+            false => Err(invalid),
+        }
+    }
+}
+
+/// The named areas of a grid container.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#grid-template-areas-property)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum GridTemplateAreas {
+    #[default]
+    None,
+    Areas(TemplateAreas),
+}
+
+impl TryFrom<Value> for GridTemplateAreas {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        if value == Value::Keyword(Keyword::None) {
+            return Ok(Self::None);
+        }
+
+        let rows = match value {
+            Value::String(row) => vec![row],
+            Value::Array(rows) => rows
+                .into_iter()
+                .map(|row| match row {
+                    Value::String(row) => Ok(row),
+                    _ => Err(StyleError::InvalidValue(&["none", "<string>+"])),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Err(StyleError::InvalidValue(&["none", "<string>+"])),
+        };
+
+        TemplateAreas::try_from(rows.as_slice()).map(Self::Areas)
+    }
+}
+
+impl From<GridTemplateAreas> for Value {
+    fn from(value: GridTemplateAreas) -> Self {
+        match value {
+            GridTemplateAreas::None => Self::Keyword(Keyword::None),
+            GridTemplateAreas::Areas(areas) => areas.row_strings().collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for GridTemplateAreas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+impl ToComputedValue for GridTemplateAreas {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_template_areas() {
+        for source in ["none", "\"a b\" \"c d\"", "\"a a\" \"b b\"", "\". a\""] {
+            assert_eq!(round_trip("grid-template-areas", source), source);
+        }
+        for invalid in ["\"a b\" \"b a\"", "\"a\" \"b c\"", "\"\"", "a"] {
+            assert_eq!(
+                round_trip("grid-template-areas", invalid),
+                "",
+                "{}",
+                invalid
+            );
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve("html { grid-template-areas: \"a  b\"  \"a c\" }");
+        let areas = |index: usize| {
+            styles[index]
+                .value(PropertyId::GridTemplateAreas)
+                .to_string()
+        };
+        assert_eq!(areas(0), "\"a b\" \"a c\"");
+        assert_eq!(areas(1), "none");
+    }
+}
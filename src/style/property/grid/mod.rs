@@ -0,0 +1,11 @@
+//! The grid layout: the tracks and areas of a grid container, and the
+//! placement of its items.
+//!
+//! [CSS Grid 2](https://drafts.csswg.org/css-grid-2/)
+mod areas;
+mod placement;
+mod track;
+
+pub use areas::*;
+pub use placement::*;
+pub use track::*;
@@ -0,0 +1,222 @@
+use crate::style::{
+    is_line_name, ComputeContext, Keyword, Number, StyleError, ToComputedValue, Value,
+};
+
+/// The name of a grid line or area, from an identifier which may also be a
+/// keyword.
+fn line_name(value: &Value) -> Option<String> {
+    let name = match value {
+        Value::CustomIdent(name) => name.clone(),
+        Value::Keyword(keyword) => keyword.to_string(),
+        _ => return None,
+    };
+
+    is_line_name(&name).then_some(name)
+}
+
+/// A line bounding a grid item, or the span of the item from its other line.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#line-placement)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum GridLine {
+    /// Placed automatically, with a span of one.
+    #[default]
+    Auto,
+    /// The line of an area of the name, or else the first line of the name.
+    Ident(String),
+    /// The nth line, counted from the end if negative, among the lines of the
+    /// name if any.
+    Line(i32, Option<String>),
+    /// A span of n lines, or lines of the name, from the other line.
+    Span(u32, Option<String>),
+}
+
+impl GridLine {
+    /// The line to use for an omitted end line: a name stands for the lines
+    /// of an area, while other lines leave the end automatic.
+    pub fn omitted_end(&self) -> Self {
+        match self {
+            Self::Ident(name) => Self::Ident(name.clone()),
+            _ => Self::Auto,
+        }
+    }
+}
+
+impl TryFrom<Value> for GridLine {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&[
+            "auto",
+            "<custom-ident>",
+            "<integer> && <custom-ident>?",
+            "span && [ <integer [1,∞]> || <custom-ident> ]",
+        ]);
+        if let Value::List(_) = value {
+            return Err(invalid);
+        }
+
+        let (mut span, mut integer, mut name) = (false, None, None);
+        let values = value.into_iter().collect::<Vec<_>>();
+        if let [Value::Keyword(Keyword::Auto)] = values.as_slice() {
+            return Ok(Self::Auto);
+        }
+        for value in &values {
+            match value {
+                Value::Keyword(Keyword::Span) if !span => span = true,
+                Value::Number(Number::Int(n)) if integer.is_none() => integer = Some(*n),
+                value if name.is_none() => name = Some(line_name(value).ok_or(invalid.clone())?),
+                _ => return Err(invalid),
+            }
+        }
+        // The keyword of a span can't be between its integer and its name.
+        if span && values.len() == 3 && values[1] == Value::Keyword(Keyword::Span) {
+            return Err(invalid);
+        }
+
+        match (span, integer, name) {
+            (false, None, Some(name)) => Ok(Self::Ident(name)),
+            (false, Some(n), name) if n != 0 => Ok(Self::Line(n, name)),
+            (true, Some(n), name) if n > 0 => Ok(Self::Span(n as u32, name)),
+            (true, None, Some(name)) => Ok(Self::Span(1, Some(name))),
+            _ => Err(invalid),
+        }
+    }
+}
+
+impl From<GridLine> for Value {
+    fn from(value: GridLine) -> Self {
+        match value {
+            GridLine::Auto => Self::Keyword(Keyword::Auto),
+            GridLine::Ident(name) => Self::CustomIdent(name),
+            GridLine::Line(n, name) => std::iter::once(Self::from(n))
+                .chain(name.map(Self::CustomIdent))
+                .collect(),
+            GridLine::Span(n, name) => std::iter::once(Self::Keyword(Keyword::Span))
+                .chain((n != 1 || name.is_none()).then(|| Self::from(n as i32)))
+                .chain(name.map(Self::CustomIdent))
+                .collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for GridLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+impl ToComputedValue for GridLine {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        self.clone()
+    }
+}
+
+longhand_newtypes!(GridLine: GridRowStart, GridRowEnd, GridColumnStart, GridColumnEnd);
+
+/// How the auto-placed items of a grid container flow into it.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#grid-auto-flow-property)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GridAutoFlow {
+    /// Whether the items fill each column in turn, rather than each row.
+    pub column: bool,
+    /// Whether the items fill the earlier holes left in the grid, even when
+    /// they are then out of order.
+    pub dense: bool,
+}
+
+impl TryFrom<Value> for GridAutoFlow {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["[ row | column ] || dense"]);
+        if let Value::List(_) = value {
+            return Err(invalid);
+        }
+
+        let (mut axis, mut dense) = (None, false);
+        for value in value {
+            match value {
+                Value::Keyword(Keyword::Row) if axis.is_none() => axis = Some(false),
+                Value::Keyword(Keyword::Column) if axis.is_none() => axis = Some(true),
+                Value::Keyword(Keyword::Dense) if !dense => dense = true,
+                _ => return Err(invalid),
+            }
+        }
+
+        Ok(Self {
+            column: axis.unwrap_or_default(),
+            dense,
+        })
+    }
+}
+
+impl From<GridAutoFlow> for Value {
+    fn from(value: GridAutoFlow) -> Self {
+        match (value.column, value.dense) {
+            (false, false) => Self::Keyword(Keyword::Row),
+            (false, true) => Self::Keyword(Keyword::Dense),
+            (true, false) => Self::Keyword(Keyword::Column),
+            (true, true) => [Keyword::Column, Keyword::Dense].into_iter().collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for GridAutoFlow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(*self))
+    }
+}
+
+impl ToComputedValue for GridAutoFlow {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_placement() {
+        for (name, source, expected) in [
+            ("grid-row-start", "auto", "auto"),
+            ("grid-row-start", "2", "2"),
+            ("grid-row-start", "span 2", "span 2"),
+            ("grid-row-start", "a 2", "2 a"),
+            ("grid-row-start", "span a", "span a"),
+            ("grid-auto-flow", "column dense", "column dense"),
+            ("grid-auto-flow", "dense", "dense"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("grid-row-start", "0"),
+            ("grid-row-start", "span 0"),
+            ("grid-row-start", "span auto"),
+            ("grid-row-start", "1 2"),
+            ("grid-auto-flow", "row column"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve("html { grid-row-start: span a; grid-auto-flow: dense }");
+        let value = |index: usize, id| styles[index].value(id).to_string();
+        assert_eq!(value(0, PropertyId::GridRowStart), "span a");
+        assert_eq!(value(1, PropertyId::GridRowStart), "auto");
+        assert_eq!(value(1, PropertyId::GridAutoFlow), "row");
+    }
+}
@@ -0,0 +1,667 @@
+use crate::style::{
+    ComputeContext, Keyword, LengthPercentage, Number, StyleError, ToComputedValue, Value,
+};
+
+/// The minimum or maximum size of a grid track.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum TrackBreadth {
+    LengthPercentage(LengthPercentage),
+    /// A share of the leftover space, in `fr` units.
+    Flex(f32),
+    MinContent,
+    MaxContent,
+    /// The content sizes, stretched to the available space as a maximum.
+    #[default]
+    Auto,
+}
+
+impl TrackBreadth {
+    /// Whether the breadth is a length or a percentage, which doesn't depend
+    /// on the content or the leftover space.
+    pub fn is_fixed(&self) -> bool {
+        matches!(self, Self::LengthPercentage(_))
+    }
+
+    pub fn is_flexible(&self) -> bool {
+        matches!(self, Self::Flex(_))
+    }
+
+    fn resolve_relative(&self, context: &ComputeContext) -> Self {
+        match self {
+            Self::LengthPercentage(breadth) => {
+                Self::LengthPercentage(breadth.resolve_non_negative(&context.length))
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+impl TryFrom<Value> for TrackBreadth {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&[
+            "<length-percentage [0,∞]>",
+            "<flex [0,∞]>",
+            "min-content",
+            "max-content",
+            "auto",
+        ]);
+
+        match value {
+            Value::Keyword(Keyword::MinContent) => Ok(Self::MinContent),
+            Value::Keyword(Keyword::MaxContent) => Ok(Self::MaxContent),
+            Value::Keyword(Keyword::Auto) => Ok(Self::Auto),
+            Value::Flex(flex) if flex >= 0.0 => Ok(Self::Flex(flex)),
+            value => match LengthPercentage::try_from(value) {
+                Ok(breadth) if !breadth.is_negative() => Ok(Self::LengthPercentage(breadth)),
+                _ => Err(invalid),
+            },
+        }
+    }
+}
+
+impl From<TrackBreadth> for Value {
+    fn from(value: TrackBreadth) -> Self {
+        match value {
+            TrackBreadth::LengthPercentage(breadth) => Self::from(breadth),
+            TrackBreadth::Flex(flex) => Self::Flex(flex),
+            TrackBreadth::MinContent => Self::Keyword(Keyword::MinContent),
+            TrackBreadth::MaxContent => Self::Keyword(Keyword::MaxContent),
+            TrackBreadth::Auto => Self::Keyword(Keyword::Auto),
+        }
+    }
+}
+
+/// The sizing function of a grid track.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackSize {
+    Breadth(TrackBreadth),
+    /// A size between a minimum, which isn't flexible, and a maximum.
+    MinMax(TrackBreadth, TrackBreadth),
+    /// The max-content size, clamped between the auto minimum and the given
+    /// maximum.
+    FitContent(LengthPercentage),
+}
+
+impl Default for TrackSize {
+    fn default() -> Self {
+        Self::Breadth(TrackBreadth::Auto)
+    }
+}
+
+impl TrackSize {
+    /// Whether either of the bounds is fixed, the size then being allowed in
+    /// an automatic repetition.
+    pub fn is_fixed(&self) -> bool {
+        match self {
+            Self::Breadth(breadth) => breadth.is_fixed(),
+            Self::MinMax(min, max) => min.is_fixed() || max.is_fixed(),
+            Self::FitContent(_) => false,
+        }
+    }
+
+    fn resolve_relative(&self, context: &ComputeContext) -> Self {
+        match self {
+            Self::Breadth(breadth) => Self::Breadth(breadth.resolve_relative(context)),
+            Self::MinMax(min, max) => {
+                Self::MinMax(min.resolve_relative(context), max.resolve_relative(context))
+            }
+            Self::FitContent(limit) => {
+                Self::FitContent(limit.resolve_non_negative(&context.length))
+            }
+        }
+    }
+}
+
+impl TryFrom<Value> for TrackSize {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<track-breadth>", "minmax()", "fit-content()"]);
+
+        match value {
+            Value::Function(name, arguments) if name == "minmax" => {
+                let [min, max] = <[Value; 2]>::try_from(arguments).map_err(|_| invalid.clone())?;
+                let (min, max) = (TrackBreadth::try_from(min)?, TrackBreadth::try_from(max)?);
+                match min.is_flexible() {
+                    true => Err(invalid),
+                    false => Ok(Self::MinMax(min, max)),
+                }
+            }
+            Value::Function(name, arguments) if name == "fit-content" => {
+                match <[Value; 1]>::try_from(arguments)
+                    .map(|[limit]| LengthPercentage::try_from(limit))
+                {
+                    Ok(Ok(limit)) if !limit.is_negative() => Ok(Self::FitContent(limit)),
+                    _ => Err(invalid),
+                }
+            }
+            value => TrackBreadth::try_from(value).map(Self::Breadth),
+        }
+    }
+}
+
+impl From<TrackSize> for Value {
+    fn from(value: TrackSize) -> Self {
+        match value {
+            TrackSize::Breadth(breadth) => Self::from(breadth),
+            TrackSize::MinMax(min, max) => {
+                Self::Function("minmax".to_string(), vec![Self::from(min), Self::from(max)])
+            }
+            TrackSize::FitContent(limit) => {
+                Self::Function("fit-content".to_string(), vec![Self::from(limit)])
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for TrackSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+/// The number of repetitions of tracks or line names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatCount {
+    Count(u32),
+    /// As many repetitions as fit in the grid container.
+    AutoFill,
+    /// As many repetitions as fit, the empty tracks then being collapsed.
+    AutoFit,
+}
+
+impl RepeatCount {
+    pub fn is_automatic(&self) -> bool {
+        !matches!(self, Self::Count(_))
+    }
+}
+
+impl TryFrom<Value> for RepeatCount {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(Number::Int(count)) if count >= 1 => Ok(Self::Count(count as u32)),
+            Value::Keyword(Keyword::AutoFill) => Ok(Self::AutoFill),
+            Value::Keyword(Keyword::AutoFit) => Ok(Self::AutoFit),
+            _ => Err(StyleError::InvalidValue(&[
+                "<integer [1,∞]>",
+                "auto-fill",
+                "auto-fit",
+            ])),
+        }
+    }
+}
+
+impl From<RepeatCount> for Value {
+    fn from(value: RepeatCount) -> Self {
+        match value {
+            RepeatCount::Count(count) => Self::from(count as i32),
+            RepeatCount::AutoFill => Self::Keyword(Keyword::AutoFill),
+            RepeatCount::AutoFit => Self::Keyword(Keyword::AutoFit),
+        }
+    }
+}
+
+/// Items interleaved with the names of the lines around them, there being
+/// one more set of names, possibly empty, than items.
+fn parse_interleaved<T>(
+    value: Value,
+    mut item: impl FnMut(Value) -> Result<T, StyleError>,
+) -> Result<(Vec<Vec<String>>, Vec<T>), StyleError> {
+    if let Value::List(_) = value {
+        return Err(StyleError::InvalidValue(&["<line-names>"]));
+    }
+
+    let (mut line_names, mut items) = (vec![None], Vec::default());
+    for value in value {
+        match value {
+            Value::LineNames(names) => match line_names.last_mut() {
+                Some(last @ None) => *last = Some(names),
+                _ => return Err(StyleError::InvalidValue(&["<line-names>"])),
+            },
+            value => {
+                items.push(item(value)?);
+                line_names.push(None);
+            }
+        }
+    }
+
+    let line_names = line_names
+        .into_iter()
+        .map(Option::unwrap_or_default)
+        .collect();
+    Ok((line_names, items))
+}
+
+/// The value of items interleaved with the names of their lines.
+fn interleaved_value<T>(line_names: Vec<Vec<String>>, items: Vec<T>) -> Value
+where
+    Value: From<T>,
+{
+    let mut values = Vec::default();
+    let mut items = items.into_iter();
+    for names in line_names {
+        if !names.is_empty() {
+            values.push(Value::LineNames(names));
+        }
+        values.extend(items.next().map(Value::from));
+    }
+
+    Value::Array(values)
+}
+
+/// A repeated fragment of a track list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackRepeat {
+    pub count: RepeatCount,
+    /// The names of the lines around and between the repeated tracks.
+    pub line_names: Vec<Vec<String>>,
+    pub sizes: Vec<TrackSize>,
+}
+
+impl TrackRepeat {
+    /// Whether all the tracks are fixed, the fragment then being allowed
+    /// alongside an automatic repetition.
+    pub fn is_fixed(&self) -> bool {
+        self.sizes.iter().all(TrackSize::is_fixed)
+    }
+}
+
+impl TryFrom<Value> for TrackRepeat {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["repeat()"]);
+        let Value::Function(name, arguments) = value else {
+            return Err(invalid);
+        };
+        let (true, Ok([count, tracks])) = (name == "repeat", <[Value; 2]>::try_from(arguments))
+        else {
+            return Err(invalid);
+        };
+
+        let count = RepeatCount::try_from(count)?;
+        let (line_names, sizes) = parse_interleaved(tracks, TrackSize::try_from)?;
+        let repeat = Self {
+            count,
+            line_names,
+            sizes,
+        };
+
+        match repeat.sizes.is_empty() || (count.is_automatic() && !repeat.is_fixed()) {
+            true => Err(invalid),
+            false => Ok(repeat),
+        }
+    }
+}
+
+impl From<TrackRepeat> for Value {
+    fn from(value: TrackRepeat) -> Self {
+        Self::Function(
+            "repeat".to_string(),
+            vec![
+                Self::from(value.count),
+                interleaved_value(value.line_names, value.sizes),
+            ],
+        )
+    }
+}
+
+/// A track, or a repeated fragment, of an explicit grid.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Track {
+    Size(TrackSize),
+    Repeat(TrackRepeat),
+}
+
+impl Track {
+    fn is_automatic_repeat(&self) -> bool {
+        matches!(self, Self::Repeat(repeat) if repeat.count.is_automatic())
+    }
+
+    fn is_fixed(&self) -> bool {
+        match self {
+            Self::Size(size) => size.is_fixed(),
+            Self::Repeat(repeat) => repeat.is_fixed(),
+        }
+    }
+}
+
+impl From<Track> for Value {
+    fn from(value: Track) -> Self {
+        match value {
+            Track::Size(size) => Self::from(size),
+            Track::Repeat(repeat) => Self::from(repeat),
+        }
+    }
+}
+
+/// The tracks of an explicit grid, and the names of the lines between them.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#track-sizing)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackList {
+    /// The names of the lines around and between the tracks.
+    pub line_names: Vec<Vec<String>>,
+    pub tracks: Vec<Track>,
+}
+
+impl TrackList {
+    fn resolve_relative(&self, context: &ComputeContext) -> Self {
+        let resolve = |sizes: &[TrackSize]| {
+            sizes
+                .iter()
+                .map(|size| size.resolve_relative(context))
+                .collect()
+        };
+        let tracks = self
+            .tracks
+            .iter()
+            .map(|track| match track {
+                Track::Size(size) => Track::Size(size.resolve_relative(context)),
+                Track::Repeat(repeat) => Track::Repeat(TrackRepeat {
+                    sizes: resolve(&repeat.sizes),
+                    ..repeat.clone()
+                }),
+            })
+            .collect();
+
+        Self {
+            line_names: self.line_names.clone(),
+            tracks,
+        }
+    }
+
+    /// Whether it only consists of single tracks, without any repetition.
+    pub fn is_explicit(&self) -> bool {
+        self.tracks
+            .iter()
+            .all(|track| matches!(track, Track::Size(_)))
+    }
+}
+
+impl TryFrom<Value> for TrackList {
+    type Error = StyleError;
+
+    /// Parse a track list, where an automatic repetition requires all the
+    /// other tracks to be fixed.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let (line_names, tracks) = parse_interleaved(value, |value| match value {
+            Value::Function(ref name, _) if name == "repeat" => {
+                TrackRepeat::try_from(value).map(Track::Repeat)
+            }
+            value => TrackSize::try_from(value).map(Track::Size),
+        })?;
+
+        let automatic = tracks
+            .iter()
+            .filter(|track| track.is_automatic_repeat())
+            .count();
+        if tracks.is_empty()
+            || automatic > 1
+            || (automatic == 1 && !tracks.iter().all(Track::is_fixed))
+        {
+            return Err(StyleError::InvalidValue(&[
+                "<track-list>",
+                "<auto-track-list>",
+            ]));
+        }
+
+        Ok(Self { line_names, tracks })
+    }
+}
+
+impl From<TrackList> for Value {
+    fn from(value: TrackList) -> Self {
+        interleaved_value(value.line_names, value.tracks)
+    }
+}
+
+impl std::fmt::Display for TrackList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+/// Names given to the lines of a subgrid.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubgridLineNames {
+    Names(Vec<String>),
+    /// Sets of names repeated a number of times, or filling the lines left.
+    Repeat(RepeatCount, Vec<Vec<String>>),
+}
+
+impl TryFrom<Value> for SubgridLineNames {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<line-names>", "<name-repeat>"]);
+
+        match value {
+            Value::LineNames(names) => Ok(Self::Names(names)),
+            Value::Function(name, arguments) if name == "repeat" => {
+                let [count, names] =
+                    <[Value; 2]>::try_from(arguments).map_err(|_| invalid.clone())?;
+                let count = match RepeatCount::try_from(count)? {
+                    RepeatCount::AutoFit => return Err(invalid),
+                    count => count,
+                };
+                let names = names
+                    .into_iter()
+                    .map(|names| match names {
+                        Value::LineNames(names) => Ok(names),
+                        _ => Err(invalid.clone()),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Self::Repeat(count, names))
+            }
+            _ => Err(invalid),
+        }
+    }
+}
+
+impl From<SubgridLineNames> for Value {
+    fn from(value: SubgridLineNames) -> Self {
+        match value {
+            SubgridLineNames::Names(names) => Self::LineNames(names),
+            SubgridLineNames::Repeat(count, names) => Self::Function(
+                "repeat".to_string(),
+                vec![
+                    Self::from(count),
+                    Self::Array(names.into_iter().map(Self::LineNames).collect()),
+                ],
+            ),
+        }
+    }
+}
+
+/// The explicit tracks of a grid container in one axis.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#track-sizing)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum GridTemplate {
+    /// No explicit tracks, all of them being implicit.
+    #[default]
+    None,
+    TrackList(TrackList),
+    /// The tracks of the parent grid, spanned by the subgrid, and the names
+    /// added to their lines.
+    Subgrid(Vec<SubgridLineNames>),
+}
+
+impl TryFrom<Value> for GridTemplate {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match &value {
+            Value::Keyword(Keyword::None) => return Ok(Self::None),
+            Value::Array(values) if values.first() == Some(&Value::Keyword(Keyword::Subgrid)) => {}
+            Value::Keyword(Keyword::Subgrid) => {}
+            _ => return TrackList::try_from(value).map(Self::TrackList),
+        }
+
+        let names = value
+            .into_iter()
+            .skip(1)
+            .map(SubgridLineNames::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let automatic = names
+            .iter()
+            .filter(|names| matches!(names, SubgridLineNames::Repeat(RepeatCount::AutoFill, _)))
+            .count();
+        match automatic {
+            0 | 1 => Ok(Self::Subgrid(names)),
+            _ => Err(StyleError::InvalidValue(&["<line-name-list>"])),
+        }
+    }
+}
+
+impl From<GridTemplate> for Value {
+    fn from(value: GridTemplate) -> Self {
+        match value {
+            GridTemplate::None => Self::Keyword(Keyword::None),
+            GridTemplate::TrackList(tracks) => Self::from(tracks),
+            GridTemplate::Subgrid(names) => std::iter::once(Self::Keyword(Keyword::Subgrid))
+                .chain(names.into_iter().map(Self::from))
+                .collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for GridTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+impl ToComputedValue for GridTemplate {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::TrackList(tracks) => Self::TrackList(tracks.resolve_relative(context)),
+            other => other.clone(),
+        }
+    }
+}
+
+longhand_newtypes!(GridTemplate: GridTemplateColumns, GridTemplateRows);
+
+/// The sizes of the implicit tracks of a grid container, repeated in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridAutoTracks(pub Vec<TrackSize>);
+
+impl Default for GridAutoTracks {
+    fn default() -> Self {
+        Self(vec![TrackSize::default()])
+    }
+}
+
+impl TryFrom<Value> for GridAutoTracks {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        if let Value::List(_) = value {
+            return Err(StyleError::InvalidValue(&["<track-size>+"]));
+        }
+
+        value
+            .into_iter()
+            .map(TrackSize::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl From<GridAutoTracks> for Value {
+    fn from(value: GridAutoTracks) -> Self {
+        value.0.into_iter().collect()
+    }
+}
+
+impl std::fmt::Display for GridAutoTracks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+impl ToComputedValue for GridAutoTracks {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        Self(
+            self.0
+                .iter()
+                .map(|size| size.resolve_relative(context))
+                .collect(),
+        )
+    }
+}
+
+longhand_newtypes!(GridAutoTracks: GridAutoColumns, GridAutoRows);
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_track_lists() {
+        for (name, source) in [
+            ("grid-template-columns", "none"),
+            ("grid-template-columns", "subgrid"),
+            ("grid-template-columns", "repeat(3, 1fr)"),
+            (
+                "grid-template-columns",
+                "repeat(auto-fill, minmax(100px, 1fr))",
+            ),
+            ("grid-template-columns", "[a] 10px [b c] auto"),
+            ("grid-template-columns", "fit-content(10px)"),
+            ("grid-template-rows", "min-content max-content"),
+            ("grid-auto-columns", "1fr auto"),
+        ] {
+            assert_eq!(round_trip(name, source), source);
+        }
+        for (name, invalid) in [
+            ("grid-template-columns", "minmax(1fr, 10px)"),
+            ("grid-template-columns", "-1fr"),
+            ("grid-template-columns", "-1px"),
+            ("grid-template-columns", "repeat(0, 1fr)"),
+            ("grid-template-columns", "repeat(auto-fill, 1fr)"),
+            ("grid-auto-rows", "none"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px; \
+                grid-template-columns: 1em calc(1px - 1em) minmax(calc(1em - 20px), 1fr); \
+                grid-template-rows: repeat(2, [a] 2em) fit-content(calc(1px - 1em)); \
+                grid-auto-rows: calc(1px - 1em) 2em }",
+        );
+        let value = |index: usize, id| styles[index].value(id).to_string();
+
+        // The negative expressions are clamped once resolved, and the tracks
+        // aren't inherited.
+        assert_eq!(
+            value(0, PropertyId::GridTemplateColumns),
+            "10px 0px minmax(0px, 1fr)"
+        );
+        assert_eq!(
+            value(0, PropertyId::GridTemplateRows),
+            "repeat(2, [a] 20px) fit-content(0px)"
+        );
+        assert_eq!(value(0, PropertyId::GridAutoRows), "0px 20px");
+        assert_eq!(value(1, PropertyId::GridTemplateColumns), "none");
+        assert_eq!(value(1, PropertyId::GridAutoRows), "auto");
+    }
+}
@@ -0,0 +1,30 @@
+/// How the values of a property are interpolated.
+///
+/// [Web Animations](https://drafts.csswg.org/web-animations-1/#animation-type)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationType {
+    NotAnimatable,
+    /// The values flip at the middle of the interpolation.
+    Discrete,
+    /// The computed values are interpolated.
+    ByComputedValue,
+    /// The lists are repeated to the same length, then interpolated item by item.
+    RepeatableList,
+}
+
+/// The groups of logical properties and their physical counterparts.
+///
+/// [CSS Logical 1](https://drafts.csswg.org/css-logical-1/#logical-property-group)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalGroup {
+    Margin,
+    Padding,
+    Inset,
+    BorderColor,
+    BorderStyle,
+    BorderWidth,
+    BorderRadius,
+    Size,
+    MinSize,
+    MaxSize,
+}
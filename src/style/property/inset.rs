@@ -0,0 +1,128 @@
+use crate::{
+    geometry::Edge,
+    style::{ComputeContext, Keyword, LengthPercentage, Style, StyleError, Value},
+};
+
+use super::ToComputedValue;
+
+/// The offset of a positioned box from a side of its containing block.
+///
+/// [CSS Position 3](https://drafts.csswg.org/css-position-3/#insets)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Inset {
+    /// The offset given by the layout, such as the static position.
+    #[default]
+    Auto,
+    LengthPercentage(LengthPercentage),
+}
+
+impl TryFrom<Value> for Inset {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Auto) => Ok(Self::Auto),
+            value => LengthPercentage::try_from(value)
+                .map(Self::LengthPercentage)
+                .map_err(|_| StyleError::InvalidValue(&["auto", "<length-percentage>"])),
+        }
+    }
+}
+
+impl From<Inset> for Value {
+    fn from(value: Inset) -> Self {
+        match value {
+            Inset::Auto => Self::Keyword(Keyword::Auto),
+            Inset::LengthPercentage(inset) => Self::from(inset),
+        }
+    }
+}
+
+impl std::fmt::Display for Inset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::LengthPercentage(inset) => write!(f, "{}", inset),
+        }
+    }
+}
+
+impl ToComputedValue for Inset {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::Auto => Self::Auto,
+            Self::LengthPercentage(inset) => {
+                Self::LengthPercentage(inset.resolve_relative(&context.length))
+            }
+        }
+    }
+}
+
+longhand_newtypes!(Inset: Top, Right, Bottom, Left);
+longhand_newtypes!(
+    Inset: InsetBlockStart,
+    InsetBlockEnd,
+    InsetInlineStart,
+    InsetInlineEnd
+);
+
+impl Style {
+    /// The computed insets.
+    pub fn inset(&self) -> Edge<Inset> {
+        Edge {
+            left: self.left.clone(),
+            right: self.right.clone(),
+            top: self.top.clone(),
+            bottom: self.bottom.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_insets() {
+        for (name, source, expected) in [
+            ("top", "auto", "auto"),
+            ("top", "-10px", "-10px"),
+            ("inset-block-start", "10%", "10%"),
+            ("inset", "1px auto 1px auto", "1px auto"),
+            ("inset-inline", "5px auto", "5px auto"),
+            ("inset-block", "1px 1px", "1px"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("top", "red"),
+            ("top", "1px 2px"),
+            ("inset-block", "1px 2px 3px"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px }
+            body { position: relative; inset: calc(-1em) calc(1em + 5%); \
+                inset-inline-start: 2em }",
+        );
+        let inset = |index: usize, id| styles[index].value(id).to_string();
+
+        // The insets can be negative, and aren't inherited.
+        assert_eq!(inset(1, PropertyId::Top), "-10px");
+        assert_eq!(inset(1, PropertyId::Right), "calc(10px + 5%)");
+        assert_eq!(inset(1, PropertyId::Left), "20px");
+        assert_eq!(inset(2, PropertyId::Top), "auto");
+    }
+}
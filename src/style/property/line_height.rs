@@ -0,0 +1,151 @@
+use crate::style::{
+    ComputeContext, FontMetrics, Keyword, Length, LengthContext, LengthPercentage, Number,
+    StyleError, Value,
+};
+
+use super::ToComputedValue;
+
+/// The height of the lines, a number being inherited as is while lengths
+/// and percentages are computed against the font size.
+///
+/// [CSS Inline 3](https://drafts.csswg.org/css-inline-3/#line-height-property)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum LineHeight {
+    /// The height given by the metrics of the font.
+    #[default]
+    Normal,
+    /// A factor of the font size.
+    Number(f32),
+    LengthPercentage(LengthPercentage),
+}
+
+impl LineHeight {
+    /// The computed height in CSS pixels, given the metrics of the font with
+    /// its normal line height.
+    pub fn to_px(&self, font: &FontMetrics) -> f32 {
+        let context = LengthContext {
+            font: *font,
+            ..LengthContext::default()
+        };
+
+        match self {
+            Self::Normal => font.line_height,
+            Self::Number(number) => font.font_size * number,
+            Self::LengthPercentage(height) => height.to_px(font.font_size, &context),
+        }
+    }
+}
+
+impl TryFrom<Value> for LineHeight {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid =
+            StyleError::InvalidValue(&["normal", "<number [0,∞]>", "<length-percentage [0,∞]>"]);
+
+        match value {
+            Value::Keyword(Keyword::Normal) => Ok(Self::Normal),
+            Value::Number(number) if f32::from(number) >= 0.0 => Ok(Self::Number(number.into())),
+            value => match LengthPercentage::try_from(value) {
+                Ok(height) if !height.is_negative() => Ok(Self::LengthPercentage(height)),
+                _ => Err(invalid),
+            },
+        }
+    }
+}
+
+impl From<LineHeight> for Value {
+    fn from(value: LineHeight) -> Self {
+        match value {
+            LineHeight::Normal => Self::Keyword(Keyword::Normal),
+            LineHeight::Number(number) => Self::from(number),
+            LineHeight::LengthPercentage(height) => Self::from(height),
+        }
+    }
+}
+
+impl std::fmt::Display for LineHeight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "normal"),
+            Self::Number(number) => write!(f, "{}", number),
+            Self::LengthPercentage(height) => write!(f, "{}", height),
+        }
+    }
+}
+
+impl ToComputedValue for LineHeight {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        let font_size = &context.font_size;
+
+        match self {
+            Self::LengthPercentage(LengthPercentage::Percentage(percentage)) => {
+                Self::LengthPercentage(LengthPercentage::Length(Length {
+                    quantity: Number::from(f32::from(font_size.quantity) * percentage / 100.0),
+                    unit: font_size.unit,
+                }))
+            }
+            // The lh unit refers to the line height of the parent.
+            Self::LengthPercentage(height) => {
+                let length = LengthContext {
+                    font: FontMetrics {
+                        line_height: context.parent_font.line_height,
+                        ..context.length.font
+                    },
+                    ..context.length
+                };
+                Self::LengthPercentage(height.resolve_non_negative(&length))
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_line_heights() {
+        for (source, expected) in [
+            ("NORMAL", "normal"),
+            ("1.5", "1.5"),
+            ("0", "0"),
+            ("150%", "150%"),
+            ("2em", "2em"),
+        ] {
+            assert_eq!(round_trip("line-height", source), expected, "{}", source);
+        }
+        for invalid in ["-1", "-1px", "-10%", "auto"] {
+            assert_eq!(round_trip("line-height", invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let p = doc.create(Some(body), "p", &[]);
+        let span = doc.create(Some(p), "span", &[]);
+        doc.create(Some(span), "em", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px; line-height: 1.5 }
+            body { line-height: 150% }
+            p { font-size: 20px; line-height: calc(1em - 30px) }
+            span { line-height: 2 }
+            em { font-size: 20px }",
+        );
+        let line_height = |index: usize| styles[index].value(PropertyId::LineHeight).to_string();
+
+        // The numbers are inherited as is, the percentages as lengths.
+        assert_eq!(line_height(0), "1.5");
+        assert_eq!(line_height(1), "15px");
+        // The negative expressions are clamped once resolved.
+        assert_eq!(line_height(2), "0px");
+        assert_eq!(line_height(4), "2");
+    }
+}
@@ -0,0 +1,237 @@
+//! The mapping of the flow-relative longhands to the physical ones.
+//!
+//! [CSS Logical 1](https://drafts.csswg.org/css-logical-1/#box)
+use crate::style::{Direction, LogicalGroup, PropertyId, WritingMode};
+
+/// A side of a box, relative to the flow of its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalSide {
+    BlockStart,
+    BlockEnd,
+    InlineStart,
+    InlineEnd,
+}
+
+/// A physical side of a box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhysicalSide {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl PhysicalSide {
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Right => Self::Left,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+        }
+    }
+}
+
+impl LogicalSide {
+    /// The physical side it maps to, given the writing mode and direction.
+    pub fn to_physical(&self, writing_mode: WritingMode, direction: Direction) -> PhysicalSide {
+        let (block_start, line_left) = match writing_mode {
+            WritingMode::HorizontalTb => (PhysicalSide::Top, PhysicalSide::Left),
+            WritingMode::VerticalRl | WritingMode::SidewaysRl => {
+                (PhysicalSide::Right, PhysicalSide::Top)
+            }
+            WritingMode::VerticalLr => (PhysicalSide::Left, PhysicalSide::Top),
+            WritingMode::SidewaysLr => (PhysicalSide::Left, PhysicalSide::Bottom),
+        };
+        let inline_start = match direction {
+            Direction::Ltr => line_left,
+            Direction::Rtl => line_left.opposite(),
+        };
+
+        match self {
+            Self::BlockStart => block_start,
+            Self::BlockEnd => block_start.opposite(),
+            Self::InlineStart => inline_start,
+            Self::InlineEnd => inline_start.opposite(),
+        }
+    }
+}
+
+/// An axis of a box, relative to the flow of its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalAxis {
+    Block,
+    Inline,
+}
+
+impl LogicalAxis {
+    /// Whether it maps to the horizontal axis, given the writing mode.
+    pub fn is_horizontal(&self, writing_mode: WritingMode) -> bool {
+        match self {
+            Self::Block => writing_mode.is_vertical(),
+            Self::Inline => !writing_mode.is_vertical(),
+        }
+    }
+}
+
+/// What a logical longhand sets, relative to the flow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowRelative {
+    Side(LogicalSide),
+    /// The corner between a block side and an inline side.
+    Corner(LogicalSide, LogicalSide),
+    Axis(LogicalAxis),
+}
+
+impl FlowRelative {
+    /// The index of the physical longhand it maps to, among the ones of its
+    /// group.
+    fn physical_index(&self, writing_mode: WritingMode, direction: Direction) -> usize {
+        let side = |side: &LogicalSide| side.to_physical(writing_mode, direction);
+
+        match self {
+            Self::Side(logical) => match side(logical) {
+                PhysicalSide::Top => 0,
+                PhysicalSide::Right => 1,
+                PhysicalSide::Bottom => 2,
+                PhysicalSide::Left => 3,
+            },
+            Self::Corner(block, inline) => match (side(block), side(inline)) {
+                (PhysicalSide::Top, PhysicalSide::Left)
+                | (PhysicalSide::Left, PhysicalSide::Top) => 0,
+                (PhysicalSide::Top, _) | (_, PhysicalSide::Top) => 1,
+                (PhysicalSide::Right, _) | (_, PhysicalSide::Right) => 2,
+                _ => 3,
+            },
+            Self::Axis(axis) => match axis.is_horizontal(writing_mode) {
+                true => 0,
+                false => 1,
+            },
+        }
+    }
+}
+
+impl LogicalGroup {
+    /// The physical longhands of the group: the sides clockwise from the top,
+    /// the corners clockwise from the top left, or the width and the height.
+    pub fn physical_longhands(&self) -> &'static [PropertyId] {
+        match self {
+            Self::Margin => &[
+                PropertyId::MarginTop,
+                PropertyId::MarginRight,
+                PropertyId::MarginBottom,
+                PropertyId::MarginLeft,
+            ],
+            Self::Padding => &[
+                PropertyId::PaddingTop,
+                PropertyId::PaddingRight,
+                PropertyId::PaddingBottom,
+                PropertyId::PaddingLeft,
+            ],
+            Self::Inset => &[
+                PropertyId::Top,
+                PropertyId::Right,
+                PropertyId::Bottom,
+                PropertyId::Left,
+            ],
+            Self::BorderColor => &[
+                PropertyId::BorderTopColor,
+                PropertyId::BorderRightColor,
+                PropertyId::BorderBottomColor,
+                PropertyId::BorderLeftColor,
+            ],
+            Self::BorderStyle => &[
+                PropertyId::BorderTopStyle,
+                PropertyId::BorderRightStyle,
+                PropertyId::BorderBottomStyle,
+                PropertyId::BorderLeftStyle,
+            ],
+            Self::BorderWidth => &[
+                PropertyId::BorderTopWidth,
+                PropertyId::BorderRightWidth,
+                PropertyId::BorderBottomWidth,
+                PropertyId::BorderLeftWidth,
+            ],
+            Self::BorderRadius => &[
+                PropertyId::BorderTopLeftRadius,
+                PropertyId::BorderTopRightRadius,
+                PropertyId::BorderBottomRightRadius,
+                PropertyId::BorderBottomLeftRadius,
+            ],
+            Self::Size => &[PropertyId::Width, PropertyId::Height],
+            Self::MinSize => &[PropertyId::MinWidth, PropertyId::MinHeight],
+            Self::MaxSize => &[PropertyId::MaxWidth, PropertyId::MaxHeight],
+        }
+    }
+}
+
+impl PropertyId {
+    /// Whether it is a flow-relative longhand.
+    pub fn is_logical(&self) -> bool {
+        self.flow_relative().is_some()
+    }
+
+    /// The physical longhand it maps to, given the writing mode and
+    /// direction, itself if it is physical.
+    pub fn to_physical(&self, writing_mode: WritingMode, direction: Direction) -> Self {
+        match (self.logical_group(), self.flow_relative()) {
+            (Some(group), Some(flow_relative)) => {
+                group.physical_longhands()[flow_relative.physical_index(writing_mode, direction)]
+            }
+            _ => *self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{Direction, PropertyId, WritingMode};
+
+    #[test]
+    fn test_001_to_physical() {
+        let physical = |id: PropertyId, writing_mode, direction| {
+            [
+                PropertyId::MarginBlockStart,
+                PropertyId::MarginInlineStart,
+                id,
+            ]
+            .map(|id| id.to_physical(writing_mode, direction).name())
+        };
+        let corner = PropertyId::BorderStartEndRadius;
+
+        assert_eq!(
+            physical(corner, WritingMode::HorizontalTb, Direction::Ltr),
+            ["margin-top", "margin-left", "border-top-right-radius"]
+        );
+        assert_eq!(
+            physical(corner, WritingMode::HorizontalTb, Direction::Rtl),
+            ["margin-top", "margin-right", "border-top-left-radius"]
+        );
+        assert_eq!(
+            physical(corner, WritingMode::VerticalRl, Direction::Ltr),
+            ["margin-right", "margin-top", "border-bottom-right-radius"]
+        );
+        assert_eq!(
+            physical(corner, WritingMode::VerticalLr, Direction::Rtl),
+            ["margin-left", "margin-bottom", "border-top-left-radius"]
+        );
+        assert_eq!(
+            physical(corner, WritingMode::SidewaysLr, Direction::Ltr),
+            ["margin-left", "margin-bottom", "border-top-left-radius"]
+        );
+
+        let size = |writing_mode| {
+            PropertyId::MaxInlineSize
+                .to_physical(writing_mode, Direction::Ltr)
+                .name()
+        };
+        assert_eq!(size(WritingMode::HorizontalTb), "max-width");
+        assert_eq!(size(WritingMode::SidewaysRl), "max-height");
+
+        assert!(!PropertyId::MarginTop.is_logical());
+        assert_eq!(
+            PropertyId::MarginTop.to_physical(WritingMode::VerticalRl, Direction::Rtl),
+            PropertyId::MarginTop
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    geometry::Edge,
+    style::{ComputeContext, Keyword, LengthPercentage, Style, StyleError, Value},
+};
+
+use super::ToComputedValue;
+
+/// The width of a margin, which may be negative.
+///
+/// [CSS Box 4](https://drafts.csswg.org/css-box-4/#margin-physical)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Margin {
+    /// The width given by the layout, such as centering a block.
+    Auto,
+    LengthPercentage(LengthPercentage),
+}
+
+impl Default for Margin {
+    fn default() -> Self {
+        Self::LengthPercentage(LengthPercentage::zero())
+    }
+}
+
+impl TryFrom<Value> for Margin {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Auto) => Ok(Self::Auto),
+            value => LengthPercentage::try_from(value)
+                .map(Self::LengthPercentage)
+                .map_err(|_| StyleError::InvalidValue(&["auto", "<length-percentage>"])),
+        }
+    }
+}
+
+impl From<Margin> for Value {
+    fn from(value: Margin) -> Self {
+        match value {
+            Margin::Auto => Self::Keyword(Keyword::Auto),
+            Margin::LengthPercentage(margin) => Self::from(margin),
+        }
+    }
+}
+
+impl std::fmt::Display for Margin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::LengthPercentage(margin) => write!(f, "{}", margin),
+        }
+    }
+}
+
+impl ToComputedValue for Margin {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::Auto => Self::Auto,
+            Self::LengthPercentage(margin) => {
+                Self::LengthPercentage(margin.resolve_relative(&context.length))
+            }
+        }
+    }
+}
+
+longhand_newtypes!(Margin: MarginTop, MarginRight, MarginBottom, MarginLeft);
+longhand_newtypes!(Margin: MarginBlockStart, MarginBlockEnd, MarginInlineStart, MarginInlineEnd);
+
+impl Style {
+    /// The computed margins.
+    pub fn margin(&self) -> Edge<Margin> {
+        Edge {
+            left: self.margin_left.clone(),
+            right: self.margin_right.clone(),
+            top: self.margin_top.clone(),
+            bottom: self.margin_bottom.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_margins() {
+        assert_eq!(round_trip("margin-top", "10PX"), "10px");
+        assert_eq!(round_trip("margin-top", "-5%"), "-5%");
+        assert_eq!(round_trip("margin-top", "auto"), "auto");
+        assert_eq!(round_trip("margin", "1px 2px 1px 2px"), "1px 2px");
+        assert_eq!(round_trip("margin", "1px auto 2px"), "1px auto 2px");
+        for invalid in ["red", "1px 2px", "none"] {
+            assert_eq!(round_trip("margin-top", invalid), "", "{}", invalid);
+        }
+        assert_eq!(round_trip("margin", "1px 2px 3px 4px 5px"), "");
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px }
+            body { margin: calc(1em + 10%) calc(-2em) }",
+        );
+        let margin = |index: usize, id| styles[index].value(id).to_string();
+
+        assert_eq!(margin(1, PropertyId::MarginTop), "calc(10px + 10%)");
+        // The margins can be negative, and aren't inherited.
+        assert_eq!(margin(1, PropertyId::MarginLeft), "-20px");
+        assert_eq!(margin(2, PropertyId::MarginLeft), "0px");
+    }
+}
@@ -1,12 +1,563 @@
+/// Generate the longhands from the property table: their ids, their
+/// [Longhand] implementations, their typed declarations, and the specified
+/// and computed styles.
+///
+/// The logical longhands have no field in the styles, their declarations
+/// being mapped to the physical longhands of their group.
+macro_rules! longhands {
+    (
+        physical {
+            $(
+                $id:ident $field:ident: $ty:ty => $computed:ty {
+                    name: $name:literal,
+                    inherited: $inherited:literal,
+                    initial: $initial:expr,
+                    grammar: $grammar:literal,
+                    parse: $parse:expr,
+                    animation: $animation:ident,
+                    logical_group: $group:expr $(,)?
+                }
+            )*
+        }
+        logical {
+            $(
+                $lid:ident: $lty:ty {
+                    name: $lname:literal,
+                    inherited: $linherited:literal,
+                    initial: $linitial:expr,
+                    grammar: $lgrammar:literal,
+                    parse: $lparse:expr,
+                    animation: $lanimation:ident,
+                    logical_group: $lgroup:expr,
+                    flow_relative: $flow:expr $(,)?
+                }
+            )*
+        }
+    ) => {
+        longhands! {
+            @ids
+            $(
+                $id: $ty {
+                    name: $name,
+                    inherited: $inherited,
+                    initial: $initial,
+                    grammar: $grammar,
+                    parse: $parse,
+                    animation: $animation,
+                    logical_group: $group,
+                    flow_relative: None,
+                }
+            )*
+            $(
+                $lid: $lty {
+                    name: $lname,
+                    inherited: $linherited,
+                    initial: $linitial,
+                    grammar: $lgrammar,
+                    parse: $lparse,
+                    animation: $lanimation,
+                    logical_group: $lgroup,
+                    flow_relative: Some($flow),
+                }
+            )*
+        }
+
+        impl PropertyDeclaration {
+            /// The value of a declaration of a logical longhand, to be parsed
+            /// as the physical longhand it maps to.
+            pub(crate) fn logical_value(&self) -> Option<Value> {
+                match self {
+                    $(Self::$lid(value) => Some(Value::from(value.clone())),)*
+                    _ => None,
+                }
+            }
+        }
+
+        /// The specified style of an element.
+        #[derive(Default)]
+        pub struct SpecStyle {
+            $(pub $field: SpecProperty<$ty>,)*
+            pub custom: CustomProperties,
+        }
+
+        impl SpecStyle {
+            /// The specified values of the longhands, from their cascaded
+            /// declarations, the logical ones being mapped against the
+            /// writing mode and direction.
+            pub(crate) fn from_cascaded(
+                values: &CascadedValues,
+                custom: CustomProperties,
+                writing_mode: WritingMode,
+                direction: Direction,
+            ) -> Self {
+                Self {
+                    $($field: specified_physical_value(
+                        values.candidates($name),
+                        &custom,
+                        writing_mode,
+                        direction,
+                    ),)*
+                    custom,
+                }
+            }
+        }
+
+        /// The computed style of an element.
+        #[derive(Clone)]
+        pub struct Style {
+            $(pub $field: $computed,)*
+            pub custom: CustomProperties,
+        }
+
+        impl Default for Style {
+            fn default() -> Self {
+                Self {
+                    $($field: <$ty as Longhand>::initial(),)*
+                    custom: CustomProperties::default(),
+                }
+            }
+        }
+
+        impl Style {
+            /// Compute the longhands, inheriting from the parent style.
+            pub(crate) fn compute_longhands(
+                spec: &SpecStyle,
+                parent: Option<&Style>,
+                context: &ComputeContext,
+            ) -> Self {
+                let mut custom = spec.custom.clone();
+                custom.compute_typed(context);
+
+                Self {
+                    $($field: spec.$field.compute(parent.map(|parent| &parent.$field), context),)*
+                    custom,
+                }
+            }
+
+            /// The computed value of a longhand, a logical one being the value
+            /// of the physical longhand it maps to.
+            pub fn value(&self, id: PropertyId) -> Value {
+                match id {
+                    $(PropertyId::$id => Value::from(self.$field.clone()),)*
+                    $(PropertyId::$lid => {
+                        self.value(id.to_physical(self.writing_mode, self.direction))
+                    })*
+                }
+            }
+        }
+    };
+    (
+        @ids
+        $(
+            $id:ident: $ty:ty {
+                name: $name:literal,
+                inherited: $inherited:literal,
+                initial: $initial:expr,
+                grammar: $grammar:literal,
+                parse: $parse:expr,
+                animation: $animation:ident,
+                logical_group: $group:expr,
+                flow_relative: $flow:expr $(,)?
+            }
+        )*
+    ) => {
+        /// The id of a longhand property.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum PropertyId {
+            $($id,)*
+        }
+
+        impl PropertyId {
+            pub const ALL: &'static [Self] = &[$(Self::$id,)*];
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$id => $name,)*
+                }
+            }
+
+            /// Whether the property inherits by default.
+            pub fn is_inherited(&self) -> bool {
+                match self {
+                    $(Self::$id => $inherited,)*
+                }
+            }
+
+            /// The value definition syntax of the property.
+            pub fn grammar(&self) -> &'static str {
+                match self {
+                    $(Self::$id => $grammar,)*
+                }
+            }
+
+            pub fn animation_type(&self) -> AnimationType {
+                match self {
+                    $(Self::$id => AnimationType::$animation,)*
+                }
+            }
+
+            /// The group of logical and physical properties it belongs to, if any.
+            pub fn logical_group(&self) -> Option<LogicalGroup> {
+                match self {
+                    $(Self::$id => $group,)*
+                }
+            }
+
+            /// The side, corner or axis of a logical longhand, relative to the flow.
+            pub fn flow_relative(&self) -> Option<FlowRelative> {
+                match self {
+                    $(Self::$id => $flow,)*
+                }
+            }
+
+            pub fn initial_value(&self) -> Value {
+                match self {
+                    $(Self::$id => Value::from(<$ty as Longhand>::initial()),)*
+                }
+            }
+
+            /// The declaration of the initial specified value.
+            pub fn initial_declaration(&self) -> PropertyDeclaration {
+                match self {
+                    $(Self::$id => PropertyDeclaration::$id(<$ty>::default()),)*
+                }
+            }
+
+            /// Parse a value of the property, without the CSS-wide keywords.
+            pub fn parse(&self, value: &[ComponentValue]) -> Result<PropertyDeclaration, StyleError> {
+                let value = Value::try_from(value)?;
+
+                match self {
+                    $(Self::$id => <$ty as Longhand>::parse(value).map(PropertyDeclaration::$id),)*
+                }
+            }
+        }
+
+        impl TryFrom<&str> for PropertyId {
+            type Error = StyleError;
+
+            /// Look up a property by its name, ASCII case-insensitively.
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                match value.to_ascii_lowercase().as_str() {
+                    $($name => Ok(Self::$id),)*
+                    _ => Err(StyleError::InvalidDeclaration),
+                }
+            }
+        }
+
+        impl std::fmt::Display for PropertyId {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.name())
+            }
+        }
+
+        $(
+            impl Longhand for $ty {
+                const ID: PropertyId = PropertyId::$id;
+                const NAME: &'static str = $name;
+                const INHERITED: bool = $inherited;
+
+                fn initial() -> Self::Computed {
+                    $initial
+                }
+
+                fn parse(value: Value) -> Result<Self, StyleError> {
+                    ($parse)(value)
+                }
+
+                fn from_declaration(declaration: PropertyDeclaration) -> Option<Self> {
+                    match declaration {
+                        PropertyDeclaration::$id(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+
+        /// A declaration whose value is parsed into the type of its property.
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum PropertyDeclaration {
+            $($id($ty),)*
+            /// A CSS-wide keyword, and the property it is declared for.
+            CssWide(PropertyId, CssWideKeyword),
+            /// A value referencing custom properties, only parsed once substituted,
+            /// with the shorthand it was declared with, if any.
+            WithVariables(PropertyId, Option<ShorthandId>, Vec<ComponentValue>),
+            /// A custom property, its value being kept as written.
+            Custom(String, Vec<ComponentValue>),
+        }
+
+        impl PropertyDeclaration {
+            /// The id of the declared property, none for custom properties.
+            pub fn id(&self) -> Option<PropertyId> {
+                match self {
+                    $(Self::$id(_) => Some(PropertyId::$id),)*
+                    Self::CssWide(id, _) | Self::WithVariables(id, ..) => Some(*id),
+                    Self::Custom(..) => None,
+                }
+            }
+
+            /// The declared value, serialized.
+            pub fn value_to_string(&self) -> String {
+                match self {
+                    $(Self::$id(value) => value.to_string(),)*
+                    Self::CssWide(_, keyword) => keyword.to_string(),
+                    Self::WithVariables(_, _, value) | Self::Custom(_, value) => {
+                        value.iter().map(ToString::to_string).collect()
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Generate the shorthands from the shorthand table: their ids, and the
+/// dispatch to their parsing and serialization.
+macro_rules! shorthands {
+    (
+        $(
+            $id:ident {
+                name: $name:literal,
+                longhands: [$($longhand:ident),* $(,)?],
+                parse: $parse:expr,
+                serialize: $serialize:expr $(,)?
+            }
+        )*
+    ) => {
+        /// The id of a shorthand property.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ShorthandId {
+            $($id,)*
+        }
+
+        impl ShorthandId {
+            pub const ALL: &'static [Self] = &[$(Self::$id,)*];
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$id => $name,)*
+                }
+            }
+
+            /// The longhands it sets, in their canonical order.
+            pub fn longhands(&self) -> &'static [PropertyId] {
+                match self {
+                    $(Self::$id => &[$(PropertyId::$longhand,)*],)*
+                }
+            }
+
+            /// Parse the value into the declarations of the longhands it sets.
+            fn parse_declared(
+                &self,
+                value: &[ComponentValue],
+            ) -> Result<Vec<PropertyDeclaration>, StyleError> {
+                match self {
+                    $(Self::$id => ($parse)(value),)*
+                }
+            }
+
+            /// Serialize the typed declarations of its longhands.
+            fn serialize_declared(&self, declarations: &[&PropertyDeclaration]) -> Option<String> {
+                match self {
+                    $(Self::$id => ($serialize)(declarations),)*
+                }
+            }
+        }
+
+        impl TryFrom<&str> for ShorthandId {
+            type Error = StyleError;
+
+            /// Look up a shorthand by its name, ASCII case-insensitively.
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                match value.to_ascii_lowercase().as_str() {
+                    $($name => Ok(Self::$id),)*
+                    _ => Err(StyleError::InvalidDeclaration),
+                }
+            }
+        }
+
+        impl std::fmt::Display for ShorthandId {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.name())
+            }
+        }
+    };
+}
+
+/// Declare the longhands sharing a type of value, such as the sides of the
+/// margin, as newtypes of it, the property table needing a type per longhand.
+macro_rules! longhand_newtypes {
+    ($inner:ty: $($name:ident),* $(,)?) => {
+        $(
+            #[derive(Debug, Clone, Default, PartialEq)]
+            pub struct $name(pub $inner);
+
+            impl std::ops::Deref for $name {
+                type Target = $inner;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl TryFrom<$crate::style::Value> for $name {
+                type Error = $crate::style::StyleError;
+
+                fn try_from(value: $crate::style::Value) -> Result<Self, Self::Error> {
+                    <$inner>::try_from(value).map(Self)
+                }
+            }
+
+            impl From<$name> for $crate::style::Value {
+                fn from(value: $name) -> Self {
+                    Self::from(value.0)
+                }
+            }
+
+            impl std::fmt::Display for $name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+
+            impl $crate::style::ToComputedValue for $name {
+                type Computed = <$inner as $crate::style::ToComputedValue>::Computed;
+
+                fn compute(&self, context: &$crate::style::ComputeContext) -> Self::Computed {
+                    self.0.compute(context)
+                }
+            }
+        )*
+    };
+}
+
+/// Generate a longhand whose value is one of a few keywords, the first one
+/// being the initial value.
+macro_rules! keyword_longhand {
+    ($(#[$meta:meta])* $name:ident { $initial:ident $(, $variant:ident)* $(,)? }, $expected:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        pub enum $name {
+            #[default]
+            $initial,
+            $($variant,)*
+        }
+
+        impl $name {
+            /// The value of a keyword, if it is one of the values.
+            pub fn from_keyword(keyword: $crate::style::Keyword) -> Option<Self> {
+                match keyword {
+                    $crate::style::Keyword::$initial => Some(Self::$initial),
+                    $($crate::style::Keyword::$variant => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl TryFrom<$crate::style::Value> for $name {
+            type Error = $crate::style::StyleError;
+
+            fn try_from(value: $crate::style::Value) -> Result<Self, Self::Error> {
+                match value {
+                    $crate::style::Value::Keyword(keyword) => Self::from_keyword(keyword),
+                    _ => None,
+                }
+                .ok_or($crate::style::StyleError::InvalidValue($expected))
+            }
+        }
+
+        impl From<$name> for $crate::style::Keyword {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::$initial => Self::$initial,
+                    $($name::$variant => Self::$variant,)*
+                }
+            }
+        }
+
+        impl From<$name> for $crate::style::Value {
+            fn from(value: $name) -> Self {
+                Self::Keyword($crate::style::Keyword::from(value))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", $crate::style::Keyword::from(*self))
+            }
+        }
+
+        impl $crate::style::ToComputedValue for $name {
+            type Computed = Self;
+
+            fn compute(&self, _context: &$crate::style::ComputeContext) -> Self::Computed {
+                *self
+            }
+        }
+    };
+}
+
+mod alignment;
 mod background;
+mod block;
+mod border;
+mod box_sizing;
+mod color;
+mod declaration;
 mod display;
+mod flex;
 mod font_family;
+mod font_size;
+mod font_stretch;
+mod font_style;
+mod font_variant;
+mod font_weight;
+mod grid;
+mod id;
+mod inset;
+mod line_height;
+mod logical;
+mod margin;
+mod overflow;
+mod padding;
+mod position;
+mod shorthand;
+mod size;
+mod text;
+mod visibility;
+mod writing_mode;
 
+pub use alignment::*;
 pub use background::*;
+pub use block::*;
+pub use border::*;
+pub use box_sizing::*;
+pub use color::*;
+pub use declaration::*;
 pub use display::*;
+pub use flex::*;
 pub use font_family::*;
+pub use font_size::*;
+pub use font_stretch::*;
+pub use font_style::*;
+pub use font_variant::*;
+pub use font_weight::*;
+pub use grid::*;
+pub use id::*;
+pub use inset::*;
+pub use line_height::*;
+pub use logical::*;
+pub use margin::*;
+pub use overflow::*;
+pub use padding::*;
+pub use position::*;
+pub use size::*;
+pub use text::*;
+pub use visibility::*;
+pub use writing_mode::*;
+pub(crate) use shorthand::*;
 
-use crate::style::value::Keyword;
+use crate::style::{value::Keyword, ComputeContext, PropertyDeclaration, PropertyId, StyleError};
 
 use super::Value;
 
@@ -29,7 +580,31 @@ impl From<Keyword> for ExplicitDefaulting {
     }
 }
 
-#[derive(Clone, Copy)]
+/// A specified value and the computed value it resolves to.
+pub trait ToComputedValue: Sized + Clone {
+    type Computed: Clone;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed;
+}
+
+/// A longhand property, implemented from the property table.
+///
+/// The default value is the initial specified value.
+pub trait Longhand: ToComputedValue + Default {
+    const ID: PropertyId;
+    const NAME: &'static str;
+    /// Whether the property inherits by default.
+    const INHERITED: bool;
+
+    fn initial() -> Self::Computed;
+
+    fn parse(value: Value) -> Result<Self, StyleError>;
+
+    /// The value of a declaration of the property.
+    fn from_declaration(declaration: PropertyDeclaration) -> Option<Self>;
+}
+
+#[derive(Debug, Clone, Copy)]
 pub enum SpecProperty<T> {
     Initial,
     Inherit,
@@ -97,3 +672,20 @@ where
         }
     }
 }
+
+impl<T: Longhand> SpecProperty<T> {
+    /// Apply the explicit defaulting and compute the value.
+    ///
+    /// The root element inherits the initial value.
+    pub fn compute(&self, parent: Option<&T::Computed>, context: &ComputeContext) -> T::Computed {
+        let inherit = || parent.cloned().unwrap_or_else(T::initial);
+
+        match self {
+            Self::Initial => T::initial(),
+            Self::Inherit => inherit(),
+            Self::Unset if T::INHERITED => inherit(),
+            Self::Unset => T::initial(),
+            Self::Value(value) => value.compute(context),
+        }
+    }
+}
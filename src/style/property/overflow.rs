@@ -0,0 +1,202 @@
+//! The handling of the content overflowing a box.
+//!
+//! [CSS Overflow 3](https://drafts.csswg.org/css-overflow-3/)
+use crate::style::{ComputeContext, Length, Style, StyleError, ToComputedValue, Value};
+
+keyword_longhand!(
+    /// The handling of the content overflowing an axis of a box.
+    Overflow {
+        Visible,
+        Hidden,
+        Clip,
+        Scroll,
+        Auto,
+    },
+    &["visible", "hidden", "clip", "scroll", "auto"]
+);
+
+impl Overflow {
+    /// Whether the box is a scroll container in the axis.
+    pub fn is_scrollable(&self) -> bool {
+        matches!(self, Self::Hidden | Self::Scroll | Self::Auto)
+    }
+}
+
+longhand_newtypes!(Overflow: OverflowX, OverflowY);
+
+keyword_longhand!(
+    /// A box of the box model, from which an edge is drawn.
+    VisualBox {
+        PaddingBox,
+        ContentBox,
+        BorderBox,
+    },
+    &["content-box", "padding-box", "border-box"]
+);
+
+/// How far the content of a box clipped in an axis can be painted outside
+/// its bounds.
+///
+/// [CSS Overflow 4](https://drafts.csswg.org/css-overflow-4/#overflow-clip-margin)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverflowClipMargin {
+    /// The edge the margin is drawn from.
+    pub visual_box: VisualBox,
+    pub margin: Length,
+}
+
+impl Default for OverflowClipMargin {
+    fn default() -> Self {
+        Self {
+            visual_box: VisualBox::default(),
+            margin: Length::px(0),
+        }
+    }
+}
+
+impl TryFrom<Value> for OverflowClipMargin {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<visual-box>", "<length [0,∞]>"]);
+
+        let (mut visual_box, mut margin) = (None, None);
+        for value in value.iter() {
+            match value {
+                Value::Keyword(_) if visual_box.is_none() => {
+                    visual_box =
+                        Some(VisualBox::try_from(value.clone()).map_err(|_| invalid.clone())?);
+                }
+                // The unitless zero is a length.
+                Value::Number(number) if margin.is_none() && f32::from(*number) == 0.0 => {
+                    margin = Some(Length::px(0));
+                }
+                value if margin.is_none() => match Length::try_from(value.clone()) {
+                    Ok(length) if f32::from(length.quantity) >= 0.0 => margin = Some(length),
+                    _ => return Err(invalid),
+                },
+                _ => return Err(invalid),
+            }
+        }
+
+        if visual_box.is_none() && margin.is_none() {
+            return Err(invalid);
+        }
+
+        Ok(Self {
+            visual_box: visual_box.unwrap_or_default(),
+            margin: margin.unwrap_or(Length::px(0)),
+        })
+    }
+}
+
+impl From<OverflowClipMargin> for Value {
+    fn from(value: OverflowClipMargin) -> Self {
+        let zero = f32::from(value.margin.quantity) == 0.0;
+
+        match (value.visual_box, zero) {
+            (VisualBox::PaddingBox, _) => Self::from(value.margin),
+            (visual_box, true) => Self::from(visual_box),
+            (visual_box, false) => {
+                Self::Array(vec![Self::from(visual_box), Self::from(value.margin)])
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for OverflowClipMargin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+impl ToComputedValue for OverflowClipMargin {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        Self {
+            visual_box: self.visual_box,
+            margin: self.margin.resolve_relative(&context.length),
+        }
+    }
+}
+
+impl Style {
+    /// Make the axes agree on being a scroll container: visible computes to
+    /// auto and clip to hidden when the other axis scrolls.
+    pub(crate) fn compute_overflow(&mut self) {
+        let (x, y) = (self.overflow_x, self.overflow_y);
+        if x.is_scrollable() == y.is_scrollable() {
+            return;
+        }
+
+        let scrollable = |overflow: Overflow| match overflow {
+            Overflow::Visible => Overflow::Auto,
+            Overflow::Clip => Overflow::Hidden,
+            overflow => overflow,
+        };
+        self.overflow_x = scrollable(x);
+        self.overflow_y = scrollable(y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        Overflow, PropertyId,
+    };
+
+    #[test]
+    fn test_001_overflow() {
+        for (name, source, expected) in [
+            ("overflow-x", "clip", "clip"),
+            ("overflow", "hidden", "hidden"),
+            ("overflow", "hidden scroll", "hidden scroll"),
+            ("overflow", "auto auto", "auto"),
+            ("overflow", "visible clip", "visible clip"),
+            ("overflow-clip-margin", "1px", "1px"),
+            ("overflow-clip-margin", "content-box 1em", "content-box 1em"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("overflow-y", "overlay"),
+            ("overflow", "auto scroll hidden"),
+            ("overflow-clip-margin", "-1px"),
+            ("overflow-clip-margin", "10%"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px; overflow: visible scroll }
+            body { overflow: clip hidden; overflow-clip-margin: content-box 1em }
+            p { overflow: clip visible }",
+        );
+
+        // The axes agree on being a scroll container, and aren't inherited.
+        assert_eq!(
+            (styles[0].overflow_x, styles[0].overflow_y),
+            (Overflow::Auto, Overflow::Scroll)
+        );
+        assert_eq!(styles[1].overflow_x, Overflow::Hidden);
+        assert_eq!(
+            (styles[2].overflow_x, styles[2].overflow_y),
+            (Overflow::Clip, Overflow::Visible)
+        );
+        assert_eq!(
+            styles[1].value(PropertyId::OverflowClipMargin).to_string(),
+            "content-box 10px"
+        );
+        assert_eq!(
+            styles[2].value(PropertyId::OverflowClipMargin).to_string(),
+            "0px"
+        );
+    }
+}
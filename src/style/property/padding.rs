@@ -0,0 +1,106 @@
+use crate::{
+    geometry::Edge,
+    style::{ComputeContext, LengthPercentage, Style, StyleError, Value},
+};
+
+use super::ToComputedValue;
+
+/// The width of a padding, which can't be negative.
+///
+/// [CSS Box 4](https://drafts.csswg.org/css-box-4/#padding-physical)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Padding(LengthPercentage);
+
+impl Default for Padding {
+    fn default() -> Self {
+        Self(LengthPercentage::zero())
+    }
+}
+
+impl TryFrom<Value> for Padding {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match LengthPercentage::try_from(value) {
+            Ok(padding) if !padding.is_negative() => Ok(Self(padding)),
+            _ => Err(StyleError::InvalidValue(&["<length-percentage [0,∞]>"])),
+        }
+    }
+}
+
+impl From<Padding> for LengthPercentage {
+    fn from(value: Padding) -> Self {
+        value.0
+    }
+}
+
+impl From<Padding> for Value {
+    fn from(value: Padding) -> Self {
+        Self::from(value.0)
+    }
+}
+
+impl std::fmt::Display for Padding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ToComputedValue for Padding {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        Self(self.0.resolve_non_negative(&context.length))
+    }
+}
+
+longhand_newtypes!(Padding: PaddingTop, PaddingRight, PaddingBottom, PaddingLeft);
+longhand_newtypes!(Padding: PaddingBlockStart, PaddingBlockEnd, PaddingInlineStart, PaddingInlineEnd);
+
+impl Style {
+    /// The computed paddings.
+    pub fn padding(&self) -> Edge<LengthPercentage> {
+        Edge {
+            left: self.padding_left.clone().into(),
+            right: self.padding_right.clone().into(),
+            top: self.padding_top.clone().into(),
+            bottom: self.padding_bottom.clone().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_paddings() {
+        assert_eq!(round_trip("padding-left", "0"), "0px");
+        assert_eq!(round_trip("padding-left", "10%"), "10%");
+        assert_eq!(round_trip("padding", "1px 2px 3px"), "1px 2px 3px");
+        for invalid in ["-1px", "auto", "-10%"] {
+            assert_eq!(round_trip("padding-left", invalid), "", "{}", invalid);
+        }
+        assert_eq!(round_trip("padding", "1px -2px"), "");
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px }
+            body { padding: calc(10px - 2em) calc(1em - 50%) 2em }",
+        );
+        let padding = |index: usize, id| styles[index].value(id).to_string();
+
+        // The negative expressions are clamped once resolved.
+        assert_eq!(padding(1, PropertyId::PaddingTop), "0px");
+        assert_eq!(padding(1, PropertyId::PaddingLeft), "calc(10px - 50%)");
+        assert_eq!(padding(1, PropertyId::PaddingBottom), "20px");
+        assert_eq!(padding(2, PropertyId::PaddingBottom), "0px");
+    }
+}
@@ -0,0 +1,232 @@
+//! The positioning schemes, and the containing blocks they resolve to.
+//!
+//! [CSS Position 3](https://drafts.csswg.org/css-position-3/)
+use crate::style::{ComputeContext, Keyword, Number, Style, StyleError, ToComputedValue, Value};
+
+keyword_longhand!(
+    /// The positioning scheme of a box.
+    PositionScheme {
+        Static,
+        Relative,
+        Absolute,
+        Fixed,
+        Sticky,
+    },
+    &["static", "relative", "absolute", "fixed", "sticky"]
+);
+
+impl PositionScheme {
+    /// Whether the box is positioned, its insets applying.
+    pub fn is_positioned(&self) -> bool {
+        *self != Self::Static
+    }
+
+    /// Whether the box is taken out of the flow.
+    pub fn is_absolutely_positioned(&self) -> bool {
+        matches!(self, Self::Absolute | Self::Fixed)
+    }
+}
+
+/// The stacking level of a positioned box.
+///
+/// [CSS 2](https://www.w3.org/TR/CSS2/visuren.html#z-index)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ZIndex {
+    /// The box doesn't establish a stacking context.
+    #[default]
+    Auto,
+    Integer(i32),
+}
+
+impl TryFrom<Value> for ZIndex {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Auto) => Ok(Self::Auto),
+            Value::Number(Number::Int(integer)) => Ok(Self::Integer(integer)),
+            _ => Err(StyleError::InvalidValue(&["auto", "<integer>"])),
+        }
+    }
+}
+
+impl From<ZIndex> for Value {
+    fn from(value: ZIndex) -> Self {
+        match value {
+            ZIndex::Auto => Self::Keyword(Keyword::Auto),
+            ZIndex::Integer(integer) => Self::Number(Number::Int(integer)),
+        }
+    }
+}
+
+impl std::fmt::Display for ZIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Integer(integer) => write!(f, "{}", integer),
+        }
+    }
+}
+
+impl ToComputedValue for ZIndex {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+keyword_longhand!(
+    /// The side of its containing block a box is floated to.
+    ///
+    /// [CSS 2](https://www.w3.org/TR/CSS2/visuren.html#float-position)
+    Float {
+        None,
+        Left,
+        Right,
+        InlineStart,
+        InlineEnd,
+    },
+    &["none", "left", "right", "inline-start", "inline-end"]
+);
+keyword_longhand!(
+    /// The sides of a box which can't be next to earlier floats.
+    ///
+    /// [CSS 2](https://www.w3.org/TR/CSS2/visuren.html#flow-control)
+    Clear {
+        None,
+        Left,
+        Right,
+        InlineStart,
+        InlineEnd,
+        Both,
+    },
+    &["none", "left", "right", "inline-start", "inline-end", "both"]
+);
+
+/// The area of a box forming a containing block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainingBlockArea {
+    ContentBox,
+    PaddingBox,
+}
+
+/// The rectangle a box is sized and positioned relative to.
+///
+/// [CSS Display 3](https://drafts.csswg.org/css-display-3/#containing-block)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainingBlock {
+    /// An area of an ancestor, given by its depth, the parent being at zero.
+    Ancestor {
+        depth: usize,
+        area: ContainingBlockArea,
+    },
+    /// The containing block of the root element, and of the absolutely
+    /// positioned boxes without a positioned ancestor.
+    Initial,
+    /// The viewport, for the fixed positioned boxes.
+    Viewport,
+}
+
+impl Style {
+    /// Apply the computed-value rules between the positioning, the floats
+    /// and the display: the absolutely positioned boxes don't float, and
+    /// they, the floats and the root element are blockified.
+    ///
+    /// [CSS 2](https://www.w3.org/TR/CSS2/visuren.html#dis-pos-flo)
+    pub(crate) fn compute_positioning(&mut self, is_root: bool) {
+        if self.position.is_absolutely_positioned() {
+            self.float = Float::None;
+        }
+
+        if is_root || self.float != Float::None || self.position.is_absolutely_positioned() {
+            self.display = self.display.blockify();
+        }
+    }
+
+    /// The containing block of the box, given the styles of its ancestors
+    /// from the parent up.
+    ///
+    /// The boxes in the flow are contained by the content box of their
+    /// nearest block container ancestor, the absolutely positioned ones by
+    /// the padding box of their nearest positioned ancestor.
+    pub fn containing_block<'a>(
+        &self,
+        ancestors: impl IntoIterator<Item = &'a Style>,
+    ) -> ContainingBlock {
+        let mut ancestors = ancestors
+            .into_iter()
+            .enumerate()
+            .filter(|(_, ancestor)| !ancestor.display.is_contents());
+
+        let ancestor = match self.position {
+            PositionScheme::Fixed => return ContainingBlock::Viewport,
+            PositionScheme::Absolute => ancestors
+                .find(|(_, ancestor)| ancestor.position.is_positioned())
+                .map(|(depth, _)| (depth, ContainingBlockArea::PaddingBox)),
+            _ => ancestors
+                .find(|(_, ancestor)| !ancestor.display.is_inline_flow())
+                .map(|(depth, _)| (depth, ContainingBlockArea::ContentBox)),
+        };
+
+        match ancestor {
+            Some((depth, area)) => ContainingBlock::Ancestor { depth, area },
+            None => ContainingBlock::Initial,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        Display, Float, Keyword, PositionScheme, Value, ZIndex,
+    };
+
+    #[test]
+    fn test_001_positioning() {
+        for (name, source) in [
+            ("position", "sticky"),
+            ("float", "inline-start"),
+            ("clear", "both"),
+            ("clear", "inline-end"),
+            ("z-index", "auto"),
+            ("z-index", "-3"),
+        ] {
+            assert_eq!(round_trip(name, source), source);
+        }
+        for (name, invalid) in [
+            ("position", "relative absolute"),
+            ("float", "center"),
+            ("clear", "top"),
+            ("z-index", "1.5"),
+            ("z-index", "1px"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let span = doc.create(Some(body), "span", &[]);
+        doc.create(Some(span), "em", &[]);
+
+        let styles = doc.resolve(
+            "html { display: inline; z-index: 2 }
+            span { float: left; position: absolute; z-index: -1 }
+            em { float: inline-end }",
+        );
+        let block = Display::try_from(Value::from(Keyword::Block)).unwrap();
+
+        // The root is blockified, and the positioning isn't inherited.
+        assert_eq!(styles[0].display, block);
+        assert_eq!(styles[1].z_index, ZIndex::Auto);
+        // The absolutely positioned boxes don't float.
+        assert_eq!(styles[2].position, PositionScheme::Absolute);
+        assert_eq!(styles[2].float, Float::None);
+        assert_eq!(styles[2].display, block);
+        assert_eq!(styles[2].z_index, ZIndex::Integer(-1));
+        assert_eq!(styles[3].float, Float::InlineEnd);
+        assert_eq!(styles[3].display, block);
+    }
+}
@@ -0,0 +1,251 @@
+use crate::style::{
+    BackgroundAttachment, BackgroundClip, BackgroundColor, BackgroundImage, BackgroundOrigin,
+    BackgroundPosition, BackgroundRepeat, BackgroundSize, ComponentValue, Image, Keyword, Layers,
+    LengthPercentage, PropertyDeclaration, RepeatStyle, StyleError, Value,
+};
+
+use super::significant;
+
+/// The longhands set by a layer of the background shorthand.
+#[derive(Default)]
+struct Layer {
+    image: Option<BackgroundImage>,
+    position: Option<BackgroundPosition>,
+    size: Option<BackgroundSize>,
+    repeat: Option<BackgroundRepeat>,
+    attachment: Option<BackgroundAttachment>,
+    origin: Option<BackgroundOrigin>,
+    clip: Option<BackgroundClip>,
+    color: Option<BackgroundColor>,
+}
+
+fn is_position_term(value: &Value) -> bool {
+    match value {
+        Value::Keyword(kw) => matches!(
+            kw,
+            Keyword::Left | Keyword::Right | Keyword::Top | Keyword::Bottom | Keyword::Center
+        ),
+        value => LengthPercentage::try_from(value.clone()).is_ok(),
+    }
+}
+
+fn is_size_term(value: &Value) -> bool {
+    match value {
+        Value::Keyword(kw) => matches!(kw, Keyword::Auto | Keyword::Cover | Keyword::Contain),
+        value => LengthPercentage::try_from(value.clone()).is_ok(),
+    }
+}
+
+fn is_repeat_term(value: &Value) -> bool {
+    match value {
+        Value::Keyword(Keyword::RepeatX | Keyword::RepeatY) => true,
+        Value::Keyword(kw) => RepeatStyle::try_from(*kw).is_ok(),
+        _ => false,
+    }
+}
+
+/// The single value, or the space-separated values.
+fn array(mut values: Vec<Value>) -> Value {
+    match values.len() {
+        1 => values.remove(0),
+        _ => Value::Array(values),
+    }
+}
+
+/// Parse a layer, whose components may come in any order, the color being
+/// only allowed in the final layer.
+fn parse_layer(values: &[&ComponentValue], last: bool) -> Result<Layer, StyleError> {
+    let invalid = StyleError::InvalidValue(&["<bg-layer>", "<final-bg-layer>"]);
+
+    // The values from the start matching the predicate, at most max of them.
+    let take = |start: usize, max: usize, predicate: fn(&Value) -> bool| -> Vec<Value> {
+        values[start..]
+            .iter()
+            .map_while(|cv| Value::try_from(*cv).ok().filter(predicate))
+            .take(max)
+            .collect()
+    };
+
+    let mut layer = Layer::default();
+    let mut boxes = Vec::<Keyword>::default();
+    let mut index = 0;
+
+    while index < values.len() {
+        let value = Value::try_from(values[index])?;
+
+        if layer.position.is_none() && is_position_term(&value) {
+            let terms = take(index, 4, is_position_term);
+            index += terms.len();
+            layer.position = Some(BackgroundPosition::try_from(array(terms))?);
+
+            // The size may only follow the position.
+            if values.get(index).is_some_and(|cv| cv.is_delim_value("/")) {
+                let terms = take(index + 1, 2, is_size_term);
+                if terms.is_empty() {
+                    return Err(invalid);
+                }
+                index += 1 + terms.len();
+                layer.size = Some(BackgroundSize::try_from(array(terms))?);
+            }
+            continue;
+        }
+
+        if layer.repeat.is_none() && is_repeat_term(&value) {
+            let terms = take(index, 2, is_repeat_term);
+            index += terms.len();
+            layer.repeat = Some(BackgroundRepeat::try_from(array(terms))?);
+            continue;
+        }
+
+        index += 1;
+        match value {
+            Value::Keyword(kw)
+                if layer.attachment.is_none() && BackgroundAttachment::try_from(kw).is_ok() =>
+            {
+                layer.attachment = BackgroundAttachment::try_from(kw).ok();
+            }
+            Value::Keyword(kw) if BackgroundClip::try_from(kw).is_ok() => match boxes.len() {
+                0 | 1 => boxes.push(kw),
+                _ => return Err(invalid),
+            },
+            Value::Keyword(Keyword::None) if layer.image.is_none() => {
+                layer.image = Some(BackgroundImage::default());
+            }
+            value if layer.image.is_none() && Image::try_from(value.clone()).is_ok() => {
+                layer.image = Image::try_from(value).ok().map(BackgroundImage::from);
+            }
+            value if last && layer.color.is_none() => {
+                layer.color = Some(BackgroundColor::try_from(value)?);
+            }
+            _ => return Err(invalid),
+        }
+    }
+
+    // A single box sets both the origin and the clip, except text which only
+    // clips. With two boxes, the first is the origin, which can't be text.
+    match boxes.as_slice() {
+        [clip] => {
+            layer.origin = BackgroundOrigin::try_from(*clip).ok();
+            layer.clip = BackgroundClip::try_from(*clip).ok();
+        }
+        [origin, clip] => {
+            layer.origin = Some(BackgroundOrigin::try_from(*origin).map_err(|_| invalid)?);
+            layer.clip = BackgroundClip::try_from(*clip).ok();
+        }
+        _ => {}
+    }
+
+    Ok(layer)
+}
+
+/// A longhand of every layer, its omitted values being initial.
+fn layers<T: Default>(layers: &[Layer], longhand: impl Fn(&Layer) -> Option<T>) -> Layers<T> {
+    Layers::from(
+        layers
+            .iter()
+            .map(|layer| longhand(layer).unwrap_or_default())
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Parse the comma-separated layers of the background shorthand.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#background)
+pub(crate) fn parse_background(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let values = significant(value);
+    let count = values.split(|cv| cv.is_comma()).count();
+
+    let parsed = values
+        .split(|cv| cv.is_comma())
+        .enumerate()
+        .map(|(index, layer)| match layer.is_empty() {
+            true => Err(StyleError::InvalidValue(&["<bg-layer>"])),
+            false => parse_layer(layer, index + 1 == count),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(vec![
+        PropertyDeclaration::BackgroundImage(layers(&parsed, |layer| layer.image.clone())),
+        PropertyDeclaration::BackgroundPosition(layers(&parsed, |layer| layer.position.clone())),
+        PropertyDeclaration::BackgroundSize(layers(&parsed, |layer| layer.size.clone())),
+        PropertyDeclaration::BackgroundRepeat(layers(&parsed, |layer| layer.repeat)),
+        PropertyDeclaration::BackgroundAttachment(layers(&parsed, |layer| layer.attachment)),
+        PropertyDeclaration::BackgroundOrigin(layers(&parsed, |layer| layer.origin)),
+        PropertyDeclaration::BackgroundClip(layers(&parsed, |layer| layer.clip)),
+        PropertyDeclaration::BackgroundColor(
+            parsed
+                .last()
+                .and_then(|layer| layer.color.clone())
+                .unwrap_or_default(),
+        ),
+    ])
+}
+
+/// Serialize the background shorthand, omitting the initial values, none if
+/// the lists of the layers don't have the same length.
+pub(crate) fn serialize_background(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::BackgroundImage(images), PropertyDeclaration::BackgroundPosition(positions), PropertyDeclaration::BackgroundSize(sizes), PropertyDeclaration::BackgroundRepeat(repeats), PropertyDeclaration::BackgroundAttachment(attachments), PropertyDeclaration::BackgroundOrigin(origins), PropertyDeclaration::BackgroundClip(clips), PropertyDeclaration::BackgroundColor(color)] =
+        declarations
+    else {
+        return None;
+    };
+
+    let count = images.len();
+    let lengths = [
+        positions.len(),
+        sizes.len(),
+        repeats.len(),
+        attachments.len(),
+        origins.len(),
+        clips.len(),
+    ];
+    if lengths.iter().any(|length| *length != count) {
+        return None;
+    }
+
+    let layers = (0..count)
+        .map(|index| {
+            let mut values = Vec::<String>::default();
+
+            if images[index] != BackgroundImage::default() {
+                values.push(images[index].to_string());
+            }
+            match (&positions[index], &sizes[index]) {
+                (position, size) if *size != BackgroundSize::default() => {
+                    values.push(format!("{} / {}", position, size))
+                }
+                (position, _) if *position != BackgroundPosition::default() => {
+                    values.push(position.to_string())
+                }
+                _ => {}
+            }
+            if repeats[index] != BackgroundRepeat::default() {
+                values.push(repeats[index].to_string());
+            }
+            if attachments[index] != BackgroundAttachment::default() {
+                values.push(attachments[index].to_string());
+            }
+            match (origins[index], clips[index]) {
+                (origin, clip)
+                    if origin == BackgroundOrigin::default()
+                        && clip == BackgroundClip::default() => {}
+                (origin, clip) if Keyword::from(origin) == Keyword::from(clip) => {
+                    values.push(origin.to_string())
+                }
+                (origin, clip) => values.push(format!("{} {}", origin, clip)),
+            }
+            if index + 1 == count && *color != BackgroundColor::default() {
+                values.push(color.to_string());
+            }
+
+            match values.is_empty() {
+                true => "none".to_string(),
+                false => values.join(" "),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Some(layers.join(", "))
+}
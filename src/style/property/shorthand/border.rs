@@ -0,0 +1,374 @@
+use crate::style::{
+    BorderBlockEndColor, BorderBlockEndStyle, BorderBlockEndWidth, BorderBlockStartColor,
+    BorderBlockStartStyle, BorderBlockStartWidth, BorderBottomColor, BorderBottomLeftRadius,
+    BorderBottomRightRadius, BorderBottomStyle, BorderBottomWidth, BorderColor,
+    BorderInlineEndColor, BorderInlineEndStyle, BorderInlineEndWidth, BorderInlineStartColor,
+    BorderInlineStartStyle, BorderInlineStartWidth, BorderLeftColor, BorderLeftStyle,
+    BorderLeftWidth, BorderRadius, BorderRightColor, BorderRightStyle, BorderRightWidth,
+    BorderStyle, BorderTopColor, BorderTopLeftRadius, BorderTopRightRadius, BorderTopStyle,
+    BorderTopWidth, BorderWidth, ComponentValue, LengthPercentage, PropertyDeclaration, StyleError,
+    Value,
+};
+
+use super::{expand_sides, serialize_sides, significant};
+
+/// The width, style and color of a border line, in any order, each of which
+/// may be omitted.
+type Line = (
+    Option<BorderWidth>,
+    Option<BorderStyle>,
+    Option<BorderColor>,
+);
+
+fn parse_line(value: &[ComponentValue]) -> Result<Line, StyleError> {
+    let invalid = StyleError::InvalidValue(&["<line-width>", "<line-style>", "<color>"]);
+    let values = significant(value);
+    if values.is_empty() {
+        return Err(invalid);
+    }
+
+    let (mut width, mut style, mut color) = (None, None, None);
+    for cv in values {
+        match Value::try_from(cv)? {
+            value if width.is_none() && BorderWidth::try_from(value.clone()).is_ok() => {
+                width = BorderWidth::try_from(value).ok();
+            }
+            value if style.is_none() && BorderStyle::try_from(value.clone()).is_ok() => {
+                style = BorderStyle::try_from(value).ok();
+            }
+            value if color.is_none() && BorderColor::try_from(value.clone()).is_ok() => {
+                color = BorderColor::try_from(value).ok();
+            }
+            _ => return Err(invalid),
+        }
+    }
+
+    Ok((width, style, color))
+}
+
+/// Serialize a border line, omitting the initial values.
+fn serialize_line(width: &BorderWidth, style: &BorderStyle, color: &BorderColor) -> String {
+    let mut values = Vec::<String>::default();
+    if *width != BorderWidth::default() {
+        values.push(width.to_string());
+    }
+    if *style != BorderStyle::default() {
+        values.push(style.to_string());
+    }
+    if *color != BorderColor::default() {
+        values.push(color.to_string());
+    }
+
+    match values.is_empty() {
+        true => "none".to_string(),
+        false => values.join(" "),
+    }
+}
+
+/// Generate the parsing and serialization of the shorthand of a side of
+/// the border.
+macro_rules! border_side_shorthand {
+    ($parse:ident, $serialize:ident, [$width:ident, $style:ident, $color:ident]) => {
+        pub(crate) fn $parse(
+            value: &[ComponentValue],
+        ) -> Result<Vec<PropertyDeclaration>, StyleError> {
+            let (width, style, color) = parse_line(value)?;
+
+            let mut declarations = Vec::<PropertyDeclaration>::default();
+            declarations.extend(width.map(|width| PropertyDeclaration::$width($width(width))));
+            declarations.extend(style.map(|style| PropertyDeclaration::$style($style(style))));
+            declarations.extend(color.map(|color| PropertyDeclaration::$color($color(color))));
+            Ok(declarations)
+        }
+
+        pub(crate) fn $serialize(declarations: &[&PropertyDeclaration]) -> Option<String> {
+            match declarations {
+                [PropertyDeclaration::$width(width), PropertyDeclaration::$style(style), PropertyDeclaration::$color(color)] => {
+                    Some(serialize_line(width, style, color))
+                }
+                _ => None,
+            }
+        }
+    };
+}
+
+border_side_shorthand!(
+    parse_border_top,
+    serialize_border_top,
+    [BorderTopWidth, BorderTopStyle, BorderTopColor]
+);
+border_side_shorthand!(
+    parse_border_right,
+    serialize_border_right,
+    [BorderRightWidth, BorderRightStyle, BorderRightColor]
+);
+border_side_shorthand!(
+    parse_border_bottom,
+    serialize_border_bottom,
+    [BorderBottomWidth, BorderBottomStyle, BorderBottomColor]
+);
+border_side_shorthand!(
+    parse_border_left,
+    serialize_border_left,
+    [BorderLeftWidth, BorderLeftStyle, BorderLeftColor]
+);
+
+border_side_shorthand!(
+    parse_border_block_start,
+    serialize_border_block_start,
+    [
+        BorderBlockStartWidth,
+        BorderBlockStartStyle,
+        BorderBlockStartColor
+    ]
+);
+border_side_shorthand!(
+    parse_border_block_end,
+    serialize_border_block_end,
+    [
+        BorderBlockEndWidth,
+        BorderBlockEndStyle,
+        BorderBlockEndColor
+    ]
+);
+border_side_shorthand!(
+    parse_border_inline_start,
+    serialize_border_inline_start,
+    [
+        BorderInlineStartWidth,
+        BorderInlineStartStyle,
+        BorderInlineStartColor
+    ]
+);
+border_side_shorthand!(
+    parse_border_inline_end,
+    serialize_border_inline_end,
+    [
+        BorderInlineEndWidth,
+        BorderInlineEndStyle,
+        BorderInlineEndColor
+    ]
+);
+
+/// Generate the parsing and serialization of the shorthand setting the same
+/// line on both sides of an axis.
+macro_rules! border_axis_shorthand {
+    (
+        $parse:ident,
+        $serialize:ident,
+        [$start_width:ident, $end_width:ident, $start_style:ident, $end_style:ident, $start_color:ident, $end_color:ident]
+    ) => {
+        pub(crate) fn $parse(
+            value: &[ComponentValue],
+        ) -> Result<Vec<PropertyDeclaration>, StyleError> {
+            let (width, style, color) = parse_line(value)?;
+
+            let mut declarations = Vec::<PropertyDeclaration>::default();
+            if let Some(width) = width {
+                declarations.extend([
+                    PropertyDeclaration::$start_width($start_width(width.clone())),
+                    PropertyDeclaration::$end_width($end_width(width)),
+                ]);
+            }
+            if let Some(style) = style {
+                declarations.extend([
+                    PropertyDeclaration::$start_style($start_style(style)),
+                    PropertyDeclaration::$end_style($end_style(style)),
+                ]);
+            }
+            if let Some(color) = color {
+                declarations.extend([
+                    PropertyDeclaration::$start_color($start_color(color.clone())),
+                    PropertyDeclaration::$end_color($end_color(color)),
+                ]);
+            }
+            Ok(declarations)
+        }
+
+        /// Serialize the shorthand, none if the sides differ.
+        pub(crate) fn $serialize(declarations: &[&PropertyDeclaration]) -> Option<String> {
+            let [PropertyDeclaration::$start_width(start_width), PropertyDeclaration::$end_width(end_width), PropertyDeclaration::$start_style(start_style), PropertyDeclaration::$end_style(end_style), PropertyDeclaration::$start_color(start_color), PropertyDeclaration::$end_color(end_color)] =
+                declarations
+            else {
+                return None;
+            };
+
+            if **start_width != **end_width
+                || **start_style != **end_style
+                || **start_color != **end_color
+            {
+                return None;
+            }
+
+            Some(serialize_line(start_width, start_style, start_color))
+        }
+    };
+}
+
+border_axis_shorthand!(
+    parse_border_block,
+    serialize_border_block,
+    [
+        BorderBlockStartWidth,
+        BorderBlockEndWidth,
+        BorderBlockStartStyle,
+        BorderBlockEndStyle,
+        BorderBlockStartColor,
+        BorderBlockEndColor
+    ]
+);
+border_axis_shorthand!(
+    parse_border_inline,
+    serialize_border_inline,
+    [
+        BorderInlineStartWidth,
+        BorderInlineEndWidth,
+        BorderInlineStartStyle,
+        BorderInlineEndStyle,
+        BorderInlineStartColor,
+        BorderInlineEndColor
+    ]
+);
+
+/// Parse the border shorthand, setting the same line on the four sides.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#border-shorthands)
+pub(crate) fn parse_border(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let (width, style, color) = parse_line(value)?;
+
+    let mut declarations = Vec::<PropertyDeclaration>::default();
+    if let Some(width) = width {
+        declarations.extend([
+            PropertyDeclaration::BorderTopWidth(BorderTopWidth(width.clone())),
+            PropertyDeclaration::BorderRightWidth(BorderRightWidth(width.clone())),
+            PropertyDeclaration::BorderBottomWidth(BorderBottomWidth(width.clone())),
+            PropertyDeclaration::BorderLeftWidth(BorderLeftWidth(width)),
+        ]);
+    }
+    if let Some(style) = style {
+        declarations.extend([
+            PropertyDeclaration::BorderTopStyle(BorderTopStyle(style)),
+            PropertyDeclaration::BorderRightStyle(BorderRightStyle(style)),
+            PropertyDeclaration::BorderBottomStyle(BorderBottomStyle(style)),
+            PropertyDeclaration::BorderLeftStyle(BorderLeftStyle(style)),
+        ]);
+    }
+    if let Some(color) = color {
+        declarations.extend([
+            PropertyDeclaration::BorderTopColor(BorderTopColor(color.clone())),
+            PropertyDeclaration::BorderRightColor(BorderRightColor(color.clone())),
+            PropertyDeclaration::BorderBottomColor(BorderBottomColor(color.clone())),
+            PropertyDeclaration::BorderLeftColor(BorderLeftColor(color)),
+        ]);
+    }
+    Ok(declarations)
+}
+
+/// Serialize the border shorthand, none if the sides differ.
+pub(crate) fn serialize_border(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::BorderTopWidth(top_width), PropertyDeclaration::BorderRightWidth(right_width), PropertyDeclaration::BorderBottomWidth(bottom_width), PropertyDeclaration::BorderLeftWidth(left_width), PropertyDeclaration::BorderTopStyle(top_style), PropertyDeclaration::BorderRightStyle(right_style), PropertyDeclaration::BorderBottomStyle(bottom_style), PropertyDeclaration::BorderLeftStyle(left_style), PropertyDeclaration::BorderTopColor(top_color), PropertyDeclaration::BorderRightColor(right_color), PropertyDeclaration::BorderBottomColor(bottom_color), PropertyDeclaration::BorderLeftColor(left_color)] =
+        declarations
+    else {
+        return None;
+    };
+
+    let widths = [&**right_width, &**bottom_width, &**left_width];
+    let styles = [&**right_style, &**bottom_style, &**left_style];
+    let colors = [&**right_color, &**bottom_color, &**left_color];
+    if widths.iter().any(|width| *width != &**top_width)
+        || styles.iter().any(|style| *style != &**top_style)
+        || colors.iter().any(|color| *color != &**top_color)
+    {
+        return None;
+    }
+
+    Some(serialize_line(top_width, top_style, top_color))
+}
+
+/// Parse the one to four radii of the corners, clockwise from the top left
+/// one.
+fn parse_radii(values: &[&ComponentValue]) -> Result<[LengthPercentage; 4], StyleError> {
+    let invalid = StyleError::InvalidValue(&["<length-percentage [0,∞]>{1,4}"]);
+
+    let radii = values
+        .iter()
+        .map(
+            |cv| match Value::try_from(*cv).and_then(LengthPercentage::try_from) {
+                Ok(radius) if !radius.is_negative() => Ok(radius),
+                _ => Err(invalid.clone()),
+            },
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+
+    expand_sides(&radii).ok_or(invalid)
+}
+
+/// Parse the border-radius shorthand, the vertical radii following a slash
+/// being the horizontal ones if omitted.
+///
+/// [CSS Backgrounds 3](https://drafts.csswg.org/css-backgrounds-3/#border-radius)
+pub(crate) fn parse_border_radius(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let values = significant(value);
+    let mut halves = values.split(|cv| cv.is_delim_value("/"));
+
+    let horizontal = parse_radii(halves.next().unwrap_or_default())?;
+    let vertical = match halves.next() {
+        Some(vertical) => parse_radii(vertical)?,
+        None => horizontal.clone(),
+    };
+    if halves.next().is_some() {
+        return Err(StyleError::InvalidValue(&[
+            "<length-percentage [0,∞]>{1,4}",
+        ]));
+    }
+
+    let [top_left, top_right, bottom_right, bottom_left] = horizontal;
+    let [top_left_v, top_right_v, bottom_right_v, bottom_left_v] = vertical;
+    let radius = |horizontal, vertical| BorderRadius {
+        horizontal,
+        vertical,
+    };
+
+    Ok(vec![
+        PropertyDeclaration::BorderTopLeftRadius(BorderTopLeftRadius(radius(top_left, top_left_v))),
+        PropertyDeclaration::BorderTopRightRadius(BorderTopRightRadius(radius(
+            top_right,
+            top_right_v,
+        ))),
+        PropertyDeclaration::BorderBottomRightRadius(BorderBottomRightRadius(radius(
+            bottom_right,
+            bottom_right_v,
+        ))),
+        PropertyDeclaration::BorderBottomLeftRadius(BorderBottomLeftRadius(radius(
+            bottom_left,
+            bottom_left_v,
+        ))),
+    ])
+}
+
+/// Serialize the border-radius shorthand, the vertical radii only if they
+/// differ from the horizontal ones.
+pub(crate) fn serialize_border_radius(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::BorderTopLeftRadius(top_left), PropertyDeclaration::BorderTopRightRadius(top_right), PropertyDeclaration::BorderBottomRightRadius(bottom_right), PropertyDeclaration::BorderBottomLeftRadius(bottom_left)] =
+        declarations
+    else {
+        return None;
+    };
+
+    let corners = [&**top_left, &**top_right, &**bottom_right, &**bottom_left];
+    let horizontal = corners.map(|corner| &corner.horizontal);
+    let vertical = corners.map(|corner| &corner.vertical);
+
+    match horizontal == vertical {
+        true => Some(serialize_sides(horizontal)),
+        false => Some(format!(
+            "{} / {}",
+            serialize_sides(horizontal),
+            serialize_sides(vertical)
+        )),
+    }
+}
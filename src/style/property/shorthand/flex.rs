@@ -0,0 +1,192 @@
+use crate::style::{
+    ColumnGap, ComponentValue, FlexBasis, FlexDirection, FlexGrow, FlexShrink, FlexWrap, Keyword,
+    LengthPercentage, PropertyDeclaration, RowGap, Size, StyleError, Value,
+};
+
+use super::significant;
+
+/// Parse the flex-flow shorthand, its longhands in any order.
+///
+/// [CSS Flexbox 1](https://drafts.csswg.org/css-flexbox-1/#flex-flow-property)
+pub(crate) fn parse_flex_flow(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let invalid = StyleError::InvalidValue(&["<'flex-direction'>", "<'flex-wrap'>"]);
+    let values = significant(value);
+    if values.is_empty() {
+        return Err(invalid);
+    }
+
+    let (mut direction, mut wrap) = (None, None);
+    for cv in values {
+        match Value::try_from(cv)? {
+            value if direction.is_none() && FlexDirection::try_from(value.clone()).is_ok() => {
+                direction = FlexDirection::try_from(value).ok();
+            }
+            value if wrap.is_none() && FlexWrap::try_from(value.clone()).is_ok() => {
+                wrap = FlexWrap::try_from(value).ok();
+            }
+            _ => return Err(invalid),
+        }
+    }
+
+    let mut declarations = Vec::<PropertyDeclaration>::default();
+    declarations.extend(direction.map(PropertyDeclaration::FlexDirection));
+    declarations.extend(wrap.map(PropertyDeclaration::FlexWrap));
+    Ok(declarations)
+}
+
+/// Serialize the flex-flow shorthand, omitting the initial values.
+pub(crate) fn serialize_flex_flow(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::FlexDirection(direction), PropertyDeclaration::FlexWrap(wrap)] =
+        declarations
+    else {
+        return None;
+    };
+
+    match (
+        *direction == FlexDirection::default(),
+        *wrap == FlexWrap::default(),
+    ) {
+        (_, true) => Some(direction.to_string()),
+        (true, false) => Some(wrap.to_string()),
+        (false, false) => Some(format!("{} {}", direction, wrap)),
+    }
+}
+
+/// Parse the flex shorthand, the flex factors being adjacent. The omitted
+/// factors are one, and the omitted basis is zero.
+///
+/// [CSS Flexbox 1](https://drafts.csswg.org/css-flexbox-1/#flex-property)
+pub(crate) fn parse_flex(value: &[ComponentValue]) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let invalid =
+        StyleError::InvalidValue(&["none", "<'flex-grow'> <'flex-shrink'>?", "<'flex-basis'>"]);
+    let values = significant(value)
+        .into_iter()
+        .map(Value::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let keyword = match values.as_slice() {
+        [Value::Keyword(Keyword::None)] => Some(0.0),
+        [Value::Keyword(Keyword::Auto)] => Some(1.0),
+        _ => None,
+    };
+    if let Some(factor) = keyword {
+        return Ok(vec![
+            PropertyDeclaration::FlexGrow(FlexGrow(factor)),
+            PropertyDeclaration::FlexShrink(FlexShrink(factor)),
+            PropertyDeclaration::FlexBasis(FlexBasis::default()),
+        ]);
+    }
+
+    let (mut factors, mut basis) = (Vec::<f32>::default(), None);
+    for value in values {
+        let factor = match &value {
+            // A unitless zero after both factors is the basis.
+            Value::Number(number) if factors.len() < 2 && f32::from(*number) >= 0.0 => {
+                Some(f32::from(*number))
+            }
+            _ => None,
+        };
+
+        match factor {
+            // The factors can't be on both sides of the basis.
+            Some(factor) if basis.is_none() || factors.is_empty() => factors.push(factor),
+            _ if basis.is_none() => {
+                basis = Some(FlexBasis::try_from(value).map_err(|_| invalid.clone())?)
+            }
+            _ => return Err(invalid),
+        }
+    }
+
+    let (grow, shrink) = match factors.as_slice() {
+        [] => (1.0, 1.0),
+        [grow] => (*grow, 1.0),
+        [grow, shrink] => (*grow, *shrink),
+        _ => return Err(invalid),
+    };
+    let basis = match (basis, factors.is_empty()) {
+        (Some(basis), _) => basis,
+        (None, false) => FlexBasis::Size(Size::LengthPercentage(LengthPercentage::Percentage(0.0))),
+        (None, true) => return Err(invalid),
+    };
+
+    Ok(vec![
+        PropertyDeclaration::FlexGrow(FlexGrow(grow)),
+        PropertyDeclaration::FlexShrink(FlexShrink(shrink)),
+        PropertyDeclaration::FlexBasis(basis),
+    ])
+}
+
+pub(crate) fn serialize_flex(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::FlexGrow(grow), PropertyDeclaration::FlexShrink(shrink), PropertyDeclaration::FlexBasis(basis)] =
+        declarations
+    else {
+        return None;
+    };
+
+    Some(format!("{} {} {}", grow, shrink, basis))
+}
+
+/// Parse the gap shorthand, the column gap defaulting to the row gap.
+///
+/// [CSS Box Alignment 3](https://drafts.csswg.org/css-align-3/#gap-shorthand)
+pub(crate) fn parse_gap(value: &[ComponentValue]) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let values = significant(value)
+        .into_iter()
+        .map(Value::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (row, column) = match values.as_slice() {
+        [row] => (row, row),
+        [row, column] => (row, column),
+        _ => return Err(StyleError::InvalidValue(&["<'row-gap'> <'column-gap'>?"])),
+    };
+
+    Ok(vec![
+        PropertyDeclaration::RowGap(RowGap::try_from(row.clone())?),
+        PropertyDeclaration::ColumnGap(ColumnGap::try_from(column.clone())?),
+    ])
+}
+
+pub(crate) fn serialize_gap(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::RowGap(row), PropertyDeclaration::ColumnGap(column)] = declarations
+    else {
+        return None;
+    };
+
+    match **row == **column {
+        true => Some(row.to_string()),
+        false => Some(format!("{} {}", row, column)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::testing::round_trip;
+
+    #[test]
+    fn test_001_flex_shorthands() {
+        for (name, source, expected) in [
+            ("flex", "none", "0 0 auto"),
+            ("flex", "auto", "1 1 auto"),
+            ("flex", "2", "2 1 0%"),
+            ("flex", "2 3em", "2 1 3em"),
+            ("flex", "10px 2", "2 1 10px"),
+            ("flex", "0 0 10px", "0 0 10px"),
+            ("flex-flow", "column wrap", "column wrap"),
+            ("flex-flow", "wrap", "wrap"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("flex", "1 2 3"),
+            ("flex", "none 1"),
+            ("flex", "-1"),
+            ("flex-flow", "row row"),
+            ("flex-flow", "wrap nowrap"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use crate::style::{
+    ComponentValue, FontFamily, FontSize, FontStretch, FontStyle, FontVariantAlternates,
+    FontVariantCaps, FontVariantEastAsian, FontVariantEmoji, FontVariantLigatures,
+    FontVariantNumeric, FontVariantPosition, FontWeight, Keyword, LineHeight, PropertyDeclaration,
+    StyleError, Value,
+};
+
+use super::significant;
+
+/// The values of the font shorthand preceding the size, in any order.
+#[derive(Default)]
+struct FontPrefix {
+    style: Option<FontStyle>,
+    caps: Option<FontVariantCaps>,
+    weight: Option<FontWeight>,
+    stretch: Option<FontStretch>,
+}
+
+impl FontPrefix {
+    /// Add a value, the angle of an oblique style being the next one, and
+    /// return the number of values consumed, none if it isn't a prefix value.
+    fn add(&mut self, values: &[Value]) -> Option<usize> {
+        let value = values.first()?;
+
+        match value {
+            // Normal sets none of the values, they are reset anyway.
+            Value::Keyword(Keyword::Normal) => Some(1),
+            Value::Keyword(Keyword::Oblique) if self.style.is_none() => match values.get(1) {
+                Some(angle @ Value::Angle(_)) => {
+                    let oblique = Value::Array(vec![value.clone(), angle.clone()]);
+                    self.style = Some(FontStyle::try_from(oblique).ok()?);
+                    Some(2)
+                }
+                _ => {
+                    self.style = Some(FontStyle::Oblique(FontStyle::default_oblique_angle()));
+                    Some(1)
+                }
+            },
+            Value::Keyword(Keyword::Italic) if self.style.is_none() => {
+                self.style = Some(FontStyle::Italic);
+                Some(1)
+            }
+            Value::Keyword(Keyword::SmallCaps) if self.caps.is_none() => {
+                self.caps = Some(FontVariantCaps::SmallCaps);
+                Some(1)
+            }
+            Value::Keyword(keyword) if self.stretch.is_none() => {
+                let stretch = FontStretch::from_keyword(*keyword)
+                    .filter(|stretch| *stretch != FontStretch::Normal);
+                match stretch {
+                    Some(stretch) => {
+                        self.stretch = Some(stretch);
+                        Some(1)
+                    }
+                    None => self.add_weight(value),
+                }
+            }
+            value => self.add_weight(value),
+        }
+    }
+
+    fn add_weight(&mut self, value: &Value) -> Option<usize> {
+        if self.weight.is_some() {
+            return None;
+        }
+
+        self.weight = Some(FontWeight::try_from(value.clone()).ok()?);
+        Some(1)
+    }
+}
+
+/// Parse the font shorthand, resetting the font variants it can't set.
+///
+/// [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#font-prop)
+pub(crate) fn parse_font(value: &[ComponentValue]) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let invalid = StyleError::InvalidValue(&[
+        "<font-style>",
+        "<font-variant-css2>",
+        "<font-weight>",
+        "<font-width-css3>",
+        "<font-size>",
+        "<line-height>",
+        "<font-family>",
+    ]);
+
+    let values = significant(value);
+    let split = values
+        .iter()
+        .position(|cv| cv.is_comma() || cv.is_delim_value("/"))
+        .unwrap_or(values.len());
+
+    // The values before the families or the line height, the size being the
+    // last one unless the line height follows.
+    let head = values[..split]
+        .iter()
+        .map(|cv| Value::try_from(*cv))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (size_index, line_height, families) =
+        match values.get(split).filter(|cv| cv.is_delim_value("/")) {
+            Some(_) => {
+                let line_height = values.get(split + 1).ok_or(invalid.clone())?;
+                let line_height = LineHeight::try_from(Value::try_from(*line_height)?)?;
+                (
+                    head.len().checked_sub(1),
+                    Some(line_height),
+                    &values[split + 2..],
+                )
+            }
+            None => {
+                // Without a line height, the first family follows the size.
+                let size = head.iter().position(|value| {
+                    FontSize::try_from(value.clone()).is_ok() && !matches!(value, Value::Number(_))
+                });
+                (size, None, &values[size.map_or(split, |size| size + 1)..])
+            }
+        };
+
+    let size_index = size_index.ok_or(invalid.clone())?;
+    let size = FontSize::try_from(head[size_index].clone()).map_err(|_| invalid.clone())?;
+
+    let mut prefix = FontPrefix::default();
+    let mut index = 0;
+    while index < size_index {
+        index += prefix
+            .add(&head[index..size_index])
+            .ok_or(invalid.clone())?;
+    }
+
+    let family = parse_families(families).map_err(|_| invalid)?;
+
+    Ok(vec![
+        PropertyDeclaration::FontStyle(prefix.style.unwrap_or_default()),
+        PropertyDeclaration::FontVariantCaps(prefix.caps.unwrap_or_default()),
+        PropertyDeclaration::FontWeight(prefix.weight.unwrap_or_default()),
+        PropertyDeclaration::FontStretch(prefix.stretch.unwrap_or_default()),
+        PropertyDeclaration::FontSize(size),
+        PropertyDeclaration::LineHeight(line_height.unwrap_or_default()),
+        PropertyDeclaration::FontFamily(family),
+        PropertyDeclaration::FontVariantLigatures(FontVariantLigatures::default()),
+        PropertyDeclaration::FontVariantAlternates(FontVariantAlternates::default()),
+        PropertyDeclaration::FontVariantNumeric(FontVariantNumeric::default()),
+        PropertyDeclaration::FontVariantEastAsian(FontVariantEastAsian::default()),
+        PropertyDeclaration::FontVariantPosition(FontVariantPosition::default()),
+        PropertyDeclaration::FontVariantEmoji(FontVariantEmoji::default()),
+    ])
+}
+
+/// Parse the comma-separated families.
+fn parse_families(values: &[&ComponentValue]) -> Result<FontFamily, StyleError> {
+    let families = values
+        .split(|cv| cv.is_comma())
+        .map(|family| match family {
+            [] => Err(StyleError::InvalidValue(&["<font-family>"])),
+            [value] => Value::try_from(*value),
+            values => values.iter().map(|cv| Value::try_from(*cv)).collect(),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    FontFamily::try_from(Value::List(families))
+}
+
+/// Serialize the font shorthand, none if the variants can't be represented.
+pub(crate) fn serialize_font(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::FontStyle(style), PropertyDeclaration::FontVariantCaps(caps), PropertyDeclaration::FontWeight(weight), PropertyDeclaration::FontStretch(stretch), PropertyDeclaration::FontSize(size), PropertyDeclaration::LineHeight(line_height), PropertyDeclaration::FontFamily(family), PropertyDeclaration::FontVariantLigatures(ligatures), PropertyDeclaration::FontVariantAlternates(alternates), PropertyDeclaration::FontVariantNumeric(numeric), PropertyDeclaration::FontVariantEastAsian(east_asian), PropertyDeclaration::FontVariantPosition(position), PropertyDeclaration::FontVariantEmoji(emoji)] =
+        declarations
+    else {
+        return None;
+    };
+
+    let variants_reset = *ligatures == FontVariantLigatures::default()
+        && *alternates == FontVariantAlternates::default()
+        && *numeric == FontVariantNumeric::default()
+        && *east_asian == FontVariantEastAsian::default()
+        && *position == FontVariantPosition::default()
+        && *emoji == FontVariantEmoji::default();
+    if !variants_reset
+        || !matches!(caps, FontVariantCaps::Normal | FontVariantCaps::SmallCaps)
+        || matches!(stretch, FontStretch::Percentage(_))
+    {
+        return None;
+    }
+
+    let mut values = Vec::<String>::default();
+    if *style != FontStyle::default() {
+        values.push(style.to_string());
+    }
+    if *caps != FontVariantCaps::default() {
+        values.push(caps.to_string());
+    }
+    if *weight != FontWeight::default() {
+        values.push(weight.to_string());
+    }
+    if *stretch != FontStretch::default() {
+        values.push(stretch.to_string());
+    }
+    match *line_height == LineHeight::default() {
+        true => values.push(size.to_string()),
+        false => values.push(format!("{}/{}", size, line_height)),
+    }
+    values.push(family.to_string());
+
+    Some(values.join(" "))
+}
+
+/// Parse the font-variant shorthand, none disabling the ligatures.
+///
+/// [CSS Fonts 4](https://drafts.csswg.org/css-fonts-4/#font-variant-prop)
+pub(crate) fn parse_font_variant(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let invalid = StyleError::InvalidValue(&[
+        "normal",
+        "none",
+        "<font-variant-ligatures>",
+        "<font-variant-caps>",
+        "<font-variant-alternates>",
+        "<font-variant-numeric>",
+        "<font-variant-east-asian>",
+        "<font-variant-position>",
+        "<font-variant-emoji>",
+    ]);
+
+    let keywords = significant(value)
+        .into_iter()
+        .map(|cv| match Value::try_from(cv) {
+            Ok(Value::Keyword(keyword)) => Ok(keyword),
+            _ => Err(invalid.clone()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut ligatures = FontVariantLigatures::default();
+    let (mut caps, mut alternates, mut position, mut emoji) = (None, None, None, None);
+    let mut numeric = FontVariantNumeric::default();
+    let mut east_asian = FontVariantEastAsian::default();
+
+    match keywords.as_slice() {
+        [Keyword::Normal] => {}
+        [Keyword::None] => ligatures.none = true,
+        keywords => {
+            for keyword in keywords {
+                let added = ligatures.add(*keyword)
+                    || numeric.add(*keyword)
+                    || east_asian.add(*keyword)
+                    || set_keyword(&mut caps, *keyword, FontVariantCaps::from_keyword)
+                    || set_keyword(
+                        &mut alternates,
+                        *keyword,
+                        FontVariantAlternates::from_keyword,
+                    )
+                    || set_keyword(&mut position, *keyword, FontVariantPosition::from_keyword)
+                    || set_keyword(&mut emoji, *keyword, FontVariantEmoji::from_keyword);
+                if !added {
+                    return Err(invalid);
+                }
+            }
+        }
+    }
+
+    Ok(vec![
+        PropertyDeclaration::FontVariantLigatures(ligatures),
+        PropertyDeclaration::FontVariantCaps(caps.unwrap_or_default()),
+        PropertyDeclaration::FontVariantAlternates(alternates.unwrap_or_default()),
+        PropertyDeclaration::FontVariantNumeric(numeric),
+        PropertyDeclaration::FontVariantEastAsian(east_asian),
+        PropertyDeclaration::FontVariantPosition(position.unwrap_or_default()),
+        PropertyDeclaration::FontVariantEmoji(emoji.unwrap_or_default()),
+    ])
+}
+
+/// Set the value of a keyword longhand, failing if the keyword isn't one of
+/// its values other than normal, or if it is already set.
+fn set_keyword<T>(field: &mut Option<T>, keyword: Keyword, from: fn(Keyword) -> Option<T>) -> bool {
+    if field.is_some() || keyword == Keyword::Normal {
+        return false;
+    }
+
+    *field = from(keyword);
+    field.is_some()
+}
+
+/// Serialize the font-variant shorthand, none if the ligatures are disabled
+/// along with other variants.
+pub(crate) fn serialize_font_variant(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::FontVariantLigatures(ligatures), PropertyDeclaration::FontVariantCaps(caps), PropertyDeclaration::FontVariantAlternates(alternates), PropertyDeclaration::FontVariantNumeric(numeric), PropertyDeclaration::FontVariantEastAsian(east_asian), PropertyDeclaration::FontVariantPosition(position), PropertyDeclaration::FontVariantEmoji(emoji)] =
+        declarations
+    else {
+        return None;
+    };
+
+    let values = [
+        (*ligatures != FontVariantLigatures::default()).then(|| ligatures.to_string()),
+        (*caps != FontVariantCaps::default()).then(|| caps.to_string()),
+        (*alternates != FontVariantAlternates::default()).then(|| alternates.to_string()),
+        (*numeric != FontVariantNumeric::default()).then(|| numeric.to_string()),
+        (*east_asian != FontVariantEastAsian::default()).then(|| east_asian.to_string()),
+        (*position != FontVariantPosition::default()).then(|| position.to_string()),
+        (*emoji != FontVariantEmoji::default()).then(|| emoji.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    match values.as_slice() {
+        [] => Some("normal".to_string()),
+        [_, _, ..] if ligatures.none => None,
+        _ => Some(values.join(" ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        Length, PropertyId,
+    };
+
+    #[test]
+    fn test_001_font() {
+        for (source, expected) in [
+            ("12px serif", "12px serif"),
+            (
+                "bold italic 12px/1.5 Georgia, serif",
+                "italic bold 12px/1.5 Georgia, serif",
+            ),
+            ("condensed 12px serif", "condensed 12px serif"),
+            (
+                "small-caps 1em \"Times New Roman\"",
+                "small-caps 1em \"Times New Roman\"",
+            ),
+            ("normal normal 12px serif", "12px serif"),
+        ] {
+            assert_eq!(round_trip("font", source), expected, "{}", source);
+        }
+        for invalid in [
+            "12px",
+            "serif 12px",
+            "12px/ serif",
+            "bold bold bold bold 12px serif",
+        ] {
+            assert_eq!(round_trip("font", invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        // The omitted longhands are reset, rather than inherited.
+        let styles = doc.resolve(
+            "html { font-variant-caps: small-caps; line-height: 2 }
+            body { font: italic 2em/150% serif }",
+        );
+        let body = &styles[1];
+        assert_eq!(body.font_size, Length::px(32.0));
+        assert_eq!(body.value(PropertyId::LineHeight).to_string(), "48px");
+        assert_eq!(
+            body.value(PropertyId::FontVariantCaps).to_string(),
+            "normal"
+        );
+        assert_eq!(body.value(PropertyId::FontStyle).to_string(), "italic");
+    }
+}
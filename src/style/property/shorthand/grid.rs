@@ -0,0 +1,478 @@
+use crate::style::{
+    ComponentValue, GridAutoColumns, GridAutoFlow, GridAutoRows, GridAutoTracks, GridColumnEnd,
+    GridColumnStart, GridLine, GridRowEnd, GridRowStart, GridTemplate, GridTemplateAreas,
+    GridTemplateColumns, GridTemplateRows, Keyword, PropertyDeclaration, StyleError, TemplateAreas,
+    Track, TrackList, TrackSize, Value,
+};
+
+/// The values of a shorthand between slashes.
+fn slash_separated(value: &[ComponentValue]) -> Result<Vec<Value>, StyleError> {
+    value
+        .split(|cv| cv.is_delim_value("/"))
+        .map(Value::try_from)
+        .collect()
+}
+
+/// Parse up to `max` grid lines separated by slashes.
+fn parse_grid_lines(value: &[ComponentValue], max: usize) -> Result<Vec<GridLine>, StyleError> {
+    let lines = slash_separated(value)?
+        .into_iter()
+        .map(GridLine::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match lines.len() <= max {
+        true => Ok(lines),
+        false => Err(StyleError::InvalidValue(&[
+            "<grid-line> [ / <grid-line> ]?",
+        ])),
+    }
+}
+
+/// Parse the grid-row shorthand.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#placement-shorthands)
+pub(crate) fn parse_grid_row(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let lines = parse_grid_lines(value, 2)?;
+    let end = lines
+        .get(1)
+        .cloned()
+        .unwrap_or_else(|| lines[0].omitted_end());
+
+    Ok(vec![
+        PropertyDeclaration::GridRowStart(GridRowStart(lines[0].clone())),
+        PropertyDeclaration::GridRowEnd(GridRowEnd(end)),
+    ])
+}
+
+/// Parse the grid-column shorthand.
+pub(crate) fn parse_grid_column(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let lines = parse_grid_lines(value, 2)?;
+    let end = lines
+        .get(1)
+        .cloned()
+        .unwrap_or_else(|| lines[0].omitted_end());
+
+    Ok(vec![
+        PropertyDeclaration::GridColumnStart(GridColumnStart(lines[0].clone())),
+        PropertyDeclaration::GridColumnEnd(GridColumnEnd(end)),
+    ])
+}
+
+/// Serialize a start and an end line, the end being omitted when it can be
+/// derived from the start.
+fn serialize_line_pair(start: &GridLine, end: &GridLine) -> String {
+    match *end == start.omitted_end() {
+        true => start.to_string(),
+        false => format!("{} / {}", start, end),
+    }
+}
+
+pub(crate) fn serialize_grid_row(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::GridRowStart(start), PropertyDeclaration::GridRowEnd(end)] =
+        declarations
+    else {
+        return None;
+    };
+
+    Some(serialize_line_pair(start, end))
+}
+
+pub(crate) fn serialize_grid_column(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::GridColumnStart(start), PropertyDeclaration::GridColumnEnd(end)] =
+        declarations
+    else {
+        return None;
+    };
+
+    Some(serialize_line_pair(start, end))
+}
+
+/// Parse the grid-area shorthand, given as the row start, column start, row
+/// end and column end lines.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#propdef-grid-area)
+pub(crate) fn parse_grid_area(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let lines = parse_grid_lines(value, 4)?;
+    let row_start = lines[0].clone();
+    let column_start = lines
+        .get(1)
+        .cloned()
+        .unwrap_or_else(|| row_start.omitted_end());
+    let row_end = lines
+        .get(2)
+        .cloned()
+        .unwrap_or_else(|| row_start.omitted_end());
+    let column_end = lines
+        .get(3)
+        .cloned()
+        .unwrap_or_else(|| column_start.omitted_end());
+
+    Ok(vec![
+        PropertyDeclaration::GridRowStart(GridRowStart(row_start)),
+        PropertyDeclaration::GridColumnStart(GridColumnStart(column_start)),
+        PropertyDeclaration::GridRowEnd(GridRowEnd(row_end)),
+        PropertyDeclaration::GridColumnEnd(GridColumnEnd(column_end)),
+    ])
+}
+
+/// Serialize the grid-area shorthand, omitting the trailing lines which can
+/// be derived from the others.
+pub(crate) fn serialize_grid_area(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::GridRowStart(row_start), PropertyDeclaration::GridColumnStart(column_start), PropertyDeclaration::GridRowEnd(row_end), PropertyDeclaration::GridColumnEnd(column_end)] =
+        declarations
+    else {
+        return None;
+    };
+
+    let mut lines = vec![&**row_start, &**column_start, &**row_end, &**column_end];
+    if **column_end == column_start.omitted_end() {
+        lines.pop();
+        if **row_end == row_start.omitted_end() {
+            lines.pop();
+            if **column_start == row_start.omitted_end() {
+                lines.pop();
+            }
+        }
+    }
+
+    Some(
+        lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" / "),
+    )
+}
+
+/// Parse the rows of the template form of grid-template, each of them being
+/// a string of cells, with a track size and line names around it.
+fn parse_template_rows(value: Value) -> Result<(TrackList, TemplateAreas), StyleError> {
+    let invalid =
+        StyleError::InvalidValue(&["[ <line-names>? <string> <track-size>? <line-names>? ]+"]);
+    let mut values = value.into_iter().peekable();
+    let (mut line_names, mut rows, mut sizes) =
+        (vec![Vec::default()], Vec::default(), Vec::default());
+
+    while values.peek().is_some() {
+        if let Some(Value::LineNames(names)) =
+            values.next_if(|value| matches!(value, Value::LineNames(_)))
+        {
+            line_names.last_mut().unwrap().extend(names);
+        }
+        let Some(Value::String(row)) = values.next() else {
+            return Err(invalid);
+        };
+        let size = values
+            .next_if(|value| !matches!(value, Value::LineNames(_) | Value::String(_)))
+            .map(TrackSize::try_from)
+            .transpose()?;
+        let names = match values.next_if(|value| matches!(value, Value::LineNames(_))) {
+            Some(Value::LineNames(names)) => names,
+            _ => Vec::default(),
+        };
+
+        rows.push(row);
+        sizes.push(Track::Size(size.unwrap_or_default()));
+        line_names.push(names);
+    }
+
+    let areas = TemplateAreas::try_from(rows.as_slice())?;
+    Ok((
+        TrackList {
+            line_names,
+            tracks: sizes,
+        },
+        areas,
+    ))
+}
+
+/// Parse the grid-template shorthand: none, the rows and columns, or the
+/// named areas with the rows in between and optionally the columns.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#explicit-grid-shorthand)
+pub(crate) fn parse_grid_template(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let invalid = StyleError::InvalidValue(&[
+        "none",
+        "<'grid-template-rows'> / <'grid-template-columns'>",
+        "[ <line-names>? <string> <track-size>? <line-names>? ]+ [ / <explicit-track-list> ]?",
+    ]);
+    let mut parts = slash_separated(value)?;
+    let has_strings = parts[0].iter().any(Value::is_string);
+
+    let (rows, columns, areas) = match (parts.len(), has_strings) {
+        (1, false) if parts[0] == Value::Keyword(Keyword::None) => (
+            GridTemplate::None,
+            GridTemplate::None,
+            GridTemplateAreas::None,
+        ),
+        (2, false) => {
+            let columns = GridTemplate::try_from(parts.remove(1))?;
+            let rows = GridTemplate::try_from(parts.remove(0))?;
+            (rows, columns, GridTemplateAreas::None)
+        }
+        (1 | 2, true) => {
+            let columns = match parts.get(1).cloned().map(TrackList::try_from).transpose()? {
+                Some(columns) if !columns.is_explicit() => return Err(invalid),
+                Some(columns) => GridTemplate::TrackList(columns),
+                None => GridTemplate::None,
+            };
+            let (rows, areas) = parse_template_rows(parts.remove(0))?;
+            (
+                GridTemplate::TrackList(rows),
+                columns,
+                GridTemplateAreas::Areas(areas),
+            )
+        }
+        _ => return Err(invalid),
+    };
+
+    Ok(vec![
+        PropertyDeclaration::GridTemplateRows(GridTemplateRows(rows)),
+        PropertyDeclaration::GridTemplateColumns(GridTemplateColumns(columns)),
+        PropertyDeclaration::GridTemplateAreas(areas),
+    ])
+}
+
+/// Serialize the grid-template shorthand, none if the rows don't match the
+/// named areas.
+pub(crate) fn serialize_grid_template(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::GridTemplateRows(rows), PropertyDeclaration::GridTemplateColumns(columns), PropertyDeclaration::GridTemplateAreas(areas)] =
+        declarations
+    else {
+        return None;
+    };
+
+    let areas = match areas {
+        GridTemplateAreas::None
+            if **rows == GridTemplate::None && **columns == GridTemplate::None =>
+        {
+            return Some(Keyword::None.to_string())
+        }
+        GridTemplateAreas::None => return Some(format!("{} / {}", rows, columns)),
+        GridTemplateAreas::Areas(areas) => areas,
+    };
+    let rows = match &**rows {
+        GridTemplate::TrackList(rows)
+            if rows.is_explicit() && rows.tracks.len() == areas.rows() =>
+        {
+            rows
+        }
+        _ => return None,
+    };
+
+    let mut values = Vec::default();
+    for ((names, row), track) in rows
+        .line_names
+        .iter()
+        .zip(areas.row_strings())
+        .zip(&rows.tracks)
+    {
+        if !names.is_empty() {
+            values.push(Value::LineNames(names.clone()));
+        }
+        values.push(Value::String(row));
+        match track {
+            Track::Size(size) if *size == TrackSize::default() => {}
+            track => values.push(Value::from(track.clone())),
+        }
+    }
+    values.extend(
+        rows.line_names
+            .last()
+            .filter(|names| !names.is_empty())
+            .map(|names| Value::LineNames(names.clone())),
+    );
+    let template = Value::Array(values).to_string();
+
+    match &**columns {
+        GridTemplate::None => Some(template),
+        GridTemplate::TrackList(columns) if columns.is_explicit() => {
+            Some(format!("{} / {}", template, columns))
+        }
+        _ => None,
+    }
+}
+
+/// Parse `auto-flow && dense?` followed by the sizes of the implicit tracks.
+fn parse_auto_flow(
+    value: Value,
+    column: bool,
+) -> Result<(GridAutoFlow, Option<GridAutoTracks>), StyleError> {
+    let mut values = value.into_iter().collect::<Vec<_>>();
+    let keywords = values
+        .iter()
+        .take(2)
+        .take_while(|value| matches!(value, Value::Keyword(Keyword::AutoFlow | Keyword::Dense)))
+        .count();
+    let dense = values[..keywords].contains(&Value::Keyword(Keyword::Dense));
+    let auto_flow = values[..keywords].contains(&Value::Keyword(Keyword::AutoFlow));
+    if !auto_flow || keywords != 1 + dense as usize {
+        return Err(StyleError::InvalidValue(&["auto-flow && dense?"]));
+    }
+
+    let sizes = values.split_off(keywords);
+    let sizes = match sizes.is_empty() {
+        true => None,
+        false => Some(GridAutoTracks::try_from(Value::Array(sizes))?),
+    };
+    Ok((GridAutoFlow { column, dense }, sizes))
+}
+
+/// Parse the grid shorthand: a grid template, or the template of one axis
+/// with the auto-flow along the other one.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#grid-shorthand)
+pub(crate) fn parse_grid(value: &[ComponentValue]) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let mut parts = slash_separated(value)?;
+    let is_auto_flow = |part: &Value| {
+        part.iter()
+            .any(|value| *value == Value::Keyword(Keyword::AutoFlow))
+    };
+
+    match (parts.len(), parts.iter().position(is_auto_flow)) {
+        (_, None) => parse_grid_template(value),
+        (2, Some(0)) => {
+            let columns = GridTemplate::try_from(parts.remove(1))?;
+            let (flow, rows) = parse_auto_flow(parts.remove(0), false)?;
+            let mut declarations = vec![
+                PropertyDeclaration::GridTemplateColumns(GridTemplateColumns(columns)),
+                PropertyDeclaration::GridAutoFlow(flow),
+            ];
+            declarations
+                .extend(rows.map(|rows| PropertyDeclaration::GridAutoRows(GridAutoRows(rows))));
+            Ok(declarations)
+        }
+        (2, Some(1)) => {
+            let (flow, columns) = parse_auto_flow(parts.remove(1), true)?;
+            let rows = GridTemplate::try_from(parts.remove(0))?;
+            let mut declarations = vec![
+                PropertyDeclaration::GridTemplateRows(GridTemplateRows(rows)),
+                PropertyDeclaration::GridAutoFlow(flow),
+            ];
+            declarations.extend(
+                columns
+                    .map(|columns| PropertyDeclaration::GridAutoColumns(GridAutoColumns(columns))),
+            );
+            Ok(declarations)
+        }
+        _ => Err(StyleError::InvalidValue(&[
+            "<'grid-template'>",
+            "<'grid-template-rows'> / [ auto-flow && dense? ] <'grid-auto-columns'>?",
+            "[ auto-flow && dense? ] <'grid-auto-rows'>? / <'grid-template-columns'>",
+        ])),
+    }
+}
+
+/// Serialize the grid shorthand, none if both the template and the implicit
+/// tracks are set.
+pub(crate) fn serialize_grid(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [template @ .., PropertyDeclaration::GridAutoRows(auto_rows), PropertyDeclaration::GridAutoColumns(auto_columns), PropertyDeclaration::GridAutoFlow(flow)] =
+        declarations
+    else {
+        return None;
+    };
+    let [PropertyDeclaration::GridTemplateRows(rows), PropertyDeclaration::GridTemplateColumns(columns), PropertyDeclaration::GridTemplateAreas(areas)] =
+        template
+    else {
+        return None;
+    };
+
+    let auto_rows_initial = **auto_rows == GridAutoTracks::default();
+    let auto_columns_initial = **auto_columns == GridAutoTracks::default();
+    if auto_rows_initial && auto_columns_initial && *flow == GridAutoFlow::default() {
+        return serialize_grid_template(template);
+    }
+    if *areas != GridTemplateAreas::None {
+        return None;
+    }
+
+    let auto_flow = |tracks: &GridAutoTracks, initial: bool| {
+        let mut auto_flow = Keyword::AutoFlow.to_string();
+        if flow.dense {
+            auto_flow.push_str(" dense");
+        }
+        if !initial {
+            auto_flow = format!("{} {}", auto_flow, tracks);
+        }
+        auto_flow
+    };
+    match flow.column {
+        false if **rows == GridTemplate::None && auto_columns_initial => Some(format!(
+            "{} / {}",
+            auto_flow(auto_rows, auto_rows_initial),
+            columns
+        )),
+        true if **columns == GridTemplate::None && auto_rows_initial => Some(format!(
+            "{} / {}",
+            rows,
+            auto_flow(auto_columns, auto_columns_initial)
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::testing::round_trip;
+
+    #[test]
+    fn test_001_placement_shorthands() {
+        for (name, source, expected) in [
+            ("grid-row", "1 / 3", "1 / 3"),
+            ("grid-row", "a", "a"),
+            ("grid-row", "1 / span 2", "1 / span 2"),
+            ("grid-column", "auto / auto", "auto"),
+            ("grid-area", "a", "a"),
+            ("grid-area", "1 / 2 / 3 / 4", "1 / 2 / 3 / 4"),
+            ("grid-area", "1 / 2", "1 / 2"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("grid-row", "1 / 2 / 3"),
+            ("grid-area", "1 / 2 / 3 / 4 / 5"),
+            ("grid-column", "/ 2"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_002_template_shorthands() {
+        for (name, source, expected) in [
+            ("grid-template", "none", "none"),
+            ("grid-template", "100px / 1fr 1fr", "100px / 1fr 1fr"),
+            (
+                "grid-template",
+                "\"a b\" 10px \"c d\" / 1fr 1fr",
+                "\"a b\" 10px \"c d\" / 1fr 1fr",
+            ),
+            ("grid", "none", "none"),
+            (
+                "grid",
+                "100px / auto-flow dense 50px",
+                "100px / auto-flow dense 50px",
+            ),
+            // The implicit row tracks being the initial ones, the grid is
+            // serialized as a template.
+            ("grid", "auto-flow / 1fr 1fr", "none / 1fr 1fr"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("grid-template", "100px"),
+            ("grid-template", "\"a b\" \"c\" / 1fr 1fr"),
+            ("grid", "auto-flow / auto-flow"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::style::{
+    BorderBlockEndColor, BorderBlockEndStyle, BorderBlockEndWidth, BorderBlockStartColor,
+    BorderBlockStartStyle, BorderBlockStartWidth, BorderColor, BorderInlineEndColor,
+    BorderInlineEndStyle, BorderInlineEndWidth, BorderInlineStartColor, BorderInlineStartStyle,
+    BorderInlineStartWidth, BorderStyle, BorderWidth, ComponentValue, Inset, InsetBlockEnd,
+    InsetBlockStart, InsetInlineEnd, InsetInlineStart, Margin, MarginBlockEnd, MarginBlockStart,
+    MarginInlineEnd, MarginInlineStart, Padding, PaddingBlockEnd, PaddingBlockStart,
+    PaddingInlineEnd, PaddingInlineStart, PropertyDeclaration, StyleError, Value,
+};
+
+use super::significant;
+
+/// Parse the one or two values of the start and end sides of an axis, the
+/// end one copying the start one if omitted.
+fn parse_axis<T>(value: &[ComponentValue]) -> Result<[T; 2], StyleError>
+where
+    T: TryFrom<Value, Error = StyleError> + Clone,
+{
+    let values = significant(value)
+        .into_iter()
+        .map(|cv| Value::try_from(cv).and_then(T::try_from))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match values.as_slice() {
+        [both] => Ok([both.clone(), both.clone()]),
+        [start, end] => Ok([start.clone(), end.clone()]),
+        _ => Err(StyleError::InvalidValue(&["{1,2}"])),
+    }
+}
+
+/// Serialize the start and end sides of an axis in their shortest form.
+fn serialize_axis<T: PartialEq + std::fmt::Display>(start: &T, end: &T) -> String {
+    match start == end {
+        true => start.to_string(),
+        false => format!("{} {}", start, end),
+    }
+}
+
+/// Generate the parsing and serialization of a shorthand setting the start
+/// and end sides of an axis, each being a newtype of the same value.
+macro_rules! axis_shorthand {
+    ($parse:ident, $serialize:ident, $ty:ty, [$start:ident, $end:ident]) => {
+        pub(crate) fn $parse(
+            value: &[ComponentValue],
+        ) -> Result<Vec<PropertyDeclaration>, StyleError> {
+            let [start, end] = parse_axis::<$ty>(value)?;
+
+            Ok(vec![
+                PropertyDeclaration::$start($start(start)),
+                PropertyDeclaration::$end($end(end)),
+            ])
+        }
+
+        pub(crate) fn $serialize(declarations: &[&PropertyDeclaration]) -> Option<String> {
+            match declarations {
+                [PropertyDeclaration::$start(start), PropertyDeclaration::$end(end)] => {
+                    Some(serialize_axis(&**start, &**end))
+                }
+                _ => None,
+            }
+        }
+    };
+}
+
+axis_shorthand!(
+    parse_margin_block,
+    serialize_margin_block,
+    Margin,
+    [MarginBlockStart, MarginBlockEnd]
+);
+axis_shorthand!(
+    parse_margin_inline,
+    serialize_margin_inline,
+    Margin,
+    [MarginInlineStart, MarginInlineEnd]
+);
+axis_shorthand!(
+    parse_padding_block,
+    serialize_padding_block,
+    Padding,
+    [PaddingBlockStart, PaddingBlockEnd]
+);
+axis_shorthand!(
+    parse_padding_inline,
+    serialize_padding_inline,
+    Padding,
+    [PaddingInlineStart, PaddingInlineEnd]
+);
+axis_shorthand!(
+    parse_inset_block,
+    serialize_inset_block,
+    Inset,
+    [InsetBlockStart, InsetBlockEnd]
+);
+axis_shorthand!(
+    parse_inset_inline,
+    serialize_inset_inline,
+    Inset,
+    [InsetInlineStart, InsetInlineEnd]
+);
+axis_shorthand!(
+    parse_border_block_width,
+    serialize_border_block_width,
+    BorderWidth,
+    [BorderBlockStartWidth, BorderBlockEndWidth]
+);
+axis_shorthand!(
+    parse_border_inline_width,
+    serialize_border_inline_width,
+    BorderWidth,
+    [BorderInlineStartWidth, BorderInlineEndWidth]
+);
+axis_shorthand!(
+    parse_border_block_style,
+    serialize_border_block_style,
+    BorderStyle,
+    [BorderBlockStartStyle, BorderBlockEndStyle]
+);
+axis_shorthand!(
+    parse_border_inline_style,
+    serialize_border_inline_style,
+    BorderStyle,
+    [BorderInlineStartStyle, BorderInlineEndStyle]
+);
+axis_shorthand!(
+    parse_border_block_color,
+    serialize_border_block_color,
+    BorderColor,
+    [BorderBlockStartColor, BorderBlockEndColor]
+);
+axis_shorthand!(
+    parse_border_inline_color,
+    serialize_border_inline_color,
+    BorderColor,
+    [BorderInlineStartColor, BorderInlineEndColor]
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::style::testing::round_trip;
+
+    #[test]
+    fn test_001_logical_shorthands() {
+        for (name, source, expected) in [
+            ("margin-inline", "1px 2px", "1px 2px"),
+            ("margin-inline", "1px 1px", "1px"),
+            ("margin-block", "auto", "auto"),
+            ("padding-block", "1px 0", "1px 0px"),
+            ("border-block", "1px solid red", "1px solid red"),
+            ("border-inline-start", "thick", "thick"),
+            ("border-block-width", "1px 2px", "1px 2px"),
+            ("border-inline-color", "red", "red"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("margin-inline", "1px 2px 3px"),
+            ("padding-block", "1px -1px"),
+            ("border-block", "1px 2px"),
+            ("border-inline-style", "solid red"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! The shorthand properties, which set several longhands at once.
+//!
+//! [CSS Cascade 5](https://drafts.csswg.org/css-cascade-5/#shorthand)
+mod background;
+mod border;
+mod flex;
+mod font;
+mod grid;
+mod logical;
+mod overflow;
+mod sides;
+mod text;
+
+pub(crate) use background::*;
+pub(crate) use border::*;
+pub(crate) use flex::*;
+pub(crate) use font::*;
+pub(crate) use grid::*;
+pub(crate) use logical::*;
+pub(crate) use overflow::*;
+pub(crate) use sides::*;
+pub(crate) use text::*;
+
+use crate::style::{ComponentValue, PropertyDeclaration, ShorthandId, StyleError};
+
+impl ShorthandId {
+    /// Parse the value into the declarations of all its longhands, the
+    /// omitted ones being reset to their initial value.
+    pub fn expand(&self, value: &[ComponentValue]) -> Result<Vec<PropertyDeclaration>, StyleError> {
+        let declared = self.parse_declared(value)?;
+
+        Ok(self
+            .longhands()
+            .iter()
+            .map(|id| {
+                declared
+                    .iter()
+                    .find(|declaration| declaration.id() == Some(*id))
+                    .cloned()
+                    .unwrap_or_else(|| id.initial_declaration())
+            })
+            .collect())
+    }
+
+    /// Serialize the declarations of its longhands, given in their canonical
+    /// order, none if the shorthand can't represent them.
+    pub fn serialize(&self, declarations: &[&PropertyDeclaration]) -> Option<String> {
+        let first = declarations.first()?;
+        let all_equal = declarations
+            .iter()
+            .all(|declaration| same_value(declaration, first));
+
+        match first {
+            PropertyDeclaration::CssWide(..) if all_equal => Some(first.value_to_string()),
+            PropertyDeclaration::WithVariables(_, Some(shorthand), _)
+                if shorthand == self && all_equal =>
+            {
+                Some(first.value_to_string())
+            }
+            _ if declarations.iter().any(|declaration| {
+                matches!(
+                    declaration,
+                    PropertyDeclaration::CssWide(..) | PropertyDeclaration::WithVariables(..)
+                )
+            }) =>
+            {
+                None
+            }
+            _ => self.serialize_declared(declarations),
+        }
+    }
+}
+
+/// The values of a shorthand, without the whitespaces.
+fn significant(value: &[ComponentValue]) -> Vec<&ComponentValue> {
+    value.iter().filter(|cv| !cv.is_whitespace()).collect()
+}
+
+/// The four sides, or corners, from one to four values, clockwise from the
+/// top one, the omitted ones copying their opposite.
+fn expand_sides<T: Clone>(values: &[T]) -> Option<[T; 4]> {
+    let [top, right, bottom, left] = match values {
+        [all] => [all, all, all, all],
+        [vertical, horizontal] => [vertical, horizontal, vertical, horizontal],
+        [top, horizontal, bottom] => [top, horizontal, bottom, horizontal],
+        [top, right, bottom, left] => [top, right, bottom, left],
+        _ => return None,
+    };
+
+    Some([top.clone(), right.clone(), bottom.clone(), left.clone()])
+}
+
+/// Serialize the four sides, or corners, in their shortest form.
+fn serialize_sides<T: PartialEq + std::fmt::Display>(sides: [&T; 4]) -> String {
+    let [top, right, bottom, left] = sides;
+
+    if left != right {
+        format!("{} {} {} {}", top, right, bottom, left)
+    } else if top != bottom {
+        format!("{} {} {}", top, right, bottom)
+    } else if top != right {
+        format!("{} {}", top, right)
+    } else {
+        top.to_string()
+    }
+}
+
+/// Whether both declare the same value, whatever their property.
+fn same_value(a: &PropertyDeclaration, b: &PropertyDeclaration) -> bool {
+    match (a, b) {
+        (PropertyDeclaration::CssWide(_, a), PropertyDeclaration::CssWide(_, b)) => a == b,
+        (
+            PropertyDeclaration::WithVariables(_, a, a_value),
+            PropertyDeclaration::WithVariables(_, b, b_value),
+        ) => a == b && a_value == b_value,
+        _ => a == b,
+    }
+}
@@ -0,0 +1,43 @@
+use crate::style::{ComponentValue, OverflowX, OverflowY, PropertyDeclaration, StyleError, Value};
+
+use super::significant;
+
+/// Parse the overflow shorthand, the vertical overflow defaulting to the
+/// horizontal one.
+///
+/// [CSS Overflow 3](https://drafts.csswg.org/css-overflow-3/#propdef-overflow)
+pub(crate) fn parse_overflow(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let values = significant(value)
+        .into_iter()
+        .map(Value::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (x, y) = match values.as_slice() {
+        [x] => (x, x),
+        [x, y] => (x, y),
+        _ => {
+            return Err(StyleError::InvalidValue(&[
+                "visible", "hidden", "clip", "scroll", "auto",
+            ]))
+        }
+    };
+
+    Ok(vec![
+        PropertyDeclaration::OverflowX(OverflowX::try_from(x.clone())?),
+        PropertyDeclaration::OverflowY(OverflowY::try_from(y.clone())?),
+    ])
+}
+
+pub(crate) fn serialize_overflow(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::OverflowX(x), PropertyDeclaration::OverflowY(y)] = declarations
+    else {
+        return None;
+    };
+
+    match **x == **y {
+        true => Some(x.to_string()),
+        false => Some(format!("{} {}", x, y)),
+    }
+}
@@ -0,0 +1,103 @@
+use crate::style::{
+    BorderBottomColor, BorderBottomStyle, BorderBottomWidth, BorderColor, BorderLeftColor,
+    BorderLeftStyle, BorderLeftWidth, BorderRightColor, BorderRightStyle, BorderRightWidth,
+    BorderStyle, BorderTopColor, BorderTopStyle, BorderTopWidth, BorderWidth, Bottom,
+    ComponentValue, Inset, Left, Margin, MarginBottom, MarginLeft, MarginRight, MarginTop, Padding,
+    PaddingBottom, PaddingLeft, PaddingRight, PaddingTop, PropertyDeclaration, Right, StyleError,
+    Top, Value,
+};
+
+use super::{expand_sides, serialize_sides, significant};
+
+/// Parse the one to four values of the sides of a box.
+fn parse_sides<T>(value: &[ComponentValue]) -> Result<[T; 4], StyleError>
+where
+    T: TryFrom<Value, Error = StyleError> + Clone,
+{
+    let values = significant(value)
+        .into_iter()
+        .map(|cv| Value::try_from(cv).and_then(T::try_from))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    expand_sides(&values).ok_or(StyleError::InvalidValue(&["{1,4}"]))
+}
+
+/// Generate the parsing and serialization of a shorthand setting the four
+/// sides of a box, each being a newtype of the same value.
+macro_rules! sides_shorthand {
+    ($parse:ident, $serialize:ident, $ty:ty, [$top:ident, $right:ident, $bottom:ident, $left:ident]) => {
+        pub(crate) fn $parse(
+            value: &[ComponentValue],
+        ) -> Result<Vec<PropertyDeclaration>, StyleError> {
+            let [top, right, bottom, left] = parse_sides::<$ty>(value)?;
+
+            Ok(vec![
+                PropertyDeclaration::$top($top(top)),
+                PropertyDeclaration::$right($right(right)),
+                PropertyDeclaration::$bottom($bottom(bottom)),
+                PropertyDeclaration::$left($left(left)),
+            ])
+        }
+
+        pub(crate) fn $serialize(declarations: &[&PropertyDeclaration]) -> Option<String> {
+            match declarations {
+                [PropertyDeclaration::$top(top), PropertyDeclaration::$right(right), PropertyDeclaration::$bottom(bottom), PropertyDeclaration::$left(left)] => {
+                    Some(serialize_sides([&**top, &**right, &**bottom, &**left]))
+                }
+                _ => None,
+            }
+        }
+    };
+}
+
+sides_shorthand!(
+    parse_margin,
+    serialize_margin,
+    Margin,
+    [MarginTop, MarginRight, MarginBottom, MarginLeft]
+);
+sides_shorthand!(
+    parse_padding,
+    serialize_padding,
+    Padding,
+    [PaddingTop, PaddingRight, PaddingBottom, PaddingLeft]
+);
+sides_shorthand!(
+    parse_border_width,
+    serialize_border_width,
+    BorderWidth,
+    [
+        BorderTopWidth,
+        BorderRightWidth,
+        BorderBottomWidth,
+        BorderLeftWidth
+    ]
+);
+sides_shorthand!(
+    parse_border_style,
+    serialize_border_style,
+    BorderStyle,
+    [
+        BorderTopStyle,
+        BorderRightStyle,
+        BorderBottomStyle,
+        BorderLeftStyle
+    ]
+);
+sides_shorthand!(
+    parse_border_color,
+    serialize_border_color,
+    BorderColor,
+    [
+        BorderTopColor,
+        BorderRightColor,
+        BorderBottomColor,
+        BorderLeftColor
+    ]
+);
+sides_shorthand!(
+    parse_inset,
+    serialize_inset,
+    Inset,
+    [Top, Right, Bottom, Left]
+);
@@ -0,0 +1,243 @@
+use crate::style::{
+    ComponentValue, Keyword, PropertyDeclaration, StyleError, TextDecorationColor,
+    TextDecorationLine, TextDecorationStyle, TextDecorationThickness, TextWrap, Value,
+    WhiteSpaceCollapse,
+};
+
+use super::significant;
+
+/// Parse the white-space shorthand, its legacy keywords setting both the
+/// collapsing and the wrapping.
+///
+/// [CSS Text 4](https://drafts.csswg.org/css-text-4/#white-space-property)
+pub(crate) fn parse_white_space(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let invalid = StyleError::InvalidValue(&[
+        "normal",
+        "pre",
+        "pre-wrap",
+        "pre-line",
+        "<white-space-collapse>",
+        "wrap",
+        "nowrap",
+    ]);
+
+    let values = significant(value)
+        .into_iter()
+        .map(Value::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let legacy = match values.as_slice() {
+        [Value::Keyword(Keyword::Normal)] => Some((WhiteSpaceCollapse::Collapse, TextWrap::Wrap)),
+        [Value::Keyword(Keyword::Pre)] => Some((WhiteSpaceCollapse::Preserve, TextWrap::Nowrap)),
+        [Value::Keyword(Keyword::PreWrap)] => Some((WhiteSpaceCollapse::Preserve, TextWrap::Wrap)),
+        [Value::Keyword(Keyword::PreLine)] => {
+            Some((WhiteSpaceCollapse::PreserveBreaks, TextWrap::Wrap))
+        }
+        _ => None,
+    };
+    if let Some((collapse, wrap)) = legacy {
+        return Ok(vec![
+            PropertyDeclaration::WhiteSpaceCollapse(collapse),
+            PropertyDeclaration::TextWrap(wrap),
+        ]);
+    }
+
+    if values.is_empty() {
+        return Err(invalid);
+    }
+
+    // Only the wrapping mode of text-wrap can be set.
+    let (mut collapse, mut wrap) = (None, None);
+    for value in values {
+        match value {
+            Value::Keyword(Keyword::Wrap) if wrap.is_none() => wrap = Some(TextWrap::Wrap),
+            Value::Keyword(Keyword::Nowrap) if wrap.is_none() => wrap = Some(TextWrap::Nowrap),
+            value if collapse.is_none() => {
+                collapse = Some(WhiteSpaceCollapse::try_from(value).map_err(|_| invalid.clone())?);
+            }
+            _ => return Err(invalid),
+        }
+    }
+
+    let mut declarations = Vec::<PropertyDeclaration>::default();
+    declarations.extend(collapse.map(PropertyDeclaration::WhiteSpaceCollapse));
+    declarations.extend(wrap.map(PropertyDeclaration::TextWrap));
+    Ok(declarations)
+}
+
+/// Serialize the white-space shorthand, with a legacy keyword if one sets
+/// the longhands.
+pub(crate) fn serialize_white_space(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::WhiteSpaceCollapse(collapse), PropertyDeclaration::TextWrap(wrap)] =
+        declarations
+    else {
+        return None;
+    };
+
+    let legacy = match (collapse, wrap) {
+        (WhiteSpaceCollapse::Collapse, TextWrap::Wrap) => Some("normal"),
+        (WhiteSpaceCollapse::Preserve, TextWrap::Nowrap) => Some("pre"),
+        (WhiteSpaceCollapse::Preserve, TextWrap::Wrap) => Some("pre-wrap"),
+        (WhiteSpaceCollapse::PreserveBreaks, TextWrap::Wrap) => Some("pre-line"),
+        _ => None,
+    };
+
+    match (legacy, wrap) {
+        (Some(legacy), _) => Some(legacy.to_string()),
+        (None, TextWrap::Wrap) => Some(collapse.to_string()),
+        (None, TextWrap::Nowrap) if *collapse == WhiteSpaceCollapse::Collapse => {
+            Some(wrap.to_string())
+        }
+        (None, TextWrap::Nowrap) => Some(format!("{} {}", collapse, wrap)),
+        _ => None,
+    }
+}
+
+/// Parse the text-decoration shorthand, its longhands in any order.
+///
+/// [CSS Text Decoration 4](https://drafts.csswg.org/css-text-decor-4/#text-decoration-property)
+pub(crate) fn parse_text_decoration(
+    value: &[ComponentValue],
+) -> Result<Vec<PropertyDeclaration>, StyleError> {
+    let invalid = StyleError::InvalidValue(&[
+        "<text-decoration-line>",
+        "<text-decoration-thickness>",
+        "<text-decoration-style>",
+        "<color>",
+    ]);
+    let values = significant(value);
+    if values.is_empty() {
+        return Err(invalid);
+    }
+
+    let (mut line, mut thickness, mut style, mut color) = (None, None, None, None);
+    for cv in values {
+        match Value::try_from(cv)? {
+            // The lines are several keywords, which may be apart.
+            value if TextDecorationLine::try_from(value.clone()).is_ok() => {
+                let added = TextDecorationLine::try_from(value)?;
+                line = Some(add_lines(line, added).ok_or(invalid.clone())?);
+            }
+            value
+                if thickness.is_none()
+                    && TextDecorationThickness::try_from(value.clone()).is_ok() =>
+            {
+                thickness = TextDecorationThickness::try_from(value).ok();
+            }
+            value if style.is_none() && TextDecorationStyle::try_from(value.clone()).is_ok() => {
+                style = TextDecorationStyle::try_from(value).ok();
+            }
+            value if color.is_none() && TextDecorationColor::try_from(value.clone()).is_ok() => {
+                color = TextDecorationColor::try_from(value).ok();
+            }
+            _ => return Err(invalid),
+        }
+    }
+
+    let mut declarations = Vec::<PropertyDeclaration>::default();
+    declarations.extend(line.map(PropertyDeclaration::TextDecorationLine));
+    declarations.extend(thickness.map(PropertyDeclaration::TextDecorationThickness));
+    declarations.extend(style.map(PropertyDeclaration::TextDecorationStyle));
+    declarations.extend(color.map(PropertyDeclaration::TextDecorationColor));
+    Ok(declarations)
+}
+
+/// Add lines to the ones already set, none if a line is set twice or if
+/// none isn't alone.
+fn add_lines(
+    line: Option<TextDecorationLine>,
+    added: TextDecorationLine,
+) -> Option<TextDecorationLine> {
+    let Some(line) = line else {
+        return Some(added);
+    };
+    let overlaps = line.underline && added.underline
+        || line.overline && added.overline
+        || line.line_through && added.line_through
+        || line.blink && added.blink;
+    if line.is_none() || added.is_none() || overlaps {
+        return None;
+    }
+
+    Some(TextDecorationLine {
+        underline: line.underline || added.underline,
+        overline: line.overline || added.overline,
+        line_through: line.line_through || added.line_through,
+        blink: line.blink || added.blink,
+    })
+}
+
+/// Serialize the text-decoration shorthand, omitting the initial values.
+pub(crate) fn serialize_text_decoration(declarations: &[&PropertyDeclaration]) -> Option<String> {
+    let [PropertyDeclaration::TextDecorationLine(line), PropertyDeclaration::TextDecorationThickness(thickness), PropertyDeclaration::TextDecorationStyle(style), PropertyDeclaration::TextDecorationColor(color)] =
+        declarations
+    else {
+        return None;
+    };
+
+    let values = [
+        (!line.is_none()).then(|| line.to_string()),
+        (*thickness != TextDecorationThickness::default()).then(|| thickness.to_string()),
+        (*style != TextDecorationStyle::default()).then(|| style.to_string()),
+        (*color != TextDecorationColor::default()).then(|| color.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    match values.is_empty() {
+        true => Some("none".to_string()),
+        false => Some(values.join(" ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{testing::round_trip, PropertyDeclarationBlock};
+
+    #[test]
+    fn test_001_white_space() {
+        for (source, expected) in [
+            ("normal", "normal"),
+            ("pre", "pre"),
+            ("pre-wrap", "pre-wrap"),
+            ("pre-line", "pre-line"),
+            ("nowrap", "nowrap"),
+            ("break-spaces", "break-spaces"),
+            ("preserve nowrap", "pre"),
+            ("wrap collapse", "normal"),
+        ] {
+            assert_eq!(round_trip("white-space", source), expected, "{}", source);
+        }
+        for invalid in ["bogus", "pre nowrap", "collapse collapse"] {
+            assert_eq!(round_trip("white-space", invalid), "", "{}", invalid);
+        }
+
+        // The combinations without a keyword aren't serialized.
+        let block = PropertyDeclarationBlock::from(
+            "white-space-collapse: preserve-spaces; text-wrap: balance",
+        );
+        assert_eq!(block.property_value("white-space"), "");
+    }
+
+    #[test]
+    fn test_002_text_decoration() {
+        for (source, expected) in [
+            ("none", "none"),
+            ("underline red wavy", "underline wavy red"),
+            ("overline 2px", "overline 2px"),
+        ] {
+            assert_eq!(
+                round_trip("text-decoration", source),
+                expected,
+                "{}",
+                source
+            );
+        }
+        for invalid in ["red red", "wavy dotted", "underline none"] {
+            assert_eq!(round_trip("text-decoration", invalid), "", "{}", invalid);
+        }
+    }
+}
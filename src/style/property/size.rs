@@ -0,0 +1,213 @@
+use crate::style::{ComputeContext, Keyword, LengthPercentage, StyleError, Value};
+
+use super::ToComputedValue;
+
+/// A preferred or minimum size of a box.
+///
+/// [CSS Sizing 3](https://drafts.csswg.org/css-sizing-3/#preferred-size-properties)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Size {
+    /// The size given by the layout.
+    #[default]
+    Auto,
+    LengthPercentage(LengthPercentage),
+    /// The smallest size the content fits in.
+    MinContent,
+    /// The size of the content without any soft wrap.
+    MaxContent,
+    /// The available space clamped between the min-content and max-content
+    /// sizes, the available space being the given one if any.
+    FitContent(Option<LengthPercentage>),
+}
+
+impl Size {
+    fn resolve_relative(&self, context: &ComputeContext) -> Self {
+        let resolve = |size: &LengthPercentage| size.resolve_non_negative(&context.length);
+
+        match self {
+            Self::LengthPercentage(size) => Self::LengthPercentage(resolve(size)),
+            Self::FitContent(size) => Self::FitContent(size.as_ref().map(resolve)),
+            other => other.clone(),
+        }
+    }
+}
+
+impl TryFrom<Value> for Size {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&[
+            "auto",
+            "<length-percentage [0,∞]>",
+            "min-content",
+            "max-content",
+            "fit-content()",
+        ]);
+        let size = |value: Value| match LengthPercentage::try_from(value) {
+            Ok(size) if !size.is_negative() => Ok(size),
+            _ => Err(invalid.clone()),
+        };
+
+        match value {
+            Value::Keyword(Keyword::Auto) => Ok(Self::Auto),
+            Value::Keyword(Keyword::MinContent) => Ok(Self::MinContent),
+            Value::Keyword(Keyword::MaxContent) => Ok(Self::MaxContent),
+            Value::Keyword(Keyword::FitContent) => Ok(Self::FitContent(None)),
+            Value::Function(name, mut arguments) if name == "fit-content" => {
+                match arguments.len() {
+                    1 => size(arguments.remove(0)).map(|size| Self::FitContent(Some(size))),
+                    _ => Err(invalid),
+                }
+            }
+            value => size(value).map(Self::LengthPercentage),
+        }
+    }
+}
+
+impl From<Size> for Value {
+    fn from(value: Size) -> Self {
+        match value {
+            Size::Auto => Self::Keyword(Keyword::Auto),
+            Size::LengthPercentage(size) => Self::from(size),
+            Size::MinContent => Self::Keyword(Keyword::MinContent),
+            Size::MaxContent => Self::Keyword(Keyword::MaxContent),
+            Size::FitContent(None) => Self::Keyword(Keyword::FitContent),
+            Size::FitContent(Some(size)) => {
+                Self::Function("fit-content".to_string(), vec![Self::from(size)])
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::LengthPercentage(size) => write!(f, "{}", size),
+            Self::MinContent => write!(f, "min-content"),
+            Self::MaxContent => write!(f, "max-content"),
+            Self::FitContent(None) => write!(f, "fit-content"),
+            Self::FitContent(Some(size)) => write!(f, "fit-content({})", size),
+        }
+    }
+}
+
+impl ToComputedValue for Size {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        self.resolve_relative(context)
+    }
+}
+
+/// A maximum size of a box, none for no maximum.
+///
+/// [CSS Sizing 3](https://drafts.csswg.org/css-sizing-3/#max-size-properties)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum MaxSize {
+    #[default]
+    None,
+    /// Any size but auto.
+    Size(Size),
+}
+
+impl TryFrom<Value> for MaxSize {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::None) => Ok(Self::None),
+            Value::Keyword(Keyword::Auto) => Err(StyleError::InvalidValue(&[
+                "none",
+                "<length-percentage [0,∞]>",
+                "min-content",
+                "max-content",
+                "fit-content()",
+            ])),
+            value => Size::try_from(value).map(Self::Size),
+        }
+    }
+}
+
+impl From<MaxSize> for Value {
+    fn from(value: MaxSize) -> Self {
+        match value {
+            MaxSize::None => Self::Keyword(Keyword::None),
+            MaxSize::Size(size) => Self::from(size),
+        }
+    }
+}
+
+impl std::fmt::Display for MaxSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Size(size) => write!(f, "{}", size),
+        }
+    }
+}
+
+impl ToComputedValue for MaxSize {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::None => Self::None,
+            Self::Size(size) => Self::Size(size.resolve_relative(context)),
+        }
+    }
+}
+
+longhand_newtypes!(Size: Width, Height, MinWidth, MinHeight);
+longhand_newtypes!(MaxSize: MaxWidth, MaxHeight);
+longhand_newtypes!(Size: InlineSize, BlockSize, MinInlineSize, MinBlockSize);
+longhand_newtypes!(MaxSize: MaxInlineSize, MaxBlockSize);
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_sizes() {
+        for source in [
+            "auto",
+            "10px",
+            "min-content",
+            "max-content",
+            "fit-content",
+            "fit-content(10%)",
+        ] {
+            assert_eq!(round_trip("width", source), source);
+        }
+        assert_eq!(round_trip("max-width", "none"), "none");
+        assert_eq!(round_trip("min-height", "auto"), "auto");
+        for invalid in ["-10px", "none", "fit-content(-1px)", "fit-content(1px, 2px)"] {
+            assert_eq!(round_trip("width", invalid), "", "{}", invalid);
+        }
+        assert_eq!(round_trip("max-width", "auto"), "");
+        assert_eq!(round_trip("min-height", "-1px"), "");
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px }
+            body { width: calc(10px - 2em); max-width: calc(-1 * 1em); \
+                height: fit-content(2em); min-height: calc(2em - 10%) }",
+        );
+        let size = |index: usize, id| styles[index].value(id).to_string();
+
+        // The negative expressions are clamped once resolved.
+        assert_eq!(size(1, PropertyId::Width), "0px");
+        assert_eq!(size(1, PropertyId::MaxWidth), "0px");
+        assert_eq!(size(1, PropertyId::Height), "fit-content(20px)");
+        assert_eq!(size(1, PropertyId::MinHeight), "calc(20px - 10%)");
+        assert_eq!(size(2, PropertyId::Width), "auto");
+        assert_eq!(size(2, PropertyId::MaxWidth), "none");
+    }
+}
@@ -0,0 +1,154 @@
+//! The alignment of the inline content in the lines.
+//!
+//! [CSS Text 4](https://drafts.csswg.org/css-text-4/#text-align-property)
+use crate::style::{Direction, Style};
+
+keyword_longhand!(
+    /// The alignment of the lines, the start and end being relative to the
+    /// direction.
+    TextAlign {
+        Start,
+        End,
+        Left,
+        Right,
+        Center,
+        Justify,
+        MatchParent,
+        JustifyAll,
+    },
+    &[
+        "start",
+        "end",
+        "left",
+        "right",
+        "center",
+        "justify",
+        "match-parent",
+        "justify-all",
+    ]
+);
+keyword_longhand!(
+    /// The alignment of the last line, and of the lines before a forced
+    /// break, auto following the text-align.
+    TextAlignLast {
+        Auto,
+        Start,
+        End,
+        Left,
+        Right,
+        Center,
+        Justify,
+        MatchParent,
+    },
+    &[
+        "auto",
+        "start",
+        "end",
+        "left",
+        "right",
+        "center",
+        "justify",
+        "match-parent",
+    ]
+);
+keyword_longhand!(
+    /// The justification opportunities of justified lines.
+    TextJustify {
+        Auto,
+        None,
+        InterWord,
+        InterCharacter,
+    },
+    &["auto", "none", "inter-word", "inter-character"]
+);
+
+impl TextAlign {
+    /// The physical alignment of start or end, given the direction.
+    fn to_physical(self, direction: Direction) -> Self {
+        match (self, direction) {
+            (Self::Start, Direction::Ltr) | (Self::End, Direction::Rtl) => Self::Left,
+            (Self::Start, Direction::Rtl) | (Self::End, Direction::Ltr) => Self::Right,
+            (align, _) => align,
+        }
+    }
+}
+
+impl TextAlignLast {
+    /// The physical alignment of start or end, given the direction.
+    fn to_physical(self, direction: Direction) -> Self {
+        match (self, direction) {
+            (Self::Start, Direction::Ltr) | (Self::End, Direction::Rtl) => Self::Left,
+            (Self::Start, Direction::Rtl) | (Self::End, Direction::Ltr) => Self::Right,
+            (align, _) => align,
+        }
+    }
+}
+
+impl Style {
+    /// Resolve match-parent to the alignment of the parent, its start and
+    /// end being made physical with the direction of the parent.
+    ///
+    /// The root element is aligned to the start.
+    pub(crate) fn compute_text_align(&mut self, parent: Option<&Style>) {
+        let direction = parent.map_or(self.direction, |parent| parent.direction);
+
+        if self.text_align == TextAlign::MatchParent {
+            self.text_align = parent
+                .map_or(TextAlign::Start, |parent| parent.text_align)
+                .to_physical(direction);
+        }
+        if self.text_align_last == TextAlignLast::MatchParent {
+            self.text_align_last = parent
+                .map_or(TextAlignLast::Auto, |parent| parent.text_align_last)
+                .to_physical(direction);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        TextAlign, TextAlignLast,
+    };
+
+    #[test]
+    fn test_001_text_alignment() {
+        for (name, source) in [
+            ("text-align", "start"),
+            ("text-align", "match-parent"),
+            ("text-align", "justify-all"),
+            ("text-align-last", "auto"),
+            ("text-justify", "inter-word"),
+        ] {
+            assert_eq!(round_trip(name, source), source);
+        }
+        for (name, invalid) in [
+            ("text-align", "middle"),
+            ("text-align", "left right"),
+            ("text-justify", "distribute"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let p = doc.create(Some(body), "p", &[]);
+        doc.create(Some(p), "span", &[]);
+
+        let styles = doc.resolve(
+            "html { text-align: match-parent; text-align-last: match-parent }
+            body { direction: rtl; text-align: end }
+            p { direction: ltr; text-align: match-parent }",
+        );
+
+        // match-parent is resolved with the direction of the parent, and the
+        // result is inherited.
+        assert_eq!(styles[0].text_align, TextAlign::Left);
+        assert_eq!(styles[0].text_align_last, TextAlignLast::Auto);
+        assert_eq!(styles[1].text_align, TextAlign::End);
+        assert_eq!(styles[2].text_align, TextAlign::Left);
+        assert_eq!(styles[3].text_align, TextAlign::Left);
+    }
+}
@@ -0,0 +1,58 @@
+//! The line breaking rules between and within words.
+//!
+//! [CSS Text 4](https://drafts.csswg.org/css-text-4/#line-breaking)
+
+keyword_longhand!(
+    /// Whether an otherwise unbreakable word may be broken to avoid an
+    /// overflow.
+    OverflowWrap { Normal, BreakWord, Anywhere },
+    &["normal", "break-word", "anywhere"]
+);
+keyword_longhand!(
+    /// The soft wrap opportunities between letters.
+    WordBreak { Normal, KeepAll, BreakAll, BreakWord },
+    &["normal", "keep-all", "break-all", "break-word"]
+);
+keyword_longhand!(
+    /// Whether the words are hyphenated when the lines wrap, only at the
+    /// soft hyphens when manual.
+    Hyphens { Manual, None, Auto },
+    &["manual", "none", "auto"]
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        Hyphens, OverflowWrap, WordBreak,
+    };
+
+    #[test]
+    fn test_001_line_breaking() {
+        for (name, source) in [
+            ("overflow-wrap", "anywhere"),
+            ("word-break", "break-word"),
+            ("word-break", "keep-all"),
+            ("hyphens", "auto"),
+        ] {
+            assert_eq!(round_trip(name, source), source);
+        }
+        for (name, invalid) in [
+            ("overflow-wrap", "break-all"),
+            ("word-break", "normal keep-all"),
+            ("hyphens", "all"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles =
+            doc.resolve("html { overflow-wrap: anywhere; word-break: break-all; hyphens: none }");
+        assert_eq!(styles[1].overflow_wrap, OverflowWrap::Anywhere);
+        assert_eq!(styles[1].word_break, WordBreak::BreakAll);
+        assert_eq!(styles[1].hyphens, Hyphens::None);
+    }
+}
@@ -0,0 +1,242 @@
+//! The lines drawn under, over or through the text.
+//!
+//! [CSS Text Decoration 4](https://drafts.csswg.org/css-text-decor-4/#line-decoration)
+use crate::style::{
+    Color, ComputeContext, Keyword, LengthPercentage, StyleError, ToComputedValue, Value,
+};
+
+/// The lines decorating the text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextDecorationLine {
+    pub underline: bool,
+    pub overline: bool,
+    pub line_through: bool,
+    /// The text blinks, which the user agents may ignore.
+    pub blink: bool,
+}
+
+impl TextDecorationLine {
+    pub fn is_none(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl TryFrom<Value> for TextDecorationLine {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid =
+            StyleError::InvalidValue(&["none", "underline", "overline", "line-through", "blink"]);
+        if value == Value::Keyword(Keyword::None) {
+            return Ok(Self::default());
+        }
+
+        let mut line = Self::default();
+        for value in value.iter() {
+            let flag = match value {
+                Value::Keyword(Keyword::Underline) => &mut line.underline,
+                Value::Keyword(Keyword::Overline) => &mut line.overline,
+                Value::Keyword(Keyword::LineThrough) => &mut line.line_through,
+                Value::Keyword(Keyword::Blink) => &mut line.blink,
+                _ => return Err(invalid),
+            };
+            if *flag {
+                return Err(invalid);
+            }
+            *flag = true;
+        }
+
+        Ok(line)
+    }
+}
+
+impl From<TextDecorationLine> for Value {
+    fn from(value: TextDecorationLine) -> Self {
+        let mut keywords = [
+            (value.underline, Keyword::Underline),
+            (value.overline, Keyword::Overline),
+            (value.line_through, Keyword::LineThrough),
+            (value.blink, Keyword::Blink),
+        ]
+        .into_iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, keyword)| Value::Keyword(keyword))
+        .collect::<Vec<_>>();
+
+        match keywords.len() {
+            0 => Value::Keyword(Keyword::None),
+            1 => keywords.remove(0),
+            _ => Value::Array(keywords),
+        }
+    }
+}
+
+impl std::fmt::Display for TextDecorationLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(*self))
+    }
+}
+
+impl ToComputedValue for TextDecorationLine {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+keyword_longhand!(
+    /// The style of the lines.
+    TextDecorationStyle {
+        Solid,
+        Double,
+        Dotted,
+        Dashed,
+        Wavy,
+    },
+    &["solid", "double", "dotted", "dashed", "wavy"]
+);
+
+/// The color of the lines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextDecorationColor(pub Color);
+
+impl Default for TextDecorationColor {
+    fn default() -> Self {
+        Self(Color::CurrentColor)
+    }
+}
+
+impl TryFrom<Value> for TextDecorationColor {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Color(color) => Ok(Self(color)),
+            _ => Err(StyleError::InvalidValue(&["<color>"])),
+        }
+    }
+}
+
+impl From<TextDecorationColor> for Value {
+    fn from(value: TextDecorationColor) -> Self {
+        Self::Color(value.0)
+    }
+}
+
+impl std::fmt::Display for TextDecorationColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ToComputedValue for TextDecorationColor {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        self.clone()
+    }
+}
+
+/// The thickness of the lines.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum TextDecorationThickness {
+    /// The thickness chosen by the user agent.
+    #[default]
+    Auto,
+    /// The thickness given by the metrics of the font.
+    FromFont,
+    LengthPercentage(LengthPercentage),
+}
+
+impl TryFrom<Value> for TextDecorationThickness {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Auto) => Ok(Self::Auto),
+            Value::Keyword(Keyword::FromFont) => Ok(Self::FromFont),
+            value => LengthPercentage::try_from(value)
+                .map(Self::LengthPercentage)
+                .map_err(|_| {
+                    StyleError::InvalidValue(&["auto", "from-font", "<length-percentage>"])
+                }),
+        }
+    }
+}
+
+impl From<TextDecorationThickness> for Value {
+    fn from(value: TextDecorationThickness) -> Self {
+        match value {
+            TextDecorationThickness::Auto => Self::Keyword(Keyword::Auto),
+            TextDecorationThickness::FromFont => Self::Keyword(Keyword::FromFont),
+            TextDecorationThickness::LengthPercentage(thickness) => Self::from(thickness),
+        }
+    }
+}
+
+impl std::fmt::Display for TextDecorationThickness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+impl ToComputedValue for TextDecorationThickness {
+    type Computed = Self;
+
+    /// The percentages are kept, being relative to 1em of the font used for
+    /// the lines.
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::LengthPercentage(thickness) => {
+                Self::LengthPercentage(thickness.resolve_relative(&context.length))
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_text_decoration() {
+        for (name, source) in [
+            ("text-decoration-line", "underline overline"),
+            ("text-decoration-line", "blink"),
+            ("text-decoration-style", "wavy"),
+            ("text-decoration-color", "red"),
+            ("text-decoration-thickness", "from-font"),
+            ("text-decoration-thickness", "10%"),
+        ] {
+            assert_eq!(round_trip(name, source), source);
+        }
+        for (name, invalid) in [
+            ("text-decoration-line", "none underline"),
+            ("text-decoration-line", "underline underline"),
+            ("text-decoration-style", "groove"),
+            ("text-decoration-thickness", "thin"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        // The decorations aren't inherited, but propagated to the descendants
+        // by the layout.
+        let styles = doc.resolve(
+            "html { font-size: 10px; text-decoration-line: underline; \
+                text-decoration-thickness: 1em }",
+        );
+        let value = |index: usize, id| styles[index].value(id).to_string();
+        assert_eq!(value(0, PropertyId::TextDecorationThickness), "10px");
+        assert_eq!(value(1, PropertyId::TextDecorationThickness), "auto");
+        assert_eq!(value(1, PropertyId::TextDecorationLine), "none");
+    }
+}
@@ -0,0 +1,118 @@
+use crate::style::{ComputeContext, Keyword, LengthPercentage, StyleError, ToComputedValue, Value};
+
+/// The indentation of the first line of a block.
+///
+/// [CSS Text 4](https://drafts.csswg.org/css-text-4/#text-indent-property)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextIndent {
+    pub length: LengthPercentage,
+    /// All the lines but the first one are indented instead.
+    pub hanging: bool,
+    /// The first lines after forced breaks are indented too.
+    pub each_line: bool,
+}
+
+impl Default for TextIndent {
+    fn default() -> Self {
+        Self {
+            length: LengthPercentage::zero(),
+            hanging: false,
+            each_line: false,
+        }
+    }
+}
+
+impl TryFrom<Value> for TextIndent {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<length-percentage>", "hanging", "each-line"]);
+
+        let (mut length, mut hanging, mut each_line) = (None, false, false);
+        for value in value.iter() {
+            match value {
+                Value::Keyword(Keyword::Hanging) if !hanging => hanging = true,
+                Value::Keyword(Keyword::EachLine) if !each_line => each_line = true,
+                value if length.is_none() => {
+                    length = Some(
+                        LengthPercentage::try_from(value.clone()).map_err(|_| invalid.clone())?,
+                    );
+                }
+                _ => return Err(invalid),
+            }
+        }
+
+        Ok(Self {
+            length: length.ok_or(invalid)?,
+            hanging,
+            each_line,
+        })
+    }
+}
+
+impl From<TextIndent> for Value {
+    fn from(value: TextIndent) -> Self {
+        let mut values = vec![Value::from(value.length)];
+        if value.hanging {
+            values.push(Value::Keyword(Keyword::Hanging));
+        }
+        if value.each_line {
+            values.push(Value::Keyword(Keyword::EachLine));
+        }
+
+        match values.len() {
+            1 => values.remove(0),
+            _ => Value::Array(values),
+        }
+    }
+}
+
+impl std::fmt::Display for TextIndent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+impl ToComputedValue for TextIndent {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        Self {
+            length: self.length.resolve_relative(&context.length),
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_text_indent() {
+        for (source, expected) in [
+            ("-1em", "-1em"),
+            ("10% hanging", "10% hanging"),
+            ("each-line 1em", "1em each-line"),
+            ("hanging 0 each-line", "0px hanging each-line"),
+        ] {
+            assert_eq!(round_trip("text-indent", source), expected, "{}", source);
+        }
+        for invalid in ["hanging", "1em 2em", "1em hanging hanging", "auto"] {
+            assert_eq!(round_trip("text-indent", invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        // The indentation can be negative, and is inherited once computed.
+        let styles = doc.resolve("html { font-size: 10px; text-indent: calc(1em - 20px) hanging }");
+        let indent = |index: usize| styles[index].value(PropertyId::TextIndent).to_string();
+        assert_eq!(indent(0), "-10px hanging");
+        assert_eq!(indent(1), "-10px hanging");
+    }
+}
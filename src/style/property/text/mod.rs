@@ -0,0 +1,17 @@
+mod align;
+mod breaking;
+mod decoration;
+mod indent;
+mod shadow;
+mod spacing;
+mod transform;
+mod white_space;
+
+pub use align::*;
+pub use breaking::*;
+pub use decoration::*;
+pub use indent::*;
+pub use shadow::*;
+pub use spacing::*;
+pub use transform::*;
+pub use white_space::*;
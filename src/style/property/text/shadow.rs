@@ -0,0 +1,172 @@
+use crate::style::{Color, ComputeContext, Keyword, Length, StyleError, ToComputedValue, Value};
+
+/// A shadow of the text, offset from it and blurred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shadow {
+    pub color: Color,
+    pub offset_x: Length,
+    pub offset_y: Length,
+    /// The blur radius, which isn't negative.
+    pub blur: Length,
+}
+
+impl TryFrom<Value> for Shadow {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<color>", "<length>{2,3}"]);
+
+        // The color comes before or after all the lengths.
+        let values = value.iter().collect::<Vec<_>>();
+        let (color, lengths) = match values.as_slice() {
+            [Value::Color(color), lengths @ ..] | [lengths @ .., Value::Color(color)] => {
+                (Some(color.clone()), lengths)
+            }
+            lengths => (None, lengths),
+        };
+        let lengths = lengths
+            .iter()
+            .map(|value| match value {
+                // The unitless zero is a length.
+                Value::Number(number) if f32::from(*number) == 0.0 => Ok(Length::px(0)),
+                value => Length::try_from((*value).clone()),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid.clone())?;
+
+        match lengths.as_slice() {
+            [offset_x, offset_y, blur @ ..] if blur.len() <= 1 => {
+                let blur = blur.first().cloned().unwrap_or(Length::px(0));
+                if f32::from(blur.quantity) < 0.0 {
+                    return Err(invalid);
+                }
+
+                Ok(Self {
+                    color: color.unwrap_or(Color::CurrentColor),
+                    offset_x: offset_x.clone(),
+                    offset_y: offset_y.clone(),
+                    blur,
+                })
+            }
+            _ => Err(invalid),
+        }
+    }
+}
+
+impl From<Shadow> for Value {
+    fn from(value: Shadow) -> Self {
+        let mut values = vec![Value::from(value.offset_x), Value::from(value.offset_y)];
+        if f32::from(value.blur.quantity) != 0.0 {
+            values.push(Value::from(value.blur));
+        }
+        if value.color != Color::CurrentColor {
+            values.push(Value::Color(value.color));
+        }
+
+        Value::Array(values)
+    }
+}
+
+impl std::fmt::Display for Shadow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+/// The shadows of the text, the first one being painted on top.
+///
+/// [CSS Text Decoration 4](https://drafts.csswg.org/css-text-decor-4/#text-shadow-property)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextShadow(pub Vec<Shadow>);
+
+impl TryFrom<Value> for TextShadow {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::None) => Ok(Self::default()),
+            Value::List(shadows) => shadows
+                .into_iter()
+                .map(Shadow::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Self),
+            value => Shadow::try_from(value).map(|shadow| Self(vec![shadow])),
+        }
+    }
+}
+
+impl From<TextShadow> for Value {
+    fn from(value: TextShadow) -> Self {
+        let mut shadows = value.0.into_iter().map(Value::from).collect::<Vec<_>>();
+        match shadows.len() {
+            0 => Self::Keyword(Keyword::None),
+            1 => shadows.remove(0),
+            _ => Self::List(shadows),
+        }
+    }
+}
+
+impl std::fmt::Display for TextShadow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(self.clone()))
+    }
+}
+
+impl ToComputedValue for TextShadow {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        let resolve = |length: &Length| length.resolve_relative(&context.length);
+
+        Self(
+            self.0
+                .iter()
+                .map(|shadow| Shadow {
+                    color: shadow.color.clone(),
+                    offset_x: resolve(&shadow.offset_x),
+                    offset_y: resolve(&shadow.offset_y),
+                    blur: resolve(&shadow.blur),
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_text_shadow() {
+        for (source, expected) in [
+            ("none", "none"),
+            ("1px 2px red", "1px 2px red"),
+            ("red 1px 2px 3px", "1px 2px 3px red"),
+            ("1px 2px, 3px 4px blue", "1px 2px, 3px 4px blue"),
+        ] {
+            assert_eq!(round_trip("text-shadow", source), expected, "{}", source);
+        }
+        for invalid in [
+            "1px",
+            "1px 2px -3px",
+            "1px 2px 3px 4px",
+            "1px 2px, none",
+            "red",
+        ] {
+            assert_eq!(round_trip("text-shadow", invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        // The lengths are computed, and the shadows inherited.
+        let styles = doc.resolve("html { font-size: 10px; text-shadow: 1em -2em 0.5em blue }");
+        let shadow = |index: usize| styles[index].value(PropertyId::TextShadow).to_string();
+        assert_eq!(shadow(0), "10px -20px 5px blue");
+        assert_eq!(shadow(1), "10px -20px 5px blue");
+    }
+}
@@ -0,0 +1,165 @@
+use crate::style::{
+    ComputeContext, Keyword, Length, LengthPercentage, StyleError, ToComputedValue, Value,
+};
+
+/// The space added between letters or words, which may be negative.
+///
+/// [CSS Text 4](https://drafts.csswg.org/css-text-4/#spacing)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Spacing {
+    #[default]
+    Normal,
+    LengthPercentage(LengthPercentage),
+}
+
+impl TryFrom<Value> for Spacing {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Normal) => Ok(Self::Normal),
+            value => LengthPercentage::try_from(value)
+                .map(Self::LengthPercentage)
+                .map_err(|_| StyleError::InvalidValue(&["normal", "<length-percentage>"])),
+        }
+    }
+}
+
+impl From<Spacing> for Value {
+    fn from(value: Spacing) -> Self {
+        match value {
+            Spacing::Normal => Self::Keyword(Keyword::Normal),
+            Spacing::LengthPercentage(spacing) => Self::from(spacing),
+        }
+    }
+}
+
+impl std::fmt::Display for Spacing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "normal"),
+            Self::LengthPercentage(spacing) => write!(f, "{}", spacing),
+        }
+    }
+}
+
+impl ToComputedValue for Spacing {
+    /// Normal computes to zero.
+    type Computed = LengthPercentage;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::Normal => LengthPercentage::zero(),
+            Self::LengthPercentage(spacing) => spacing.resolve_relative(&context.length),
+        }
+    }
+}
+
+longhand_newtypes!(Spacing: LetterSpacing, WordSpacing);
+
+/// The width of a tab, a number being a multiple of the advance of a space.
+///
+/// [CSS Text 4](https://drafts.csswg.org/css-text-4/#tab-size-property)
+#[derive(Debug, Clone, PartialEq)]
+pub enum TabSize {
+    Number(f32),
+    Length(Length),
+}
+
+impl Default for TabSize {
+    fn default() -> Self {
+        Self::Number(8.0)
+    }
+}
+
+impl TryFrom<Value> for TabSize {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<number [0,∞]>", "<length [0,∞]>"]);
+
+        match value {
+            Value::Number(number) if f32::from(number) >= 0.0 => Ok(Self::Number(number.into())),
+            value => match Length::try_from(value) {
+                Ok(length) if f32::from(length.quantity) >= 0.0 => Ok(Self::Length(length)),
+                _ => Err(invalid),
+            },
+        }
+    }
+}
+
+impl From<TabSize> for Value {
+    fn from(value: TabSize) -> Self {
+        match value {
+            TabSize::Number(number) => Self::from(number),
+            TabSize::Length(length) => Self::from(length),
+        }
+    }
+}
+
+impl std::fmt::Display for TabSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{}", number),
+            Self::Length(length) => write!(f, "{}", length),
+        }
+    }
+}
+
+impl ToComputedValue for TabSize {
+    type Computed = Self;
+
+    fn compute(&self, context: &ComputeContext) -> Self::Computed {
+        match self {
+            Self::Number(number) => Self::Number(*number),
+            Self::Length(length) => Self::Length(length.resolve_relative(&context.length)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        PropertyId,
+    };
+
+    #[test]
+    fn test_001_spacing() {
+        for (name, source, expected) in [
+            ("letter-spacing", "normal", "normal"),
+            ("letter-spacing", "-1px", "-1px"),
+            ("letter-spacing", "0", "0px"),
+            ("word-spacing", "50%", "50%"),
+            ("tab-size", "4", "4"),
+            ("tab-size", "1.5", "1.5"),
+            ("tab-size", "2em", "2em"),
+        ] {
+            assert_eq!(round_trip(name, source), expected, "{}", source);
+        }
+        for (name, invalid) in [
+            ("letter-spacing", "auto"),
+            ("word-spacing", "1px 2px"),
+            ("tab-size", "-1"),
+            ("tab-size", "-1px"),
+            ("tab-size", "10%"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve(
+            "html { font-size: 10px; letter-spacing: calc(1em - 5%); word-spacing: normal; \
+                tab-size: 2em }",
+        );
+        let value = |id| styles[1].value(id).to_string();
+
+        // The spacings are inherited once computed, normal being zero.
+        assert_eq!(value(PropertyId::LetterSpacing), "calc(10px - 5%)");
+        assert_eq!(value(PropertyId::WordSpacing), "0px");
+        assert_eq!(value(PropertyId::TabSize), "20px");
+    }
+}
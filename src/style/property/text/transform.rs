@@ -0,0 +1,169 @@
+use crate::style::{ComputeContext, Keyword, StyleError, ToComputedValue, Value};
+
+/// The case a text is transformed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextCase {
+    /// The first letter of each word is uppercased.
+    Capitalize,
+    Uppercase,
+    Lowercase,
+}
+
+/// The transformations of the case and width of the characters of a text.
+///
+/// [CSS Text 4](https://drafts.csswg.org/css-text-4/#text-transform-property)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextTransform {
+    pub case: Option<TextCase>,
+    /// The characters are replaced by their fullwidth forms.
+    pub full_width: bool,
+    /// The small kana are replaced by their full-size forms.
+    pub full_size_kana: bool,
+}
+
+impl TextTransform {
+    /// Transform the case of a text, the width transformations being left to
+    /// the fonts.
+    pub fn apply(&self, text: &str) -> String {
+        match self.case {
+            None => text.to_string(),
+            Some(TextCase::Uppercase) => text.to_uppercase(),
+            Some(TextCase::Lowercase) => text.to_lowercase(),
+            Some(TextCase::Capitalize) => {
+                let mut word_start = true;
+                text.chars()
+                    .flat_map(|c| {
+                        let capitalized = match word_start && c.is_alphanumeric() {
+                            true => c.to_uppercase().collect::<Vec<_>>(),
+                            false => vec![c],
+                        };
+                        word_start = c.is_whitespace();
+                        capitalized
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl TryFrom<Value> for TextTransform {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&[
+            "none",
+            "capitalize",
+            "uppercase",
+            "lowercase",
+            "full-width",
+            "full-size-kana",
+        ]);
+        if value == Value::Keyword(Keyword::None) {
+            return Ok(Self::default());
+        }
+
+        let mut transform = Self::default();
+        for value in value.iter() {
+            match value {
+                Value::Keyword(keyword) => {
+                    let case = match keyword {
+                        Keyword::Capitalize => Some(TextCase::Capitalize),
+                        Keyword::Uppercase => Some(TextCase::Uppercase),
+                        Keyword::Lowercase => Some(TextCase::Lowercase),
+                        _ => None,
+                    };
+                    match (keyword, case) {
+                        (_, Some(case)) if transform.case.is_none() => transform.case = Some(case),
+                        (Keyword::FullWidth, _) if !transform.full_width => {
+                            transform.full_width = true
+                        }
+                        (Keyword::FullSizeKana, _) if !transform.full_size_kana => {
+                            transform.full_size_kana = true
+                        }
+                        _ => return Err(invalid),
+                    }
+                }
+                _ => return Err(invalid),
+            }
+        }
+
+        Ok(transform)
+    }
+}
+
+impl From<TextTransform> for Value {
+    fn from(value: TextTransform) -> Self {
+        let mut keywords = Vec::<Value>::default();
+        keywords.extend(value.case.map(|case| {
+            Value::Keyword(match case {
+                TextCase::Capitalize => Keyword::Capitalize,
+                TextCase::Uppercase => Keyword::Uppercase,
+                TextCase::Lowercase => Keyword::Lowercase,
+            })
+        }));
+        if value.full_width {
+            keywords.push(Value::Keyword(Keyword::FullWidth));
+        }
+        if value.full_size_kana {
+            keywords.push(Value::Keyword(Keyword::FullSizeKana));
+        }
+
+        match keywords.len() {
+            0 => Value::Keyword(Keyword::None),
+            1 => keywords.remove(0),
+            _ => Value::Array(keywords),
+        }
+    }
+}
+
+impl std::fmt::Display for TextTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Value::from(*self))
+    }
+}
+
+impl ToComputedValue for TextTransform {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::testing::{round_trip, TestDocument};
+
+    #[test]
+    fn test_001_text_transform() {
+        for (source, expected) in [
+            ("none", "none"),
+            ("uppercase full-width", "uppercase full-width"),
+            (
+                "full-size-kana full-width capitalize",
+                "capitalize full-width full-size-kana",
+            ),
+        ] {
+            assert_eq!(round_trip("text-transform", source), expected, "{}", source);
+        }
+        for invalid in [
+            "uppercase lowercase",
+            "none full-width",
+            "full-width full-width",
+        ] {
+            assert_eq!(round_trip("text-transform", invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        let styles = doc.resolve("html { text-transform: capitalize } p { text-transform: none }");
+        assert_eq!(
+            styles[1].text_transform.apply("the 2nd  step"),
+            "The 2nd  Step"
+        );
+        assert_eq!(styles[2].text_transform.apply("the step"), "the step");
+    }
+}
@@ -0,0 +1,94 @@
+//! The handling of the white space and the wrapping of the lines.
+//!
+//! [CSS Text 4](https://drafts.csswg.org/css-text-4/#white-space-processing)
+
+keyword_longhand!(
+    /// Whether the sequences of white space and the segment breaks are
+    /// collapsed.
+    WhiteSpaceCollapse {
+        Collapse,
+        Discard,
+        Preserve,
+        PreserveBreaks,
+        PreserveSpaces,
+        BreakSpaces,
+    },
+    &[
+        "collapse",
+        "discard",
+        "preserve",
+        "preserve-breaks",
+        "preserve-spaces",
+        "break-spaces",
+    ]
+);
+keyword_longhand!(
+    /// Whether the lines wrap at the soft wrap opportunities, and how the
+    /// wrapping points are chosen.
+    TextWrap { Wrap, Nowrap, Balance, Stable, Pretty },
+    &["wrap", "nowrap", "balance", "stable", "pretty"]
+);
+
+impl TextWrap {
+    pub fn wraps(&self) -> bool {
+        *self != Self::Nowrap
+    }
+}
+
+impl WhiteSpaceCollapse {
+    /// Whether the spaces and tabs are kept as is.
+    pub fn preserves_spaces(&self) -> bool {
+        matches!(
+            self,
+            Self::Preserve | Self::PreserveSpaces | Self::BreakSpaces
+        )
+    }
+
+    /// Whether the segment breaks are kept as forced line breaks.
+    pub fn preserves_breaks(&self) -> bool {
+        matches!(
+            self,
+            Self::Preserve | Self::PreserveBreaks | Self::BreakSpaces
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        TextWrap, WhiteSpaceCollapse,
+    };
+
+    #[test]
+    fn test_001_white_space_longhands() {
+        for (name, source) in [
+            ("white-space-collapse", "preserve-breaks"),
+            ("white-space-collapse", "break-spaces"),
+            ("text-wrap", "balance"),
+            ("text-wrap", "pretty"),
+        ] {
+            assert_eq!(round_trip(name, source), source);
+        }
+        for (name, invalid) in [
+            ("white-space-collapse", "pre"),
+            ("text-wrap", "wrap nowrap"),
+        ] {
+            assert_eq!(round_trip(name, invalid), "", "{}", invalid);
+        }
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        doc.create(Some(html), "body", &[]);
+
+        let styles = doc.resolve("html { white-space: pre-line }");
+        let body = &styles[1];
+        assert_eq!(
+            body.white_space_collapse,
+            WhiteSpaceCollapse::PreserveBreaks
+        );
+        assert!(body.white_space_collapse.preserves_breaks());
+        assert!(!body.white_space_collapse.preserves_spaces());
+        assert_eq!(body.text_wrap, TextWrap::Wrap);
+    }
+}
@@ -0,0 +1,44 @@
+keyword_longhand!(
+    /// Whether a box is painted, collapse hiding the rows and columns of
+    /// tables and flex lines without painting their content.
+    ///
+    /// [CSS Display 3](https://drafts.csswg.org/css-display-3/#visibility)
+    Visibility {
+        Visible,
+        Hidden,
+        Collapse,
+    },
+    &["visible", "hidden", "collapse"]
+);
+
+impl Visibility {
+    pub fn is_visible(&self) -> bool {
+        *self == Self::Visible
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        Visibility,
+    };
+
+    #[test]
+    fn test_001_visibility() {
+        assert_eq!(round_trip("visibility", "COLLAPSE"), "collapse");
+        assert_eq!(round_trip("visibility", "none"), "");
+        assert_eq!(round_trip("visibility", "hidden visible"), "");
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        // A descendant of a hidden box may be visible.
+        let styles = doc.resolve("html { visibility: hidden } p { visibility: visible }");
+        assert_eq!(styles[1].visibility, Visibility::Hidden);
+        assert!(!styles[1].visibility.is_visible());
+        assert!(styles[2].visibility.is_visible());
+    }
+}
@@ -0,0 +1,199 @@
+use crate::style::{ComputeContext, Keyword, StyleError, Value, WritingMode};
+
+use super::ToComputedValue;
+
+impl TryFrom<Value> for WritingMode {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::HorizontalTb) => Ok(Self::HorizontalTb),
+            Value::Keyword(Keyword::VerticalRl) => Ok(Self::VerticalRl),
+            Value::Keyword(Keyword::VerticalLr) => Ok(Self::VerticalLr),
+            Value::Keyword(Keyword::SidewaysRl) => Ok(Self::SidewaysRl),
+            Value::Keyword(Keyword::SidewaysLr) => Ok(Self::SidewaysLr),
+            _ => Err(StyleError::InvalidValue(&[
+                "horizontal-tb",
+                "vertical-rl",
+                "vertical-lr",
+                "sideways-rl",
+                "sideways-lr",
+            ])),
+        }
+    }
+}
+
+impl From<WritingMode> for Keyword {
+    fn from(value: WritingMode) -> Self {
+        match value {
+            WritingMode::HorizontalTb => Self::HorizontalTb,
+            WritingMode::VerticalRl => Self::VerticalRl,
+            WritingMode::VerticalLr => Self::VerticalLr,
+            WritingMode::SidewaysRl => Self::SidewaysRl,
+            WritingMode::SidewaysLr => Self::SidewaysLr,
+        }
+    }
+}
+
+impl From<WritingMode> for Value {
+    fn from(value: WritingMode) -> Self {
+        Self::Keyword(Keyword::from(value))
+    }
+}
+
+impl ToComputedValue for WritingMode {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+/// The inline base direction.
+///
+/// [CSS Writing Modes 4](https://drafts.csswg.org/css-writing-modes-4/#direction)
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl TryFrom<Value> for Direction {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Ltr) => Ok(Self::Ltr),
+            Value::Keyword(Keyword::Rtl) => Ok(Self::Rtl),
+            _ => Err(StyleError::InvalidValue(&["ltr", "rtl"])),
+        }
+    }
+}
+
+impl From<Direction> for Keyword {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Ltr => Self::Ltr,
+            Direction::Rtl => Self::Rtl,
+        }
+    }
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Keyword::from(*self))
+    }
+}
+
+impl From<Direction> for Value {
+    fn from(value: Direction) -> Self {
+        Self::Keyword(Keyword::from(value))
+    }
+}
+
+impl ToComputedValue for Direction {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+/// The orientation of the glyphs in a vertical line.
+///
+/// [CSS Writing Modes 4](https://drafts.csswg.org/css-writing-modes-4/#text-orientation)
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum TextOrientation {
+    /// The horizontal scripts are rotated, the vertical ones kept upright.
+    #[default]
+    Mixed,
+    Upright,
+    /// All the glyphs are rotated, as in a horizontal line.
+    Sideways,
+}
+
+impl TryFrom<Value> for TextOrientation {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Mixed) => Ok(Self::Mixed),
+            Value::Keyword(Keyword::Upright) => Ok(Self::Upright),
+            Value::Keyword(Keyword::Sideways) => Ok(Self::Sideways),
+            _ => Err(StyleError::InvalidValue(&["mixed", "upright", "sideways"])),
+        }
+    }
+}
+
+impl From<TextOrientation> for Keyword {
+    fn from(value: TextOrientation) -> Self {
+        match value {
+            TextOrientation::Mixed => Self::Mixed,
+            TextOrientation::Upright => Self::Upright,
+            TextOrientation::Sideways => Self::Sideways,
+        }
+    }
+}
+
+impl std::fmt::Display for TextOrientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Keyword::from(*self))
+    }
+}
+
+impl From<TextOrientation> for Value {
+    fn from(value: TextOrientation) -> Self {
+        Self::Keyword(Keyword::from(value))
+    }
+}
+
+impl ToComputedValue for TextOrientation {
+    type Computed = Self;
+
+    fn compute(&self, _context: &ComputeContext) -> Self::Computed {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::{round_trip, TestDocument},
+        Direction, PropertyId, WritingMode,
+    };
+
+    #[test]
+    fn test_001_writing_modes() {
+        for source in ["horizontal-tb", "vertical-rl", "sideways-lr"] {
+            assert_eq!(round_trip("writing-mode", source), source);
+        }
+        assert_eq!(round_trip("direction", "RTL"), "rtl");
+        for invalid in ["lr-tb", "tb", "horizontal"] {
+            assert_eq!(round_trip("writing-mode", invalid), "", "{}", invalid);
+        }
+        assert_eq!(round_trip("direction", "auto"), "");
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        doc.create(Some(body), "p", &[]);
+
+        let styles = doc.resolve(
+            "body { writing-mode: vertical-rl; direction: rtl; inline-size: calc(1px - 1em); \
+                margin-inline-start: 1em }",
+        );
+
+        // The writing mode and direction are inherited, and map the logical
+        // properties of the element itself.
+        assert_eq!(styles[2].writing_mode, WritingMode::VerticalRl);
+        assert_eq!(styles[2].direction, Direction::Rtl);
+        assert_eq!(styles[0].writing_mode, WritingMode::HorizontalTb);
+        assert_eq!(styles[1].value(PropertyId::Height).to_string(), "0px");
+        assert_eq!(
+            styles[1].value(PropertyId::MarginBottom).to_string(),
+            "16px"
+        );
+        assert_eq!(styles[2].value(PropertyId::MarginBottom).to_string(), "0px");
+    }
+}
@@ -0,0 +1,161 @@
+//! An in-memory document used to test the style system.
+use crate::style::{
+    Cascade, ComputedStyle, Element, ElementState, Origin, PropertyDeclarationBlock, StyleResolver,
+    Stylesheet,
+};
+
+/// Parse the value of a property, longhand or shorthand, and serialize it
+/// back, empty if the declaration is invalid.
+pub fn round_trip(name: &str, source: &str) -> String {
+    PropertyDeclarationBlock::from(format!("{}: {}", name, source).as_str()).property_value(name)
+}
+
+#[derive(Default)]
+struct TestNode {
+    local_name: String,
+    attributes: Vec<(String, String)>,
+    states: Vec<ElementState>,
+    text: Option<String>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+}
+
+#[derive(Default)]
+pub struct TestDocument {
+    nodes: Vec<TestNode>,
+}
+
+impl TestDocument {
+    /// Create an element, appended to the children of its parent.
+    pub fn create(
+        &mut self,
+        parent: Option<usize>,
+        local_name: &str,
+        attributes: &[(&str, &str)],
+    ) -> usize {
+        let index = self.nodes.len();
+
+        self.nodes.push(TestNode {
+            local_name: local_name.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            parent,
+            ..Default::default()
+        });
+
+        if let Some(parent) = parent {
+            self.nodes[parent].children.push(index);
+        }
+
+        index
+    }
+
+    pub fn set_text(&mut self, node: usize, text: &str) {
+        self.nodes[node].text = Some(text.to_string());
+    }
+
+    pub fn set_state(&mut self, node: usize, state: ElementState) {
+        self.nodes[node].states.push(state);
+    }
+
+    pub fn element(&self, node: usize) -> TestElement<'_> {
+        TestElement { doc: self, node }
+    }
+
+    /// Resolve the styles of the elements, the first one being the root of
+    /// the document, with an author stylesheet. The styles are indexed by
+    /// node.
+    pub fn resolve(&self, author: &str) -> Vec<ComputedStyle> {
+        let author = Stylesheet::from(author);
+        let cascade = Cascade::new([(Origin::Author, &author)]);
+
+        let mut styles = StyleResolver::new(&cascade).resolve_tree(&self.element(0));
+        assert_eq!(styles.len(), self.nodes.len(), "detached elements");
+        styles.sort_by_key(|(element, _)| element.node);
+        styles.into_iter().map(|(_, style)| style).collect()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct TestElement<'a> {
+    doc: &'a TestDocument,
+    pub node: usize,
+}
+
+impl<'a> TestElement<'a> {
+    fn data(&self) -> &'a TestNode {
+        &self.doc.nodes[self.node]
+    }
+
+    fn sibling(&self, offset: isize) -> Option<Self> {
+        let parent = self.data().parent?;
+        let siblings = &self.doc.nodes[parent].children;
+        let position = siblings.iter().position(|n| *n == self.node)? as isize;
+
+        usize::try_from(position + offset)
+            .ok()
+            .and_then(|position| siblings.get(position))
+            .map(|node| self.doc.element(*node))
+    }
+}
+
+impl Element for TestElement<'_> {
+    fn local_name(&self) -> &str {
+        &self.data().local_name
+    }
+
+    fn id(&self) -> Option<&str> {
+        self.attribute("id")
+    }
+
+    fn classes(&self) -> Vec<&str> {
+        self.attribute("class")
+            .map(|classes| classes.split_ascii_whitespace().collect())
+            .unwrap_or_default()
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.data()
+            .attributes
+            .iter()
+            .find(|(attr, _)| attr == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn attribute_names(&self) -> Vec<&str> {
+        self.data()
+            .attributes
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    fn parent_element(&self) -> Option<Self> {
+        self.data().parent.map(|node| self.doc.element(node))
+    }
+
+    fn prev_sibling_element(&self) -> Option<Self> {
+        self.sibling(-1)
+    }
+
+    fn next_sibling_element(&self) -> Option<Self> {
+        self.sibling(1)
+    }
+
+    fn first_child_element(&self) -> Option<Self> {
+        self.data()
+            .children
+            .first()
+            .map(|node| self.doc.element(*node))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data().children.is_empty() && self.data().text.is_none()
+    }
+
+    fn is_in_state(&self, state: ElementState) -> bool {
+        self.data().states.contains(&state)
+    }
+}
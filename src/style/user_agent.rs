@@ -0,0 +1,148 @@
+use std::sync::OnceLock;
+
+use crate::style::{Cascade, Origin, Stylesheet};
+
+/// The source of the user-agent stylesheet of HTML documents.
+pub const HTML_STYLESHEET: &str = include_str!("../../assets/css/html.css");
+
+/// The font family of the bundled LiberationSans fonts, the default font
+/// of the user-agent stylesheet.
+pub const DEFAULT_FONT_FAMILY: &str = "Liberation Sans";
+
+/// The user-agent stylesheet of HTML documents, parsed on first use.
+pub fn html_stylesheet() -> &'static Stylesheet {
+    static STYLESHEET: OnceLock<Stylesheet> = OnceLock::new();
+    STYLESHEET.get_or_init(|| Stylesheet::from(HTML_STYLESHEET))
+}
+
+/// The stylesheet of the user-agent origin.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum UserAgentStylesheet<'a> {
+    /// The built-in stylesheet of HTML documents.
+    #[default]
+    Html,
+    Custom(&'a Stylesheet),
+    Disabled,
+}
+
+impl<'a> UserAgentStylesheet<'a> {
+    pub fn stylesheet(&self) -> Option<&'a Stylesheet> {
+        match self {
+            Self::Html => Some(html_stylesheet()),
+            Self::Custom(stylesheet) => Some(stylesheet),
+            Self::Disabled => None,
+        }
+    }
+}
+
+impl Cascade {
+    /// Collect the user-agent stylesheet, then the user and author stylesheets.
+    pub fn with_user_agent<'a, I>(user_agent: UserAgentStylesheet<'a>, stylesheets: I) -> Self
+    where
+        I: IntoIterator<Item = (Origin, &'a Stylesheet)>,
+    {
+        let user_agent = user_agent
+            .stylesheet()
+            .map(|stylesheet| (Origin::UserAgent, stylesheet));
+
+        Self::new(user_agent.into_iter().chain(stylesheets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        testing::TestDocument, Cascade, CascadeInputs, Display, Length, Origin, PropertyId,
+        StyleResolver, Stylesheet,
+    };
+
+    use super::{html_stylesheet, UserAgentStylesheet, DEFAULT_FONT_FAMILY};
+
+    #[test]
+    fn test_001_html_stylesheet() {
+        assert!(std::ptr::eq(html_stylesheet(), html_stylesheet()));
+        assert!(html_stylesheet().rules.len() > 50);
+
+        let cascade = Cascade::with_user_agent(UserAgentStylesheet::Html, []);
+        // Every rule of the stylesheet has a valid selector list.
+        assert_eq!(cascade.len(), html_stylesheet().rules.len());
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let head = doc.create(Some(html), "head", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let section = doc.create(Some(body), "section", &[]);
+        let h1 = doc.create(Some(section), "h1", &[]);
+
+        let styles = StyleResolver::new(&cascade).resolve_tree(&doc.element(html));
+        let style = |node: usize| &styles.iter().find(|(e, _)| e.node == node).unwrap().1;
+
+        assert!(style(head).display.is_none());
+        assert_ne!(style(body).display, Display::default());
+        assert_eq!(style(h1).font_size, Length::px(24.0));
+        assert!(style(h1)
+            .font_family
+            .to_string()
+            .contains(DEFAULT_FONT_FAMILY));
+
+        let values = cascade.cascade(&doc.element(h1), None, None, &CascadeInputs::default());
+        assert_eq!(
+            values.winner("font-size").unwrap().origin(),
+            Origin::UserAgent
+        );
+    }
+
+    #[test]
+    fn test_002_swap_or_disable() {
+        let author = Stylesheet::from("div { display: none }");
+        let user_agent = Stylesheet::from("div { display: block; color: red }");
+
+        let mut doc = TestDocument::default();
+        let div = doc.create(None, "div", &[]);
+        let element = doc.element(div);
+        let inputs = CascadeInputs::default();
+
+        let cascade =
+            Cascade::with_user_agent(UserAgentStylesheet::Disabled, [(Origin::Author, &author)]);
+        assert_eq!(cascade.len(), 1);
+
+        let cascade = Cascade::with_user_agent(
+            UserAgentStylesheet::Custom(&user_agent),
+            [(Origin::Author, &author)],
+        );
+        let values = cascade.cascade(&element, None, None, &inputs);
+        assert_eq!(values.winner("display").unwrap().origin(), Origin::Author);
+        assert_eq!(values.winner("color").unwrap().origin(), Origin::UserAgent);
+    }
+
+    /// The colors of the user-agent stylesheet reach the computed style, and
+    /// are inherited unless an author rule overrides them.
+    #[test]
+    fn test_003_color_rules() {
+        let author = Stylesheet::from("body { color: red } mark.warning { color: orange }");
+        let cascade =
+            Cascade::with_user_agent(UserAgentStylesheet::Html, [(Origin::Author, &author)]);
+
+        let mut doc = TestDocument::default();
+        let html = doc.create(None, "html", &[]);
+        let body = doc.create(Some(html), "body", &[]);
+        let p = doc.create(Some(body), "p", &[]);
+        let mark = doc.create(Some(p), "mark", &[]);
+        let span = doc.create(Some(mark), "span", &[]);
+        let warning = doc.create(Some(p), "mark", &[("class", "warning")]);
+
+        let styles = StyleResolver::new(&cascade).resolve_tree(&doc.element(html));
+        let style = |node: usize| &styles.iter().find(|(e, _)| e.node == node).unwrap().1;
+        let color = |node: usize| style(node).value(PropertyId::Color).to_string();
+
+        assert_eq!(color(html), "canvastext");
+        assert_eq!(color(p), "red");
+        assert_eq!(color(mark), "black");
+        assert_eq!(color(span), "black");
+        assert_eq!(color(warning), "orange");
+        assert_eq!(style(mark).background_color.to_string(), "yellow");
+
+        let values = cascade.cascade(&doc.element(mark), None, None, &CascadeInputs::default());
+        assert_eq!(values.winner("color").unwrap().origin(), Origin::UserAgent);
+    }
+}
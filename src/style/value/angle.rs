@@ -0,0 +1,55 @@
+use crate::style::StyleError;
+
+use super::{AngleUnit, Dimension, Number, Unit, Value};
+
+/// An angle, such as a hue or a rotation.
+#[derive(Debug, Clone, Copy)]
+pub struct Angle {
+    pub quantity: Number,
+    pub unit: AngleUnit,
+}
+
+impl Angle {
+    pub fn deg<N: Into<Number>>(quantity: N) -> Self {
+        Self::new(quantity, AngleUnit::Deg)
+    }
+
+    /// The angle in degrees, its canonical unit.
+    pub fn to_deg(&self) -> f32 {
+        f32::from(self.quantity) * self.unit.deg_per_unit()
+    }
+
+    pub fn to_radians(&self) -> f32 {
+        self.to_deg().to_radians()
+    }
+}
+
+quantity!(Angle, AngleUnit, Angle, Deg, to_deg, "<angle>");
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, Angle, AngleUnit, Value};
+
+    #[test]
+    fn test_001_angle() {
+        let value = Value::try_from(&parse_component_values("0.5TURN")[0]).unwrap();
+        let angle = Angle::try_from(value).unwrap();
+        assert_eq!(angle.to_string(), "0.5turn");
+        assert_eq!(angle.to_deg(), 180.0);
+        assert_eq!(angle, Angle::deg(180));
+        assert_eq!(
+            Angle::new(200, AngleUnit::Grad)
+                .to_canonical_unit()
+                .to_string(),
+            "180deg"
+        );
+
+        assert!(Angle::new(1, AngleUnit::Rad) < Angle::deg(60));
+        assert_eq!(
+            (angle + Angle::new(0.25, AngleUnit::Turn)).to_string(),
+            "0.75turn"
+        );
+        assert_eq!((angle - Angle::deg(90)).to_string(), "90deg");
+        assert_eq!((-(angle * 2.0) / 4.0).to_string(), "-0.25turn");
+    }
+}
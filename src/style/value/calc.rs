@@ -0,0 +1,1267 @@
+use crate::style::{
+    BlockDelimiter, ComponentValue, ComputeContext, Function, StyleError, TokenKind,
+};
+
+use super::{
+    AbsoluteLengthUnit, AngleUnit, Dimension, DurationUnit, FrequencyUnit, Length, LengthContext,
+    LengthUnit, Number, Percentage, ResolutionUnit, Unit, Value,
+};
+
+/// The base types of a calculation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaseType {
+    Length,
+    Angle,
+    Time,
+    Frequency,
+    Resolution,
+    Percent,
+}
+
+impl BaseType {
+    const ALL: [Self; 6] = [
+        Self::Length,
+        Self::Angle,
+        Self::Time,
+        Self::Frequency,
+        Self::Resolution,
+        Self::Percent,
+    ];
+
+    fn of(unit: &Unit) -> Self {
+        match unit {
+            Unit::Length(_) => Self::Length,
+            Unit::Angle(_) => Self::Angle,
+            Unit::Duration(_) => Self::Time,
+            Unit::Frequency(_) => Self::Frequency,
+            Unit::Resolution(_) => Self::Resolution,
+        }
+    }
+
+    /// The canonical unit the values of the type are converted to.
+    fn canonical_unit(&self) -> Option<Unit> {
+        match self {
+            Self::Length => Some(Unit::Length(LengthUnit::Absolute(AbsoluteLengthUnit::Px))),
+            Self::Angle => Some(Unit::Angle(AngleUnit::Deg)),
+            Self::Time => Some(Unit::Duration(DurationUnit::S)),
+            Self::Frequency => Some(Unit::Frequency(FrequencyUnit::Hz)),
+            Self::Resolution => Some(Unit::Resolution(ResolutionUnit::Dppx)),
+            Self::Percent => None,
+        }
+    }
+}
+
+/// The type of a calculation, as the exponents of its base types.
+///
+/// [CSS Typed OM](https://drafts.css-houdini.org/css-typed-om-1/#numeric-typing)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CalcType {
+    exponents: [i8; 6],
+    /// The base type the percentages resolve against.
+    percent_hint: Option<BaseType>,
+}
+
+impl CalcType {
+    /// The type of a number, all the exponents being zero.
+    pub fn number() -> Self {
+        Self::default()
+    }
+
+    pub fn of(base: BaseType) -> Self {
+        let mut ty = Self::default();
+        ty.exponents[base as usize] = 1;
+        ty
+    }
+
+    pub fn exponent(&self, base: BaseType) -> i8 {
+        self.exponents[base as usize]
+    }
+
+    pub fn percent_hint(&self) -> Option<BaseType> {
+        self.percent_hint
+    }
+
+    pub fn is_number(&self) -> bool {
+        self.exponents.iter().all(|exp| *exp == 0)
+    }
+
+    /// Checks if the type matches the base type, the percentages being
+    /// allowed if they resolve against it.
+    pub fn matches(&self, base: BaseType, percentages: bool) -> bool {
+        if *self == Self::of(base) {
+            return true;
+        }
+
+        percentages
+            && (*self == Self::of(BaseType::Percent)
+                || (self.exponents == Self::of(base).exponents && self.percent_hint == Some(base)))
+    }
+
+    /// Move the percent exponent to the hinted base type.
+    fn apply_hint(mut self, hint: BaseType) -> Self {
+        let percent = self.exponents[BaseType::Percent as usize];
+        self.exponents[BaseType::Percent as usize] = 0;
+        self.exponents[hint as usize] += percent;
+        self.percent_hint = Some(hint);
+        self
+    }
+
+    /// Apply the hint of one type to the other, failing if they are different.
+    fn unify_hints(self, other: Self) -> Option<(Self, Self)> {
+        match (self.percent_hint, other.percent_hint) {
+            (Some(lhs), Some(rhs)) if lhs != rhs => None,
+            (Some(hint), None) => Some((self, other.apply_hint(hint))),
+            (None, Some(hint)) => Some((self.apply_hint(hint), other)),
+            _ => Some((self, other)),
+        }
+    }
+
+    /// The type of the sum of two values.
+    pub fn sum(self, other: Self) -> Option<Self> {
+        let (lhs, rhs) = self.unify_hints(other)?;
+
+        if lhs.exponents == rhs.exponents {
+            return Some(lhs);
+        }
+
+        let percent = BaseType::Percent as usize;
+        if lhs.exponents[percent] == 0 && rhs.exponents[percent] == 0 {
+            return None;
+        }
+
+        BaseType::ALL[..5]
+            .iter()
+            .map(|hint| (lhs.apply_hint(*hint), rhs.apply_hint(*hint)))
+            .find(|(lhs, rhs)| lhs.exponents == rhs.exponents)
+            .map(|(lhs, _)| lhs)
+    }
+
+    /// The type of the product of two values.
+    pub fn product(self, other: Self) -> Option<Self> {
+        let (mut lhs, rhs) = self.unify_hints(other)?;
+
+        for (exp, other) in lhs.exponents.iter_mut().zip(rhs.exponents) {
+            *exp += other;
+        }
+
+        Some(lhs)
+    }
+
+    /// The type of the inverse of a value.
+    pub fn invert(mut self) -> Self {
+        for exp in self.exponents.iter_mut() {
+            *exp = -*exp;
+        }
+        self
+    }
+
+    /// The base type with an exponent of one, if it is the only one.
+    fn single(&self) -> Option<BaseType> {
+        let mut bases = BaseType::ALL
+            .iter()
+            .filter(|base| self.exponent(**base) != 0);
+
+        match (bases.next(), bases.next()) {
+            (Some(base), None) if self.exponent(*base) == 1 => Some(*base),
+            _ => None,
+        }
+    }
+}
+
+/// The unit of a numeric value in a calculation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalcUnit {
+    Number,
+    Percentage,
+    Dimension(Unit),
+}
+
+/// A numeric value of a calculation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalcValue {
+    pub value: f32,
+    pub unit: CalcUnit,
+}
+
+impl CalcValue {
+    pub fn number(value: f32) -> Self {
+        Self {
+            value,
+            unit: CalcUnit::Number,
+        }
+    }
+
+    pub fn percentage(value: f32) -> Self {
+        Self {
+            value,
+            unit: CalcUnit::Percentage,
+        }
+    }
+
+    pub fn dimension(value: f32, unit: Unit) -> Self {
+        Self {
+            value,
+            unit: CalcUnit::Dimension(unit),
+        }
+    }
+
+    pub fn ty(&self) -> CalcType {
+        match &self.unit {
+            CalcUnit::Number => CalcType::number(),
+            CalcUnit::Percentage => CalcType::of(BaseType::Percent),
+            CalcUnit::Dimension(unit) => CalcType::of(BaseType::of(unit)),
+        }
+    }
+
+    /// Convert the value to the canonical unit of its type, failing if
+    /// the context lacks what a relative unit needs.
+    fn canonical(&self, context: &CalcContext) -> Option<Typed> {
+        let unit = match &self.unit {
+            CalcUnit::Dimension(unit) => unit,
+            _ => {
+                return Some(Typed {
+                    value: self.value,
+                    ty: self.ty(),
+                })
+            }
+        };
+
+        let factor = match unit {
+            Unit::Length(LengthUnit::Absolute(unit)) => unit.px_per_unit(),
+            Unit::Length(unit) => context.length?.px_per_unit(*unit),
+            Unit::Angle(unit) => unit.deg_per_unit(),
+            Unit::Duration(unit) => unit.seconds_per_unit(),
+            Unit::Frequency(unit) => unit.hz_per_unit(),
+            Unit::Resolution(unit) => unit.dppx_per_unit(),
+        };
+
+        Some(Typed {
+            value: self.value * factor,
+            ty: self.ty(),
+        })
+    }
+}
+
+impl std::fmt::Display for CalcValue {
+    /// The non-finite values are written as constants, multiplied by one of
+    /// their unit.
+    ///
+    /// [CSS Values 4](https://drafts.csswg.org/css-values-4/#calc-serialize)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.value.is_finite() {
+            let constant = match self.value {
+                value if value.is_nan() => "NaN",
+                value if value > 0.0 => "infinity",
+                _ => "-infinity",
+            };
+
+            return match &self.unit {
+                CalcUnit::Number => write!(f, "{}", constant),
+                CalcUnit::Percentage => write!(f, "{} * 1%", constant),
+                CalcUnit::Dimension(unit) => write!(f, "{} * 1{}", constant, unit),
+            };
+        }
+
+        match &self.unit {
+            CalcUnit::Number => write!(f, "{}", self.value),
+            CalcUnit::Percentage => write!(f, "{}%", self.value),
+            CalcUnit::Dimension(unit) => write!(f, "{}{}", self.value, unit),
+        }
+    }
+}
+
+/// A value in the canonical unit of its type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Typed {
+    value: f32,
+    ty: CalcType,
+}
+
+impl Typed {
+    fn number(value: f32) -> Self {
+        Self {
+            value,
+            ty: CalcType::number(),
+        }
+    }
+
+    /// Convert back to a numeric value, if its type has a unit.
+    fn to_value(self) -> Option<CalcValue> {
+        if self.ty.is_number() {
+            return Some(CalcValue::number(self.value));
+        }
+
+        match self.ty.single()? {
+            BaseType::Percent => Some(CalcValue::percentage(self.value)),
+            base => Some(CalcValue::dimension(self.value, base.canonical_unit()?)),
+        }
+    }
+}
+
+/// What the relative values of a calculation are resolved against.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CalcContext {
+    /// The value a percentage of 100% stands for.
+    pub percentage_basis: Option<CalcValue>,
+    /// What the relative lengths are resolved against.
+    pub length: Option<LengthContext>,
+}
+
+impl CalcContext {
+    /// The context of the computation of an element's values.
+    pub fn new(context: &ComputeContext) -> Self {
+        Self {
+            percentage_basis: None,
+            length: Some(context.length),
+        }
+    }
+
+    pub fn with_percentage_basis(mut self, basis: CalcValue) -> Self {
+        self.percentage_basis = Some(basis);
+        self
+    }
+}
+
+/// The rounding strategies of round().
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingStrategy {
+    #[default]
+    Nearest,
+    Up,
+    Down,
+    ToZero,
+}
+
+impl RoundingStrategy {
+    fn round(&self, value: f32, step: f32) -> f32 {
+        let ratio = value / step;
+
+        let rounded = match self {
+            Self::Nearest => (ratio + 0.5).floor(),
+            Self::Up => ratio.ceil(),
+            Self::Down => ratio.floor(),
+            Self::ToZero => ratio.trunc(),
+        };
+
+        rounded * step
+    }
+}
+
+impl TryFrom<&str> for RoundingStrategy {
+    type Error = StyleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "nearest" => Ok(Self::Nearest),
+            "up" => Ok(Self::Up),
+            "down" => Ok(Self::Down),
+            "to-zero" => Ok(Self::ToZero),
+            _ => Err(StyleError::InvalidValue(&["<rounding-strategy>"])),
+        }
+    }
+}
+
+impl std::fmt::Display for RoundingStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nearest => write!(f, "nearest"),
+            Self::Up => write!(f, "up"),
+            Self::Down => write!(f, "down"),
+            Self::ToZero => write!(f, "to-zero"),
+        }
+    }
+}
+
+/// The math functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathFunction {
+    Calc,
+    Min,
+    Max,
+    Clamp,
+    Round(RoundingStrategy),
+    Mod,
+    Rem,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Atan2,
+    Pow,
+    Sqrt,
+    Hypot,
+    Log,
+    Exp,
+    Abs,
+    Sign,
+}
+
+impl MathFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Calc => "calc",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Clamp => "clamp",
+            Self::Round(_) => "round",
+            Self::Mod => "mod",
+            Self::Rem => "rem",
+            Self::Sin => "sin",
+            Self::Cos => "cos",
+            Self::Tan => "tan",
+            Self::Asin => "asin",
+            Self::Acos => "acos",
+            Self::Atan => "atan",
+            Self::Atan2 => "atan2",
+            Self::Pow => "pow",
+            Self::Sqrt => "sqrt",
+            Self::Hypot => "hypot",
+            Self::Log => "log",
+            Self::Exp => "exp",
+            Self::Abs => "abs",
+            Self::Sign => "sign",
+        }
+    }
+
+    /// The type of the function's result, given the types of its arguments.
+    fn ty(&self, args: &[CalcType]) -> Option<CalcType> {
+        let same = || {
+            args.iter()
+                .skip(1)
+                .try_fold(*args.first()?, |ty, arg| ty.sum(*arg))
+        };
+        let numbers = || args.iter().all(CalcType::is_number);
+
+        match (self, args.len()) {
+            (Self::Calc | Self::Abs, 1) => same(),
+            (Self::Min | Self::Max | Self::Hypot, 1..) => same(),
+            (Self::Clamp, 3) | (Self::Round(_) | Self::Mod | Self::Rem, 2) => same(),
+            (Self::Sin | Self::Cos | Self::Tan, 1)
+                if args[0].is_number() || args[0] == CalcType::of(BaseType::Angle) =>
+            {
+                Some(CalcType::number())
+            }
+            (Self::Asin | Self::Acos | Self::Atan, 1) if numbers() => {
+                Some(CalcType::of(BaseType::Angle))
+            }
+            (Self::Atan2, 2) => same().map(|_| CalcType::of(BaseType::Angle)),
+            (Self::Pow, 2) | (Self::Sqrt | Self::Exp, 1) | (Self::Log, 1..=2) if numbers() => {
+                Some(CalcType::number())
+            }
+            (Self::Sign, 1) => Some(CalcType::number()),
+            _ => None,
+        }
+    }
+
+    /// Evaluate the function, the arguments being checked beforehand.
+    fn evaluate(&self, args: &[Typed]) -> Option<Typed> {
+        let ty = args[0].ty;
+
+        // The arguments of the same type must have the same unit.
+        let same = args.iter().all(|arg| arg.ty.exponents == ty.exponents);
+        let value = |index: usize| args[index].value;
+        let angle = |value: f32| Typed {
+            value: value.to_degrees(),
+            ty: CalcType::of(BaseType::Angle),
+        };
+        let radians = || {
+            if ty.is_number() {
+                value(0)
+            } else {
+                value(0).to_radians()
+            }
+        };
+
+        let result = match self {
+            Self::Calc => args[0],
+            Self::Min if same => Typed {
+                value: args
+                    .iter()
+                    .map(|arg| arg.value)
+                    .fold(f32::INFINITY, f32::min),
+                ty,
+            },
+            Self::Max if same => Typed {
+                value: args
+                    .iter()
+                    .map(|arg| arg.value)
+                    .fold(f32::NEG_INFINITY, f32::max),
+                ty,
+            },
+            Self::Clamp if same => Typed {
+                value: value(1).min(value(2)).max(value(0)),
+                ty,
+            },
+            Self::Round(strategy) if same => Typed {
+                value: strategy.round(value(0), value(1)),
+                ty,
+            },
+            Self::Mod if same => Typed {
+                value: value(0) - value(1) * (value(0) / value(1)).floor(),
+                ty,
+            },
+            Self::Rem if same => Typed {
+                value: value(0) - value(1) * (value(0) / value(1)).trunc(),
+                ty,
+            },
+            Self::Sin => Typed::number(radians().sin()),
+            Self::Cos => Typed::number(radians().cos()),
+            Self::Tan => Typed::number(radians().tan()),
+            Self::Asin => angle(value(0).asin()),
+            Self::Acos => angle(value(0).acos()),
+            Self::Atan => angle(value(0).atan()),
+            Self::Atan2 if same => angle(value(0).atan2(value(1))),
+            Self::Pow => Typed::number(value(0).powf(value(1))),
+            Self::Sqrt => Typed::number(value(0).sqrt()),
+            Self::Hypot if same => Typed {
+                value: args.iter().map(|arg| arg.value.powi(2)).sum::<f32>().sqrt(),
+                ty,
+            },
+            Self::Log if args.len() == 2 => Typed::number(value(0).log(value(1))),
+            Self::Log => Typed::number(value(0).ln()),
+            Self::Exp => Typed::number(value(0).exp()),
+            Self::Abs => Typed {
+                value: value(0).abs(),
+                ty,
+            },
+            Self::Sign => Typed::number(match value(0) {
+                value if value > 0.0 => 1.0,
+                value if value < 0.0 => -1.0,
+                value => value,
+            }),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+}
+
+impl TryFrom<&str> for MathFunction {
+    type Error = StyleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "calc" => Ok(Self::Calc),
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            "clamp" => Ok(Self::Clamp),
+            "round" => Ok(Self::Round(RoundingStrategy::default())),
+            "mod" => Ok(Self::Mod),
+            "rem" => Ok(Self::Rem),
+            "sin" => Ok(Self::Sin),
+            "cos" => Ok(Self::Cos),
+            "tan" => Ok(Self::Tan),
+            "asin" => Ok(Self::Asin),
+            "acos" => Ok(Self::Acos),
+            "atan" => Ok(Self::Atan),
+            "atan2" => Ok(Self::Atan2),
+            "pow" => Ok(Self::Pow),
+            "sqrt" => Ok(Self::Sqrt),
+            "hypot" => Ok(Self::Hypot),
+            "log" => Ok(Self::Log),
+            "exp" => Ok(Self::Exp),
+            "abs" => Ok(Self::Abs),
+            "sign" => Ok(Self::Sign),
+            _ => Err(StyleError::InvalidValue(&["<math-function>"])),
+        }
+    }
+}
+
+/// A node of a calculation tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalcNode {
+    Value(CalcValue),
+    Sum(Vec<CalcNode>),
+    Product(Vec<CalcNode>),
+    Negate(Box<CalcNode>),
+    Invert(Box<CalcNode>),
+    Function(MathFunction, Vec<CalcNode>),
+}
+
+impl CalcNode {
+    /// The type of the node, None if the types of its children are inconsistent.
+    pub fn ty(&self) -> Option<CalcType> {
+        match self {
+            Self::Value(value) => Some(value.ty()),
+            Self::Sum(children) => children
+                .iter()
+                .map(Self::ty)
+                .reduce(|lhs, rhs| lhs?.sum(rhs?))
+                .flatten(),
+            Self::Product(children) => children
+                .iter()
+                .map(Self::ty)
+                .reduce(|lhs, rhs| lhs?.product(rhs?))
+                .flatten(),
+            Self::Negate(child) => child.ty(),
+            Self::Invert(child) => child.ty().map(CalcType::invert),
+            Self::Function(function, args) => {
+                function.ty(&args.iter().map(Self::ty).collect::<Option<Vec<_>>>()?)
+            }
+        }
+    }
+
+    /// Evaluate the node, failing if a value can't be resolved in the context.
+    fn evaluate(&self, context: &CalcContext) -> Option<Typed> {
+        match self {
+            Self::Value(value) => match (&value.unit, context.percentage_basis) {
+                (CalcUnit::Percentage, Some(basis)) => {
+                    let basis = basis.canonical(context)?;
+                    Some(Typed {
+                        value: basis.value * value.value / 100.0,
+                        ty: basis.ty,
+                    })
+                }
+                _ => value.canonical(context),
+            },
+            Self::Sum(children) => {
+                children
+                    .iter()
+                    .try_fold(None, |sum: Option<Typed>, child| {
+                        let child = child.evaluate(context)?;
+                        match sum {
+                            None => Some(Some(child)),
+                            Some(sum) if sum.ty.exponents == child.ty.exponents => {
+                                Some(Some(Typed {
+                                    value: sum.value + child.value,
+                                    ty: sum.ty,
+                                }))
+                            }
+                            Some(_) => None,
+                        }
+                    })?
+            }
+            Self::Product(children) => {
+                children
+                    .iter()
+                    .try_fold(Typed::number(1.0), |product, child| {
+                        let child = child.evaluate(context)?;
+                        Some(Typed {
+                            value: product.value * child.value,
+                            ty: product.ty.product(child.ty)?,
+                        })
+                    })
+            }
+            Self::Negate(child) => child.evaluate(context).map(|child| Typed {
+                value: -child.value,
+                ty: child.ty,
+            }),
+            Self::Invert(child) => child.evaluate(context).map(|child| Typed {
+                value: 1.0 / child.value,
+                ty: child.ty.invert(),
+            }),
+            Self::Function(function, args) => function.evaluate(
+                &args
+                    .iter()
+                    .map(|arg| arg.evaluate(context))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+        }
+    }
+
+    /// Resolve the relative lengths into pixels, the other values being kept.
+    fn absolutize(self, context: &LengthContext) -> Self {
+        let absolutize = |children: Vec<Self>| {
+            children
+                .into_iter()
+                .map(|child| child.absolutize(context))
+                .collect()
+        };
+
+        match self {
+            Self::Value(CalcValue {
+                value,
+                unit: CalcUnit::Dimension(Unit::Length(unit @ LengthUnit::Relative(_))),
+            }) => Self::Value(CalcValue::dimension(
+                value * context.px_per_unit(unit),
+                Unit::Length(LengthUnit::Absolute(AbsoluteLengthUnit::Px)),
+            )),
+            Self::Sum(children) => Self::Sum(absolutize(children)),
+            Self::Product(children) => Self::Product(absolutize(children)),
+            Self::Negate(child) => Self::Negate(Box::new(child.absolutize(context))),
+            Self::Invert(child) => Self::Invert(Box::new(child.absolutize(context))),
+            Self::Function(function, args) => Self::Function(function, absolutize(args)),
+            node => node,
+        }
+    }
+
+    /// Simplify the tree, folding what can be computed without context.
+    fn simplify(self) -> Self {
+        let node = match self {
+            Self::Sum(children) => Self::simplify_sum(children),
+            Self::Product(children) => Self::simplify_product(children),
+            Self::Negate(child) => match child.simplify() {
+                Self::Value(value) => Self::Value(CalcValue {
+                    value: -value.value,
+                    ..value
+                }),
+                Self::Negate(child) => *child,
+                child => Self::Negate(Box::new(child)),
+            },
+            Self::Invert(child) => match child.simplify() {
+                Self::Invert(child) => *child,
+                child => Self::Invert(Box::new(child)),
+            },
+            // A nested calc() is only a parenthesized expression.
+            Self::Function(MathFunction::Calc, mut args) => args.remove(0).simplify(),
+            Self::Function(function, args) => {
+                Self::Function(function, args.into_iter().map(Self::simplify).collect())
+            }
+            node => node,
+        };
+
+        match node
+            .evaluate(&CalcContext::default())
+            .and_then(Typed::to_value)
+        {
+            Some(value) => Self::Value(value),
+            None => node,
+        }
+    }
+
+    fn simplify_sum(children: Vec<Self>) -> Self {
+        let mut sum = Vec::<Self>::default();
+
+        for child in children.into_iter().map(Self::simplify) {
+            let children = match child {
+                Self::Sum(children) => children,
+                child => vec![child],
+            };
+
+            for child in children {
+                // The values of the same unit are summed up.
+                let same_unit = sum.iter_mut().find_map(|node| match (node, &child) {
+                    (Self::Value(lhs), Self::Value(rhs)) if lhs.unit == rhs.unit => Some(lhs),
+                    _ => None,
+                });
+
+                match (same_unit, &child) {
+                    (Some(lhs), Self::Value(rhs)) => lhs.value += rhs.value,
+                    _ => sum.push(child),
+                }
+            }
+        }
+
+        match sum.len() {
+            1 => sum.remove(0),
+            _ => Self::Sum(sum),
+        }
+    }
+
+    fn simplify_product(children: Vec<Self>) -> Self {
+        let mut factor = 1.0;
+        let mut product = Vec::<Self>::default();
+
+        for child in children.into_iter().map(Self::simplify) {
+            let children = match child {
+                Self::Product(children) => children,
+                child => vec![child],
+            };
+
+            for child in children {
+                match child {
+                    Self::Value(CalcValue {
+                        value,
+                        unit: CalcUnit::Number,
+                    }) => factor *= value,
+                    child => product.push(child),
+                }
+            }
+        }
+
+        // A single value, or sum of values, is scaled by the factor.
+        match product.as_mut_slice() {
+            [] => return Self::Value(CalcValue::number(factor)),
+            [Self::Value(value)] => {
+                value.value *= factor;
+                return product.remove(0);
+            }
+            [Self::Sum(children)] if children.iter().all(|c| matches!(c, Self::Value(_))) => {
+                for child in children.iter_mut() {
+                    if let Self::Value(value) = child {
+                        value.value *= factor;
+                    }
+                }
+                return product.remove(0);
+            }
+            _ => {}
+        }
+
+        if factor != 1.0 {
+            product.insert(0, Self::Value(CalcValue::number(factor)));
+        }
+
+        Self::Product(product)
+    }
+}
+
+impl std::fmt::Display for CalcNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Value(value) => write!(f, "{}", value),
+            Self::Sum(children) => {
+                for (i, child) in children.iter().enumerate() {
+                    match (i, child) {
+                        (0, child) => write!(f, "{}", child)?,
+                        (_, Self::Negate(child)) => write!(f, " - {}", child)?,
+                        (_, Self::Value(value)) if value.value < 0.0 => write!(
+                            f,
+                            " - {}",
+                            CalcValue {
+                                value: -value.value,
+                                ..*value
+                            }
+                        )?,
+                        (_, child) => write!(f, " + {}", child)?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Product(children) => {
+                for (i, child) in children.iter().enumerate() {
+                    let (operator, child) = match child {
+                        Self::Invert(child) => (" / ", child.as_ref()),
+                        child => (" * ", child),
+                    };
+
+                    if i > 0 {
+                        write!(f, "{}", operator)?;
+                    }
+
+                    match child {
+                        Self::Sum(_) => write!(f, "({})", child)?,
+                        child => write!(f, "{}", child)?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Negate(child) => match child.as_ref() {
+                Self::Sum(_) => write!(f, "-1 * ({})", child),
+                child => write!(f, "-1 * {}", child),
+            },
+            Self::Invert(child) => match child.as_ref() {
+                Self::Sum(_) => write!(f, "1 / ({})", child),
+                child => write!(f, "1 / {}", child),
+            },
+            Self::Function(MathFunction::Calc, args) => match &args[0] {
+                Self::Sum(_) => write!(f, "({})", args[0]),
+                arg => write!(f, "{}", arg),
+            },
+            Self::Function(function, args) => {
+                write!(f, "{}(", function.name())?;
+                if let MathFunction::Round(strategy) = function {
+                    if *strategy != RoundingStrategy::Nearest {
+                        write!(f, "{}, ", strategy)?;
+                    }
+                }
+                let args = args
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "{})", args)
+            }
+        }
+    }
+}
+
+/// Parse the math expressions of a calculation.
+///
+/// # Grammar
+/// <calc-sum> = <calc-product> [ [ '+' | '-' ] <calc-product> ]*
+/// <calc-product> = <calc-value> [ [ '*' | '/' ] <calc-value> ]*
+/// <calc-value> = <number> | <dimension> | <percentage> | <calc-keyword> | ( <calc-sum> )
+struct CalcParser<'a> {
+    values: Vec<&'a ComponentValue>,
+    position: usize,
+}
+
+impl<'a> CalcParser<'a> {
+    fn new(values: &'a [ComponentValue]) -> Self {
+        Self {
+            values: values.iter().filter(|cv| !cv.is_whitespace()).collect(),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a ComponentValue> {
+        self.values.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<&'a ComponentValue> {
+        let next = self.peek();
+        self.position += 1;
+        next
+    }
+
+    /// Parse the whole input as a sum.
+    fn parse(values: &'a [ComponentValue]) -> Result<CalcNode, StyleError> {
+        let mut parser = Self::new(values);
+        let sum = parser.parse_sum()?;
+
+        match parser.peek() {
+            None => Ok(sum),
+            Some(_) => Err(StyleError::InvalidValue(&["<calc-sum>"])),
+        }
+    }
+
+    fn operator(&self) -> Option<&'a str> {
+        match self.peek()?.as_token_kind()? {
+            TokenKind::Delim(delim) => Some(delim.as_str()),
+            _ => None,
+        }
+    }
+
+    fn parse_sum(&mut self) -> Result<CalcNode, StyleError> {
+        let mut children = vec![self.parse_product()?];
+
+        while let Some(operator @ ("+" | "-")) = self.operator() {
+            self.next();
+            let child = self.parse_product()?;
+            children.push(match operator {
+                "-" => CalcNode::Negate(Box::new(child)),
+                _ => child,
+            });
+        }
+
+        Ok(match children.len() {
+            1 => children.remove(0),
+            _ => CalcNode::Sum(children),
+        })
+    }
+
+    fn parse_product(&mut self) -> Result<CalcNode, StyleError> {
+        let mut children = vec![self.parse_value()?];
+
+        while let Some(operator @ ("*" | "/")) = self.operator() {
+            self.next();
+            let child = self.parse_value()?;
+            children.push(match operator {
+                "/" => CalcNode::Invert(Box::new(child)),
+                _ => child,
+            });
+        }
+
+        Ok(match children.len() {
+            1 => children.remove(0),
+            _ => CalcNode::Product(children),
+        })
+    }
+
+    fn parse_value(&mut self) -> Result<CalcNode, StyleError> {
+        let invalid = StyleError::InvalidValue(&["<calc-value>"]);
+
+        let kind = match self.next().ok_or(invalid.clone())? {
+            ComponentValue::Function(function) => return parse_function(function),
+            ComponentValue::Block(block) if block.delimiter == BlockDelimiter::Parenthesis => {
+                return Self::parse(&block.values)
+            }
+            ComponentValue::Block(_) => return Err(invalid),
+            ComponentValue::Token(token) => &token.kind,
+        };
+
+        let value = match kind {
+            TokenKind::Number(number) => CalcValue::number(number.to_f32()),
+            TokenKind::Percentage(number) => CalcValue::percentage(number.to_f32()),
+            TokenKind::Dimension(dimension) => CalcValue::dimension(
+                dimension.number.to_f32(),
+                Unit::try_from(dimension.unit.as_str())?,
+            ),
+            TokenKind::Ident(ident) => match ident.to_ascii_lowercase().as_str() {
+                "e" => CalcValue::number(std::f32::consts::E),
+                "pi" => CalcValue::number(std::f32::consts::PI),
+                "infinity" => CalcValue::number(f32::INFINITY),
+                "-infinity" => CalcValue::number(f32::NEG_INFINITY),
+                "nan" => CalcValue::number(f32::NAN),
+                _ => return Err(invalid),
+            },
+            _ => return Err(invalid),
+        };
+
+        Ok(CalcNode::Value(value))
+    }
+}
+
+/// Parse a math function, without simplifying it.
+fn parse_function(function: &Function) -> Result<CalcNode, StyleError> {
+    let mut math = MathFunction::try_from(function.name.as_str())?;
+
+    let mut args = function
+        .value
+        .split(ComponentValue::is_comma)
+        .collect::<Vec<_>>();
+
+    // The rounding strategy is an optional first argument.
+    if let (MathFunction::Round(strategy), Some(first)) = (&mut math, args.first()) {
+        let mut first = first.iter().filter(|cv| !cv.is_whitespace());
+        if let (Some(ComponentValue::Token(token)), None) = (first.next(), first.next()) {
+            if let TokenKind::Ident(ident) = &token.kind {
+                if let Ok(parsed) = RoundingStrategy::try_from(ident.as_str()) {
+                    *strategy = parsed;
+                    args.remove(0);
+                }
+            }
+        }
+    }
+
+    let mut args = args
+        .into_iter()
+        .map(CalcParser::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The step of round() defaults to 1.
+    if matches!(math, MathFunction::Round(_)) && args.len() == 1 {
+        args.push(CalcNode::Value(CalcValue::number(1.0)));
+    }
+
+    Ok(CalcNode::Function(math, args))
+}
+
+/// A math function, simplified and type-checked.
+///
+/// [CSS Values 4](https://drafts.csswg.org/css-values-4/#math)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calc {
+    root: CalcNode,
+    ty: CalcType,
+}
+
+impl Calc {
+    /// Checks if the function is a math function.
+    pub fn is_math_function(function: &Function) -> bool {
+        MathFunction::try_from(function.name.as_str()).is_ok()
+    }
+
+    pub fn root(&self) -> &CalcNode {
+        &self.root
+    }
+
+    pub fn ty(&self) -> CalcType {
+        self.ty
+    }
+
+    /// Resolve the calculation, failing if the context lacks the percentage
+    /// basis or the font metrics it needs.
+    ///
+    /// The value is in the canonical unit of its type: px, deg, s, Hz or dppx,
+    /// NaN being censored to zero and the infinities clamped to the largest
+    /// finite values.
+    ///
+    /// [CSS Values 4](https://drafts.csswg.org/css-values-4/#calc-ieee)
+    pub fn resolve(&self, context: &CalcContext) -> Result<CalcValue, StyleError> {
+        let value = self
+            .root
+            .evaluate(context)
+            .and_then(Typed::to_value)
+            .ok_or(StyleError::InvalidValue(&["<calc-sum>"]))?;
+
+        Ok(CalcValue {
+            value: match value.value.is_nan() {
+                true => 0.0,
+                false => value.value.clamp(f32::MIN, f32::MAX),
+            },
+            ..value
+        })
+    }
+
+    /// Resolve the calculation as a length, in pixels.
+    pub fn resolve_length(&self, context: &CalcContext) -> Result<Length, StyleError> {
+        match self.resolve(context)? {
+            CalcValue {
+                value,
+                unit: CalcUnit::Dimension(Unit::Length(unit)),
+            } => Ok(Length::new(value, unit)),
+            _ => Err(StyleError::InvalidValue(&["<length>"])),
+        }
+    }
+
+    /// Resolve the relative lengths into pixels, keeping the percentages
+    /// until their basis is known.
+    pub fn absolutize(&self, context: &LengthContext) -> Self {
+        Self {
+            root: self.root.clone().absolutize(context).simplify(),
+            ty: self.ty,
+        }
+    }
+}
+
+impl TryFrom<&Function> for Calc {
+    type Error = StyleError;
+
+    fn try_from(function: &Function) -> Result<Self, Self::Error> {
+        let root = parse_function(function)?;
+        let ty = root.ty().ok_or(StyleError::InvalidValue(&["<calc-sum>"]))?;
+
+        Ok(Self {
+            root: root.simplify(),
+            ty,
+        })
+    }
+}
+
+impl std::fmt::Display for Calc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.root {
+            CalcNode::Function(function, _) if *function != MathFunction::Calc => {
+                write!(f, "{}", self.root)
+            }
+            root => write!(f, "calc({})", root),
+        }
+    }
+}
+
+impl From<Calc> for Value {
+    fn from(value: Calc) -> Self {
+        Self::Calc(value)
+    }
+}
+
+impl From<CalcValue> for Value {
+    fn from(value: CalcValue) -> Self {
+        match value.unit {
+            CalcUnit::Number => Self::Number(Number::from(value.value)),
+            CalcUnit::Percentage => Percentage::try_from(value.value / 100.0)
+                .map(Self::Percentage)
+                .unwrap_or(Self::Number(Number::from(value.value))),
+            CalcUnit::Dimension(Unit::Length(unit)) => Self::Length(Length::new(value.value, unit)),
+            CalcUnit::Dimension(unit) => Self::Dimension(Dimension {
+                quantity: Number::from(value.value),
+                unit,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, ComponentValue, LengthContext, ViewportSize};
+
+    use super::{BaseType, Calc, CalcContext, CalcValue, Unit};
+
+    fn parse(source: &str) -> Result<Calc, crate::style::StyleError> {
+        match parse_component_values(source).first() {
+            Some(ComponentValue::Function(function)) => Calc::try_from(function),
+            _ => panic!("not a function"),
+        }
+    }
+
+    fn resolve(source: &str, context: &CalcContext) -> String {
+        parse(source).unwrap().resolve(context).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_001_simplification() {
+        let serialize = |source: &str| parse(source).unwrap().to_string();
+
+        assert_eq!(serialize("calc(1in + 2in * 3)"), "calc(672px)");
+        assert_eq!(serialize("calc(1in - 48in / 96)"), "calc(48px)");
+        assert_eq!(serialize("calc((1em + 2em) * 2)"), "calc(6em)");
+        assert_eq!(serialize("calc(100% - 2em - 1em)"), "calc(100% - 3em)");
+        assert_eq!(serialize("calc(2 * (10% + 1em))"), "calc(20% + 2em)");
+        assert_eq!(serialize("min(10%, 2em)"), "min(10%, 2em)");
+        assert_eq!(serialize("max(1in, 3in, 2in)"), "calc(288px)");
+        assert_eq!(serialize("clamp(1s, 5s, 3s)"), "calc(3s)");
+        assert_eq!(serialize("calc(1turn / 4)"), "calc(90deg)");
+        assert_eq!(serialize("round(up, 7s, 5s)"), "calc(10s)");
+        assert_eq!(
+            serialize("round(down, 1em, 5in)"),
+            "round(down, 1em, 480px)"
+        );
+
+        // The non-finite values are multiplied by one of their unit.
+        assert_eq!(serialize("calc(1px / 0)"), "calc(infinity * 1px)");
+        assert_eq!(serialize("calc(infinity * 1px)"), "calc(infinity * 1px)");
+        assert_eq!(serialize("calc(-infinity * 1%)"), "calc(-infinity * 1%)");
+        assert_eq!(serialize("calc(NaN * 1deg)"), "calc(NaN * 1deg)");
+        assert_eq!(serialize("calc(infinity)"), "calc(infinity)");
+        assert_eq!(
+            serialize("calc(1em - infinity * 1px)"),
+            "calc(1em - infinity * 1px)"
+        );
+    }
+
+    #[test]
+    fn test_002_type_checking() {
+        let ty = |source: &str| parse(source).map(|calc| calc.ty());
+
+        assert!(ty("calc(1in + 1s)").is_err());
+        assert_eq!(ty("calc(1in * 1in)").unwrap().exponent(BaseType::Length), 2);
+        assert!(ty("calc(1in * 2cm / 1in)")
+            .unwrap()
+            .matches(BaseType::Length, false));
+        assert!(ty("calc(10% + 1em)")
+            .unwrap()
+            .matches(BaseType::Length, true));
+        assert!(!ty("calc(10% + 1em)")
+            .unwrap()
+            .matches(BaseType::Length, false));
+        assert!(ty("calc(10% + 1deg)")
+            .unwrap()
+            .matches(BaseType::Angle, true));
+        assert!(ty("calc(10% + 1em + 1deg)").is_err());
+        assert!(ty("sin(1in)").is_err());
+        assert!(ty("pow(2in, 2)").is_err());
+        assert!(ty("round(1in)").is_err());
+        assert!(ty("calc(1in 2in)").is_err());
+        assert!(ty("calc(1in +2in)").is_err());
+        assert!(ty("mod(1s, 300ms)").unwrap().matches(BaseType::Time, false));
+    }
+
+    #[test]
+    fn test_003_math_functions() {
+        let context = CalcContext::default();
+
+        assert_eq!(resolve("calc(2 + 3 * 4)", &context), "14");
+        assert_eq!(resolve("sqrt(16)", &context), "4");
+        assert_eq!(resolve("pow(2, 10)", &context), "1024");
+        assert_eq!(resolve("hypot(3in, 4in)", &context), "480px");
+        assert_eq!(resolve("abs(-2s)", &context), "2s");
+        assert_eq!(resolve("sign(-2deg)", &context), "-1");
+        assert_eq!(resolve("mod(-7, 3)", &context), "2");
+        assert_eq!(resolve("rem(-7, 3)", &context), "-1");
+        assert_eq!(resolve("round(2.5)", &context), "3");
+        assert_eq!(resolve("round(to-zero, -2.5)", &context), "-2");
+        assert_eq!(resolve("cos(0.5turn)", &context), "-1");
+        assert_eq!(resolve("atan2(1in, 1in)", &context), "45deg");
+        assert_eq!(resolve("calc(log(exp(2)))", &context), "2");
+        assert_eq!(resolve("calc(1s + 500ms)", &context), "1.5s");
+        assert_eq!(resolve("calc(2dppx + 96dpi)", &context), "3dppx");
+    }
+
+    #[test]
+    fn test_004_resolution() {
+        let calc = parse("calc(50% - 1em + 1rem)").unwrap();
+
+        // The percentage basis and the font size are unknown.
+        assert!(calc.resolve(&CalcContext::default()).is_err());
+
+        let mut length = LengthContext::new(20.0, 16.0, ViewportSize::new(800.0, 600.0));
+        length.font.x_height = 9.0;
+
+        let context = CalcContext {
+            percentage_basis: Some(CalcValue::dimension(2.0, Unit::try_from("in").unwrap())),
+            length: Some(length),
+        };
+        assert_eq!(calc.resolve(&context).unwrap().to_string(), "92px");
+        assert_eq!(resolve("min(10%, 1em)", &context), "19.2px");
+        assert_eq!(resolve("calc(50vw - 2ex)", &context), "382px");
+        assert_eq!(resolve("calc(10% * 3)", &CalcContext::default()), "30%");
+        assert!(parse("calc(1vw)")
+            .unwrap()
+            .resolve(&CalcContext::default())
+            .is_err());
+
+        // NaN is censored to zero, and the infinities clamped.
+        assert_eq!(resolve("calc(NaN * 1px)", &CalcContext::default()), "0px");
+        assert_eq!(
+            parse("calc(-infinity * 1s)")
+                .unwrap()
+                .resolve(&CalcContext::default())
+                .unwrap()
+                .value,
+            f32::MIN
+        );
+    }
+}
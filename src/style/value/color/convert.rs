@@ -0,0 +1,898 @@
+use super::{
+    Color, ColorComponent, ColorFunction, ColorSpace, Hsl, Hwb, Lab, Lch, Rgb, RgbChannel,
+};
+
+type Matrix = [[f64; 3]; 3];
+
+fn multiply(matrix: &Matrix, vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+const LINEAR_SRGB_TO_XYZ: Matrix = [
+    [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+    [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+    [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+];
+
+const XYZ_TO_LINEAR_SRGB: Matrix = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [
+        0.05563007969699366,
+        -0.20397695888897652,
+        1.0569715142428786,
+    ],
+];
+
+const LINEAR_P3_TO_XYZ: Matrix = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0, 0.04511338185890264, 1.043944368900976],
+];
+
+const XYZ_TO_LINEAR_P3: Matrix = [
+    [2.493496911941425, -0.9313836179191239, -0.40271078445071684],
+    [
+        -0.8294889695615747,
+        1.7626640603183463,
+        0.023624685841943577,
+    ],
+    [
+        0.03584583024378447,
+        -0.07617238926804182,
+        0.9568845240076872,
+    ],
+];
+
+const LINEAR_A98_TO_XYZ: Matrix = [
+    [0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+    [0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+    [0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+];
+
+const XYZ_TO_LINEAR_A98: Matrix = [
+    [
+        2.0415879038107465,
+        -0.5650069742788596,
+        -0.34473135077832956,
+    ],
+    [-0.9692436362808795, 1.8759675015077202, 0.04155505740717557],
+    [
+        0.013444280632031142,
+        -0.11836239223101838,
+        1.0151749943912054,
+    ],
+];
+
+/// ProPhoto RGB is relative to the D50 white point.
+const LINEAR_PROPHOTO_TO_XYZ_D50: Matrix = [
+    [0.7977666449006423, 0.13518129740053308, 0.0313477341283922],
+    [0.2880748288194013, 0.711835234241873, 0.00008993693872564],
+    [0.0, 0.0, 0.8251046025104602],
+];
+
+const XYZ_D50_TO_LINEAR_PROPHOTO: Matrix = [
+    [
+        1.3457868816471583,
+        -0.25557208737979464,
+        -0.05110186497554526,
+    ],
+    [-0.5446307051249019, 1.5082477428451468, 0.02052744743642139],
+    [0.0, 0.0, 1.2119675456389452],
+];
+
+const LINEAR_REC2020_TO_XYZ: Matrix = [
+    [0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+    [0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+    [0.0, 0.028072693049087428, 1.060985057710791],
+];
+
+const XYZ_TO_LINEAR_REC2020: Matrix = [
+    [
+        1.7166511879712674,
+        -0.35567078377639233,
+        -0.25336628137365974,
+    ],
+    [-0.6666843518324892, 1.6164812366349395, 0.01576854581391113],
+    [
+        0.017639857445310783,
+        -0.042770613257808524,
+        0.9421031212354738,
+    ],
+];
+
+/// The Bradford chromatic adaptation from D65 to D50.
+const D65_TO_D50: Matrix = [
+    [
+        1.0479298208405488,
+        0.022946793341019088,
+        -0.05019222954313557,
+    ],
+    [
+        0.029627815688159344,
+        0.990434484573249,
+        -0.01707382502938514,
+    ],
+    [
+        -0.009243058152591178,
+        0.015055144896577895,
+        0.7518742899580008,
+    ],
+];
+
+const D50_TO_D65: Matrix = [
+    [
+        0.9554734527042182,
+        -0.023098536874261423,
+        0.0632593086610217,
+    ],
+    [
+        -0.028369706963208136,
+        1.0099954580058226,
+        0.021041398966943008,
+    ],
+    [
+        0.012314001688319899,
+        -0.020507696433477912,
+        1.3303659366080753,
+    ],
+];
+
+const XYZ_TO_LMS: Matrix = [
+    [0.819022437996703, 0.3619062600528904, -0.1288737815209879],
+    [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+    [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+
+const LMS_TO_OKLAB: Matrix = [
+    [0.210454268309314, 0.7936177747023054, -0.0040720430116193],
+    [1.9779985324311684, -2.42859224204858, 0.450593709617411],
+    [0.0259040424655478, 0.7827717124575296, -0.8086757548750374],
+];
+
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.3963377773761749, 0.2158037573099136],
+    [1.0, -0.1055613458156586, -0.0638541728258133],
+    [1.0, -0.0894841775298119, -1.2914855480194092],
+];
+
+const LMS_TO_XYZ: Matrix = [
+    [1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+    [-0.0405757452148008, 1.112286803280317, -0.0717110580655164],
+    [-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+
+/// The D50 white point of Lab.
+const D50: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+/// The chroma under which a hue is powerless.
+const ACHROMATIC: f64 = 1e-5;
+
+/// Apply a transfer function to the magnitude of a component, keeping its sign.
+fn signed(value: f64, transfer: impl Fn(f64) -> f64) -> f64 {
+    value.signum() * transfer(value.abs())
+}
+
+fn srgb_to_linear(value: f64) -> f64 {
+    signed(value, |v| match v <= 0.04045 {
+        true => v / 12.92,
+        false => ((v + 0.055) / 1.055).powf(2.4),
+    })
+}
+
+fn linear_to_srgb(value: f64) -> f64 {
+    signed(value, |v| match v <= 0.0031308 {
+        true => v * 12.92,
+        false => 1.055 * v.powf(1.0 / 2.4) - 0.055,
+    })
+}
+
+fn a98_to_linear(value: f64) -> f64 {
+    signed(value, |v| v.powf(563.0 / 256.0))
+}
+
+fn linear_to_a98(value: f64) -> f64 {
+    signed(value, |v| v.powf(256.0 / 563.0))
+}
+
+fn prophoto_to_linear(value: f64) -> f64 {
+    signed(value, |v| match v <= 16.0 / 512.0 {
+        true => v / 16.0,
+        false => v.powf(1.8),
+    })
+}
+
+fn linear_to_prophoto(value: f64) -> f64 {
+    signed(value, |v| match v >= 1.0 / 512.0 {
+        true => v.powf(1.0 / 1.8),
+        false => v * 16.0,
+    })
+}
+
+const REC2020_ALPHA: f64 = 1.09929682680944;
+const REC2020_BETA: f64 = 0.018053968510807;
+
+fn rec2020_to_linear(value: f64) -> f64 {
+    signed(value, |v| match v < REC2020_BETA * 4.5 {
+        true => v / 4.5,
+        false => ((v + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45),
+    })
+}
+
+fn linear_to_rec2020(value: f64) -> f64 {
+    signed(value, |v| match v > REC2020_BETA {
+        true => REC2020_ALPHA * v.powf(0.45) - (REC2020_ALPHA - 1.0),
+        false => v * 4.5,
+    })
+}
+
+fn lab_to_xyz_d50([l, a, b]: [f64; 3]) -> [f64; 3] {
+    const KAPPA: f64 = 24389.0 / 27.0;
+    const EPSILON: f64 = 216.0 / 24389.0;
+
+    let f1 = (l + 16.0) / 116.0;
+    let f0 = a / 500.0 + f1;
+    let f2 = f1 - b / 200.0;
+
+    let x = match f0.powi(3) > EPSILON {
+        true => f0.powi(3),
+        false => (116.0 * f0 - 16.0) / KAPPA,
+    };
+    let y = match l > KAPPA * EPSILON {
+        true => f1.powi(3),
+        false => l / KAPPA,
+    };
+    let z = match f2.powi(3) > EPSILON {
+        true => f2.powi(3),
+        false => (116.0 * f2 - 16.0) / KAPPA,
+    };
+
+    [x * D50[0], y * D50[1], z * D50[2]]
+}
+
+fn xyz_d50_to_lab(xyz: [f64; 3]) -> [f64; 3] {
+    const KAPPA: f64 = 24389.0 / 27.0;
+    const EPSILON: f64 = 216.0 / 24389.0;
+
+    let [f0, f1, f2] = [0, 1, 2].map(|i| {
+        let value = xyz[i] / D50[i];
+        match value > EPSILON {
+            true => value.cbrt(),
+            false => (KAPPA * value + 16.0) / 116.0,
+        }
+    });
+
+    [116.0 * f1 - 16.0, 500.0 * (f0 - f1), 200.0 * (f1 - f2)]
+}
+
+fn oklab_to_xyz(oklab: [f64; 3]) -> [f64; 3] {
+    let lms = multiply(&OKLAB_TO_LMS, oklab).map(|v| v.powi(3));
+    multiply(&LMS_TO_XYZ, lms)
+}
+
+fn xyz_to_oklab(xyz: [f64; 3]) -> [f64; 3] {
+    let lms = multiply(&XYZ_TO_LMS, xyz).map(f64::cbrt);
+    multiply(&LMS_TO_OKLAB, lms)
+}
+
+/// Convert rectangular opponent components to polar ones, the hue of
+/// an achromatic color being missing.
+fn to_polar([l, a, b]: [f64; 3]) -> [Option<f64>; 3] {
+    let chroma = (a * a + b * b).sqrt();
+    let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+
+    [Some(l), Some(chroma), (chroma > ACHROMATIC).then_some(hue)]
+}
+
+fn from_polar([l, c, h]: [f64; 3]) -> [f64; 3] {
+    let hue = h.to_radians();
+    [l, c * hue.cos(), c * hue.sin()]
+}
+
+fn srgb_to_hsl([r, g, b]: [f64; 3]) -> [Option<f64>; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (min + max) / 2.0;
+    let delta = max - min;
+
+    if delta.abs() < ACHROMATIC {
+        return [None, Some(0.0), Some(lightness * 100.0)];
+    }
+
+    let saturation = match lightness == 0.0 || lightness == 1.0 {
+        true => 0.0,
+        false => (max - lightness) / lightness.min(1.0 - lightness),
+    };
+
+    let hue = if max == r {
+        (g - b) / delta + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    } * 60.0;
+
+    [Some(hue), Some(saturation * 100.0), Some(lightness * 100.0)]
+}
+
+fn srgb_to_hwb(rgb: [f64; 3]) -> [Option<f64>; 3] {
+    let [hue, _, _] = srgb_to_hsl(rgb);
+    let whiteness = rgb[0].min(rgb[1]).min(rgb[2]);
+    let blackness = 1.0 - rgb[0].max(rgb[1]).max(rgb[2]);
+
+    // The hue is powerless for grays.
+    let hue = hue.filter(|_| whiteness + blackness < 1.0 - ACHROMATIC);
+    [hue, Some(whiteness * 100.0), Some(blackness * 100.0)]
+}
+
+/// The analogous components, carried forward when converting a color with
+/// missing components.
+///
+/// [CSS Color 4](https://drafts.csswg.org/css-color-4/#interpolation-missing)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Analogous {
+    Red,
+    Green,
+    Blue,
+    Lightness,
+    Colorfulness,
+    Hue,
+    OpponentA,
+    OpponentB,
+}
+
+impl ColorSpace {
+    fn analogous(&self) -> [Option<Analogous>; 3] {
+        use Analogous::*;
+
+        match self {
+            Self::Hsl => [Some(Hue), Some(Colorfulness), Some(Lightness)],
+            Self::Hwb => [Some(Hue), None, None],
+            Self::Lab | Self::Oklab => [Some(Lightness), Some(OpponentA), Some(OpponentB)],
+            Self::Lch | Self::Oklch => [Some(Lightness), Some(Colorfulness), Some(Hue)],
+            _ => [Some(Red), Some(Green), Some(Blue)],
+        }
+    }
+
+    /// The index of the hue component.
+    pub fn hue_index(&self) -> Option<usize> {
+        match self {
+            Self::Hsl | Self::Hwb => Some(0),
+            Self::Lch | Self::Oklch => Some(2),
+            _ => None,
+        }
+    }
+}
+
+impl ColorFunction {
+    pub fn new(space: ColorSpace, components: [ColorComponent; 3], alpha: ColorComponent) -> Self {
+        Self {
+            space,
+            components,
+            alpha,
+        }
+    }
+
+    /// The components, the missing ones being zero.
+    fn values(&self) -> [f64; 3] {
+        self.components
+            .map(|component| f64::from(component.unwrap_or_default()))
+    }
+
+    /// Convert the color to XYZ, relative to the D65 white point.
+    pub fn to_xyz_d65(&self) -> [f64; 3] {
+        let values = self.values();
+
+        match self.space {
+            ColorSpace::Srgb => multiply(&LINEAR_SRGB_TO_XYZ, values.map(srgb_to_linear)),
+            ColorSpace::SrgbLinear => multiply(&LINEAR_SRGB_TO_XYZ, values),
+            ColorSpace::DisplayP3 => multiply(&LINEAR_P3_TO_XYZ, values.map(srgb_to_linear)),
+            ColorSpace::A98Rgb => multiply(&LINEAR_A98_TO_XYZ, values.map(a98_to_linear)),
+            ColorSpace::ProphotoRgb => multiply(
+                &D50_TO_D65,
+                multiply(&LINEAR_PROPHOTO_TO_XYZ_D50, values.map(prophoto_to_linear)),
+            ),
+            ColorSpace::Rec2020 => multiply(&LINEAR_REC2020_TO_XYZ, values.map(rec2020_to_linear)),
+            ColorSpace::XyzD50 => multiply(&D50_TO_D65, values),
+            ColorSpace::XyzD65 => values,
+            ColorSpace::Hsl | ColorSpace::Hwb => {
+                let [h, a, b] = self.components;
+                let rgb = match self.space {
+                    ColorSpace::Hsl => Hsl {
+                        hue: h,
+                        saturation: a,
+                        lightness: b,
+                        alpha: None,
+                    }
+                    .to_rgb(),
+                    _ => Hwb {
+                        hue: h,
+                        whiteness: a,
+                        blackness: b,
+                        alpha: None,
+                    }
+                    .to_rgb(),
+                };
+                multiply(
+                    &LINEAR_SRGB_TO_XYZ,
+                    rgb.map(|c| srgb_to_linear(f64::from(c))),
+                )
+            }
+            ColorSpace::Lab => multiply(&D50_TO_D65, lab_to_xyz_d50(values)),
+            ColorSpace::Lch => multiply(&D50_TO_D65, lab_to_xyz_d50(from_polar(values))),
+            ColorSpace::Oklab => oklab_to_xyz(values),
+            ColorSpace::Oklch => oklab_to_xyz(from_polar(values)),
+        }
+    }
+
+    /// Convert a color in XYZ, relative to the D65 white point, to the color space.
+    pub fn from_xyz_d65(space: ColorSpace, xyz: [f64; 3], alpha: ColorComponent) -> Self {
+        let some = |values: [f64; 3]| values.map(Some);
+
+        let components = match space {
+            ColorSpace::Srgb => some(multiply(&XYZ_TO_LINEAR_SRGB, xyz).map(linear_to_srgb)),
+            ColorSpace::SrgbLinear => some(multiply(&XYZ_TO_LINEAR_SRGB, xyz)),
+            ColorSpace::DisplayP3 => some(multiply(&XYZ_TO_LINEAR_P3, xyz).map(linear_to_srgb)),
+            ColorSpace::A98Rgb => some(multiply(&XYZ_TO_LINEAR_A98, xyz).map(linear_to_a98)),
+            ColorSpace::ProphotoRgb => some(
+                multiply(&XYZ_D50_TO_LINEAR_PROPHOTO, multiply(&D65_TO_D50, xyz))
+                    .map(linear_to_prophoto),
+            ),
+            ColorSpace::Rec2020 => {
+                some(multiply(&XYZ_TO_LINEAR_REC2020, xyz).map(linear_to_rec2020))
+            }
+            ColorSpace::XyzD50 => some(multiply(&D65_TO_D50, xyz)),
+            ColorSpace::XyzD65 => some(xyz),
+            ColorSpace::Hsl => srgb_to_hsl(multiply(&XYZ_TO_LINEAR_SRGB, xyz).map(linear_to_srgb)),
+            ColorSpace::Hwb => srgb_to_hwb(multiply(&XYZ_TO_LINEAR_SRGB, xyz).map(linear_to_srgb)),
+            ColorSpace::Lab => some(xyz_d50_to_lab(multiply(&D65_TO_D50, xyz))),
+            ColorSpace::Lch => to_polar(xyz_d50_to_lab(multiply(&D65_TO_D50, xyz))),
+            ColorSpace::Oklab => some(xyz_to_oklab(xyz)),
+            ColorSpace::Oklch => to_polar(xyz_to_oklab(xyz)),
+        };
+
+        Self {
+            space,
+            components: components.map(|component| component.map(|value| value as f32)),
+            alpha,
+        }
+    }
+
+    /// Convert the color to another color space.
+    ///
+    /// The missing components are carried forward to their analogous
+    /// components, if any.
+    pub fn convert(&self, space: ColorSpace) -> Self {
+        if self.space == space {
+            return *self;
+        }
+
+        let mut converted = Self::from_xyz_d65(space, self.to_xyz_d65(), self.alpha);
+
+        let missing = self
+            .space
+            .analogous()
+            .into_iter()
+            .zip(self.components)
+            .filter_map(|(analogous, component)| analogous.filter(|_| component.is_none()))
+            .collect::<Vec<_>>();
+
+        for (analogous, component) in space.analogous().iter().zip(&mut converted.components) {
+            if analogous.is_some_and(|analogous| missing.contains(&analogous)) {
+                *component = None;
+            }
+        }
+
+        converted
+    }
+
+    /// Checks if the color is within the gamut of its color space.
+    fn is_in_gamut(&self) -> bool {
+        const EPSILON: f32 = 1e-4;
+
+        self.components
+            .iter()
+            .all(|c| (-EPSILON..=1.0 + EPSILON).contains(&c.unwrap_or_default()))
+    }
+
+    /// Clip the components into the gamut of the color space.
+    fn clip(&self) -> Self {
+        Self {
+            components: self
+                .components
+                .map(|c| Some(c.unwrap_or_default().clamp(0.0, 1.0))),
+            ..*self
+        }
+    }
+
+    /// Convert the color to sRGB, mapping it into the gamut by reducing its
+    /// OkLCh chroma.
+    ///
+    /// [CSS Color 4](https://drafts.csswg.org/css-color-4/#binsearch)
+    pub fn to_srgb_gamut(&self) -> Self {
+        const JND: f32 = 0.02;
+        const MIN_CONVERGENCE: f32 = 0.0001;
+
+        let srgb = self.convert(ColorSpace::Srgb);
+        if srgb.is_in_gamut() {
+            return srgb.clip();
+        }
+
+        let origin = self.convert(ColorSpace::Oklch);
+        let lightness = origin.components[0].unwrap_or_default();
+        if lightness >= 1.0 {
+            return Self::new(ColorSpace::Srgb, [Some(1.0); 3], self.alpha);
+        }
+        if lightness <= 0.0 {
+            return Self::new(ColorSpace::Srgb, [Some(0.0); 3], self.alpha);
+        }
+
+        let delta_eok = |lhs: &Self, rhs: &Self| {
+            let [l1, a1, b1] = lhs.convert(ColorSpace::Oklab).values();
+            let [l2, a2, b2] = rhs.convert(ColorSpace::Oklab).values();
+            ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt() as f32
+        };
+
+        let mut current = origin;
+        let mut clipped = current.convert(ColorSpace::Srgb).clip();
+        if delta_eok(&clipped, &current) < JND {
+            return clipped;
+        }
+
+        let mut min = 0.0;
+        let mut max = origin.components[1].unwrap_or_default();
+        let mut min_in_gamut = true;
+
+        while max - min > MIN_CONVERGENCE {
+            let chroma = (min + max) / 2.0;
+            current.components[1] = Some(chroma);
+
+            let srgb = current.convert(ColorSpace::Srgb);
+            if min_in_gamut && srgb.is_in_gamut() {
+                min = chroma;
+                continue;
+            }
+
+            clipped = srgb.clip();
+            let delta = delta_eok(&clipped, &current);
+            if delta < JND {
+                if JND - delta < MIN_CONVERGENCE {
+                    return clipped;
+                }
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+
+        clipped
+    }
+
+    /// Turn the color into the color of its color space's function.
+    pub fn into_color(self) -> Color {
+        let [c0, c1, c2] = self.components;
+
+        match self.space {
+            ColorSpace::Hsl => Color::Hsl(Hsl {
+                hue: c0,
+                saturation: c1,
+                lightness: c2,
+                alpha: self.alpha,
+            }),
+            ColorSpace::Hwb => Color::Hwb(Hwb {
+                hue: c0,
+                whiteness: c1,
+                blackness: c2,
+                alpha: self.alpha,
+            }),
+            ColorSpace::Lab | ColorSpace::Oklab => {
+                let lab = Lab {
+                    lightness: c0,
+                    a: c1,
+                    b: c2,
+                    alpha: self.alpha,
+                };
+                match self.space {
+                    ColorSpace::Lab => Color::Lab(lab),
+                    _ => Color::Oklab(lab),
+                }
+            }
+            ColorSpace::Lch | ColorSpace::Oklch => {
+                let lch = Lch {
+                    lightness: c0,
+                    chroma: c1,
+                    hue: c2,
+                    alpha: self.alpha,
+                };
+                match self.space {
+                    ColorSpace::Lch => Color::Lch(lch),
+                    _ => Color::Oklch(lch),
+                }
+            }
+            _ => Color::Function(self),
+        }
+    }
+}
+
+/// A color in sRGB, with components between 0 and 1, ready to be painted.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rgba {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+    pub alpha: f32,
+}
+
+impl Rgba {
+    pub fn new(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// The channels in linear-light sRGB, to blend colors.
+    pub fn to_linear(&self) -> Self {
+        let linear = |c: f32| srgb_to_linear(f64::from(c)) as f32;
+
+        Self {
+            red: linear(self.red),
+            green: linear(self.green),
+            blue: linear(self.blue),
+            alpha: self.alpha,
+        }
+    }
+
+    /// The 8-bit channels.
+    pub fn to_u8(&self) -> [u8; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+}
+
+impl From<Rgba> for ColorFunction {
+    fn from(value: Rgba) -> Self {
+        Self::new(
+            ColorSpace::Srgb,
+            [Some(value.red), Some(value.green), Some(value.blue)],
+            Some(value.alpha),
+        )
+    }
+}
+
+impl From<ColorFunction> for Rgba {
+    /// Convert the color to sRGB, mapped into its gamut.
+    fn from(value: ColorFunction) -> Self {
+        let srgb = value.to_srgb_gamut();
+        let [red, green, blue] = srgb.components.map(Option::unwrap_or_default);
+
+        Self {
+            red,
+            green,
+            blue,
+            alpha: srgb.alpha.unwrap_or_default().clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Color {
+    /// The color in its own color space, the current color being needed to
+    /// resolve currentcolor.
+    pub fn to_color_function(&self, current_color: Option<&Rgba>) -> Option<ColorFunction> {
+        let srgb = |[r, g, b]: [u8; 3], alpha: f32| {
+            ColorFunction::new(
+                ColorSpace::Srgb,
+                [r, g, b].map(|c| Some(f32::from(c) / 255.0)),
+                Some(alpha),
+            )
+        };
+
+        let function = match self {
+            Self::Hex(hex) => {
+                let [r, g, b, a] = super::parser::parse_hex(hex)?;
+                srgb([r, g, b], f32::from(a) / 255.0)
+            }
+            Self::Named(name) => srgb(super::named_color(name)?, 1.0),
+            Self::System(color) => srgb(color.rgb(), 1.0),
+            Self::Transparent => srgb([0, 0, 0], 0.0),
+            Self::CurrentColor => ColorFunction::from(*current_color?),
+            Self::Rgb(Rgb {
+                red,
+                green,
+                blue,
+                alpha,
+            }) => ColorFunction::new(
+                ColorSpace::Srgb,
+                [red, green, blue].map(|c| RgbChannel::value(c).map(|c| c / 255.0)),
+                *alpha,
+            ),
+            Self::Hsl(hsl) => ColorFunction::new(
+                ColorSpace::Hsl,
+                [hsl.hue, hsl.saturation, hsl.lightness],
+                hsl.alpha,
+            ),
+            Self::Hwb(hwb) => ColorFunction::new(
+                ColorSpace::Hwb,
+                [hwb.hue, hwb.whiteness, hwb.blackness],
+                hwb.alpha,
+            ),
+            Self::Lab(lab) => {
+                ColorFunction::new(ColorSpace::Lab, [lab.lightness, lab.a, lab.b], lab.alpha)
+            }
+            Self::Oklab(lab) => {
+                ColorFunction::new(ColorSpace::Oklab, [lab.lightness, lab.a, lab.b], lab.alpha)
+            }
+            Self::Lch(lch) => ColorFunction::new(
+                ColorSpace::Lch,
+                [lch.lightness, lch.chroma, lch.hue],
+                lch.alpha,
+            ),
+            Self::Oklch(lch) => ColorFunction::new(
+                ColorSpace::Oklch,
+                [lch.lightness, lch.chroma, lch.hue],
+                lch.alpha,
+            ),
+            Self::Function(function) => *function,
+            Self::Mix(mix) => mix.mix(current_color)?,
+        };
+
+        Some(function)
+    }
+
+    /// Resolve the color to sRGB, for painting.
+    pub fn to_rgba(&self, current_color: &Rgba) -> Rgba {
+        self.to_color_function(Some(current_color))
+            .map(Rgba::from)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, Color, ColorFunction, ColorSpace, Rgba};
+
+    fn parse(source: &str) -> ColorFunction {
+        Color::try_from(&parse_component_values(source)[0])
+            .unwrap()
+            .to_color_function(None)
+            .unwrap()
+    }
+
+    fn assert_close(actual: [Option<f32>; 3], expected: [f32; 3], tolerance: f32) {
+        for (actual, expected) in actual.into_iter().zip(expected) {
+            let actual = actual.unwrap_or(f32::NAN);
+            assert!(
+                (actual - expected).abs() <= tolerance,
+                "{} != {}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_001_conversions() {
+        let red = parse("red");
+        assert_close(
+            red.convert(ColorSpace::SrgbLinear).components,
+            [1.0, 0.0, 0.0],
+            1e-4,
+        );
+        assert_close(
+            red.convert(ColorSpace::XyzD65).components,
+            [0.4124, 0.2126, 0.0193],
+            1e-4,
+        );
+        assert_close(
+            red.convert(ColorSpace::XyzD50).components,
+            [0.4360, 0.2225, 0.0139],
+            1e-4,
+        );
+        assert_close(
+            red.convert(ColorSpace::Lab).components,
+            [54.29, 80.80, 69.89],
+            0.05,
+        );
+        assert_close(
+            red.convert(ColorSpace::Lch).components,
+            [54.29, 106.84, 40.85],
+            0.05,
+        );
+        assert_close(
+            red.convert(ColorSpace::Oklab).components,
+            [0.628, 0.2249, 0.1258],
+            1e-3,
+        );
+        assert_close(
+            red.convert(ColorSpace::Oklch).components,
+            [0.628, 0.2577, 29.23],
+            1e-2,
+        );
+        assert_close(
+            red.convert(ColorSpace::DisplayP3).components,
+            [0.9175, 0.2003, 0.1386],
+            1e-3,
+        );
+        assert_close(
+            red.convert(ColorSpace::A98Rgb).components,
+            [0.8590, 0.0, 0.0],
+            1e-3,
+        );
+        assert_close(
+            red.convert(ColorSpace::ProphotoRgb).components,
+            [0.7023, 0.2757, 0.1036],
+            1e-3,
+        );
+        assert_close(
+            red.convert(ColorSpace::Rec2020).components,
+            [0.7919, 0.2310, 0.0738],
+            1e-3,
+        );
+        assert_close(
+            parse("lime").convert(ColorSpace::Hwb).components,
+            [120.0, 0.0, 0.0],
+            1e-3,
+        );
+
+        // Every conversion round-trips.
+        let color = parse("color(display-p3 0.2 0.4 0.6)");
+        for space in [
+            ColorSpace::Srgb,
+            ColorSpace::A98Rgb,
+            ColorSpace::ProphotoRgb,
+            ColorSpace::Rec2020,
+            ColorSpace::Hsl,
+            ColorSpace::Lch,
+            ColorSpace::Oklch,
+        ] {
+            let round_trip = color.convert(space).convert(ColorSpace::DisplayP3);
+            assert_close(round_trip.components, [0.2, 0.4, 0.6], 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_002_missing_components() {
+        // A gray has a powerless hue.
+        assert_eq!(parse("gray").convert(ColorSpace::Oklch).components[2], None);
+        assert_eq!(parse("gray").convert(ColorSpace::Hsl).components[0], None);
+
+        // The missing lightness is carried forward, the missing red isn't.
+        let lch = parse("lch(none 50 120)").convert(ColorSpace::Oklch);
+        assert_eq!(lch.components[0], None);
+        assert!(lch.components[2].is_some());
+        let rgb = parse("rgb(none 128 0)").convert(ColorSpace::Oklab);
+        assert!(rgb.components.iter().all(Option::is_some));
+        assert_eq!(
+            parse("rgb(none 128 0)")
+                .convert(ColorSpace::Rec2020)
+                .components[0],
+            None
+        );
+    }
+
+    #[test]
+    fn test_003_gamut_mapping() {
+        assert_eq!(Rgba::from(parse("#ff8000")).to_u8(), [255, 128, 0, 255]);
+
+        // The out of gamut colors keep their hue and lightness.
+        let p3 = Rgba::from(parse("color(display-p3 0 1 0)"));
+        assert!(p3.red >= 0.0 && p3.green <= 1.0 && p3.blue >= 0.0);
+        let mapped = ColorFunction::from(p3).convert(ColorSpace::Oklch);
+        let origin = parse("color(display-p3 0 1 0)").convert(ColorSpace::Oklch);
+        assert!((mapped.components[0].unwrap() - origin.components[0].unwrap()).abs() < 0.02);
+        assert!((mapped.components[2].unwrap() - origin.components[2].unwrap()).abs() < 3.0);
+        assert_eq!(p3.to_u8(), [0, 251, 41, 255]);
+
+        assert_eq!(Rgba::from(parse("oklch(1.2 0.4 30)")).to_u8(), [255; 4]);
+        assert_eq!(
+            Rgba::from(parse("lab(0 100 100 / 0.5)")).to_u8(),
+            [0, 0, 0, 128]
+        );
+
+        let current = Rgba::new(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(Color::CurrentColor.to_rgba(&current), current);
+    }
+}
@@ -0,0 +1,318 @@
+use std::fmt::Display;
+
+use crate::style::{ComponentValue, StyleError, TokenKind};
+
+use super::{Color, ColorFunction, ColorSpace, Rgba};
+
+/// How the hues of polar color spaces are interpolated.
+///
+/// [CSS Color 4](https://drafts.csswg.org/css-color-4/#hue-interpolation)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HueInterpolation {
+    #[default]
+    Shorter,
+    Longer,
+    Increasing,
+    Decreasing,
+}
+
+impl TryFrom<&str> for HueInterpolation {
+    type Error = StyleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "shorter" => Ok(Self::Shorter),
+            "longer" => Ok(Self::Longer),
+            "increasing" => Ok(Self::Increasing),
+            "decreasing" => Ok(Self::Decreasing),
+            _ => Err(StyleError::InvalidValue(&[
+                "shorter",
+                "longer",
+                "increasing",
+                "decreasing",
+            ])),
+        }
+    }
+}
+
+impl Display for HueInterpolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shorter => write!(f, "shorter"),
+            Self::Longer => write!(f, "longer"),
+            Self::Increasing => write!(f, "increasing"),
+            Self::Decreasing => write!(f, "decreasing"),
+        }
+    }
+}
+
+impl HueInterpolation {
+    /// Adjust the hues, in degrees, so that the interpolation follows the arc.
+    fn fixup(&self, h1: f32, h2: f32) -> (f32, f32) {
+        let (h1, h2) = (h1.rem_euclid(360.0), h2.rem_euclid(360.0));
+        let delta = h2 - h1;
+
+        match self {
+            Self::Shorter if delta > 180.0 => (h1 + 360.0, h2),
+            Self::Shorter if delta < -180.0 => (h1, h2 + 360.0),
+            Self::Longer if (0.0..180.0).contains(&delta) => (h1 + 360.0, h2),
+            Self::Longer if (-180.0..=0.0).contains(&delta) && delta != 0.0 => (h1, h2 + 360.0),
+            Self::Increasing if h2 < h1 => (h1, h2 + 360.0),
+            Self::Decreasing if h1 < h2 => (h1 + 360.0, h2),
+            _ => (h1, h2),
+        }
+    }
+}
+
+/// The color space in which colors are interpolated, and how the hues are.
+///
+/// [CSS Color 4](https://drafts.csswg.org/css-color-4/#color-interpolation-method)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorInterpolation {
+    pub space: ColorSpace,
+    pub hue: HueInterpolation,
+}
+
+impl TryFrom<&[&ComponentValue]> for ColorInterpolation {
+    type Error = StyleError;
+
+    /// Parse `in <color-space> [<hue-interpolation-method> hue]?`.
+    fn try_from(values: &[&ComponentValue]) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<color-interpolation-method>"]);
+        let idents = values
+            .iter()
+            .map(|cv| match cv.as_token_kind() {
+                Some(TokenKind::Ident(ident)) => Ok(ident.as_str()),
+                _ => Err(invalid.clone()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match idents.as_slice() {
+            [kw, space] if kw.eq_ignore_ascii_case("in") => Ok(Self {
+                space: ColorSpace::try_from(*space)?,
+                hue: HueInterpolation::default(),
+            }),
+            [kw, space, hue, suffix]
+                if kw.eq_ignore_ascii_case("in") && suffix.eq_ignore_ascii_case("hue") =>
+            {
+                match ColorSpace::try_from(*space)? {
+                    space if space.is_polar() => Ok(Self {
+                        space,
+                        hue: HueInterpolation::try_from(*hue)?,
+                    }),
+                    _ => Err(invalid),
+                }
+            }
+            _ => Err(invalid),
+        }
+    }
+}
+
+impl Display for ColorInterpolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "in {}", self.space)?;
+        match self.hue {
+            HueInterpolation::Shorter => Ok(()),
+            hue => write!(f, " {} hue", hue),
+        }
+    }
+}
+
+/// A color of the color-mix() function.
+///
+/// [CSS Color 5](https://drafts.csswg.org/css-color-5/#color-mix)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorMix {
+    pub interpolation: ColorInterpolation,
+    /// The colors, and their percentages if any.
+    pub colors: [(Color, Option<f32>); 2],
+}
+
+impl ColorMix {
+    /// The normalized weights of the colors, and the alpha multiplier.
+    fn weights(&self) -> (f32, f32, f32) {
+        let (p1, p2) = match (self.colors[0].1, self.colors[1].1) {
+            (None, None) => (50.0, 50.0),
+            (Some(p1), None) => (p1, 100.0 - p1),
+            (None, Some(p2)) => (100.0 - p2, p2),
+            (Some(p1), Some(p2)) => (p1, p2),
+        };
+
+        let sum = p1 + p2;
+        (p1 / sum, p2 / sum, (sum / 100.0).min(1.0))
+    }
+
+    /// Mix the colors, the current color being needed to resolve currentcolor.
+    pub fn mix(&self, current_color: Option<&Rgba>) -> Option<ColorFunction> {
+        let [c1, c2] = [&self.colors[0].0, &self.colors[1].0]
+            .map(|color| color.to_color_function(current_color));
+        let (mut c1, mut c2) = (c1?.convert(self.interpolation.space), c2?.convert(self.interpolation.space));
+        let (w1, w2, multiplier) = self.weights();
+
+        // A missing component takes the value of the other color.
+        for (a, b) in c1.components.iter_mut().zip(c2.components.iter_mut()) {
+            *a = a.or(*b);
+            *b = b.or(*a);
+        }
+        c1.alpha = c1.alpha.or(c2.alpha);
+        c2.alpha = c2.alpha.or(c1.alpha);
+
+        let hue_index = self.interpolation.space.hue_index();
+        if let Some(i) = hue_index {
+            if let (Some(h1), Some(h2)) = (c1.components[i], c2.components[i]) {
+                let (h1, h2) = self.interpolation.hue.fixup(h1, h2);
+                c1.components[i] = Some(h1);
+                c2.components[i] = Some(h2);
+            }
+        }
+
+        // The components are interpolated premultiplied by the alpha.
+        let (a1, a2) = (c1.alpha.unwrap_or(1.0), c2.alpha.unwrap_or(1.0));
+        let alpha = a1 * w1 + a2 * w2;
+
+        let mut components = [None; 3];
+        for (i, component) in components.iter_mut().enumerate() {
+            let (Some(v1), Some(v2)) = (c1.components[i], c2.components[i]) else {
+                continue;
+            };
+
+            *component = Some(match Some(i) == hue_index {
+                true => (v1 * w1 + v2 * w2).rem_euclid(360.0),
+                false if alpha == 0.0 => 0.0,
+                false => (v1 * a1 * w1 + v2 * a2 * w2) / alpha,
+            });
+        }
+
+        let alpha = c1.alpha.map(|_| alpha * multiplier);
+        Some(ColorFunction::new(self.interpolation.space, components, alpha))
+    }
+}
+
+impl TryFrom<&[ComponentValue]> for ColorMix {
+    type Error = StyleError;
+
+    /// Parse the arguments of color-mix().
+    fn try_from(values: &[ComponentValue]) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<color-interpolation-method>", "<color>"]);
+        let arguments = values
+            .split(ComponentValue::is_comma)
+            .map(|argument| {
+                argument
+                    .iter()
+                    .filter(|cv| !cv.is_whitespace())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let [method, first, second] = arguments.as_slice() else {
+            return Err(invalid);
+        };
+
+        let interpolation = ColorInterpolation::try_from(method.as_slice())?;
+
+        let color = |argument: &[&ComponentValue]| {
+            let (color, percentage) = match argument {
+                [color] => (color, None),
+                [color, percentage] | [percentage, color]
+                    if matches!(percentage.as_token_kind(), Some(TokenKind::Percentage(_))) =>
+                {
+                    (color, Some(percentage))
+                }
+                _ => return Err(invalid.clone()),
+            };
+
+            let percentage = match percentage.and_then(|cv| cv.as_token_kind()) {
+                Some(TokenKind::Percentage(number)) => match number.to_f32() {
+                    p if (0.0..=100.0).contains(&p) => Some(p),
+                    _ => return Err(invalid.clone()),
+                },
+                _ => None,
+            };
+
+            Ok((Color::try_from(*color)?, percentage))
+        };
+
+        let colors = [color(first)?, color(second)?];
+        if colors[0].1.unwrap_or(1.0) + colors[1].1.unwrap_or(1.0) == 0.0 {
+            return Err(invalid);
+        }
+
+        Ok(Self {
+            interpolation,
+            colors,
+        })
+    }
+}
+
+impl Display for ColorMix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "color-mix({}", self.interpolation)?;
+
+        for (color, percentage) in &self.colors {
+            write!(f, ", {}", color)?;
+            if let Some(percentage) = percentage {
+                write!(f, " {}%", percentage)?;
+            }
+        }
+
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, Color, Rgba};
+
+    fn parse(source: &str) -> Color {
+        Color::try_from(&parse_component_values(source)[0]).unwrap()
+    }
+
+    fn rgba(source: &str) -> [u8; 4] {
+        parse(source).to_rgba(&Rgba::default()).to_u8()
+    }
+
+    #[test]
+    fn test_001_color_mix() {
+        assert_eq!(
+            parse("color-mix(in oklch, red 40%, blue)").to_string(),
+            "oklch(0.5223904 0.29100198 314.1248)"
+        );
+        assert_eq!(
+            rgba("color-mix(in oklch, red 40%, blue)"),
+            [161, 0, 213, 255]
+        );
+
+        assert_eq!(rgba("color-mix(in srgb, red, blue)"), [128, 0, 128, 255]);
+        assert_eq!(
+            rgba("color-mix(in srgb, red 30%, blue 30%)"),
+            [128, 0, 128, 153]
+        );
+        assert_eq!(
+            rgba("color-mix(in srgb, rgb(255 0 0 / 0), blue)"),
+            [0, 0, 255, 128]
+        );
+        assert_eq!(
+            rgba("color-mix(in hsl longer hue, hsl(0 100% 50%), hsl(120 100% 50%))"),
+            [0, 0, 255, 255]
+        );
+
+        // The mixes with currentcolor are resolved at used-value time.
+        let mix = parse("color-mix(in srgb, currentcolor 25%, white)");
+        assert_eq!(
+            mix.to_string(),
+            "color-mix(in srgb, currentcolor 25%, white)"
+        );
+        let current = Rgba::new(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(mix.to_rgba(&current).to_u8(), [191, 191, 191, 255]);
+
+        assert!(
+            Color::try_from(&parse_component_values("color-mix(in srgb, red 0%, blue 0%)")[0])
+                .is_err()
+        );
+        assert!(Color::try_from(
+            &parse_component_values("color-mix(in srgb longer hue, red, blue)")[0]
+        )
+        .is_err());
+        assert!(Color::try_from(&parse_component_values("color-mix(red, blue)")[0]).is_err());
+    }
+}
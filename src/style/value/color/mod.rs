@@ -0,0 +1,372 @@
+mod convert;
+mod mix;
+mod named;
+mod parser;
+
+pub use convert::*;
+pub use mix::*;
+pub use named::*;
+
+use std::fmt::Display;
+
+use crate::style::StyleError;
+
+use super::Value;
+
+/// A missing color component, the none keyword, is None.
+pub type ColorComponent = Option<f32>;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RgbChannel {
+    Percentage(f32),
+    /// A number between 0 and 255.
+    Number(f32),
+    /// The missing component.
+    None,
+}
+
+impl RgbChannel {
+    /// The channel between 0 and 255, unclamped.
+    pub fn value(&self) -> ColorComponent {
+        match self {
+            Self::Percentage(percentage) => Some(percentage * 2.55),
+            Self::Number(number) => Some(*number),
+            Self::None => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Hsl {
+    /// The hue, in degrees.
+    pub hue: ColorComponent,
+    /// The saturation, between 0 and 100.
+    pub saturation: ColorComponent,
+    /// The lightness, between 0 and 100.
+    pub lightness: ColorComponent,
+    pub alpha: ColorComponent,
+}
+
+impl Hsl {
+    /// The sRGB channels, between 0 and 1.
+    pub fn to_rgb(&self) -> [f32; 3] {
+        let hue = self.hue.unwrap_or_default().rem_euclid(360.0);
+        let saturation = self.saturation.unwrap_or_default() / 100.0;
+        let lightness = self.lightness.unwrap_or_default() / 100.0;
+
+        let channel = |n: f32| {
+            let k = (n + hue / 30.0) % 12.0;
+            let a = saturation * lightness.min(1.0 - lightness);
+            lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+
+        [channel(0.0), channel(8.0), channel(4.0)]
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Hwb {
+    /// The hue, in degrees.
+    pub hue: ColorComponent,
+    /// The whiteness, between 0 and 100.
+    pub whiteness: ColorComponent,
+    /// The blackness, between 0 and 100.
+    pub blackness: ColorComponent,
+    pub alpha: ColorComponent,
+}
+
+impl Hwb {
+    /// The sRGB channels, between 0 and 1.
+    pub fn to_rgb(&self) -> [f32; 3] {
+        let whiteness = self.whiteness.unwrap_or_default() / 100.0;
+        let blackness = self.blackness.unwrap_or_default() / 100.0;
+
+        if whiteness + blackness >= 1.0 {
+            let gray = whiteness / (whiteness + blackness);
+            return [gray; 3];
+        }
+
+        let hsl = Hsl {
+            hue: self.hue,
+            saturation: Some(100.0),
+            lightness: Some(50.0),
+            alpha: None,
+        };
+
+        hsl.to_rgb()
+            .map(|channel| channel * (1.0 - whiteness - blackness) + whiteness)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rgb {
+    pub red: RgbChannel,
+    pub green: RgbChannel,
+    pub blue: RgbChannel,
+    pub alpha: ColorComponent,
+}
+
+/// A color of the lab() and oklab() functions.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Lab {
+    pub lightness: ColorComponent,
+    pub a: ColorComponent,
+    pub b: ColorComponent,
+    pub alpha: ColorComponent,
+}
+
+/// A color of the lch() and oklch() functions.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Lch {
+    pub lightness: ColorComponent,
+    pub chroma: ColorComponent,
+    /// The hue, in degrees.
+    pub hue: ColorComponent,
+    pub alpha: ColorComponent,
+}
+
+/// The color spaces, the predefined ones of the color() function and
+/// the ones of the color functions.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ColorSpace {
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    A98Rgb,
+    ProphotoRgb,
+    Rec2020,
+    XyzD50,
+    XyzD65,
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+}
+
+impl ColorSpace {
+    /// Checks if the color space can be used by the color() function.
+    pub fn is_predefined(&self) -> bool {
+        !matches!(
+            self,
+            Self::Hsl | Self::Hwb | Self::Lab | Self::Lch | Self::Oklab | Self::Oklch
+        )
+    }
+
+    /// Checks if the color space has a hue component.
+    pub fn is_polar(&self) -> bool {
+        matches!(self, Self::Hsl | Self::Hwb | Self::Lch | Self::Oklch)
+    }
+}
+
+impl TryFrom<&str> for ColorSpace {
+    type Error = StyleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "srgb" => Ok(Self::Srgb),
+            "srgb-linear" => Ok(Self::SrgbLinear),
+            "display-p3" => Ok(Self::DisplayP3),
+            "a98-rgb" => Ok(Self::A98Rgb),
+            "prophoto-rgb" => Ok(Self::ProphotoRgb),
+            "rec2020" => Ok(Self::Rec2020),
+            "xyz-d50" => Ok(Self::XyzD50),
+            "xyz" | "xyz-d65" => Ok(Self::XyzD65),
+            "hsl" => Ok(Self::Hsl),
+            "hwb" => Ok(Self::Hwb),
+            "lab" => Ok(Self::Lab),
+            "lch" => Ok(Self::Lch),
+            "oklab" => Ok(Self::Oklab),
+            "oklch" => Ok(Self::Oklch),
+            _ => Err(StyleError::InvalidValue(&[
+                "<predefined-rgb>",
+                "<xyz-space>",
+            ])),
+        }
+    }
+}
+
+impl Display for ColorSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Srgb => write!(f, "srgb"),
+            Self::SrgbLinear => write!(f, "srgb-linear"),
+            Self::DisplayP3 => write!(f, "display-p3"),
+            Self::A98Rgb => write!(f, "a98-rgb"),
+            Self::ProphotoRgb => write!(f, "prophoto-rgb"),
+            Self::Rec2020 => write!(f, "rec2020"),
+            Self::XyzD50 => write!(f, "xyz-d50"),
+            Self::XyzD65 => write!(f, "xyz-d65"),
+            Self::Hsl => write!(f, "hsl"),
+            Self::Hwb => write!(f, "hwb"),
+            Self::Lab => write!(f, "lab"),
+            Self::Lch => write!(f, "lch"),
+            Self::Oklab => write!(f, "oklab"),
+            Self::Oklch => write!(f, "oklch"),
+        }
+    }
+}
+
+/// A color of the color() function, or any color in a color space.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ColorFunction {
+    pub space: ColorSpace,
+    pub components: [ColorComponent; 3],
+    pub alpha: ColorComponent,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Color {
+    /// The hexadecimal digits of a hex color.
+    Hex(String),
+    /// A named color, in lowercase.
+    Named(String),
+    System(SystemColor),
+    CurrentColor,
+    Rgb(Rgb),
+    Hsl(Hsl),
+    Hwb(Hwb),
+    Lab(Lab),
+    Lch(Lch),
+    Oklab(Lab),
+    Oklch(Lch),
+    Function(ColorFunction),
+    /// A color-mix() which can't be resolved until used, as it refers to
+    /// currentcolor.
+    Mix(Box<ColorMix>),
+    Transparent,
+}
+
+impl Color {
+    /// The sRGB channels between 0 and 255, and the alpha, of the legacy
+    /// colors.
+    fn legacy_rgba(&self) -> Option<([f32; 3], f32)> {
+        let rgb = |channels: [f32; 3]| channels.map(|channel| channel * 255.0);
+
+        match self {
+            Self::Hex(hex) => {
+                let [r, g, b, a] = parser::parse_hex(hex)?;
+                Some(([r, g, b].map(f32::from), f32::from(a) / 255.0))
+            }
+            Self::Rgb(color) => Some((
+                [color.red, color.green, color.blue].map(|c| c.value().unwrap_or_default()),
+                color.alpha.unwrap_or_default(),
+            )),
+            Self::Hsl(color) => Some((rgb(color.to_rgb()), color.alpha.unwrap_or_default())),
+            Self::Hwb(color) => Some((rgb(color.to_rgb()), color.alpha.unwrap_or_default())),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<Value> for Color {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Color(color) => Ok(color),
+            _ => Err(StyleError::InvalidValue(&[
+                "rgba",
+                "hsl",
+                "hsla",
+                "named-color",
+                "hwb",
+                "lch",
+                "lab",
+            ])),
+        }
+    }
+}
+
+/// Serialize a component, a missing one being none.
+fn component(component: ColorComponent) -> String {
+    match component {
+        Some(value) => value.to_string(),
+        None => "none".to_string(),
+    }
+}
+
+/// Serialize the alpha, omitted when opaque.
+fn alpha(alpha: ColorComponent) -> String {
+    match alpha {
+        Some(alpha) if alpha >= 1.0 => String::default(),
+        alpha => format!(" / {}", component(alpha.map(|alpha| alpha.max(0.0)))),
+    }
+}
+
+/// Round the alpha of a legacy color to the shortest value, with two or
+/// three decimals, which maps to the same 8-bit alpha.
+///
+/// [CSSOM](https://drafts.csswg.org/cssom/#serializing-css-values)
+fn legacy_alpha(alpha: f32) -> f32 {
+    let byte = (alpha * 255.0).round();
+    let rounded = (byte / 2.55).round() / 100.0;
+
+    if (rounded * 255.0).round() == byte {
+        rounded
+    } else {
+        (byte / 0.255).round() / 1000.0
+    }
+}
+
+impl Display for Color {
+    /// Serialize the color as specified.
+    ///
+    /// [CSS Color 4](https://drafts.csswg.org/css-color-4/#serializing-color-values)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some((channels, alpha)) = self.legacy_rgba() {
+            let [r, g, b] = channels.map(|channel| channel.clamp(0.0, 255.0).round());
+            let alpha = alpha.clamp(0.0, 1.0);
+
+            return match legacy_alpha(alpha) {
+                alpha if alpha >= 1.0 => write!(f, "rgb({}, {}, {})", r, g, b),
+                alpha => write!(f, "rgba({}, {}, {}, {})", r, g, b, alpha),
+            };
+        }
+
+        match self {
+            Color::Named(name) => write!(f, "{}", name),
+            Color::System(color) => write!(f, "{}", color),
+            Color::CurrentColor => write!(f, "currentcolor"),
+            Color::Transparent => write!(f, "transparent"),
+            Color::Lab(lab) | Color::Oklab(lab) => write!(
+                f,
+                "{}({} {} {}{})",
+                if matches!(self, Color::Lab(_)) {
+                    "lab"
+                } else {
+                    "oklab"
+                },
+                component(lab.lightness),
+                component(lab.a),
+                component(lab.b),
+                alpha(lab.alpha)
+            ),
+            Color::Lch(lch) | Color::Oklch(lch) => write!(
+                f,
+                "{}({} {} {}{})",
+                if matches!(self, Color::Lch(_)) {
+                    "lch"
+                } else {
+                    "oklch"
+                },
+                component(lch.lightness),
+                component(lch.chroma),
+                component(lch.hue),
+                alpha(lch.alpha)
+            ),
+            Color::Function(color) => write!(
+                f,
+                "color({} {} {} {}{})",
+                color.space,
+                component(color.components[0]),
+                component(color.components[1]),
+                component(color.components[2]),
+                alpha(color.alpha)
+            ),
+            Color::Mix(mix) => write!(f, "{}", mix),
+            _ => unreachable!("legacy colors are serialized as rgb()"),
+        }
+    }
+}
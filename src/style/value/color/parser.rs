@@ -0,0 +1,609 @@
+use crate::style::{
+    Calc, CalcContext, CalcUnit, CalcValue, ComponentValue, Function, SimpleBlock, StyleError,
+    Token, TokenKind, Unit,
+};
+
+use super::{
+    named_color, Color, ColorComponent, ColorFunction, ColorMix, ColorSpace, Hsl, Hwb, Lab, Lch,
+    Rgb, RgbChannel, SystemColor,
+};
+
+/// Parse the digits of a hex color into its channels.
+pub(super) fn parse_hex(hex: &str) -> Option<[u8; 4]> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digit = |index: usize, width: usize| {
+        let digits = hex.get(index * width..(index + 1) * width)?;
+        u8::from_str_radix(&digits.repeat(3 - width), 16).ok()
+    };
+
+    match hex.len() {
+        3 | 4 => Some([
+            digit(0, 1)?,
+            digit(1, 1)?,
+            digit(2, 1)?,
+            digit(3, 1).unwrap_or(255),
+        ]),
+        6 | 8 => Some([
+            digit(0, 2)?,
+            digit(1, 2)?,
+            digit(2, 2)?,
+            digit(3, 2).unwrap_or(255),
+        ]),
+        _ => None,
+    }
+}
+
+/// An argument of a color function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Argument {
+    Number(f32),
+    Percentage(f32),
+    /// An angle, in degrees.
+    Angle(f32),
+    None,
+}
+
+impl TryFrom<&ComponentValue> for Argument {
+    type Error = StyleError;
+
+    fn try_from(value: &ComponentValue) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<number>", "<percentage>", "<angle>", "none"]);
+
+        // The math functions are resolved straight away, as they can't
+        // depend on the context.
+        let value = match value {
+            ComponentValue::Function(function) if Calc::is_math_function(function) => {
+                Calc::try_from(function)?.resolve(&CalcContext::default())?
+            }
+            ComponentValue::Token(token) => match &token.kind {
+                TokenKind::Number(number) => CalcValue::number(number.to_f32()),
+                TokenKind::Percentage(number) => CalcValue::percentage(number.to_f32()),
+                // The angles are converted to degrees.
+                TokenKind::Dimension(_) => Calc::try_from(&Function::new("calc", [value.clone()]))?
+                    .resolve(&CalcContext::default())?,
+                TokenKind::Ident(ident) if ident.eq_ignore_ascii_case("none") => {
+                    return Ok(Self::None)
+                }
+                _ => return Err(invalid),
+            },
+            _ => return Err(invalid),
+        };
+
+        match value.unit {
+            CalcUnit::Number => Ok(Self::Number(value.value)),
+            CalcUnit::Percentage => Ok(Self::Percentage(value.value)),
+            CalcUnit::Dimension(Unit::Angle(_)) => Ok(Self::Angle(value.value)),
+            _ => Err(invalid),
+        }
+    }
+}
+
+impl Argument {
+    /// A number or a percentage, the percentage of the reference value.
+    fn number(&self, reference: f32) -> Result<ColorComponent, StyleError> {
+        match self {
+            Self::Number(number) => Ok(Some(*number)),
+            Self::Percentage(percentage) => Ok(Some(percentage * reference / 100.0)),
+            Self::None => Ok(None),
+            Self::Angle(_) => Err(StyleError::InvalidValue(&["<number>", "<percentage>"])),
+        }
+    }
+
+    /// A hue, as a number of degrees or an angle.
+    fn hue(&self) -> Result<ColorComponent, StyleError> {
+        match self {
+            Self::Number(degrees) | Self::Angle(degrees) => Ok(Some(*degrees)),
+            Self::None => Ok(None),
+            Self::Percentage(_) => Err(StyleError::InvalidValue(&["<hue>"])),
+        }
+    }
+
+    /// An alpha, clamped between 0 and 1.
+    fn alpha(&self) -> Result<ColorComponent, StyleError> {
+        self.number(1.0)
+            .map(|alpha| alpha.map(|alpha| alpha.clamp(0.0, 1.0)))
+    }
+}
+
+/// The arguments of a color function, and its alpha.
+struct Arguments {
+    components: Vec<Argument>,
+    alpha: Argument,
+    /// The legacy, comma-separated, syntax.
+    legacy: bool,
+}
+
+impl Arguments {
+    /// Split the arguments of the function, in either the modern syntax,
+    /// space-separated with a / before the alpha, or the legacy one.
+    fn parse(values: &[ComponentValue]) -> Result<Self, StyleError> {
+        let invalid = StyleError::InvalidValue(&["<color>"]);
+        let values = values
+            .iter()
+            .filter(|cv| !cv.is_whitespace())
+            .collect::<Vec<_>>();
+
+        let legacy = values.iter().any(|cv| cv.is_comma());
+
+        let (components, alpha) = if legacy {
+            // The values and the commas alternate.
+            let mut components = Vec::<&ComponentValue>::default();
+            for (i, cv) in values.iter().enumerate() {
+                match (i % 2, cv.is_comma()) {
+                    (0, false) => components.push(cv),
+                    (1, true) => {}
+                    _ => return Err(invalid),
+                }
+            }
+
+            if values.len() % 2 == 0 {
+                return Err(invalid);
+            }
+
+            match components.len() {
+                3 => (components, None),
+                4 => {
+                    let alpha = components.pop();
+                    (components, alpha)
+                }
+                _ => return Err(invalid),
+            }
+        } else {
+            match values.iter().position(|cv| cv.is_delim_value("/")) {
+                Some(slash) if slash + 2 == values.len() => {
+                    (values[..slash].to_vec(), Some(values[slash + 1]))
+                }
+                Some(_) => return Err(invalid),
+                None => (values, None),
+            }
+        };
+
+        let components = components
+            .into_iter()
+            .map(Argument::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let alpha = alpha
+            .map(Argument::try_from)
+            .transpose()?
+            .unwrap_or(Argument::Number(1.0));
+
+        // The missing components are only allowed by the modern syntax.
+        if legacy && (components.contains(&Argument::None) || alpha == Argument::None) {
+            return Err(invalid);
+        }
+
+        Ok(Self {
+            components,
+            alpha,
+            legacy,
+        })
+    }
+
+    fn expect(&self, count: usize) -> Result<&[Argument], StyleError> {
+        match self.components.len() == count {
+            true => Ok(&self.components),
+            false => Err(StyleError::InvalidValue(&["<color>"])),
+        }
+    }
+}
+
+fn parse_rgb(args: &Arguments) -> Result<Color, StyleError> {
+    let invalid = StyleError::InvalidValue(&["<color>"]);
+    let components = args.expect(3)?;
+
+    // The legacy syntax doesn't mix numbers and percentages.
+    let percentages = components
+        .iter()
+        .filter(|arg| matches!(arg, Argument::Percentage(_)))
+        .count();
+    if args.legacy && percentages != 0 && percentages != 3 {
+        return Err(invalid);
+    }
+
+    let channel = |arg: &Argument| match arg {
+        Argument::Number(number) => Ok(RgbChannel::Number(*number)),
+        Argument::Percentage(percentage) => Ok(RgbChannel::Percentage(*percentage)),
+        Argument::None => Ok(RgbChannel::None),
+        Argument::Angle(_) => Err(invalid.clone()),
+    };
+
+    Ok(Color::Rgb(Rgb {
+        red: channel(&components[0])?,
+        green: channel(&components[1])?,
+        blue: channel(&components[2])?,
+        alpha: args.alpha.alpha()?,
+    }))
+}
+
+fn parse_hsl(args: &Arguments) -> Result<Color, StyleError> {
+    let components = args.expect(3)?;
+
+    // The legacy syntax requires percentages.
+    if args.legacy
+        && components[1..]
+            .iter()
+            .any(|arg| !matches!(arg, Argument::Percentage(_)))
+    {
+        return Err(StyleError::InvalidValue(&["<percentage>"]));
+    }
+
+    Ok(Color::Hsl(Hsl {
+        hue: components[0].hue()?,
+        saturation: components[1].number(100.0)?,
+        lightness: components[2].number(100.0)?,
+        alpha: args.alpha.alpha()?,
+    }))
+}
+
+fn parse_hwb(args: &Arguments) -> Result<Color, StyleError> {
+    let components = args.expect(3)?;
+
+    Ok(Color::Hwb(Hwb {
+        hue: components[0].hue()?,
+        whiteness: components[1].number(100.0)?,
+        blackness: components[2].number(100.0)?,
+        alpha: args.alpha.alpha()?,
+    }))
+}
+
+/// Parse lab() or oklab(), given the references of 100% of the components.
+fn parse_lab(args: &Arguments, lightness: f32, ab: f32) -> Result<Lab, StyleError> {
+    let components = args.expect(3)?;
+
+    Ok(Lab {
+        lightness: components[0]
+            .number(lightness)?
+            .map(|l| l.clamp(0.0, lightness)),
+        a: components[1].number(ab)?,
+        b: components[2].number(ab)?,
+        alpha: args.alpha.alpha()?,
+    })
+}
+
+/// Parse lch() or oklch(), given the references of 100% of the components.
+fn parse_lch(args: &Arguments, lightness: f32, chroma: f32) -> Result<Lch, StyleError> {
+    let components = args.expect(3)?;
+
+    Ok(Lch {
+        lightness: components[0]
+            .number(lightness)?
+            .map(|l| l.clamp(0.0, lightness)),
+        chroma: components[1].number(chroma)?.map(|c| c.max(0.0)),
+        hue: components[2].hue()?,
+        alpha: args.alpha.alpha()?,
+    })
+}
+
+fn parse_color_function(values: &[ComponentValue]) -> Result<Color, StyleError> {
+    let mut values = values.iter().skip_while(|cv| cv.is_whitespace());
+
+    let space = match values.next().and_then(ComponentValue::as_token_kind) {
+        Some(TokenKind::Ident(space)) => match ColorSpace::try_from(space.as_str())? {
+            space if space.is_predefined() => space,
+            _ => return Err(StyleError::InvalidValue(&["<colorspace-params>"])),
+        },
+        _ => return Err(StyleError::InvalidValue(&["<colorspace-params>"])),
+    };
+
+    let args = Arguments::parse(&values.cloned().collect::<Vec<_>>())?;
+    if args.legacy {
+        return Err(StyleError::InvalidValue(&["<colorspace-params>"]));
+    }
+
+    let components = args.expect(3)?;
+    Ok(Color::Function(ColorFunction {
+        space,
+        components: [
+            components[0].number(1.0)?,
+            components[1].number(1.0)?,
+            components[2].number(1.0)?,
+        ],
+        alpha: args.alpha.alpha()?,
+    }))
+}
+
+/// Replace the channel keywords by the values of the origin color's channels.
+fn substitute_channels(values: &[ComponentValue], channels: &[(&str, f32)]) -> Vec<ComponentValue> {
+    values
+        .iter()
+        .map(|cv| match cv {
+            ComponentValue::Token(Token {
+                kind: TokenKind::Ident(ident),
+                location,
+            }) => match channels
+                .iter()
+                .find(|(name, _)| ident.eq_ignore_ascii_case(name))
+            {
+                Some((_, value)) => ComponentValue::Token(Token::number(
+                    value.to_string().as_str(),
+                    *location,
+                )),
+                None => cv.clone(),
+            },
+            ComponentValue::Function(function) => ComponentValue::Function(Function {
+                name: function.name.clone(),
+                value: substitute_channels(&function.value, channels),
+            }),
+            ComponentValue::Block(block) => ComponentValue::Block(SimpleBlock {
+                delimiter: block.delimiter,
+                values: substitute_channels(&block.values, channels),
+            }),
+            _ => cv.clone(),
+        })
+        .collect()
+}
+
+/// Turn a relative color into an absolute one, by substituting the channel
+/// keywords with the channels of the origin color, in the function's color space.
+///
+/// [CSS Color 5](https://drafts.csswg.org/css-color-5/#relative-colors)
+fn resolve_relative_color(function: &Function) -> Result<Option<Function>, StyleError> {
+    let invalid = StyleError::InvalidValue(&["<color>"]);
+    let mut values = function
+        .value
+        .iter()
+        .enumerate()
+        .filter(|(_, cv)| !cv.is_whitespace());
+
+    match values.next().and_then(|(_, cv)| cv.as_token_kind()) {
+        Some(TokenKind::Ident(ident)) if ident.eq_ignore_ascii_case("from") => {}
+        _ => return Ok(None),
+    }
+
+    // The origin must be resolvable without the current color.
+    let (index, origin) = values.next().ok_or(invalid.clone())?;
+    let origin = Color::try_from(origin)?
+        .to_color_function(None)
+        .ok_or(invalid.clone())?;
+    let arguments = function.value[index + 1..].to_vec();
+
+    if arguments.iter().any(ComponentValue::is_comma) {
+        return Err(invalid);
+    }
+
+    let name = function.name.to_ascii_lowercase();
+    let space = match name.as_str() {
+        "rgb" | "rgba" => ColorSpace::Srgb,
+        "hsl" | "hsla" => ColorSpace::Hsl,
+        "color" => match arguments
+            .iter()
+            .find(|cv| !cv.is_whitespace())
+            .and_then(ComponentValue::as_token_kind)
+        {
+            Some(TokenKind::Ident(space)) => ColorSpace::try_from(space.as_str())?,
+            _ => return Err(invalid),
+        },
+        name => ColorSpace::try_from(name)?,
+    };
+
+    let color = origin.convert(space);
+    let [c0, c1, c2] = color.components.map(Option::unwrap_or_default);
+    let names = match (name.as_str(), space) {
+        ("rgb" | "rgba", _) => ["r", "g", "b"],
+        (_, ColorSpace::Hsl) => ["h", "s", "l"],
+        (_, ColorSpace::Hwb) => ["h", "w", "b"],
+        (_, ColorSpace::Lab | ColorSpace::Oklab) => ["l", "a", "b"],
+        (_, ColorSpace::Lch | ColorSpace::Oklch) => ["l", "c", "h"],
+        (_, ColorSpace::XyzD50 | ColorSpace::XyzD65) => ["x", "y", "z"],
+        _ => ["r", "g", "b"],
+    };
+
+    // The rgb() channels are numbers between 0 and 255.
+    let scale = match names == ["r", "g", "b"] && name != "color" {
+        true => 255.0,
+        false => 1.0,
+    };
+
+    let channels = [
+        (names[0], c0 * scale),
+        (names[1], c1 * scale),
+        (names[2], c2 * scale),
+        ("alpha", color.alpha.unwrap_or_default()),
+    ];
+
+    Ok(Some(Function {
+        name,
+        value: substitute_channels(&arguments, &channels),
+    }))
+}
+
+impl TryFrom<&Function> for Color {
+    type Error = StyleError;
+
+    fn try_from(function: &Function) -> Result<Self, Self::Error> {
+        if let Some(function) = resolve_relative_color(function)? {
+            return Self::try_from(&function);
+        }
+
+        let name = function.name.to_ascii_lowercase();
+
+        if name == "color" {
+            return parse_color_function(&function.value);
+        }
+
+        // The mix of absolute colors is resolved straight away.
+        if name == "color-mix" {
+            let mix = ColorMix::try_from(function.value.as_slice())?;
+            return Ok(match mix.mix(None) {
+                Some(color) => color.into_color(),
+                None => Color::Mix(Box::new(mix)),
+            });
+        }
+
+        let args = Arguments::parse(&function.value)?;
+        let modern = |color: Result<Color, StyleError>| match args.legacy {
+            true => Err(StyleError::InvalidValue(&["<color>"])),
+            false => color,
+        };
+
+        match name.as_str() {
+            "rgb" | "rgba" => parse_rgb(&args),
+            "hsl" | "hsla" => parse_hsl(&args),
+            "hwb" => modern(parse_hwb(&args)),
+            "lab" => modern(parse_lab(&args, 100.0, 125.0).map(Color::Lab)),
+            "lch" => modern(parse_lch(&args, 100.0, 150.0).map(Color::Lch)),
+            "oklab" => modern(parse_lab(&args, 1.0, 0.4).map(Color::Oklab)),
+            "oklch" => modern(parse_lch(&args, 1.0, 0.4).map(Color::Oklch)),
+            _ => Err(StyleError::InvalidValue(&["<color>"])),
+        }
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = StyleError;
+
+    /// Parse a color keyword.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let name = value.to_ascii_lowercase();
+
+        match name.as_str() {
+            "transparent" => Ok(Self::Transparent),
+            "currentcolor" => Ok(Self::CurrentColor),
+            name if named_color(name).is_some() => Ok(Self::Named(name.to_string())),
+            name => SystemColor::try_from(name).map(Self::System),
+        }
+    }
+}
+
+impl TryFrom<&ComponentValue> for Color {
+    type Error = StyleError;
+
+    /// Parse a color.
+    ///
+    /// [CSS Color 4](https://drafts.csswg.org/css-color-4/#color-syntax)
+    fn try_from(value: &ComponentValue) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<color>"]);
+
+        match value {
+            ComponentValue::Function(function) => Self::try_from(function),
+            ComponentValue::Token(token) => match &token.kind {
+                TokenKind::Hash(hex) if parse_hex(hex).is_some() => Ok(Self::Hex(hex.clone())),
+                TokenKind::Ident(ident) => Self::try_from(ident.as_str()),
+                _ => Err(invalid),
+            },
+            _ => Err(invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, Color, ColorSpace, Lch, StyleError};
+
+    fn parse(source: &str) -> Result<Color, StyleError> {
+        Color::try_from(&parse_component_values(source)[0])
+    }
+
+    fn serialize(source: &str) -> String {
+        parse(source).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_001_hex_and_keywords() {
+        assert_eq!(serialize("#0af"), "rgb(0, 170, 255)");
+        assert_eq!(serialize("#0af8"), "rgba(0, 170, 255, 0.533)");
+        assert_eq!(serialize("#abcd"), "rgba(170, 187, 204, 0.867)");
+        assert_eq!(serialize("#000000cc"), "rgba(0, 0, 0, 0.8)");
+        assert_eq!(serialize("#00000001"), "rgba(0, 0, 0, 0.004)");
+        assert_eq!(serialize("#FF8000"), "rgb(255, 128, 0)");
+        assert_eq!(serialize("#ff800080"), "rgba(255, 128, 0, 0.5)");
+        assert!(parse("#ff80").is_ok() && parse("#ff800").is_err() && parse("#ggg").is_err());
+
+        assert_eq!(serialize("RebeccaPurple"), "rebeccapurple");
+        assert_eq!(serialize("currentColor"), "currentcolor");
+        assert_eq!(serialize("transparent"), "transparent");
+        assert_eq!(serialize("CanvasText"), "canvastext");
+        assert!(parse("notacolor").is_err());
+    }
+
+    #[test]
+    fn test_002_rgb_hsl_hwb() {
+        assert_eq!(serialize("rgb(255, 0, 127.6)"), "rgb(255, 0, 128)");
+        assert_eq!(
+            serialize("rgba(100%, 50%, 0%, 0.25)"),
+            "rgba(255, 128, 0, 0.25)"
+        );
+        assert_eq!(serialize("rgb(300 -20 none / 50%)"), "rgba(255, 0, 0, 0.5)");
+        assert_eq!(serialize("rgb(0 0 0 / 0.123)"), "rgba(0, 0, 0, 0.12)");
+        assert_eq!(serialize("rgb(0 0 0 / 0.867)"), "rgba(0, 0, 0, 0.867)");
+        assert_eq!(serialize("rgb(calc(100 + 55) 0 0)"), "rgb(155, 0, 0)");
+        assert!(parse("rgb(100%, 0, 0)").is_err());
+        assert!(parse("rgb(255, 0, none)").is_err());
+        assert!(parse("rgb(255 0 0 0.5)").is_err());
+        assert!(parse("rgb(255, 0 0)").is_err());
+
+        assert_eq!(serialize("hsl(120, 100%, 50%)"), "rgb(0, 255, 0)");
+        assert_eq!(
+            serialize("hsla(0.5turn 100 25 / 0.5)"),
+            "rgba(0, 128, 128, 0.5)"
+        );
+        assert!(parse("hsl(120, 100, 50)").is_err());
+
+        assert_eq!(serialize("hwb(0 0% 0%)"), "rgb(255, 0, 0)");
+        assert_eq!(serialize("hwb(90deg 60% 60%)"), "rgb(128, 128, 128)");
+        assert!(parse("hwb(0, 0%, 0%)").is_err());
+    }
+
+    #[test]
+    fn test_003_lab_lch_and_color() {
+        assert_eq!(serialize("lab(50% 40 -20%)"), "lab(50 40 -25)");
+        assert_eq!(
+            serialize("lch(52.2 72.2 50 / 0.5)"),
+            "lch(52.2 72.2 50 / 0.5)"
+        );
+        assert_eq!(serialize("oklab(40% 0.1 none)"), "oklab(0.4 0.1 none)");
+        assert_eq!(serialize("oklch(120% 100% 1turn)"), "oklch(1 0.4 360)");
+        assert_eq!(
+            parse("lch(50 -10 none)").unwrap(),
+            Color::Lch(Lch {
+                lightness: Some(50.0),
+                chroma: Some(0.0),
+                hue: None,
+                alpha: Some(1.0),
+            })
+        );
+
+        assert_eq!(
+            serialize("color(display-p3 1 50% 0 / none)"),
+            "color(display-p3 1 0.5 0 / none)"
+        );
+        assert_eq!(
+            serialize("color(xyz 0.2 0.3 0.4)"),
+            "color(xyz-d65 0.2 0.3 0.4)"
+        );
+        assert!(matches!(
+            parse("color(rec2020 0 0 0)"),
+            Ok(Color::Function(color)) if color.space == ColorSpace::Rec2020
+        ));
+        assert!(parse("color(cmyk 0 0 0)").is_err());
+        assert!(parse("lab(50, 40, 20)").is_err());
+    }
+
+    #[test]
+    fn test_004_relative_colors() {
+        assert_eq!(
+            serialize("rgb(from #0000ff r g b / 50%)"),
+            "rgba(0, 0, 255, 0.5)"
+        );
+        assert_eq!(serialize("rgb(from red b g r)"), "rgb(0, 0, 255)");
+        assert_eq!(
+            serialize("hsl(from red calc(h + 120) s l / calc(alpha / 2))"),
+            "rgba(0, 255, 0, 0.5)"
+        );
+        assert_eq!(
+            serialize("oklab(from oklab(0.5 0.1 -0.1) l a none)"),
+            "oklab(0.5 0.1 none)"
+        );
+        assert_eq!(
+            serialize("color(from color(srgb 1 0 0) srgb-linear r 0.5 0.5)"),
+            "color(srgb-linear 1 0.5 0.5)"
+        );
+        assert!(parse("rgb(from currentcolor r g b)").is_err());
+        assert!(parse("rgb(from red, r, g, b)").is_err());
+        assert!(parse("rgb(from red r g)").is_err());
+    }
+}
@@ -8,7 +8,7 @@ pub struct Dimension {
 
 impl std::fmt::Display for Dimension {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.quantity, self.unit)
+        write!(f, "{}{}", self.quantity, self.unit)
     }
 }
 
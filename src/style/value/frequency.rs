@@ -0,0 +1,44 @@
+use crate::style::StyleError;
+
+use super::{Dimension, FrequencyUnit, Number, Unit, Value};
+
+/// A frequency, such as the pitch of a voice.
+#[derive(Debug, Clone, Copy)]
+pub struct Frequency {
+    pub quantity: Number,
+    pub unit: FrequencyUnit,
+}
+
+impl Frequency {
+    pub fn hz<N: Into<Number>>(quantity: N) -> Self {
+        Self::new(quantity, FrequencyUnit::Hz)
+    }
+
+    /// The frequency in hertz, its canonical unit.
+    pub fn to_hz(&self) -> f32 {
+        f32::from(self.quantity) * self.unit.hz_per_unit()
+    }
+}
+
+quantity!(
+    Frequency,
+    FrequencyUnit,
+    Frequency,
+    Hz,
+    to_hz,
+    "<frequency>"
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, Frequency, Value};
+
+    #[test]
+    fn test_001_frequency() {
+        let value = Value::try_from(&parse_component_values("1.5khz")[0]).unwrap();
+        let frequency = Frequency::try_from(value).unwrap();
+        assert_eq!(frequency.to_string(), "1.5kHz");
+        assert_eq!(frequency, Frequency::hz(1500));
+        assert_eq!(frequency.to_canonical_unit().to_string(), "1500Hz");
+    }
+}
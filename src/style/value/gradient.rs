@@ -1,8 +1,722 @@
+use std::fmt::Display;
+
+use crate::style::{ComponentValue, Function, StyleError, TokenKind};
+
+use super::{
+    Angle, AngleUnit, Color, ColorInterpolation, HorizontalSide, LengthPercentage, Position, Value,
+    VerticalSide,
+};
+
+/// Checks if the component value is the given keyword.
+fn is_keyword(value: &ComponentValue, keyword: &str) -> bool {
+    matches!(value.as_token_kind(), Some(TokenKind::Ident(ident)) if ident.eq_ignore_ascii_case(keyword))
+}
+
+/// Parse an angle, which requires a unit unless it is zero.
+fn parse_angle(value: &ComponentValue) -> Result<Angle, StyleError> {
+    match value.as_token_kind() {
+        Some(TokenKind::Number(number)) if number.to_f32() == 0.0 => Ok(Angle::deg(0.0)),
+        Some(TokenKind::Dimension(dimension)) => Ok(Angle::new(
+            dimension.number.to_f32(),
+            AngleUnit::try_from(dimension.unit.as_str())?,
+        )),
+        _ => Err(StyleError::InvalidValue(&["<angle>"])),
+    }
+}
+
+/// The direction of a linear gradient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineDirection {
+    Angle(Angle),
+    /// Towards a side, or a corner when both sides are set.
+    To {
+        horizontal: Option<HorizontalSide>,
+        vertical: Option<VerticalSide>,
+    },
+}
+
+impl Display for LineDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Angle(angle) => write!(f, "{}", angle),
+            Self::To {
+                horizontal,
+                vertical,
+            } => {
+                write!(f, "to")?;
+                if let Some(horizontal) = horizontal {
+                    write!(f, " {}", horizontal)?;
+                }
+                if let Some(vertical) = vertical {
+                    write!(f, " {}", vertical)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl TryFrom<&[&ComponentValue]> for LineDirection {
+    type Error = StyleError;
+
+    fn try_from(values: &[&ComponentValue]) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<angle>", "to <side-or-corner>"]);
+
+        match values {
+            [angle] => parse_angle(angle).map(Self::Angle),
+            [to, sides @ ..] if is_keyword(to, "to") && matches!(sides.len(), 1 | 2) => {
+                let mut horizontal = None;
+                let mut vertical = None;
+
+                for side in sides {
+                    match side.as_token_kind() {
+                        Some(TokenKind::Ident(ident)) => {
+                            match ident.to_ascii_lowercase().as_str() {
+                                "left" if horizontal.is_none() => {
+                                    horizontal = Some(HorizontalSide::Left)
+                                }
+                                "right" if horizontal.is_none() => {
+                                    horizontal = Some(HorizontalSide::Right)
+                                }
+                                "top" if vertical.is_none() => vertical = Some(VerticalSide::Top),
+                                "bottom" if vertical.is_none() => {
+                                    vertical = Some(VerticalSide::Bottom)
+                                }
+                                _ => return Err(invalid),
+                            }
+                        }
+                        _ => return Err(invalid),
+                    }
+                }
+
+                Ok(Self::To {
+                    horizontal,
+                    vertical,
+                })
+            }
+            _ => Err(invalid),
+        }
+    }
+}
+
+/// The position of a color stop of a conic gradient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnglePercentage {
+    Angle(Angle),
+    /// The percentage, as written.
+    Percentage(f32),
+}
+
+impl Display for AnglePercentage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Angle(angle) => write!(f, "{}", angle),
+            Self::Percentage(percentage) => write!(f, "{}%", percentage),
+        }
+    }
+}
+
+impl TryFrom<&ComponentValue> for AnglePercentage {
+    type Error = StyleError;
+
+    fn try_from(value: &ComponentValue) -> Result<Self, Self::Error> {
+        match value.as_token_kind() {
+            Some(TokenKind::Percentage(number)) => Ok(Self::Percentage(number.to_f32())),
+            _ => parse_angle(value).map(Self::Angle),
+        }
+    }
+}
+
+/// A color stop, with up to two positions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorStop<P> {
+    pub color: Color,
+    pub position: Option<P>,
+    /// The end of a double-position color stop.
+    pub second_position: Option<P>,
+}
+
+/// An item of the color stop list of a gradient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradientItem<P> {
+    Stop(ColorStop<P>),
+    /// A color hint, the position of the midpoint between two stops.
+    Hint(P),
+}
+
+impl<P: Display> Display for GradientItem<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stop(stop) => {
+                write!(f, "{}", stop.color)?;
+                for position in [&stop.position, &stop.second_position]
+                    .into_iter()
+                    .flatten()
+                {
+                    write!(f, " {}", position)?;
+                }
+                Ok(())
+            }
+            Self::Hint(position) => write!(f, "{}", position),
+        }
+    }
+}
+
+/// Parse the color stop list, which starts and ends with a color stop and
+/// has no consecutive hints.
+fn parse_color_stop_list<P>(
+    arguments: &[Vec<&ComponentValue>],
+) -> Result<Vec<GradientItem<P>>, StyleError>
+where
+    P: for<'a> TryFrom<&'a ComponentValue, Error = StyleError>,
+{
+    let invalid = StyleError::InvalidValue(&["<color-stop-list>"]);
+
+    let items = arguments
+        .iter()
+        .map(|argument| match argument.as_slice() {
+            [position] if Color::try_from(*position).is_err() => {
+                P::try_from(*position).map(GradientItem::Hint)
+            }
+            [color, positions @ ..] if positions.len() <= 2 => {
+                let mut positions = positions.iter().map(|cv| P::try_from(*cv));
+                Ok(GradientItem::Stop(ColorStop {
+                    color: Color::try_from(*color)?,
+                    position: positions.next().transpose()?,
+                    second_position: positions.next().transpose()?,
+                }))
+            }
+            _ => Err(invalid.clone()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let stops = items
+        .iter()
+        .filter(|item| matches!(item, GradientItem::Stop(_)))
+        .count();
+    let misplaced_hint = items
+        .windows(2)
+        .any(|pair| matches!(pair, [GradientItem::Hint(_), GradientItem::Hint(_)]));
+
+    match (items.first(), items.last()) {
+        (Some(GradientItem::Stop(_)), Some(GradientItem::Stop(_)))
+            if stops >= 2 && !misplaced_hint =>
+        {
+            Ok(items)
+        }
+        _ => Err(invalid),
+    }
+}
+
+fn write_items<P: Display>(
+    f: &mut std::fmt::Formatter<'_>,
+    prelude: Vec<String>,
+    items: &[GradientItem<P>],
+) -> std::fmt::Result {
+    let mut arguments = Vec::default();
+    if !prelude.is_empty() {
+        arguments.push(prelude.join(" "));
+    }
+    arguments.extend(items.iter().map(ToString::to_string));
+
+    write!(f, "{})", arguments.join(", "))
+}
+
+/// Split the color interpolation method, either first or last, from the rest
+/// of the prelude.
+fn split_interpolation<'a>(
+    values: &[&'a ComponentValue],
+) -> Result<(Option<ColorInterpolation>, Vec<&'a ComponentValue>), StyleError> {
+    let Some(start) = values.iter().position(|cv| is_keyword(cv, "in")) else {
+        return Ok((None, values.to_vec()));
+    };
+
+    let end = match start {
+        0 if values.get(3).is_some_and(|cv| is_keyword(cv, "hue")) => 4,
+        0 => 2.min(values.len()),
+        _ => values.len(),
+    };
+
+    let interpolation = ColorInterpolation::try_from(&values[start..end])?;
+    let rest = values[..start]
+        .iter()
+        .chain(&values[end..])
+        .copied()
+        .collect();
+
+    Ok((Some(interpolation), rest))
+}
+
+/// A linear-gradient() or repeating-linear-gradient().
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LinearGradient {
+    pub repeating: bool,
+    pub direction: Option<LineDirection>,
+    pub interpolation: Option<ColorInterpolation>,
+    pub items: Vec<GradientItem<LengthPercentage>>,
+}
+
+impl LinearGradient {
+    /// Create the gradient from its prelude, without the color stops.
+    fn with_prelude(repeating: bool, prelude: &[&ComponentValue]) -> Result<Self, StyleError> {
+        let mut gradient = Self {
+            repeating,
+            ..Default::default()
+        };
+        let (interpolation, rest) = split_interpolation(prelude)?;
+        gradient.interpolation = interpolation;
+
+        match rest.is_empty() {
+            true if interpolation.is_some() => Ok(gradient),
+            true => Err(StyleError::InvalidValue(&["<linear-gradient-syntax>"])),
+            false => {
+                gradient.direction = Some(LineDirection::try_from(rest.as_slice())?);
+                Ok(gradient)
+            }
+        }
+    }
+}
+
+/// The ending shape of a radial gradient.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Gradient {}
+pub enum RadialShape {
+    Circle,
+    Ellipse,
+}
+
+impl Display for RadialShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Circle => write!(f, "circle"),
+            Self::Ellipse => write!(f, "ellipse"),
+        }
+    }
+}
+
+/// The size of the ending shape, relative to the gradient box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RadialExtent {
+    ClosestSide,
+    ClosestCorner,
+    FarthestSide,
+    FarthestCorner,
+}
+
+impl TryFrom<&str> for RadialExtent {
+    type Error = StyleError;
 
-impl std::fmt::Display for Gradient {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "closest-side" => Ok(Self::ClosestSide),
+            "closest-corner" => Ok(Self::ClosestCorner),
+            "farthest-side" => Ok(Self::FarthestSide),
+            "farthest-corner" => Ok(Self::FarthestCorner),
+            _ => Err(StyleError::InvalidValue(&["<radial-extent>"])),
+        }
+    }
+}
+
+impl Display for RadialExtent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ClosestSide => write!(f, "closest-side"),
+            Self::ClosestCorner => write!(f, "closest-corner"),
+            Self::FarthestSide => write!(f, "farthest-side"),
+            Self::FarthestCorner => write!(f, "farthest-corner"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RadialSize {
+    Extent(RadialExtent),
+    /// The radius of a circle, which can't be a percentage.
+    Circle(LengthPercentage),
+    /// The horizontal and vertical radii of an ellipse.
+    Ellipse(LengthPercentage, LengthPercentage),
+}
+
+impl Display for RadialSize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        todo!()
+        match self {
+            Self::Extent(extent) => write!(f, "{}", extent),
+            Self::Circle(radius) => write!(f, "{}", radius),
+            Self::Ellipse(x, y) => write!(f, "{} {}", x, y),
+        }
+    }
+}
+
+/// A radial-gradient() or repeating-radial-gradient().
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RadialGradient {
+    pub repeating: bool,
+    pub shape: Option<RadialShape>,
+    pub size: Option<RadialSize>,
+    pub position: Option<Position>,
+    pub interpolation: Option<ColorInterpolation>,
+    pub items: Vec<GradientItem<LengthPercentage>>,
+}
+
+impl RadialGradient {
+    /// Create the gradient from its prelude, without the color stops.
+    fn with_prelude(repeating: bool, prelude: &[&ComponentValue]) -> Result<Self, StyleError> {
+        let mut gradient = Self {
+            repeating,
+            ..Default::default()
+        };
+        let invalid = StyleError::InvalidValue(&["<radial-gradient-syntax>"]);
+        let (interpolation, rest) = split_interpolation(prelude)?;
+        gradient.interpolation = interpolation;
+
+        let (shape, position) = match rest.iter().position(|cv| is_keyword(cv, "at")) {
+            Some(at) => (&rest[..at], Some(Position::try_from(&rest[at + 1..])?)),
+            None => (rest.as_slice(), None),
+        };
+        gradient.position = position;
+
+        if shape.is_empty() && gradient.position.is_none() && gradient.interpolation.is_none() {
+            return Err(invalid);
+        }
+
+        let mut extent = None;
+        let mut radii = Vec::default();
+        for cv in shape {
+            let keyword = match cv.as_token_kind() {
+                Some(TokenKind::Ident(ident)) => ident.to_ascii_lowercase(),
+                _ => {
+                    radii.push(LengthPercentage::try_from(*cv)?);
+                    continue;
+                }
+            };
+
+            match keyword.as_str() {
+                "circle" if gradient.shape.is_none() => gradient.shape = Some(RadialShape::Circle),
+                "ellipse" if gradient.shape.is_none() => {
+                    gradient.shape = Some(RadialShape::Ellipse)
+                }
+                keyword if extent.is_none() => extent = Some(RadialExtent::try_from(keyword)?),
+                _ => return Err(invalid),
+            }
+        }
+
+        gradient.size = match (gradient.shape, extent, radii.len()) {
+            (_, Some(extent), 0) => Some(RadialSize::Extent(extent)),
+            (_, None, 0) => None,
+            (None | Some(RadialShape::Circle), None, 1) => match radii.remove(0) {
+                LengthPercentage::Percentage(_) => return Err(invalid),
+                radius => Some(RadialSize::Circle(radius)),
+            },
+            (None | Some(RadialShape::Ellipse), None, 2) => {
+                Some(RadialSize::Ellipse(radii.remove(0), radii.remove(0)))
+            }
+            _ => return Err(invalid),
+        };
+
+        Ok(gradient)
+    }
+}
+
+/// A conic-gradient() or repeating-conic-gradient().
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConicGradient {
+    pub repeating: bool,
+    /// The starting angle.
+    pub from: Option<Angle>,
+    pub position: Option<Position>,
+    pub interpolation: Option<ColorInterpolation>,
+    pub items: Vec<GradientItem<AnglePercentage>>,
+}
+
+impl ConicGradient {
+    /// Create the gradient from its prelude, without the color stops.
+    fn with_prelude(repeating: bool, prelude: &[&ComponentValue]) -> Result<Self, StyleError> {
+        let mut gradient = Self {
+            repeating,
+            ..Default::default()
+        };
+        let invalid = StyleError::InvalidValue(&["<conic-gradient-syntax>"]);
+        let (interpolation, rest) = split_interpolation(prelude)?;
+        gradient.interpolation = interpolation;
+
+        let rest = match rest.as_slice() {
+            [from, angle, rest @ ..] if is_keyword(from, "from") => {
+                gradient.from = Some(parse_angle(angle)?);
+                rest
+            }
+            rest => rest,
+        };
+
+        match rest {
+            [at, position @ ..] if is_keyword(at, "at") => {
+                gradient.position = Some(Position::try_from(position)?);
+                Ok(gradient)
+            }
+            [] if gradient.from.is_some() || gradient.interpolation.is_some() => Ok(gradient),
+            _ => Err(invalid),
+        }
+    }
+}
+
+/// An image of the gradient functions.
+///
+/// [CSS Images 4](https://drafts.csswg.org/css-images-4/#gradients)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Gradient {
+    Linear(LinearGradient),
+    Radial(RadialGradient),
+    Conic(ConicGradient),
+}
+
+impl Gradient {
+    pub fn is_gradient_function(function: &Function) -> bool {
+        let name = function.name.to_ascii_lowercase();
+        matches!(
+            name.strip_prefix("repeating-").unwrap_or(&name),
+            "linear-gradient" | "radial-gradient" | "conic-gradient"
+        )
+    }
+
+    pub fn is_repeating(&self) -> bool {
+        match self {
+            Self::Linear(gradient) => gradient.repeating,
+            Self::Radial(gradient) => gradient.repeating,
+            Self::Conic(gradient) => gradient.repeating,
+        }
+    }
+}
+
+impl TryFrom<&Function> for Gradient {
+    type Error = StyleError;
+
+    fn try_from(function: &Function) -> Result<Self, Self::Error> {
+        let name = function.name.to_ascii_lowercase();
+        let (repeating, name) = match name.strip_prefix("repeating-") {
+            Some(name) => (true, name),
+            None => (false, name.as_str()),
+        };
+
+        let arguments = function
+            .value
+            .split(ComponentValue::is_comma)
+            .map(|argument| {
+                argument
+                    .iter()
+                    .filter(|cv| !cv.is_whitespace())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // The first argument is either the prelude or a color stop.
+        let (prelude, stops) = match arguments.split_first() {
+            Some((prelude, stops)) => (prelude.as_slice(), stops),
+            None => return Err(StyleError::InvalidValue(&["<gradient>"])),
+        };
+
+        match name {
+            "linear-gradient" => {
+                let (mut gradient, stops) = match LinearGradient::with_prelude(repeating, prelude) {
+                    Ok(gradient) => (gradient, stops),
+                    Err(_) => (
+                        LinearGradient {
+                            repeating,
+                            ..Default::default()
+                        },
+                        arguments.as_slice(),
+                    ),
+                };
+                gradient.items = parse_color_stop_list(stops)?;
+                Ok(Self::Linear(gradient))
+            }
+            "radial-gradient" => {
+                let (mut gradient, stops) = match RadialGradient::with_prelude(repeating, prelude) {
+                    Ok(gradient) => (gradient, stops),
+                    Err(_) => (
+                        RadialGradient {
+                            repeating,
+                            ..Default::default()
+                        },
+                        arguments.as_slice(),
+                    ),
+                };
+                gradient.items = parse_color_stop_list(stops)?;
+                Ok(Self::Radial(gradient))
+            }
+            "conic-gradient" => {
+                let (mut gradient, stops) = match ConicGradient::with_prelude(repeating, prelude) {
+                    Ok(gradient) => (gradient, stops),
+                    Err(_) => (
+                        ConicGradient {
+                            repeating,
+                            ..Default::default()
+                        },
+                        arguments.as_slice(),
+                    ),
+                };
+                gradient.items = parse_color_stop_list(stops)?;
+                Ok(Self::Conic(gradient))
+            }
+            _ => Err(StyleError::InvalidValue(&["<gradient>"])),
+        }
+    }
+}
+
+impl TryFrom<Value> for Gradient {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Gradient(gradient) => Ok(gradient),
+            _ => Err(StyleError::InvalidValue(&["<gradient>"])),
+        }
+    }
+}
+
+impl From<Gradient> for Value {
+    fn from(value: Gradient) -> Self {
+        Self::Gradient(value)
+    }
+}
+
+impl Display for Gradient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_repeating() {
+            write!(f, "repeating-")?;
+        }
+
+        match self {
+            Self::Linear(gradient) => {
+                write!(f, "linear-gradient(")?;
+                let prelude = [
+                    gradient.direction.as_ref().map(ToString::to_string),
+                    gradient.interpolation.as_ref().map(ToString::to_string),
+                ];
+                write_items(f, prelude.into_iter().flatten().collect(), &gradient.items)
+            }
+            Self::Radial(gradient) => {
+                write!(f, "radial-gradient(")?;
+                let prelude = [
+                    gradient.shape.as_ref().map(ToString::to_string),
+                    gradient.size.as_ref().map(ToString::to_string),
+                    gradient
+                        .position
+                        .as_ref()
+                        .map(|position| format!("at {}", position)),
+                    gradient.interpolation.as_ref().map(ToString::to_string),
+                ];
+                write_items(f, prelude.into_iter().flatten().collect(), &gradient.items)
+            }
+            Self::Conic(gradient) => {
+                write!(f, "conic-gradient(")?;
+                let prelude = [
+                    gradient.from.map(|angle| format!("from {}", angle)),
+                    gradient
+                        .position
+                        .as_ref()
+                        .map(|position| format!("at {}", position)),
+                    gradient.interpolation.as_ref().map(ToString::to_string),
+                ];
+                write_items(f, prelude.into_iter().flatten().collect(), &gradient.items)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, Gradient, StyleError, Value};
+
+    fn parse(source: &str) -> Result<Gradient, StyleError> {
+        Value::try_from(&parse_component_values(source)[0]).and_then(Gradient::try_from)
+    }
+
+    fn serialize(source: &str) -> String {
+        parse(source).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_001_linear_gradient() {
+        assert_eq!(
+            serialize("linear-gradient(red, blue)"),
+            "linear-gradient(red, blue)"
+        );
+        assert_eq!(
+            serialize("linear-gradient(45DEG, red 10%, 30%, blue 1in 2in)"),
+            "linear-gradient(45deg, red 10%, 30%, blue 1in 2in)"
+        );
+        assert_eq!(
+            serialize("repeating-linear-gradient(to top left, #fff 0, #000 1em)"),
+            "repeating-linear-gradient(to left top, rgb(255, 255, 255) 0px, rgb(0, 0, 0) 1em)"
+        );
+        assert_eq!(
+            serialize("linear-gradient(in oklch longer hue, red, blue)"),
+            "linear-gradient(in oklch longer hue, red, blue)"
+        );
+        assert_eq!(
+            serialize("linear-gradient(0.25turn in srgb, red, blue)"),
+            "linear-gradient(0.25turn in srgb, red, blue)"
+        );
+        assert_eq!(
+            serialize("linear-gradient(0, red, blue)"),
+            "linear-gradient(0deg, red, blue)"
+        );
+        assert_eq!(
+            serialize("linear-gradient(-0.0 in srgb, red, blue)"),
+            "linear-gradient(0deg in srgb, red, blue)"
+        );
+
+        assert!(parse("linear-gradient(red)").is_err());
+        assert!(parse("linear-gradient(red, 10%)").is_err());
+        assert!(parse("linear-gradient(red, 10%, 20%, blue)").is_err());
+        assert!(parse("linear-gradient(to left right, red, blue)").is_err());
+        assert!(parse("linear-gradient(45, red, blue)").is_err());
+        assert!(parse("linear-gradient(red 1% 2% 3%, blue)").is_err());
+        assert!(parse("linear-gradient(in srgb longer hue, red, blue)").is_err());
+    }
+
+    #[test]
+    fn test_002_radial_gradient() {
+        assert_eq!(
+            serialize("radial-gradient(red, blue)"),
+            "radial-gradient(red, blue)"
+        );
+        assert_eq!(
+            serialize("radial-gradient(closest-side circle at top, red, blue)"),
+            "radial-gradient(circle closest-side at center top, red, blue)"
+        );
+        assert_eq!(
+            serialize("radial-gradient(1in 50%, red, blue)"),
+            "radial-gradient(1in 50%, red, blue)"
+        );
+        assert_eq!(
+            serialize("repeating-radial-gradient(circle 2em at 10% 20% in lab, red, blue 50%)"),
+            "repeating-radial-gradient(circle 2em at 10% 20% in lab, red, blue 50%)"
+        );
+
+        assert!(parse("radial-gradient(circle 50%, red, blue)").is_err());
+        assert!(parse("radial-gradient(ellipse 1in, red, blue)").is_err());
+        assert!(parse("radial-gradient(circle 1in 2in, red, blue)").is_err());
+        assert!(parse("radial-gradient(circle closest-side 1in, red, blue)").is_err());
+        assert!(parse("radial-gradient(at, red, blue)").is_err());
+    }
+
+    #[test]
+    fn test_003_conic_gradient() {
+        assert_eq!(
+            serialize("conic-gradient(from 90deg at 25% bottom, red 0deg 90deg, 50%, blue)"),
+            "conic-gradient(from 90deg at 25% bottom, red 0deg 90deg, 50%, blue)"
+        );
+        assert_eq!(
+            serialize("repeating-conic-gradient(in hsl, red 10%, blue 20%)"),
+            "repeating-conic-gradient(in hsl, red 10%, blue 20%)"
+        );
+
+        assert_eq!(
+            serialize("conic-gradient(from 0, red 0 25%, blue)"),
+            "conic-gradient(from 0deg, red 0deg 25%, blue)"
+        );
+
+        assert!(parse("conic-gradient(from 90deg at, red, blue)").is_err());
+        assert!(parse("conic-gradient(from 1, red, blue)").is_err());
+        assert!(parse("conic-gradient(red 1in, blue)").is_err());
     }
 }
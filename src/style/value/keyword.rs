@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::style::{Style, StyleError};
+use crate::style::StyleError;
 
 use super::Value;
 
@@ -67,13 +67,210 @@ pub enum Keyword {
     Normal,
     Italic,
     Oblique,
+
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Center,
+
+    Repeat,
+    NoRepeat,
+    RepeatX,
+    RepeatY,
+    Space,
+    Round,
+
+    Auto,
+    Cover,
+    Contain,
+
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+
+    Thin,
+    Medium,
+    Thick,
+    Hidden,
+    Dotted,
+    Dashed,
+    Solid,
+    Double,
+    Groove,
+    Ridge,
+    Inset,
+    Outset,
+    MinContent,
+    MaxContent,
+    FitContent,
+
+    HorizontalTb,
+    VerticalRl,
+    VerticalLr,
+    SidewaysRl,
+    SidewaysLr,
+    Ltr,
+    Rtl,
+    Mixed,
+    Upright,
+    Sideways,
+
+    XxSmall,
+    XSmall,
+    Small,
+    Large,
+    XLarge,
+    XxLarge,
+    XxxLarge,
+    Larger,
+    Smaller,
+    Bold,
+    Bolder,
+    Lighter,
+    UltraCondensed,
+    ExtraCondensed,
+    Condensed,
+    SemiCondensed,
+    SemiExpanded,
+    Expanded,
+    ExtraExpanded,
+    UltraExpanded,
+    SmallCaps,
+    AllSmallCaps,
+    PetiteCaps,
+    AllPetiteCaps,
+    Unicase,
+    TitlingCaps,
+    Sub,
+    Super,
+    CommonLigatures,
+    NoCommonLigatures,
+    DiscretionaryLigatures,
+    NoDiscretionaryLigatures,
+    HistoricalLigatures,
+    NoHistoricalLigatures,
+    Contextual,
+    NoContextual,
+    LiningNums,
+    OldstyleNums,
+    ProportionalNums,
+    TabularNums,
+    DiagonalFractions,
+    StackedFractions,
+    Ordinal,
+    SlashedZero,
+    Jis78,
+    Jis83,
+    Jis90,
+    Jis04,
+    Simplified,
+    Traditional,
+    FullWidth,
+    ProportionalWidth,
+    HistoricalForms,
+    Unicode,
+
+    Collapse,
+    Discard,
+    Preserve,
+    PreserveBreaks,
+    PreserveSpaces,
+    BreakSpaces,
+    Wrap,
+    Nowrap,
+    Balance,
+    Stable,
+    Pretty,
+    Pre,
+    PreWrap,
+    PreLine,
+    Start,
+    End,
+    Justify,
+    MatchParent,
+    JustifyAll,
+    InterWord,
+    InterCharacter,
+    Capitalize,
+    Uppercase,
+    Lowercase,
+    FullSizeKana,
+    BreakWord,
+    Anywhere,
+    KeepAll,
+    BreakAll,
+    Manual,
+    Wavy,
+    Underline,
+    Overline,
+    LineThrough,
+    Blink,
+    FromFont,
+    Hanging,
+    EachLine,
+
+    Static,
+    Relative,
+    Absolute,
+    Sticky,
+    InlineStart,
+    InlineEnd,
+    Both,
+    Visible,
+    Clip,
+
+    Row,
+    RowReverse,
+    Column,
+    ColumnReverse,
+    WrapReverse,
+    Content,
+    Stretch,
+    Baseline,
+    First,
+    Last,
+    Safe,
+    Unsafe,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+    FlexStart,
+    FlexEnd,
+    SelfStart,
+    SelfEnd,
+
+    AutoFill,
+    AutoFit,
+    Subgrid,
+    Dense,
+    AutoFlow,
+    Span,
+
+    InlineBlock,
+    InlineTable,
+    InlineFlex,
+    InlineGrid,
 }
 
 impl TryFrom<&str> for Keyword {
     type Error = StyleError;
 
+    /// Parse a keyword, ASCII case-insensitively.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        match value.to_ascii_lowercase().as_str() {
             "border-box" => Ok(Self::BorderBox),
             "padding-box" => Ok(Self::PaddingBox),
             "content-box" => Ok(Self::ContentBox),
@@ -114,10 +311,10 @@ impl TryFrom<&str> for Keyword {
             "ruby-text" => Ok(Self::RubyText),
             "ruby-base-container" => Ok(Self::RubyBaseContainer),
             "ruby-text-container" => Ok(Self::RubyTextContainer),
-        
+
             "none" => Ok(Self::None),
             "contents" => Ok(Self::Contents),
-        
+
             "serif" => Ok(Self::Serif),
             "sans-serif" => Ok(Self::SansSerif),
             "monospace" => Ok(Self::Monospace),
@@ -131,12 +328,208 @@ impl TryFrom<&str> for Keyword {
             "emoji" => Ok(Self::Emoji),
             "math" => Ok(Self::Math),
             "fangsong" => Ok(Self::Fangsong),
-        
+
             "normal" => Ok(Self::Normal),
             "italic" => Ok(Self::Italic),
             "oblique" => Ok(Self::Oblique),
 
-            _ => Err(StyleError::InvalidValue(&["<keyword>"]))
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            "top" => Ok(Self::Top),
+            "bottom" => Ok(Self::Bottom),
+            "center" => Ok(Self::Center),
+
+            "repeat" => Ok(Self::Repeat),
+            "no-repeat" => Ok(Self::NoRepeat),
+            "repeat-x" => Ok(Self::RepeatX),
+            "repeat-y" => Ok(Self::RepeatY),
+            "space" => Ok(Self::Space),
+            "round" => Ok(Self::Round),
+
+            "auto" => Ok(Self::Auto),
+            "cover" => Ok(Self::Cover),
+            "contain" => Ok(Self::Contain),
+
+            "multiply" => Ok(Self::Multiply),
+            "screen" => Ok(Self::Screen),
+            "overlay" => Ok(Self::Overlay),
+            "darken" => Ok(Self::Darken),
+            "lighten" => Ok(Self::Lighten),
+            "color-dodge" => Ok(Self::ColorDodge),
+            "color-burn" => Ok(Self::ColorBurn),
+            "hard-light" => Ok(Self::HardLight),
+            "soft-light" => Ok(Self::SoftLight),
+            "difference" => Ok(Self::Difference),
+            "exclusion" => Ok(Self::Exclusion),
+            "hue" => Ok(Self::Hue),
+            "saturation" => Ok(Self::Saturation),
+            "color" => Ok(Self::Color),
+            "luminosity" => Ok(Self::Luminosity),
+
+            "thin" => Ok(Self::Thin),
+            "medium" => Ok(Self::Medium),
+            "thick" => Ok(Self::Thick),
+            "hidden" => Ok(Self::Hidden),
+            "dotted" => Ok(Self::Dotted),
+            "dashed" => Ok(Self::Dashed),
+            "solid" => Ok(Self::Solid),
+            "double" => Ok(Self::Double),
+            "groove" => Ok(Self::Groove),
+            "ridge" => Ok(Self::Ridge),
+            "inset" => Ok(Self::Inset),
+            "outset" => Ok(Self::Outset),
+            "min-content" => Ok(Self::MinContent),
+            "max-content" => Ok(Self::MaxContent),
+            "fit-content" => Ok(Self::FitContent),
+
+            "horizontal-tb" => Ok(Self::HorizontalTb),
+            "vertical-rl" => Ok(Self::VerticalRl),
+            "vertical-lr" => Ok(Self::VerticalLr),
+            "sideways-rl" => Ok(Self::SidewaysRl),
+            "sideways-lr" => Ok(Self::SidewaysLr),
+            "ltr" => Ok(Self::Ltr),
+            "rtl" => Ok(Self::Rtl),
+            "mixed" => Ok(Self::Mixed),
+            "upright" => Ok(Self::Upright),
+            "sideways" => Ok(Self::Sideways),
+
+            "xx-small" => Ok(Self::XxSmall),
+            "x-small" => Ok(Self::XSmall),
+            "small" => Ok(Self::Small),
+            "large" => Ok(Self::Large),
+            "x-large" => Ok(Self::XLarge),
+            "xx-large" => Ok(Self::XxLarge),
+            "xxx-large" => Ok(Self::XxxLarge),
+            "larger" => Ok(Self::Larger),
+            "smaller" => Ok(Self::Smaller),
+            "bold" => Ok(Self::Bold),
+            "bolder" => Ok(Self::Bolder),
+            "lighter" => Ok(Self::Lighter),
+            "ultra-condensed" => Ok(Self::UltraCondensed),
+            "extra-condensed" => Ok(Self::ExtraCondensed),
+            "condensed" => Ok(Self::Condensed),
+            "semi-condensed" => Ok(Self::SemiCondensed),
+            "semi-expanded" => Ok(Self::SemiExpanded),
+            "expanded" => Ok(Self::Expanded),
+            "extra-expanded" => Ok(Self::ExtraExpanded),
+            "ultra-expanded" => Ok(Self::UltraExpanded),
+            "small-caps" => Ok(Self::SmallCaps),
+            "all-small-caps" => Ok(Self::AllSmallCaps),
+            "petite-caps" => Ok(Self::PetiteCaps),
+            "all-petite-caps" => Ok(Self::AllPetiteCaps),
+            "unicase" => Ok(Self::Unicase),
+            "titling-caps" => Ok(Self::TitlingCaps),
+            "sub" => Ok(Self::Sub),
+            "super" => Ok(Self::Super),
+            "common-ligatures" => Ok(Self::CommonLigatures),
+            "no-common-ligatures" => Ok(Self::NoCommonLigatures),
+            "discretionary-ligatures" => Ok(Self::DiscretionaryLigatures),
+            "no-discretionary-ligatures" => Ok(Self::NoDiscretionaryLigatures),
+            "historical-ligatures" => Ok(Self::HistoricalLigatures),
+            "no-historical-ligatures" => Ok(Self::NoHistoricalLigatures),
+            "contextual" => Ok(Self::Contextual),
+            "no-contextual" => Ok(Self::NoContextual),
+            "lining-nums" => Ok(Self::LiningNums),
+            "oldstyle-nums" => Ok(Self::OldstyleNums),
+            "proportional-nums" => Ok(Self::ProportionalNums),
+            "tabular-nums" => Ok(Self::TabularNums),
+            "diagonal-fractions" => Ok(Self::DiagonalFractions),
+            "stacked-fractions" => Ok(Self::StackedFractions),
+            "ordinal" => Ok(Self::Ordinal),
+            "slashed-zero" => Ok(Self::SlashedZero),
+            "jis78" => Ok(Self::Jis78),
+            "jis83" => Ok(Self::Jis83),
+            "jis90" => Ok(Self::Jis90),
+            "jis04" => Ok(Self::Jis04),
+            "simplified" => Ok(Self::Simplified),
+            "traditional" => Ok(Self::Traditional),
+            "full-width" => Ok(Self::FullWidth),
+            "proportional-width" => Ok(Self::ProportionalWidth),
+            "historical-forms" => Ok(Self::HistoricalForms),
+            "unicode" => Ok(Self::Unicode),
+
+            "collapse" => Ok(Self::Collapse),
+            "discard" => Ok(Self::Discard),
+            "preserve" => Ok(Self::Preserve),
+            "preserve-breaks" => Ok(Self::PreserveBreaks),
+            "preserve-spaces" => Ok(Self::PreserveSpaces),
+            "break-spaces" => Ok(Self::BreakSpaces),
+            "wrap" => Ok(Self::Wrap),
+            "nowrap" => Ok(Self::Nowrap),
+            "balance" => Ok(Self::Balance),
+            "stable" => Ok(Self::Stable),
+            "pretty" => Ok(Self::Pretty),
+            "pre" => Ok(Self::Pre),
+            "pre-wrap" => Ok(Self::PreWrap),
+            "pre-line" => Ok(Self::PreLine),
+            "start" => Ok(Self::Start),
+            "end" => Ok(Self::End),
+            "justify" => Ok(Self::Justify),
+            "match-parent" => Ok(Self::MatchParent),
+            "justify-all" => Ok(Self::JustifyAll),
+            "inter-word" => Ok(Self::InterWord),
+            "inter-character" => Ok(Self::InterCharacter),
+            "capitalize" => Ok(Self::Capitalize),
+            "uppercase" => Ok(Self::Uppercase),
+            "lowercase" => Ok(Self::Lowercase),
+            "full-size-kana" => Ok(Self::FullSizeKana),
+            "break-word" => Ok(Self::BreakWord),
+            "anywhere" => Ok(Self::Anywhere),
+            "keep-all" => Ok(Self::KeepAll),
+            "break-all" => Ok(Self::BreakAll),
+            "manual" => Ok(Self::Manual),
+            "wavy" => Ok(Self::Wavy),
+            "underline" => Ok(Self::Underline),
+            "overline" => Ok(Self::Overline),
+            "line-through" => Ok(Self::LineThrough),
+            "blink" => Ok(Self::Blink),
+            "from-font" => Ok(Self::FromFont),
+            "hanging" => Ok(Self::Hanging),
+            "each-line" => Ok(Self::EachLine),
+
+            "static" => Ok(Self::Static),
+            "relative" => Ok(Self::Relative),
+            "absolute" => Ok(Self::Absolute),
+            "sticky" => Ok(Self::Sticky),
+            "inline-start" => Ok(Self::InlineStart),
+            "inline-end" => Ok(Self::InlineEnd),
+            "both" => Ok(Self::Both),
+            "visible" => Ok(Self::Visible),
+            "clip" => Ok(Self::Clip),
+
+            "row" => Ok(Self::Row),
+            "row-reverse" => Ok(Self::RowReverse),
+            "column" => Ok(Self::Column),
+            "column-reverse" => Ok(Self::ColumnReverse),
+            "wrap-reverse" => Ok(Self::WrapReverse),
+            "content" => Ok(Self::Content),
+            "stretch" => Ok(Self::Stretch),
+            "baseline" => Ok(Self::Baseline),
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            "safe" => Ok(Self::Safe),
+            "unsafe" => Ok(Self::Unsafe),
+            "space-between" => Ok(Self::SpaceBetween),
+            "space-around" => Ok(Self::SpaceAround),
+            "space-evenly" => Ok(Self::SpaceEvenly),
+            "flex-start" => Ok(Self::FlexStart),
+            "flex-end" => Ok(Self::FlexEnd),
+            "self-start" => Ok(Self::SelfStart),
+            "self-end" => Ok(Self::SelfEnd),
+
+            "auto-fill" => Ok(Self::AutoFill),
+            "auto-fit" => Ok(Self::AutoFit),
+            "subgrid" => Ok(Self::Subgrid),
+            "dense" => Ok(Self::Dense),
+            "auto-flow" => Ok(Self::AutoFlow),
+            "span" => Ok(Self::Span),
+
+            "inline-block" => Ok(Self::InlineBlock),
+            "inline-table" => Ok(Self::InlineTable),
+            "inline-flex" => Ok(Self::InlineFlex),
+            "inline-grid" => Ok(Self::InlineGrid),
+
+            _ => Err(StyleError::InvalidValue(&["<keyword>"])),
         }
     }
 }
@@ -196,13 +589,205 @@ impl Display for Keyword {
             Keyword::Text => write!(f, "text"),
             Keyword::Revert => write!(f, "revert"),
             Keyword::RevertLayer => write!(f, "revert-layer"),
+            Keyword::Left => write!(f, "left"),
+            Keyword::Right => write!(f, "right"),
+            Keyword::Top => write!(f, "top"),
+            Keyword::Bottom => write!(f, "bottom"),
+            Keyword::Center => write!(f, "center"),
+            Keyword::Repeat => write!(f, "repeat"),
+            Keyword::NoRepeat => write!(f, "no-repeat"),
+            Keyword::RepeatX => write!(f, "repeat-x"),
+            Keyword::RepeatY => write!(f, "repeat-y"),
+            Keyword::Space => write!(f, "space"),
+            Keyword::Round => write!(f, "round"),
+            Keyword::Auto => write!(f, "auto"),
+            Keyword::Cover => write!(f, "cover"),
+            Keyword::Contain => write!(f, "contain"),
+            Keyword::Multiply => write!(f, "multiply"),
+            Keyword::Screen => write!(f, "screen"),
+            Keyword::Overlay => write!(f, "overlay"),
+            Keyword::Darken => write!(f, "darken"),
+            Keyword::Lighten => write!(f, "lighten"),
+            Keyword::ColorDodge => write!(f, "color-dodge"),
+            Keyword::ColorBurn => write!(f, "color-burn"),
+            Keyword::HardLight => write!(f, "hard-light"),
+            Keyword::SoftLight => write!(f, "soft-light"),
+            Keyword::Difference => write!(f, "difference"),
+            Keyword::Exclusion => write!(f, "exclusion"),
+            Keyword::Hue => write!(f, "hue"),
+            Keyword::Saturation => write!(f, "saturation"),
+            Keyword::Color => write!(f, "color"),
+            Keyword::Luminosity => write!(f, "luminosity"),
+
+            Keyword::Thin => write!(f, "thin"),
+            Keyword::Medium => write!(f, "medium"),
+            Keyword::Thick => write!(f, "thick"),
+            Keyword::Hidden => write!(f, "hidden"),
+            Keyword::Dotted => write!(f, "dotted"),
+            Keyword::Dashed => write!(f, "dashed"),
+            Keyword::Solid => write!(f, "solid"),
+            Keyword::Double => write!(f, "double"),
+            Keyword::Groove => write!(f, "groove"),
+            Keyword::Ridge => write!(f, "ridge"),
+            Keyword::Inset => write!(f, "inset"),
+            Keyword::Outset => write!(f, "outset"),
+            Keyword::MinContent => write!(f, "min-content"),
+            Keyword::MaxContent => write!(f, "max-content"),
+            Keyword::FitContent => write!(f, "fit-content"),
+
+            Keyword::HorizontalTb => write!(f, "horizontal-tb"),
+            Keyword::VerticalRl => write!(f, "vertical-rl"),
+            Keyword::VerticalLr => write!(f, "vertical-lr"),
+            Keyword::SidewaysRl => write!(f, "sideways-rl"),
+            Keyword::SidewaysLr => write!(f, "sideways-lr"),
+            Keyword::Ltr => write!(f, "ltr"),
+            Keyword::Rtl => write!(f, "rtl"),
+            Keyword::Mixed => write!(f, "mixed"),
+            Keyword::Upright => write!(f, "upright"),
+            Keyword::Sideways => write!(f, "sideways"),
+
+            Keyword::XxSmall => write!(f, "xx-small"),
+            Keyword::XSmall => write!(f, "x-small"),
+            Keyword::Small => write!(f, "small"),
+            Keyword::Large => write!(f, "large"),
+            Keyword::XLarge => write!(f, "x-large"),
+            Keyword::XxLarge => write!(f, "xx-large"),
+            Keyword::XxxLarge => write!(f, "xxx-large"),
+            Keyword::Larger => write!(f, "larger"),
+            Keyword::Smaller => write!(f, "smaller"),
+            Keyword::Bold => write!(f, "bold"),
+            Keyword::Bolder => write!(f, "bolder"),
+            Keyword::Lighter => write!(f, "lighter"),
+            Keyword::UltraCondensed => write!(f, "ultra-condensed"),
+            Keyword::ExtraCondensed => write!(f, "extra-condensed"),
+            Keyword::Condensed => write!(f, "condensed"),
+            Keyword::SemiCondensed => write!(f, "semi-condensed"),
+            Keyword::SemiExpanded => write!(f, "semi-expanded"),
+            Keyword::Expanded => write!(f, "expanded"),
+            Keyword::ExtraExpanded => write!(f, "extra-expanded"),
+            Keyword::UltraExpanded => write!(f, "ultra-expanded"),
+            Keyword::SmallCaps => write!(f, "small-caps"),
+            Keyword::AllSmallCaps => write!(f, "all-small-caps"),
+            Keyword::PetiteCaps => write!(f, "petite-caps"),
+            Keyword::AllPetiteCaps => write!(f, "all-petite-caps"),
+            Keyword::Unicase => write!(f, "unicase"),
+            Keyword::TitlingCaps => write!(f, "titling-caps"),
+            Keyword::Sub => write!(f, "sub"),
+            Keyword::Super => write!(f, "super"),
+            Keyword::CommonLigatures => write!(f, "common-ligatures"),
+            Keyword::NoCommonLigatures => write!(f, "no-common-ligatures"),
+            Keyword::DiscretionaryLigatures => write!(f, "discretionary-ligatures"),
+            Keyword::NoDiscretionaryLigatures => write!(f, "no-discretionary-ligatures"),
+            Keyword::HistoricalLigatures => write!(f, "historical-ligatures"),
+            Keyword::NoHistoricalLigatures => write!(f, "no-historical-ligatures"),
+            Keyword::Contextual => write!(f, "contextual"),
+            Keyword::NoContextual => write!(f, "no-contextual"),
+            Keyword::LiningNums => write!(f, "lining-nums"),
+            Keyword::OldstyleNums => write!(f, "oldstyle-nums"),
+            Keyword::ProportionalNums => write!(f, "proportional-nums"),
+            Keyword::TabularNums => write!(f, "tabular-nums"),
+            Keyword::DiagonalFractions => write!(f, "diagonal-fractions"),
+            Keyword::StackedFractions => write!(f, "stacked-fractions"),
+            Keyword::Ordinal => write!(f, "ordinal"),
+            Keyword::SlashedZero => write!(f, "slashed-zero"),
+            Keyword::Jis78 => write!(f, "jis78"),
+            Keyword::Jis83 => write!(f, "jis83"),
+            Keyword::Jis90 => write!(f, "jis90"),
+            Keyword::Jis04 => write!(f, "jis04"),
+            Keyword::Simplified => write!(f, "simplified"),
+            Keyword::Traditional => write!(f, "traditional"),
+            Keyword::FullWidth => write!(f, "full-width"),
+            Keyword::ProportionalWidth => write!(f, "proportional-width"),
+            Keyword::HistoricalForms => write!(f, "historical-forms"),
+            Keyword::Unicode => write!(f, "unicode"),
+
+            Keyword::Collapse => write!(f, "collapse"),
+            Keyword::Discard => write!(f, "discard"),
+            Keyword::Preserve => write!(f, "preserve"),
+            Keyword::PreserveBreaks => write!(f, "preserve-breaks"),
+            Keyword::PreserveSpaces => write!(f, "preserve-spaces"),
+            Keyword::BreakSpaces => write!(f, "break-spaces"),
+            Keyword::Wrap => write!(f, "wrap"),
+            Keyword::Nowrap => write!(f, "nowrap"),
+            Keyword::Balance => write!(f, "balance"),
+            Keyword::Stable => write!(f, "stable"),
+            Keyword::Pretty => write!(f, "pretty"),
+            Keyword::Pre => write!(f, "pre"),
+            Keyword::PreWrap => write!(f, "pre-wrap"),
+            Keyword::PreLine => write!(f, "pre-line"),
+            Keyword::Start => write!(f, "start"),
+            Keyword::End => write!(f, "end"),
+            Keyword::Justify => write!(f, "justify"),
+            Keyword::MatchParent => write!(f, "match-parent"),
+            Keyword::JustifyAll => write!(f, "justify-all"),
+            Keyword::InterWord => write!(f, "inter-word"),
+            Keyword::InterCharacter => write!(f, "inter-character"),
+            Keyword::Capitalize => write!(f, "capitalize"),
+            Keyword::Uppercase => write!(f, "uppercase"),
+            Keyword::Lowercase => write!(f, "lowercase"),
+            Keyword::FullSizeKana => write!(f, "full-size-kana"),
+            Keyword::BreakWord => write!(f, "break-word"),
+            Keyword::Anywhere => write!(f, "anywhere"),
+            Keyword::KeepAll => write!(f, "keep-all"),
+            Keyword::BreakAll => write!(f, "break-all"),
+            Keyword::Manual => write!(f, "manual"),
+            Keyword::Wavy => write!(f, "wavy"),
+            Keyword::Underline => write!(f, "underline"),
+            Keyword::Overline => write!(f, "overline"),
+            Keyword::LineThrough => write!(f, "line-through"),
+            Keyword::Blink => write!(f, "blink"),
+            Keyword::FromFont => write!(f, "from-font"),
+            Keyword::Hanging => write!(f, "hanging"),
+            Keyword::EachLine => write!(f, "each-line"),
+
+            Keyword::Static => write!(f, "static"),
+            Keyword::Relative => write!(f, "relative"),
+            Keyword::Absolute => write!(f, "absolute"),
+            Keyword::Sticky => write!(f, "sticky"),
+            Keyword::InlineStart => write!(f, "inline-start"),
+            Keyword::InlineEnd => write!(f, "inline-end"),
+            Keyword::Both => write!(f, "both"),
+            Keyword::Visible => write!(f, "visible"),
+            Keyword::Clip => write!(f, "clip"),
+
+            Keyword::Row => write!(f, "row"),
+            Keyword::RowReverse => write!(f, "row-reverse"),
+            Keyword::Column => write!(f, "column"),
+            Keyword::ColumnReverse => write!(f, "column-reverse"),
+            Keyword::WrapReverse => write!(f, "wrap-reverse"),
+            Keyword::Content => write!(f, "content"),
+            Keyword::Stretch => write!(f, "stretch"),
+            Keyword::Baseline => write!(f, "baseline"),
+            Keyword::First => write!(f, "first"),
+            Keyword::Last => write!(f, "last"),
+            Keyword::Safe => write!(f, "safe"),
+            Keyword::Unsafe => write!(f, "unsafe"),
+            Keyword::SpaceBetween => write!(f, "space-between"),
+            Keyword::SpaceAround => write!(f, "space-around"),
+            Keyword::SpaceEvenly => write!(f, "space-evenly"),
+            Keyword::FlexStart => write!(f, "flex-start"),
+            Keyword::FlexEnd => write!(f, "flex-end"),
+            Keyword::SelfStart => write!(f, "self-start"),
+            Keyword::SelfEnd => write!(f, "self-end"),
+
+            Keyword::AutoFill => write!(f, "auto-fill"),
+            Keyword::AutoFit => write!(f, "auto-fit"),
+            Keyword::Subgrid => write!(f, "subgrid"),
+            Keyword::Dense => write!(f, "dense"),
+            Keyword::AutoFlow => write!(f, "auto-flow"),
+            Keyword::Span => write!(f, "span"),
+
+            Keyword::InlineBlock => write!(f, "inline-block"),
+            Keyword::InlineTable => write!(f, "inline-table"),
+            Keyword::InlineFlex => write!(f, "inline-flex"),
+            Keyword::InlineGrid => write!(f, "inline-grid"),
         }
     }
 }
 
 impl Keyword {
     pub fn is_either(&self, values: &[Self]) -> bool {
-        values.iter().any(|value| *value == *self)
+        values.contains(self)
     }
 
     pub fn is_either_func(values: &[Self]) -> impl Fn(&&Keyword) -> bool + '_ {
@@ -214,4 +799,4 @@ impl From<Keyword> for Value {
     fn from(value: Keyword) -> Self {
         Self::Keyword(value)
     }
-}
\ No newline at end of file
+}
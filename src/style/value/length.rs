@@ -1,17 +1,31 @@
-use crate::style::StyleError;
-
-use super::{Dimension, LengthUnit, Number, Percentage, Unit, Value};
+use crate::style::{ComponentValue, StyleError, TokenKind};
 
+use super::{
+    AbsoluteLengthUnit, BaseType, Calc, Dimension, LengthUnit, Number, Percentage, Unit, Value,
+};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Length {
     pub quantity: Number,
-    pub unit: LengthUnit
+    pub unit: LengthUnit,
+}
+
+impl Length {
+    pub fn new<N: Into<Number>>(quantity: N, unit: LengthUnit) -> Self {
+        Self {
+            quantity: quantity.into(),
+            unit,
+        }
+    }
+
+    pub fn px<N: Into<Number>>(quantity: N) -> Self {
+        Self::new(quantity, LengthUnit::Absolute(AbsoluteLengthUnit::Px))
+    }
 }
 
 impl std::fmt::Display for Length {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.quantity, self.unit)
+        write!(f, "{}{}", self.quantity, self.unit)
     }
 }
 
@@ -19,7 +33,7 @@ impl From<Length> for Dimension {
     fn from(value: Length) -> Self {
         Self {
             quantity: value.quantity,
-            unit: Unit::from(value.unit)
+            unit: Unit::from(value.unit),
         }
     }
 }
@@ -31,11 +45,17 @@ impl TryFrom<Value> for Length {
         match value {
             Value::Dimension(dim) => dim.try_into(),
             Value::Length(length) => Ok(length),
-            _ => Err(StyleError::InvalidValue(&["<length>"]))
+            _ => Err(StyleError::InvalidValue(&["<length>"])),
         }
     }
 }
 
+impl From<Length> for Value {
+    fn from(value: Length) -> Self {
+        Self::Length(value)
+    }
+}
+
 impl TryFrom<Dimension> for Length {
     type Error = StyleError;
 
@@ -43,7 +63,7 @@ impl TryFrom<Dimension> for Length {
         if let Unit::Length(unit) = value.unit {
             return Ok(Self {
                 quantity: value.quantity,
-                unit
+                unit,
             });
         }
 
@@ -57,7 +77,103 @@ impl std::ops::Mul<Percentage> for Length {
     fn mul(self, rhs: Percentage) -> Self::Output {
         Length {
             quantity: self.quantity * rhs,
-            unit: self.unit
+            unit: self.unit,
+        }
+    }
+}
+
+/// A length or a percentage, such as the positions of gradients.
+#[derive(Debug, PartialEq, Clone)]
+pub enum LengthPercentage {
+    Length(Length),
+    /// The percentage, as written, which may be negative or above 100%.
+    Percentage(f32),
+    Calc(Calc),
+}
+
+impl LengthPercentage {
+    pub fn zero() -> Self {
+        Self::Length(Length::px(0))
+    }
+
+    /// Checks if the length or the percentage is negative.
+    pub fn is_negative(&self) -> bool {
+        match self {
+            Self::Length(length) => f32::from(length.quantity) < 0.0,
+            Self::Percentage(percentage) => *percentage < 0.0,
+            Self::Calc(_) => false,
+        }
+    }
+}
+
+impl std::fmt::Display for LengthPercentage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Length(length) => write!(f, "{}", length),
+            Self::Percentage(percentage) => write!(f, "{}%", percentage),
+            Self::Calc(calc) => write!(f, "{}", calc),
         }
     }
-}
\ No newline at end of file
+}
+
+impl TryFrom<&ComponentValue> for LengthPercentage {
+    type Error = StyleError;
+
+    fn try_from(value: &ComponentValue) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<length-percentage>"]);
+
+        match value {
+            ComponentValue::Function(function) if Calc::is_math_function(function) => {
+                match Calc::try_from(function)? {
+                    calc if calc.ty().matches(BaseType::Length, true) => Ok(Self::Calc(calc)),
+                    _ => Err(invalid),
+                }
+            }
+            ComponentValue::Token(token) => match &token.kind {
+                TokenKind::Percentage(number) => Ok(Self::Percentage(number.to_f32())),
+                // The unitless zero is a length.
+                TokenKind::Number(number) if number.to_f32() == 0.0 => Ok(Self::zero()),
+                TokenKind::Dimension(dimension) => {
+                    let unit = LengthUnit::try_from(dimension.unit.as_str())?;
+                    Ok(Self::Length(Length::new(dimension.number.to_f32(), unit)))
+                }
+                _ => Err(invalid),
+            },
+            _ => Err(invalid),
+        }
+    }
+}
+
+impl TryFrom<Value> for LengthPercentage {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Length(length) => Ok(Self::Length(length)),
+            Value::Percentage(percentage) => Ok(Self::Percentage(f32::from(percentage) * 100.0)),
+            // The unitless zero is a length.
+            Value::Number(number) if f32::from(number) == 0.0 => Ok(Self::zero()),
+            Value::Calc(calc) if calc.ty().matches(BaseType::Length, true) => Ok(Self::Calc(calc)),
+            _ => Err(StyleError::InvalidValue(&["<length-percentage>"])),
+        }
+    }
+}
+
+impl From<LengthPercentage> for Value {
+    fn from(value: LengthPercentage) -> Self {
+        match value {
+            LengthPercentage::Length(length) => Self::Length(length),
+            // The percentages are finite once parsed.
+            LengthPercentage::Percentage(percentage) => Percentage::try_from(percentage / 100.0)
+                .map(Self::Percentage)
+                .unwrap_or(Self::Number(Number::from(0.0))),
+            LengthPercentage::Calc(calc) => Self::Calc(calc),
+        }
+    }
+}
+
+impl From<Length> for LengthPercentage {
+    fn from(value: Length) -> Self {
+        Self::Length(value)
+    }
+}
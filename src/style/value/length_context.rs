@@ -0,0 +1,357 @@
+use crate::style::StyleError;
+
+use super::{
+    AbsoluteLengthUnit, CalcContext, CalcValue, FontRelativeLengthUnit, Length, LengthPercentage,
+    LengthUnit, RelativeLengthUnit, Unit, ViewportRelativeLengthUnit,
+};
+
+/// The metrics of a font, in CSS pixels, the font-relative units refer to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+    pub font_size: f32,
+    pub x_height: f32,
+    /// The advance of the "0" glyph.
+    pub ch_width: f32,
+    pub cap_height: f32,
+    /// The advance of the "水" glyph.
+    pub ic_width: f32,
+    /// The computed line height.
+    pub line_height: f32,
+}
+
+impl FontMetrics {
+    /// The metrics approximated from the font size, for when the font
+    /// can't be measured.
+    pub fn approximate(font_size: f32) -> Self {
+        Self {
+            font_size,
+            x_height: font_size * 0.5,
+            ch_width: font_size * 0.5,
+            cap_height: font_size * 0.7,
+            ic_width: font_size,
+            line_height: font_size * 1.2,
+        }
+    }
+}
+
+impl Default for FontMetrics {
+    fn default() -> Self {
+        Self::approximate(16.0)
+    }
+}
+
+/// The size of a viewport, in CSS pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ViewportSize {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl ViewportSize {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self { width, height }
+    }
+}
+
+/// The writing modes, which set whether the inline axis is horizontal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WritingMode {
+    #[default]
+    HorizontalTb,
+    VerticalRl,
+    VerticalLr,
+    SidewaysRl,
+    SidewaysLr,
+}
+
+impl WritingMode {
+    /// Checks if the inline axis is vertical.
+    pub fn is_vertical(&self) -> bool {
+        !matches!(self, Self::HorizontalTb)
+    }
+}
+
+impl TryFrom<&str> for WritingMode {
+    type Error = StyleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "horizontal-tb" => Ok(Self::HorizontalTb),
+            "vertical-rl" => Ok(Self::VerticalRl),
+            "vertical-lr" => Ok(Self::VerticalLr),
+            "sideways-rl" => Ok(Self::SidewaysRl),
+            "sideways-lr" => Ok(Self::SidewaysLr),
+            _ => Err(StyleError::InvalidValue(&[
+                "horizontal-tb",
+                "vertical-rl",
+                "vertical-lr",
+                "sideways-rl",
+                "sideways-lr",
+            ])),
+        }
+    }
+}
+
+impl std::fmt::Display for WritingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HorizontalTb => write!(f, "horizontal-tb"),
+            Self::VerticalRl => write!(f, "vertical-rl"),
+            Self::VerticalLr => write!(f, "vertical-lr"),
+            Self::SidewaysRl => write!(f, "sideways-rl"),
+            Self::SidewaysLr => write!(f, "sideways-lr"),
+        }
+    }
+}
+
+/// What lengths are resolved against to get CSS pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LengthContext {
+    /// The font of the element.
+    pub font: FontMetrics,
+    /// The font of the root element.
+    pub root_font: FontMetrics,
+    /// The viewport with the dynamic browser interface expanded.
+    pub small_viewport: ViewportSize,
+    /// The viewport with the dynamic browser interface retracted, which is
+    /// the default viewport.
+    pub large_viewport: ViewportSize,
+    /// The viewport as currently displayed.
+    pub dynamic_viewport: ViewportSize,
+    pub writing_mode: WritingMode,
+}
+
+impl LengthContext {
+    /// A context with a single viewport, and fonts approximated from their sizes.
+    pub fn new(font_size: f32, root_font_size: f32, viewport: ViewportSize) -> Self {
+        Self {
+            font: FontMetrics::approximate(font_size),
+            root_font: FontMetrics::approximate(root_font_size),
+            small_viewport: viewport,
+            large_viewport: viewport,
+            dynamic_viewport: viewport,
+            writing_mode: WritingMode::default(),
+        }
+    }
+
+    /// The number of CSS pixels in one unit.
+    pub fn px_per_unit(&self, unit: LengthUnit) -> f32 {
+        match unit {
+            LengthUnit::Absolute(unit) => unit.px_per_unit(),
+            LengthUnit::Relative(RelativeLengthUnit::Font(unit)) => self.font_relative(unit),
+            LengthUnit::Relative(RelativeLengthUnit::Viewport(unit)) => {
+                self.viewport_relative(unit)
+            }
+        }
+    }
+
+    fn font_relative(&self, unit: FontRelativeLengthUnit) -> f32 {
+        use FontRelativeLengthUnit::*;
+
+        let font = match unit {
+            Em | Ex | Cap | Ch | Ic | Lh => &self.font,
+            Rem | Rex | Rcap | Rch | Ric | Rlh => &self.root_font,
+        };
+
+        match unit {
+            Em | Rem => font.font_size,
+            Ex | Rex => font.x_height,
+            Cap | Rcap => font.cap_height,
+            Ch | Rch => font.ch_width,
+            Ic | Ric => font.ic_width,
+            Lh | Rlh => font.line_height,
+        }
+    }
+
+    fn viewport_relative(&self, unit: ViewportRelativeLengthUnit) -> f32 {
+        use ViewportRelativeLengthUnit::*;
+
+        let viewport = match unit {
+            Svw | Svh | Svi | Svb | Svmin | Svmax => &self.small_viewport,
+            Lvw | Lvh | Lvi | Lvb | Lvmin | Lvmax => &self.large_viewport,
+            Dvw | Dvh | Dvi | Dvb | Dvmin | Dvmax => &self.dynamic_viewport,
+            Vw | Vh | Vi | Vb | Vmin | Vmax => &self.large_viewport,
+        };
+
+        // The inline and block axes depend on the writing mode.
+        let (inline, block) = match self.writing_mode.is_vertical() {
+            true => (viewport.height, viewport.width),
+            false => (viewport.width, viewport.height),
+        };
+
+        let size = match unit {
+            Vw | Svw | Lvw | Dvw => viewport.width,
+            Vh | Svh | Lvh | Dvh => viewport.height,
+            Vi | Svi | Lvi | Dvi => inline,
+            Vb | Svb | Lvb | Dvb => block,
+            Vmin | Svmin | Lvmin | Dvmin => viewport.width.min(viewport.height),
+            Vmax | Svmax | Lvmax | Dvmax => viewport.width.max(viewport.height),
+        };
+
+        size / 100.0
+    }
+}
+
+impl Length {
+    /// The length in CSS pixels.
+    pub fn to_px(&self, context: &LengthContext) -> f32 {
+        f32::from(self.quantity) * context.px_per_unit(self.unit)
+    }
+
+    /// Resolve the length into CSS pixels.
+    pub fn resolve(&self, context: &LengthContext) -> Self {
+        Self::px(self.to_px(context))
+    }
+
+    /// Resolve the relative units into CSS pixels, absolute units being kept.
+    pub fn resolve_relative(&self, context: &LengthContext) -> Self {
+        match self.unit {
+            LengthUnit::Absolute(_) => self.clone(),
+            LengthUnit::Relative(_) => self.resolve(context),
+        }
+    }
+}
+
+impl LengthPercentage {
+    /// Resolve the relative units of the length and of the expressions, the
+    /// expressions without percentages into lengths.
+    pub fn resolve_relative(&self, context: &LengthContext) -> Self {
+        let calc_context = CalcContext {
+            percentage_basis: None,
+            length: Some(*context),
+        };
+
+        match self {
+            Self::Length(length) => Self::Length(length.resolve_relative(context)),
+            Self::Calc(calc) => calc
+                .resolve_length(&calc_context)
+                .map(Self::Length)
+                .unwrap_or_else(|_| Self::Calc(calc.absolutize(context))),
+            other => other.clone(),
+        }
+    }
+
+    /// Resolve the relative units of a length which can't be negative, the
+    /// expressions resolving to a negative length being clamped to zero.
+    pub fn resolve_non_negative(&self, context: &LengthContext) -> Self {
+        match self.resolve_relative(context) {
+            Self::Length(length) if f32::from(length.quantity) < 0.0 => Self::zero(),
+            other => other,
+        }
+    }
+
+    /// The length in CSS pixels, the percentages being of the basis, itself
+    /// in CSS pixels.
+    pub fn to_px(&self, basis: f32, context: &LengthContext) -> f32 {
+        match self {
+            Self::Length(length) => length.to_px(context),
+            Self::Percentage(percentage) => basis * percentage / 100.0,
+            Self::Calc(calc) => {
+                let px = Unit::Length(LengthUnit::Absolute(AbsoluteLengthUnit::Px));
+                let calc_context = CalcContext {
+                    percentage_basis: Some(CalcValue::dimension(basis, px)),
+                    length: Some(*context),
+                };
+
+                calc.resolve_length(&calc_context)
+                    .map(|length| length.to_px(context))
+                    .unwrap_or_default()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{
+        parse_component_values, FontMetrics, Length, LengthContext, LengthPercentage, LengthUnit,
+        Value, ViewportSize, WritingMode,
+    };
+
+    fn px(source: &str, context: &LengthContext) -> f32 {
+        let split = source.find(|c: char| c.is_alphabetic()).unwrap();
+        let (quantity, unit) = source.split_at(split);
+        let length = Length::new(
+            quantity.parse::<f32>().unwrap(),
+            LengthUnit::try_from(unit).unwrap(),
+        );
+        length.to_px(context)
+    }
+
+    #[test]
+    fn test_001_absolute_lengths() {
+        let context = LengthContext::default();
+
+        assert_eq!(px("10px", &context), 10.0);
+        assert_eq!(px("10PX", &context), 10.0);
+        assert_eq!(px("1in", &context), 96.0);
+        assert_eq!(px("2.54cm", &context), 96.0);
+        assert_eq!(px("25.4mm", &context), 96.0);
+        assert_eq!(px("4q", &context), 3.7795277);
+        assert_eq!(px("72pt", &context), 96.0);
+        assert_eq!(px("1pc", &context), 16.0);
+    }
+
+    #[test]
+    fn test_002_relative_lengths() {
+        let mut context = LengthContext::new(20.0, 10.0, ViewportSize::new(800.0, 600.0));
+        context.small_viewport = ViewportSize::new(800.0, 500.0);
+        context.font = FontMetrics {
+            x_height: 9.0,
+            ch_width: 11.0,
+            cap_height: 14.0,
+            ic_width: 20.0,
+            line_height: 30.0,
+            ..context.font
+        };
+
+        assert_eq!(px("2em", &context), 40.0);
+        assert_eq!(px("2rem", &context), 20.0);
+        assert_eq!(px("1ex", &context), 9.0);
+        assert_eq!(px("1rex", &context), 5.0);
+        assert_eq!(px("1ch", &context), 11.0);
+        assert_eq!(px("1cap", &context), 14.0);
+        assert_eq!(px("1ic", &context), 20.0);
+        assert_eq!(px("1ric", &context), 10.0);
+        assert_eq!(px("1lh", &context), 30.0);
+        assert_eq!(px("1rlh", &context), 12.0);
+
+        assert_eq!(px("10vw", &context), 80.0);
+        assert_eq!(px("10vh", &context), 60.0);
+        assert_eq!(px("10svh", &context), 50.0);
+        assert_eq!(px("10lvh", &context), 60.0);
+        assert_eq!(px("10dvh", &context), 60.0);
+        assert_eq!(px("10vmin", &context), 60.0);
+        assert_eq!(px("10svmax", &context), 80.0);
+        assert_eq!(px("10vi", &context), 80.0);
+        assert_eq!(px("10vb", &context), 60.0);
+
+        context.writing_mode = WritingMode::VerticalRl;
+        assert_eq!(px("10vi", &context), 60.0);
+        assert_eq!(px("10lvb", &context), 80.0);
+
+        assert_eq!(
+            Length::new(3, LengthUnit::try_from("rem").unwrap()).resolve(&context),
+            Length::px(30.0)
+        );
+    }
+
+    #[test]
+    fn test_003_resolve_relative_calc() {
+        let context = LengthContext::new(20.0, 10.0, ViewportSize::new(800.0, 600.0));
+        let resolve = |source: &str| {
+            let value = Value::try_from(parse_component_values(source).as_slice()).unwrap();
+            LengthPercentage::try_from(value)
+                .unwrap()
+                .resolve_relative(&context)
+                .to_string()
+        };
+
+        assert_eq!(resolve("calc(1em + 10vw)"), "100px");
+        assert_eq!(resolve("calc(1in + 1px)"), "97px");
+        // The percentages are kept, the relative lengths being resolved.
+        assert_eq!(resolve("calc(50% + 1em)"), "calc(50% + 20px)");
+        assert_eq!(resolve("calc(100% - 1rem)"), "calc(100% - 10px)");
+        assert_eq!(resolve("min(50%, 2em + 1vh)"), "min(50%, 46px)");
+    }
+}
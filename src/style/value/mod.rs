@@ -1,25 +1,165 @@
+/// Implement the conversions, serialization, comparison and arithmetic of a
+/// quantity paired with a unit, comparisons being made in the canonical unit.
+macro_rules! quantity {
+    ($ty:ident, $unit:ident, $variant:ident, $canonical:ident, $to_canonical:ident, $name:literal) => {
+        impl $ty {
+            pub fn new<N: Into<Number>>(quantity: N, unit: $unit) -> Self {
+                Self {
+                    quantity: quantity.into(),
+                    unit,
+                }
+            }
+
+            /// Convert the quantity into the canonical unit.
+            pub fn to_canonical_unit(&self) -> Self {
+                Self::new(self.$to_canonical(), $unit::$canonical)
+            }
+
+            /// Combine two quantities, the unit being kept when both share it.
+            fn combine(self, rhs: Self, op: impl Fn(f32, f32) -> f32) -> Self {
+                match self.unit == rhs.unit {
+                    true => Self::new(op(f32::from(self.quantity), f32::from(rhs.quantity)), self.unit),
+                    false => Self::new(op(self.$to_canonical(), rhs.$to_canonical()), $unit::$canonical),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}{}", self.quantity, self.unit)
+            }
+        }
+
+        impl PartialEq for $ty {
+            fn eq(&self, other: &Self) -> bool {
+                self.$to_canonical() == other.$to_canonical()
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                self.$to_canonical().partial_cmp(&other.$to_canonical())
+            }
+        }
+
+        impl std::ops::Add for $ty {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                self.combine(rhs, |lhs, rhs| lhs + rhs)
+            }
+        }
+
+        impl std::ops::Sub for $ty {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.combine(rhs, |lhs, rhs| lhs - rhs)
+            }
+        }
+
+        impl std::ops::Mul<f32> for $ty {
+            type Output = Self;
+
+            fn mul(self, rhs: f32) -> Self::Output {
+                Self::new(f32::from(self.quantity) * rhs, self.unit)
+            }
+        }
+
+        impl std::ops::Div<f32> for $ty {
+            type Output = Self;
+
+            fn div(self, rhs: f32) -> Self::Output {
+                Self::new(f32::from(self.quantity) / rhs, self.unit)
+            }
+        }
+
+        impl std::ops::Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                self * -1.0
+            }
+        }
+
+        impl From<$ty> for Dimension {
+            fn from(value: $ty) -> Self {
+                Self {
+                    quantity: value.quantity,
+                    unit: Unit::$variant(value.unit),
+                }
+            }
+        }
+
+        impl TryFrom<Dimension> for $ty {
+            type Error = StyleError;
+
+            fn try_from(value: Dimension) -> Result<Self, Self::Error> {
+                match value.unit {
+                    Unit::$variant(unit) => Ok(Self::new(value.quantity, unit)),
+                    _ => Err(StyleError::InvalidValue(&[$name])),
+                }
+            }
+        }
+
+        impl TryFrom<Value> for $ty {
+            type Error = StyleError;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                match value {
+                    Value::$ty(value) => Ok(value),
+                    Value::Dimension(dimension) => Self::try_from(dimension),
+                    _ => Err(StyleError::InvalidValue(&[$name])),
+                }
+            }
+        }
+
+        impl From<$ty> for Value {
+            fn from(value: $ty) -> Self {
+                Self::$ty(value)
+            }
+        }
+    };
+}
+
+mod angle;
+mod calc;
 mod color;
 mod dimension;
+mod frequency;
 mod gradient;
 mod image;
 mod keyword;
 mod length;
+mod length_context;
 mod number;
 mod percentage;
+mod position;
+mod resolution;
+mod time;
 mod unit;
 mod url;
 
+pub use angle::*;
+pub use calc::*;
 pub use color::*;
 pub use dimension::*;
+pub use frequency::*;
 pub use gradient::*;
 pub use image::*;
 pub use keyword::*;
 pub use length::*;
+pub use length_context::*;
 pub use number::Number;
 pub use percentage::*;
+pub use position::*;
+pub use resolution::*;
+pub use time::*;
 pub use unit::*;
 pub use url::*;
 
+use crate::style::{BlockDelimiter, ComponentValue, StyleError, TokenKind};
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Value {
     String(String),
@@ -28,8 +168,19 @@ pub enum Value {
     Percentage(Percentage),
     Dimension(Dimension),
     Length(Length),
+    Angle(Angle),
+    Time(Time),
+    Frequency(Frequency),
+    Resolution(Resolution),
+    /// A flexible length, in `fr` units, a share of the leftover space of a
+    /// grid container.
+    Flex(f32),
 
     Keyword(Keyword),
+    /// An identifier chosen by the author, such as the name of a grid area.
+    CustomIdent(String),
+    /// Names of grid lines, between square brackets.
+    LineNames(Vec<String>),
 
     Color(Color),
 
@@ -39,7 +190,16 @@ pub enum Value {
 
     Gradient(Gradient),
 
+    Calc(Calc),
+
+    /// A function which isn't otherwise typed, such as `fit-content()`, and
+    /// its comma-separated arguments.
+    Function(String, Vec<Value>),
+
+    /// Values separated by whitespaces.
     Array(Vec<Value>),
+    /// Values separated by commas, such as the layers of a background.
+    List(Vec<Value>),
 }
 
 impl std::fmt::Display for Value {
@@ -48,6 +208,8 @@ impl std::fmt::Display for Value {
             Value::String(str) => write!(f, "\"{}\"", str),
             Value::Length(length) => write!(f, "{}", length),
             Value::Keyword(kw) => write!(f, "{}", kw),
+            Value::CustomIdent(ident) => write!(f, "{}", ident),
+            Value::LineNames(names) => write!(f, "[{}]", names.join(" ")),
             Value::Color(color) => write!(f, "{}", color),
             Value::Image(image) => write!(f, "{}", image),
             Value::Array(array) => {
@@ -58,11 +220,33 @@ impl std::fmt::Display for Value {
                     .join(" ");
                 write!(f, "{}", str_array)
             }
+            Value::List(list) => {
+                let str_list = list
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "{}", str_list)
+            }
             Value::Url(url) => write!(f, "{}", url),
             Value::Gradient(gradient) => write!(f, "{}", gradient),
             Value::Number(number) => write!(f, "{}", number),
             Value::Dimension(dimension) => write!(f, "{}", dimension),
             Value::Percentage(percentage) => write!(f, "{}", percentage),
+            Value::Calc(calc) => write!(f, "{}", calc),
+            Value::Function(name, arguments) => {
+                let str_arguments = arguments
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "{}({})", name, str_arguments)
+            }
+            Value::Angle(angle) => write!(f, "{}", angle),
+            Value::Time(time) => write!(f, "{}", time),
+            Value::Frequency(frequency) => write!(f, "{}", frequency),
+            Value::Resolution(resolution) => write!(f, "{}", resolution),
+            Value::Flex(flex) => write!(f, "{}fr", flex),
         }
     }
 }
@@ -72,7 +256,7 @@ impl IntoIterator for Value {
     type IntoIter = Box<dyn Iterator<Item = Self>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        if let Self::Array(values) = self {
+        if let Self::Array(values) | Self::List(values) = self {
             return Box::new(values.into_iter());
         }
 
@@ -101,6 +285,138 @@ impl From<String> for Value {
     }
 }
 
+/// The functions parsed into [Value::Function].
+const VALUE_FUNCTIONS: &[&str] = &["fit-content", "minmax", "repeat"];
+
+/// Whether an identifier can be a custom one, which excludes the CSS-wide
+/// keywords and `default`.
+///
+/// [CSS Values 4](https://drafts.csswg.org/css-values-4/#custom-idents)
+pub(crate) fn is_custom_ident(ident: &str) -> bool {
+    ["initial", "inherit", "unset", "revert", "revert-layer", "default"]
+        .iter()
+        .all(|reserved| !ident.eq_ignore_ascii_case(reserved))
+}
+
+/// Whether an identifier can name a grid line, which also excludes `span`
+/// and `auto`.
+///
+/// [CSS Grid 2](https://drafts.csswg.org/css-grid-2/#typedef-line-names)
+pub(crate) fn is_line_name(ident: &str) -> bool {
+    is_custom_ident(ident)
+        && ["span", "auto"]
+            .iter()
+            .all(|reserved| !ident.eq_ignore_ascii_case(reserved))
+}
+
+impl TryFrom<&ComponentValue> for Value {
+    type Error = StyleError;
+
+    fn try_from(value: &ComponentValue) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<value>"]);
+
+        if let ComponentValue::Function(function) = value {
+            if Calc::is_math_function(function) {
+                return Calc::try_from(function).map(Self::Calc);
+            }
+            if Gradient::is_gradient_function(function) {
+                return Gradient::try_from(function).map(Self::Gradient);
+            }
+            if function.is("url") || function.is("src") {
+                return Url::try_from(value).map(Self::Url);
+            }
+            if VALUE_FUNCTIONS.iter().any(|name| function.is(name)) {
+                let arguments = match Self::try_from(function.value.as_slice())? {
+                    Self::List(arguments) => arguments,
+                    argument => vec![argument],
+                };
+                return Ok(Self::Function(function.name.to_ascii_lowercase(), arguments));
+            }
+            return Color::try_from(function).map(Self::Color);
+        }
+
+        if let ComponentValue::Block(block) = value {
+            if block.delimiter != BlockDelimiter::SquareBracket {
+                return Err(invalid);
+            }
+            return block
+                .values
+                .iter()
+                .filter(|cv| !cv.is_whitespace())
+                .map(|cv| match cv.as_token_kind() {
+                    Some(TokenKind::Ident(ident)) if is_line_name(ident) => Ok(ident.clone()),
+                    _ => Err(StyleError::InvalidValue(&["<custom-ident>"])),
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(Self::LineNames);
+        }
+
+        match value.as_token_kind().ok_or(invalid.clone())? {
+            TokenKind::Ident(ident) => Keyword::try_from(ident.as_str())
+                .map(Self::Keyword)
+                .or_else(|_| Color::try_from(ident.as_str()).map(Self::Color))
+                .or_else(|_| match is_custom_ident(ident) {
+                    true => Ok(Self::CustomIdent(ident.clone())),
+                    false => Err(invalid),
+                }),
+            TokenKind::String(str) => Ok(Self::String(str.clone())),
+            TokenKind::Url(_) => Url::try_from(value).map(Self::Url),
+            TokenKind::Hash(_) => Color::try_from(value).map(Self::Color),
+            TokenKind::Number(number) if number.is_integer() => {
+                Ok(Self::from(number.to_f32() as i32))
+            }
+            TokenKind::Number(number) => Ok(Self::from(number.to_f32())),
+            TokenKind::Percentage(number) => {
+                Percentage::try_from(number.to_f32() / 100.0).map(Self::Percentage)
+            }
+            TokenKind::Dimension(dimension) if dimension.unit.eq_ignore_ascii_case("fr") => {
+                Ok(Self::Flex(dimension.number.to_f32()))
+            }
+            TokenKind::Dimension(dimension) => {
+                let quantity = Number::from(dimension.number.to_f32());
+
+                match Unit::try_from(dimension.unit.as_str())? {
+                    Unit::Length(unit) => Ok(Self::Length(Length { quantity, unit })),
+                    Unit::Angle(unit) => Ok(Self::Angle(Angle::new(quantity, unit))),
+                    Unit::Duration(unit) => Ok(Self::Time(Time::new(quantity, unit))),
+                    Unit::Frequency(unit) => Ok(Self::Frequency(Frequency::new(quantity, unit))),
+                    Unit::Resolution(unit) => Ok(Self::Resolution(Resolution::new(quantity, unit))),
+                }
+            }
+            _ => Err(invalid),
+        }
+    }
+}
+
+impl TryFrom<&[ComponentValue]> for Value {
+    type Error = StyleError;
+
+    /// Convert the value of a declaration, several values being kept in an
+    /// array, and comma-separated values in a list.
+    fn try_from(value: &[ComponentValue]) -> Result<Self, Self::Error> {
+        let items = value.split(ComponentValue::is_comma).collect::<Vec<_>>();
+        if items.len() > 1 {
+            return items
+                .into_iter()
+                .map(Self::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Self::List);
+        }
+
+        let mut values = value
+            .iter()
+            .filter(|cv| !cv.is_whitespace())
+            .map(Self::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match values.len() {
+            0 => Err(StyleError::InvalidValue(&["<value>"])),
+            1 => Ok(values.remove(0)),
+            _ => Ok(Self::Array(values)),
+        }
+    }
+}
+
 impl Value {
     pub fn either<'a, V>(&self, values: &'a [V]) -> Option<&'a V>
     where
@@ -127,7 +443,7 @@ impl Value {
     }
 
     pub fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self> + 'a> {
-        if let Self::Array(values) = self {
+        if let Self::Array(values) | Self::List(values) = self {
             return Box::new(values.iter());
         }
 
@@ -137,7 +453,9 @@ impl Value {
     pub fn iter_colors<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Color> + 'a> {
         match self {
             Self::Color(color) => Box::new(std::iter::once(color)),
-            Self::Array(values) => Box::new(values.iter().flat_map(|v| v.iter_colors())),
+            Self::Array(values) | Self::List(values) => {
+                Box::new(values.iter().flat_map(|v| v.iter_colors()))
+            }
             _ => Box::new(std::iter::empty()),
         }
     }
@@ -145,7 +463,9 @@ impl Value {
     pub fn iter_keywords<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Keyword> + 'a> {
         match self {
             Self::Keyword(kw) => Box::new(std::iter::once(kw)),
-            Self::Array(values) => Box::new(values.iter().flat_map(|v| v.iter_keywords())),
+            Self::Array(values) | Self::List(values) => {
+                Box::new(values.iter().flat_map(|v| v.iter_keywords()))
+            }
             _ => Box::new(std::iter::empty()),
         }
     }
@@ -153,7 +473,9 @@ impl Value {
     pub fn iter_str<'a>(&'a self) -> Box<dyn Iterator<Item = &'a str> + 'a> {
         match self {
             Self::String(str) => Box::new(std::iter::once(str.as_str())),
-            Self::Array(values) => Box::new(values.iter().flat_map(|v| v.iter_str())),
+            Self::Array(values) | Self::List(values) => {
+                Box::new(values.iter().flat_map(|v| v.iter_str()))
+            }
             _ => Box::new(std::iter::empty()),
         }
     }
@@ -54,6 +54,15 @@ impl std::ops::Mul<i32> for Number {
     }
 }
 
+impl From<Number> for f32 {
+    fn from(value: Number) -> Self {
+        match value {
+            Number::Int(int) => int as f32,
+            Number::Float(float) => float,
+        }
+    }
+}
+
 impl From<i32> for Number {
     fn from(value: i32) -> Self {
         Self::Int(value)
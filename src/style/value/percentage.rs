@@ -5,9 +5,9 @@ use super::Value;
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Percentage(f32);
 
-impl Into<f32> for Percentage {
-    fn into(self) -> f32 {
-        self.0
+impl From<Percentage> for f32 {
+    fn from(value: Percentage) -> Self {
+        value.0
     }
 }
 
@@ -20,8 +20,10 @@ impl std::fmt::Display for Percentage {
 impl TryFrom<f32> for Percentage {
     type Error = StyleError;
 
+    /// A percentage from its fraction, which may be negative or above one,
+    /// the properties checking their own ranges.
     fn try_from(value: f32) -> Result<Self, Self::Error> {
-        if value <= 1.0 && value >= 0.0 {
+        if value.is_finite() {
             return Ok(Self(value))
         }
 
@@ -0,0 +1,387 @@
+use std::fmt::Display;
+
+use crate::style::{ComponentValue, StyleError, TokenKind};
+
+use super::{Keyword, LengthContext, LengthPercentage, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HorizontalSide {
+    Left,
+    Right,
+}
+
+impl Display for HorizontalSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Left => write!(f, "left"),
+            Self::Right => write!(f, "right"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerticalSide {
+    Top,
+    Bottom,
+}
+
+impl Display for VerticalSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Top => write!(f, "top"),
+            Self::Bottom => write!(f, "bottom"),
+        }
+    }
+}
+
+/// A position on an axis, from the center or from a side.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PositionComponent<Side> {
+    Center,
+    /// An offset from the side, none being the side itself.
+    Side(Side, Option<LengthPercentage>),
+    /// An offset from the start of the axis.
+    Offset(LengthPercentage),
+}
+
+impl<Side: Display> Display for PositionComponent<Side> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Center => write!(f, "center"),
+            Self::Side(side, None) => write!(f, "{}", side),
+            Self::Side(side, Some(offset)) => write!(f, "{} {}", side, offset),
+            Self::Offset(offset) => write!(f, "{}", offset),
+        }
+    }
+}
+
+/// A position in a box, such as the center of a radial gradient.
+///
+/// [CSS Values 4](https://drafts.csswg.org/css-values-4/#position)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    pub horizontal: PositionComponent<HorizontalSide>,
+    pub vertical: PositionComponent<VerticalSide>,
+}
+
+impl Position {
+    pub fn center() -> Self {
+        Self {
+            horizontal: PositionComponent::Center,
+            vertical: PositionComponent::Center,
+        }
+    }
+
+    /// Resolve the relative units of the offsets.
+    pub fn resolve_relative(&self, context: &LengthContext) -> Self {
+        fn resolve<Side: Clone>(
+            component: &PositionComponent<Side>,
+            context: &LengthContext,
+        ) -> PositionComponent<Side> {
+            let resolve = |offset: &LengthPercentage| offset.resolve_relative(context);
+            match component {
+                PositionComponent::Center => PositionComponent::Center,
+                PositionComponent::Side(side, offset) => {
+                    PositionComponent::Side(side.clone(), offset.as_ref().map(resolve))
+                }
+                PositionComponent::Offset(offset) => PositionComponent::Offset(resolve(offset)),
+            }
+        }
+
+        Self {
+            horizontal: resolve(&self.horizontal, context),
+            vertical: resolve(&self.vertical, context),
+        }
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::center()
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.horizontal, self.vertical)
+    }
+}
+
+/// A keyword or an offset of a position.
+enum Term {
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Offset(LengthPercentage),
+}
+
+impl TryFrom<&ComponentValue> for Term {
+    type Error = StyleError;
+
+    fn try_from(value: &ComponentValue) -> Result<Self, Self::Error> {
+        match value.as_token_kind() {
+            Some(TokenKind::Ident(ident)) => {
+                Self::try_from(Value::Keyword(Keyword::try_from(ident.as_str())?))
+            }
+            _ => LengthPercentage::try_from(value).map(Self::Offset),
+        }
+    }
+}
+
+impl TryFrom<Value> for Term {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Keyword(Keyword::Center) => Ok(Self::Center),
+            Value::Keyword(Keyword::Left) => Ok(Self::Left),
+            Value::Keyword(Keyword::Right) => Ok(Self::Right),
+            Value::Keyword(Keyword::Top) => Ok(Self::Top),
+            Value::Keyword(Keyword::Bottom) => Ok(Self::Bottom),
+            Value::Keyword(_) => Err(StyleError::InvalidValue(&["<position>"])),
+            value => LengthPercentage::try_from(value).map(Self::Offset),
+        }
+    }
+}
+
+impl Term {
+    fn horizontal(self) -> Option<PositionComponent<HorizontalSide>> {
+        match self {
+            Self::Center => Some(PositionComponent::Center),
+            Self::Left => Some(PositionComponent::Side(HorizontalSide::Left, None)),
+            Self::Right => Some(PositionComponent::Side(HorizontalSide::Right, None)),
+            Self::Offset(offset) => Some(PositionComponent::Offset(offset)),
+            _ => None,
+        }
+    }
+
+    fn vertical(self) -> Option<PositionComponent<VerticalSide>> {
+        match self {
+            Self::Center => Some(PositionComponent::Center),
+            Self::Top => Some(PositionComponent::Side(VerticalSide::Top, None)),
+            Self::Bottom => Some(PositionComponent::Side(VerticalSide::Bottom, None)),
+            Self::Offset(offset) => Some(PositionComponent::Offset(offset)),
+            _ => None,
+        }
+    }
+
+    fn is_vertical(&self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+
+    fn is_horizontal(&self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+}
+
+impl Position {
+    /// The position described by its keywords and offsets, of one, two or
+    /// four values, or three in a background position.
+    fn from_terms(terms: Vec<Term>, three_values: bool) -> Result<Self, StyleError> {
+        let invalid = StyleError::InvalidValue(&["<position>"]);
+
+        if three_values && terms.len() == 3 {
+            return Self::from_three_terms(terms).ok_or(invalid);
+        }
+
+        let position = |horizontal: Option<_>, vertical: Option<_>| match (horizontal, vertical) {
+            (Some(horizontal), Some(vertical)) => Ok(Self {
+                horizontal,
+                vertical,
+            }),
+            _ => Err(invalid.clone()),
+        };
+
+        match <[Term; 4]>::try_from(terms) {
+            Ok([side, offset, other_side, other_offset]) => {
+                let (Term::Offset(offset), Term::Offset(other_offset)) = (offset, other_offset)
+                else {
+                    return Err(invalid);
+                };
+
+                let ((horizontal, h_offset), (vertical, v_offset)) =
+                    match side.is_vertical() && other_side.is_horizontal() {
+                        true => ((other_side, other_offset), (side, offset)),
+                        false => ((side, offset), (other_side, other_offset)),
+                    };
+
+                let horizontal = match horizontal {
+                    Term::Left => HorizontalSide::Left,
+                    Term::Right => HorizontalSide::Right,
+                    _ => return Err(invalid),
+                };
+                let vertical = match vertical {
+                    Term::Top => VerticalSide::Top,
+                    Term::Bottom => VerticalSide::Bottom,
+                    _ => return Err(invalid),
+                };
+
+                Ok(Self {
+                    horizontal: PositionComponent::Side(horizontal, Some(h_offset)),
+                    vertical: PositionComponent::Side(vertical, Some(v_offset)),
+                })
+            }
+            Err(terms) => match <[Term; 2]>::try_from(terms) {
+                // The keywords may be swapped.
+                Ok([first, second]) if first.is_vertical() || second.is_horizontal() => {
+                    match (&first, &second) {
+                        (Term::Offset(_), _) | (_, Term::Offset(_)) => Err(invalid),
+                        _ => position(second.horizontal(), first.vertical()),
+                    }
+                }
+                Ok([first, second]) => position(first.horizontal(), second.vertical()),
+                Err(mut terms) if terms.len() == 1 => match terms.remove(0) {
+                    term if term.is_vertical() => {
+                        position(Some(PositionComponent::Center), term.vertical())
+                    }
+                    term => position(term.horizontal(), Some(PositionComponent::Center)),
+                },
+                Err(_) => Err(invalid),
+            },
+        }
+    }
+
+    /// The position of three values, two keywords one of which is followed
+    /// by an offset.
+    fn from_three_terms(terms: Vec<Term>) -> Option<Self> {
+        let mut groups = Vec::<(Term, Option<LengthPercentage>)>::default();
+        for term in terms {
+            match (term, groups.last_mut()) {
+                (Term::Offset(offset), Some((keyword, last @ None)))
+                    if !matches!(keyword, Term::Center) =>
+                {
+                    *last = Some(offset);
+                }
+                (Term::Offset(_), _) => return None,
+                (keyword, _) => groups.push((keyword, None)),
+            }
+        }
+
+        let [first, second] = <[_; 2]>::try_from(groups).ok()?;
+        let (horizontal, vertical) = match first.0.is_vertical() || second.0.is_horizontal() {
+            true => (second, first),
+            false => (first, second),
+        };
+
+        let horizontal = match horizontal {
+            (Term::Center, _) => PositionComponent::Center,
+            (Term::Left, offset) => PositionComponent::Side(HorizontalSide::Left, offset),
+            (Term::Right, offset) => PositionComponent::Side(HorizontalSide::Right, offset),
+            _ => return None,
+        };
+        let vertical = match vertical {
+            (Term::Center, _) => PositionComponent::Center,
+            (Term::Top, offset) => PositionComponent::Side(VerticalSide::Top, offset),
+            (Term::Bottom, offset) => PositionComponent::Side(VerticalSide::Bottom, offset),
+            _ => return None,
+        };
+
+        Some(Self {
+            horizontal,
+            vertical,
+        })
+    }
+
+    /// Parse a background position, which may have three values.
+    pub fn try_from_background(value: Value) -> Result<Self, StyleError> {
+        let terms = value
+            .into_iter()
+            .map(Term::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_terms(terms, true)
+    }
+}
+
+impl TryFrom<&[&ComponentValue]> for Position {
+    type Error = StyleError;
+
+    /// Parse a position of one, two or four values.
+    fn try_from(values: &[&ComponentValue]) -> Result<Self, Self::Error> {
+        let terms = values
+            .iter()
+            .map(|cv| Term::try_from(*cv))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_terms(terms, false)
+    }
+}
+
+impl TryFrom<Value> for Position {
+    type Error = StyleError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let terms = value
+            .into_iter()
+            .map(Term::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_terms(terms, false)
+    }
+}
+
+impl From<Position> for Value {
+    fn from(value: Position) -> Self {
+        let mut values = Vec::<Value>::default();
+        let mut push = |keyword: Option<Keyword>, offset: Option<LengthPercentage>| {
+            values.extend(keyword.map(Value::Keyword));
+            values.extend(offset.map(Value::from));
+        };
+
+        match value.horizontal {
+            PositionComponent::Center => push(Some(Keyword::Center), None),
+            PositionComponent::Side(HorizontalSide::Left, offset) => {
+                push(Some(Keyword::Left), offset)
+            }
+            PositionComponent::Side(HorizontalSide::Right, offset) => {
+                push(Some(Keyword::Right), offset)
+            }
+            PositionComponent::Offset(offset) => push(None, Some(offset)),
+        }
+        match value.vertical {
+            PositionComponent::Center => push(Some(Keyword::Center), None),
+            PositionComponent::Side(VerticalSide::Top, offset) => push(Some(Keyword::Top), offset),
+            PositionComponent::Side(VerticalSide::Bottom, offset) => {
+                push(Some(Keyword::Bottom), offset)
+            }
+            PositionComponent::Offset(offset) => push(None, Some(offset)),
+        }
+
+        Self::Array(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, ComponentValue, Position};
+
+    fn parse(source: &str) -> Option<String> {
+        let values = parse_component_values(source);
+        let values = values
+            .iter()
+            .filter(|cv| !cv.is_whitespace())
+            .collect::<Vec<&ComponentValue>>();
+        Position::try_from(values.as_slice())
+            .ok()
+            .map(|position| position.to_string())
+    }
+
+    #[test]
+    fn test_001_position() {
+        assert_eq!(parse("center").as_deref(), Some("center center"));
+        assert_eq!(parse("top").as_deref(), Some("center top"));
+        assert_eq!(parse("25%").as_deref(), Some("25% center"));
+        assert_eq!(parse("1in bottom").as_deref(), Some("1in bottom"));
+        assert_eq!(parse("top right").as_deref(), Some("right top"));
+        assert_eq!(parse("center left").as_deref(), Some("left center"));
+        assert_eq!(
+            parse("bottom 10% right 2em").as_deref(),
+            Some("right 2em bottom 10%")
+        );
+
+        assert_eq!(parse("left right"), None);
+        assert_eq!(parse("top 10%"), None);
+        assert_eq!(parse("10% left"), None);
+        assert_eq!(parse("left 10% top"), None);
+        assert_eq!(parse("left 10% center 5%"), None);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::style::StyleError;
+
+use super::{Dimension, Number, ResolutionUnit, Unit, Value};
+
+/// A resolution, such as the pixel density of a media query.
+#[derive(Debug, Clone, Copy)]
+pub struct Resolution {
+    pub quantity: Number,
+    pub unit: ResolutionUnit,
+}
+
+impl Resolution {
+    pub fn dppx<N: Into<Number>>(quantity: N) -> Self {
+        Self::new(quantity, ResolutionUnit::Dppx)
+    }
+
+    /// The resolution in dots per CSS pixel, its canonical unit.
+    pub fn to_dppx(&self) -> f32 {
+        f32::from(self.quantity) * self.unit.dppx_per_unit()
+    }
+}
+
+quantity!(
+    Resolution,
+    ResolutionUnit,
+    Resolution,
+    Dppx,
+    to_dppx,
+    "<resolution>"
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, Resolution, ResolutionUnit, Value};
+
+    #[test]
+    fn test_001_resolution() {
+        let value = Value::try_from(&parse_component_values("192dpi")[0]).unwrap();
+        let resolution = Resolution::try_from(value).unwrap();
+        assert_eq!(resolution.to_string(), "192dpi");
+        assert_eq!(resolution, Resolution::dppx(2));
+        assert_eq!(resolution, Resolution::new(2, ResolutionUnit::X));
+        assert!(Resolution::new(1, ResolutionUnit::Dpcm) < Resolution::dppx(1));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::style::StyleError;
+
+use super::{Dimension, DurationUnit, Number, Unit, Value};
+
+/// A duration, such as of a transition.
+#[derive(Debug, Clone, Copy)]
+pub struct Time {
+    pub quantity: Number,
+    pub unit: DurationUnit,
+}
+
+impl Time {
+    pub fn s<N: Into<Number>>(quantity: N) -> Self {
+        Self::new(quantity, DurationUnit::S)
+    }
+
+    pub fn ms<N: Into<Number>>(quantity: N) -> Self {
+        Self::new(quantity, DurationUnit::Ms)
+    }
+
+    /// The duration in seconds, its canonical unit.
+    pub fn to_seconds(&self) -> f32 {
+        f32::from(self.quantity) * self.unit.seconds_per_unit()
+    }
+}
+
+quantity!(Time, DurationUnit, Duration, S, to_seconds, "<time>");
+
+#[cfg(test)]
+mod tests {
+    use crate::style::{parse_component_values, Time, Value};
+
+    #[test]
+    fn test_001_time() {
+        let value = Value::try_from(&parse_component_values("250MS")[0]).unwrap();
+        let time = Time::try_from(value).unwrap();
+        assert_eq!(time.to_string(), "250ms");
+        assert_eq!(time.to_seconds(), 0.25);
+        assert_eq!(time, Time::s(0.25));
+        assert!(time > Time::s(0.2));
+        assert_eq!((time + Time::ms(250)).to_string(), "500ms");
+        assert_eq!((time + Time::s(1)).to_string(), "1.25s");
+    }
+}
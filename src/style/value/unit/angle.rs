@@ -8,11 +8,23 @@ pub enum AngleUnit {
     Turn
 }
 
+impl AngleUnit {
+    /// The number of degrees in one unit.
+    pub fn deg_per_unit(&self) -> f32 {
+        match self {
+            Self::Deg => 1.0,
+            Self::Grad => 0.9,
+            Self::Rad => 180.0 / std::f32::consts::PI,
+            Self::Turn => 360.0
+        }
+    }
+}
+
 impl TryFrom<&str> for AngleUnit {
     type Error = StyleError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        match value.to_ascii_lowercase().as_str() {
             "deg" => Ok(Self::Deg),
             "grad" => Ok(Self::Grad),
             "rad" => Ok(Self::Rad),
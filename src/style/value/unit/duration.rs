@@ -7,11 +7,21 @@ pub enum DurationUnit {
     Ms
 }
 
+impl DurationUnit {
+    /// The number of seconds in one unit.
+    pub fn seconds_per_unit(&self) -> f32 {
+        match self {
+            Self::S => 1.0,
+            Self::Ms => 0.001
+        }
+    }
+}
+
 impl TryFrom<&str> for DurationUnit {
     type Error = StyleError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        match value.to_ascii_lowercase().as_str() {
             "s" => Ok(Self::S),
             "ms" => Ok(Self::Ms),
             _ => Err(StyleError::InvalidValue(&["<duration-unit>"]))
@@ -6,13 +6,23 @@ pub enum FrequencyUnit {
     KHz
 }
 
+impl FrequencyUnit {
+    /// The number of hertz in one unit.
+    pub fn hz_per_unit(&self) -> f32 {
+        match self {
+            Self::Hz => 1.0,
+            Self::KHz => 1000.0
+        }
+    }
+}
+
 impl TryFrom<&str> for FrequencyUnit {
     type Error = StyleError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "Hz" => Ok(Self::Hz),
-            "kHz" => Ok(Self::KHz),
+        match value.to_ascii_lowercase().as_str() {
+            "hz" => Ok(Self::Hz),
+            "khz" => Ok(Self::KHz),
             _ => Err(StyleError::InvalidValue(&["<frequency-unit>"]))
         }
     }
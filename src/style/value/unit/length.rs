@@ -1,4 +1,4 @@
-use crate::style::{Style, StyleError};
+use crate::style::StyleError;
 
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -43,18 +43,33 @@ pub enum AbsoluteLengthUnit {
     Px
 }
 
+impl AbsoluteLengthUnit {
+    /// The number of CSS pixels in one unit.
+    pub fn px_per_unit(&self) -> f32 {
+        match self {
+            Self::Cm => 96.0 / 2.54,
+            Self::Mm => 96.0 / 25.4,
+            Self::Q => 96.0 / 101.6,
+            Self::In => 96.0,
+            Self::Pt => 96.0 / 72.0,
+            Self::Pc => 16.0,
+            Self::Px => 1.0
+        }
+    }
+}
+
 impl TryFrom<&str> for AbsoluteLengthUnit {
     type Error = StyleError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        match value.to_ascii_lowercase().as_str() {
             "cm" => Ok(Self::Cm),
             "mm" => Ok(Self::Mm),
-            "Q" => Ok(Self::Q),
+            "q" => Ok(Self::Q),
             "in" => Ok(Self::In),
-            "Pt" => Ok(Self::Pt),
-            "Pc" => Ok(Self::Pc),
-            "Px" => Ok(Self::Px),
+            "pt" => Ok(Self::Pt),
+            "pc" => Ok(Self::Pc),
+            "px" => Ok(Self::Px),
             _ => Err(StyleError::InvalidValue(&["<absolute-length-unit>"]))
         }
     }
@@ -137,7 +152,7 @@ impl TryFrom<&str> for ViewportRelativeLengthUnit {
     type Error = StyleError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        match value.to_ascii_lowercase().as_str() {
             "vw" => Ok(Self::Vw),
             "svw" => Ok(Self::Svw),
             "lvw" => Ok(Self::Lvw),
@@ -145,10 +160,12 @@ impl TryFrom<&str> for ViewportRelativeLengthUnit {
 
             "vh" => Ok(Self::Vh),
             "svh" => Ok(Self::Svh),
-            "lvh" => Ok(Self::Lvw),
-            
+            "lvh" => Ok(Self::Lvh),
+            "dvh" => Ok(Self::Dvh),
+
             "vi" => Ok(Self::Vi),
             "svi" => Ok(Self::Svi),
+            "lvi" => Ok(Self::Lvi),
             "dvi" => Ok(Self::Dvi),
 
             "vb" => Ok(Self::Vb),
@@ -222,17 +239,18 @@ impl TryFrom<&str> for FontRelativeLengthUnit {
     type Error = StyleError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        match value.to_ascii_lowercase().as_str() {
             "em" => Ok(Self::Em),
             "rem" => Ok(Self::Rem),
             "ex" => Ok(Self::Ex),
+            "rex" => Ok(Self::Rex),
             "cap" => Ok(Self::Cap),
             "rcap" => Ok(Self::Rcap),
             "ch" => Ok(Self::Ch),
             "rch" => Ok(Self::Rch),
             "ic" => Ok(Self::Ic),
-            "Ric" => Ok(Self::Ric),
-            "Lh" => Ok(Self::Lh),
+            "ric" => Ok(Self::Ric),
+            "lh" => Ok(Self::Lh),
             "rlh" => Ok(Self::Rlh),
             _ => Err(StyleError::InvalidValue(&["<font-relative-length-unit>"]))
         }
@@ -5,17 +5,31 @@ use crate::style::StyleError;
 pub enum ResolutionUnit {
     Dpi,
     Dpcm,
-    Dppx
+    Dppx,
+    /// An alias of dppx.
+    X
+}
+
+impl ResolutionUnit {
+    /// The number of dots per CSS pixel in one unit.
+    pub fn dppx_per_unit(&self) -> f32 {
+        match self {
+            Self::Dpi => 1.0 / 96.0,
+            Self::Dpcm => 2.54 / 96.0,
+            Self::Dppx | Self::X => 1.0
+        }
+    }
 }
 
 impl TryFrom<&str> for ResolutionUnit {
     type Error = StyleError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        match value.to_ascii_lowercase().as_str() {
             "dpi" => Ok(Self::Dpi),
             "dpcm" => Ok(Self::Dpcm),
             "dppx" => Ok(Self::Dppx),
+            "x" => Ok(Self::X),
             _ => Err(StyleError::InvalidValue(&["<resolution-unit>"]))
         }
     }
@@ -27,6 +41,7 @@ impl std::fmt::Display for ResolutionUnit {
             ResolutionUnit::Dpi => write!(f, "dpi"),
             ResolutionUnit::Dpcm => write!(f, "dpcm"),
             ResolutionUnit::Dppx => write!(f, "dppx"),
+            ResolutionUnit::X => write!(f, "x"),
         }
     }
 }
\ No newline at end of file
@@ -1,8 +1,44 @@
+use crate::style::{ComponentValue, StyleError, TokenKind};
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Url(String);
 
+impl Url {
+    pub fn new<S: ToString>(url: S) -> Self {
+        Self(url.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 impl std::fmt::Display for Url {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "url(\"{}\")", self.0)
     }
 }
+
+impl TryFrom<&ComponentValue> for Url {
+    type Error = StyleError;
+
+    /// Parse an unquoted url, or a url() or src() function with a string.
+    fn try_from(value: &ComponentValue) -> Result<Self, Self::Error> {
+        let invalid = StyleError::InvalidValue(&["<url>"]);
+
+        match value {
+            ComponentValue::Function(function) if function.is("url") || function.is("src") => {
+                let mut arguments = function.value.iter().filter(|cv| !cv.is_whitespace());
+
+                match (arguments.next().and_then(|cv| cv.as_token_kind()), arguments.next()) {
+                    (Some(TokenKind::String(url)), None) => Ok(Self::new(url)),
+                    _ => Err(invalid),
+                }
+            }
+            _ => match value.as_token_kind() {
+                Some(TokenKind::Url(url)) => Ok(Self::new(url)),
+                _ => Err(invalid),
+            },
+        }
+    }
+}